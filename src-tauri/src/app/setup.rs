@@ -14,6 +14,7 @@ use crate::device_handler;
 use crate::dictionary;
 use crate::hotkey;
 use crate::keyboard_capture;
+use crate::listening;
 use crate::model;
 use crate::parakeet;
 use crate::paths;
@@ -58,14 +59,21 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
 
     // Create shared state for recording manager
     let recording_state = Arc::new(Mutex::new(recording::RecordingManager::new()));
+    if let Ok(mut manager) = recording_state.lock() {
+        manager.set_trim_config(recording::TrimConfig::from_settings(app.handle()));
+    }
     app.manage(recording_state.clone());
 
+    // Long-form session slot (populated by start_long_form_recording)
+    let long_form_state: crate::app::state::LongFormState = Arc::new(Mutex::new(None));
+    app.manage(long_form_state);
+
     // Create and manage audio monitor state for device testing
     let audio_monitor = Arc::new(audio::AudioMonitorHandle::spawn());
     app.manage(audio_monitor.clone());
 
     // Pre-initialize the audio engine at startup
-    setup_audio_engine(app, &worktree_context, &audio_monitor);
+    setup_audio_engine(app, &audio_monitor);
 
     // Create shared transcription model (single ~3GB Parakeet model)
     crate::debug!("Creating SharedTranscriptionModel...");
@@ -75,7 +83,10 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     let recordings_dir = paths::get_recordings_dir(worktree_context.as_ref())
         .unwrap_or_else(|_| std::path::PathBuf::from(".").join("heycat").join("recordings"));
     let recording_detectors = Arc::new(Mutex::new(
-        recording::RecordingDetectors::with_recordings_dir(recordings_dir.clone()),
+        recording::RecordingDetectors::with_config_and_recordings_dir(
+            recording::SilenceConfig::from_settings(app.handle()),
+            recordings_dir.clone(),
+        ),
     ));
     app.manage(recording_detectors.clone());
 
@@ -88,8 +99,14 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     // Manage shared transcription model for Tauri commands
     app.manage(shared_transcription_model.clone());
 
+    // History of pasted and typed outputs for "scratch that"
+    let output_history: crate::app::state::OutputHistoryState =
+        Arc::new(crate::transcription::OutputHistory::new());
+    app.manage(output_history.clone());
+
     // Create and manage voice command executor and registry
     let (command_matcher, action_dispatcher) = setup_voice_commands(app)?;
+    app.manage(command_matcher.clone());
 
     // Eager model loading at startup (if models exist)
     load_transcription_model(app, &shared_transcription_model);
@@ -166,9 +183,61 @@ pub fn setup(app: &App) -> Result<(), Box<dyn std::error::Error>> {
         crate::info!("No recording shortcut configured - user will set one during onboarding");
     }
 
+    // Optional pause/resume shortcut
+    let pause_shortcut = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get("hotkey.pauseShortcut"))
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+
+    if let Some(shortcut) = pause_shortcut {
+        register_pause_shortcut(&service, &shortcut, integration.clone(), recording_state.clone());
+    }
+
+    // Optional "scratch that" shortcut
+    let scratch_shortcut = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get("hotkey.scratchShortcut"))
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+
+    if let Some(shortcut) = scratch_shortcut {
+        register_scratch_shortcut(&service, &shortcut, app.handle().clone(), output_history);
+    }
+
+    // Optional command hotkey, for commands when the command prefix is required
+    let command_shortcut = app
+        .store(&settings_file)
+        .ok()
+        .and_then(|store| store.get("hotkey.commandShortcut"))
+        .and_then(|v| v.as_str().map(|s| s.to_string()));
+
+    if let Some(shortcut) = command_shortcut {
+        register_command_shortcut(
+            &service,
+            &shortcut,
+            integration.clone(),
+            recording_state.clone(),
+            transcription_service.clone(),
+        );
+    }
+
     // Store service in state for cleanup on exit
     app.manage(service);
 
+    // Continuous dictation session slot (populated by start_continuous_dictation)
+    let dictation_state: crate::app::state::DictationState = Arc::new(Mutex::new(None));
+    app.manage(dictation_state);
+
+    // Wake phrase listening is opt-in; start it now if enabled in settings
+    let listening_state: crate::app::state::ListeningState = Arc::new(Mutex::new(None));
+    app.manage(listening_state);
+    if listening::WakePhraseConfig::from_settings(app.handle()).enabled {
+        if let Err(e) = commands::listening::start_wake_listener(app.handle()) {
+            crate::warn!("Failed to start wake phrase listening: {}", e);
+        }
+    }
+
     // Create keyboard capture state for shortcut recording with fn key support
     let keyboard_capture = Arc::new(Mutex::new(keyboard_capture::KeyboardCapture::new()));
     app.manage(keyboard_capture);
@@ -336,21 +405,8 @@ fn setup_turso_database(
 }
 
 /// Pre-initialize the audio engine at startup.
-fn setup_audio_engine(
-    app: &App,
-    worktree_context: &Option<worktree::WorktreeContext>,
-    audio_monitor: &Arc<audio::AudioMonitorHandle>,
-) {
-    let saved_device = {
-        let settings_file = worktree_context
-            .as_ref()
-            .map(|ctx| ctx.settings_file_name())
-            .unwrap_or_else(|| worktree::DEFAULT_SETTINGS_FILE.to_string());
-        app.store(&settings_file)
-            .ok()
-            .and_then(|store| store.get("audio.selectedDevice"))
-            .and_then(|v| v.as_str().map(|s| s.to_string()))
-    };
+fn setup_audio_engine(app: &App, audio_monitor: &Arc<audio::AudioMonitorHandle>) {
+    let saved_device = audio::preferred_input_device(app.handle());
 
     if let Some(ref device) = saved_device {
         crate::debug!("Pre-initializing audio engine with saved device: {}", device);
@@ -365,8 +421,8 @@ fn setup_audio_engine(
         crate::info!("Audio engine pre-initialized and running");
     }
 
-    // Initialize device change handler to restart audio engine when devices connect/disconnect
-    device_handler::init_device_change_handler();
+    // Initialize device change handler to fall back between devices when they connect/disconnect
+    device_handler::init_device_change_handler(app.handle().clone());
 }
 
 /// Set up voice command executor and registry.
@@ -380,7 +436,13 @@ fn setup_voice_commands(
     Box<dyn std::error::Error>,
 > {
     crate::debug!("Creating voice command infrastructure...");
-    let executor_state = voice_commands::executor::ExecutorState::new();
+    let script_config = voice_commands::actions::ScriptConfig::from_settings(app.handle());
+    let output_history = app.state::<crate::app::state::OutputHistoryState>();
+    let dispatcher = voice_commands::executor::ActionDispatcher::new()
+        .with_script_config(script_config)
+        .with_output_history(output_history.inner().clone())
+        .with_app_handle(app.handle().clone());
+    let executor_state = voice_commands::executor::ExecutorState::with_dispatcher(dispatcher);
     let dispatcher = executor_state.dispatcher.clone();
     app.manage(executor_state);
 
@@ -413,7 +475,7 @@ fn load_transcription_model(app: &App, shared_model: &Arc<parakeet::SharedTransc
         }
     } else {
         crate::info!(
-            "TDT model not found, batch transcription and wake word detection will require download first"
+            "TDT model not found, batch transcription and wake phrase listening will require download first"
         );
     }
 }
@@ -452,6 +514,7 @@ fn setup_transcription_service(
         window_monitor.clone(),
         turso_client.clone(),
     ));
+    app.manage(context_resolver.clone());
 
     // Create expander for transcription service from dictionary entries
     {
@@ -487,11 +550,16 @@ fn setup_transcription_service(
     if let Some(dispatcher) = action_dispatcher {
         let service_command_emitter =
             Arc::new(commands::TauriEventEmitter::new(app.handle().clone()));
+        let executor_state = app.state::<voice_commands::executor::ExecutorState>();
+        let disambiguator = executor_state.disambiguator.clone();
+        let confirmations = executor_state.confirmations.clone();
         transcription_service = transcription_service
             .with_turso_client(turso_client.clone())
             .with_command_matcher(command_matcher.clone())
             .with_action_dispatcher(dispatcher.clone())
-            .with_command_emitter(service_command_emitter);
+            .with_command_emitter(service_command_emitter)
+            .with_disambiguator(disambiguator)
+            .with_confirmation_gate(confirmations);
         crate::debug!("Voice commands wired to TranscriptionService");
     }
 
@@ -552,10 +620,15 @@ fn setup_hotkey_integration(
     // Wire up voice command integration
     if let Some(dispatcher) = action_dispatcher {
         let command_emitter = Arc::new(commands::TauriEventEmitter::new(app.handle().clone()));
+        let executor_state = app.state::<voice_commands::executor::ExecutorState>();
+        let disambiguator = executor_state.disambiguator.clone();
+        let confirmations = executor_state.confirmations.clone();
         integration_builder = integration_builder.with_voice_commands(hotkey::integration::VoiceCommandConfig {
             turso_client: turso_client.clone(),
             matcher: command_matcher.clone(),
             dispatcher,
+            disambiguator,
+            confirmations,
             emitter: Some(command_emitter),
         });
         crate::debug!("Voice command integration wired up using grouped config");
@@ -652,6 +725,42 @@ pub fn on_window_destroyed(window: &tauri::Window) {
                 crate::debug!("Hotkey '{}' unregistered successfully", shortcut);
             }
         }
+
+        if let Some(shortcut) = window
+            .app_handle()
+            .store(&settings_file)
+            .ok()
+            .and_then(|store| store.get("hotkey.pauseShortcut"))
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+        {
+            if let Err(e) = service.backend.unregister(&shortcut) {
+                crate::warn!("Failed to unregister pause hotkey '{}': {}", shortcut, e);
+            }
+        }
+
+        if let Some(shortcut) = window
+            .app_handle()
+            .store(&settings_file)
+            .ok()
+            .and_then(|store| store.get("hotkey.scratchShortcut"))
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+        {
+            if let Err(e) = service.backend.unregister(&shortcut) {
+                crate::warn!("Failed to unregister scratch hotkey '{}': {}", shortcut, e);
+            }
+        }
+
+        if let Some(shortcut) = window
+            .app_handle()
+            .store(&settings_file)
+            .ok()
+            .and_then(|store| store.get("hotkey.commandShortcut"))
+            .and_then(|v| v.as_str().map(|s| s.to_string()))
+        {
+            if let Err(e) = service.backend.unregister(&shortcut) {
+                crate::warn!("Failed to unregister command hotkey '{}': {}", shortcut, e);
+            }
+        }
     }
 
     // Stop window monitor on window close
@@ -668,3 +777,108 @@ pub fn on_window_destroyed(window: &tauri::Window) {
         }
     }
 }
+
+/// Register the pause/resume hotkey.
+///
+/// The callback spawns onto the async runtime so the shortcut backend's
+/// event loop is never blocked by the integration lock.
+fn register_pause_shortcut(
+    service: &HotkeyServiceHandle,
+    shortcut: &str,
+    integration: commands::HotkeyIntegrationState,
+    recording_state: commands::ProductionState,
+) {
+    crate::info!("Registering pause shortcut: {}", shortcut);
+    let result = service.backend.register(
+        shortcut,
+        Box::new(move || {
+            let integration = integration.clone();
+            let state = recording_state.clone();
+            tauri::async_runtime::spawn(async move {
+                match integration.lock() {
+                    Ok(mut guard) => {
+                        guard.handle_pause_toggle(&state);
+                    }
+                    Err(e) => {
+                        crate::error!("Failed to acquire integration lock: {}", e);
+                    }
+                }
+            });
+        }),
+    );
+
+    if let Err(e) = result {
+        crate::warn!("Failed to register pause shortcut '{}': {}", shortcut, e);
+    }
+}
+
+/// Register the "scratch that" hotkey.
+///
+/// Removes the last pasted or typed output from the frontmost app, the same
+/// as saying "scratch that".
+fn register_scratch_shortcut(
+    service: &HotkeyServiceHandle,
+    shortcut: &str,
+    app_handle: tauri::AppHandle,
+    output_history: crate::app::state::OutputHistoryState,
+) {
+    crate::info!("Registering scratch shortcut: {}", shortcut);
+    let result = service.backend.register(
+        shortcut,
+        Box::new(move || {
+            let app_handle = app_handle.clone();
+            let history = output_history.clone();
+            tauri::async_runtime::spawn(async move {
+                let result = crate::transcription::scratch_last_output(&app_handle, &history).await;
+                if let Err(e) = result {
+                    crate::info!("Scratch shortcut: {}", e);
+                }
+            });
+        }),
+    );
+
+    if let Err(e) = result {
+        crate::warn!("Failed to register scratch shortcut '{}': {}", shortcut, e);
+    }
+}
+
+/// Register the command hotkey.
+///
+/// Starts and stops a recording like the recording hotkey in toggle mode, but
+/// the recording is matched against voice commands even when the command
+/// prefix is required and wasn't said.
+fn register_command_shortcut(
+    service: &HotkeyServiceHandle,
+    shortcut: &str,
+    integration: commands::HotkeyIntegrationState,
+    recording_state: commands::ProductionState,
+    transcription_service: crate::app::state::TranscriptionServiceState,
+) {
+    crate::info!("Registering command shortcut: {}", shortcut);
+    let result = service.backend.register(
+        shortcut,
+        Box::new(move || {
+            let integration = integration.clone();
+            let state = recording_state.clone();
+            let transcription_service = transcription_service.clone();
+            tauri::async_runtime::spawn(async move {
+                transcription_service.arm_command_hotkey();
+                match integration.lock() {
+                    Ok(mut guard) => {
+                        if !guard.handle_toggle(&state) {
+                            transcription_service.disarm_command_hotkey();
+                        }
+                    }
+                    Err(e) => {
+                        transcription_service.disarm_command_hotkey();
+                        crate::error!("Failed to acquire integration lock: {}", e);
+                    }
+                }
+            });
+        }),
+    );
+
+    if let Err(e) = result {
+        crate::warn!("Failed to register command shortcut '{}': {}", shortcut, e);
+    }
+}
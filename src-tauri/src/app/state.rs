@@ -6,12 +6,17 @@
 use std::sync::{Arc, Mutex};
 
 use crate::audio::{AudioMonitorHandle, AudioThreadHandle};
+use crate::commands::dictation::DictationSession;
+use crate::commands::long_form::LongFormSession;
 use crate::commands::TauriEventEmitter;
 use crate::hotkey::HotkeyIntegration;
 use crate::keyboard_capture::KeyboardCapture;
+use crate::listening::WakeListener;
 use crate::recording::RecordingManager;
-use crate::transcription::RecordingTranscriptionService;
+use crate::transcription::{OutputHistory, RecordingTranscriptionService};
 use crate::turso::TursoClient;
+use crate::voice_commands::matcher::CommandMatcher;
+use crate::window_context::ContextResolver;
 
 /// Type alias for Turso client state
 pub type TursoClientState = Arc<TursoClient>;
@@ -30,12 +35,30 @@ pub type HotkeyIntegrationState =
 pub type TranscriptionServiceState =
     Arc<RecordingTranscriptionService<TauriEventEmitter, TauriEventEmitter>>;
 
+/// Type alias for the history of outputs "scratch that" can remove
+pub type OutputHistoryState = Arc<OutputHistory>;
+
+/// Type alias for the voice command matcher
+pub type CommandMatcherState = Arc<CommandMatcher>;
+
+/// Type alias for the window context resolver
+pub type ContextResolverState = Arc<ContextResolver>;
+
 /// Type alias for audio monitor state (the thread handle)
 pub type AudioMonitorState = Arc<AudioMonitorHandle>;
 
 /// Type alias for hotkey service state (uses dynamic backend)
 pub type HotkeyServiceState = crate::hotkey::HotkeyServiceDyn;
 
+/// Type alias for the active long-form session (None when not in long-form mode)
+pub type LongFormState = Arc<Mutex<Option<LongFormSession>>>;
+
+/// Type alias for the wake phrase listener (None when listening mode is off)
+pub type ListeningState = Arc<Mutex<Option<WakeListener>>>;
+
+/// Type alias for the continuous dictation session (None when not dictating)
+pub type DictationState = Arc<Mutex<Option<DictationSession>>>;
+
 /// Type alias for keyboard capture state
 pub type KeyboardCaptureState = Arc<Mutex<KeyboardCapture>>;
 
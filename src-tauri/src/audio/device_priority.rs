@@ -0,0 +1,153 @@
+// Audio input device priority list
+// Picks the capture device from an ordered preference list and decides when
+// to fall back to, or return from, another device as devices come and go
+
+use super::AudioInputDevice;
+use crate::util::SettingsAccess;
+use serde::{Deserialize, Serialize};
+
+/// Settings key for the ordered device preference list
+pub const SETTING_DEVICE_PRIORITY: &str = "audio.devicePriority";
+/// Settings key for the single selected device (used when no list is set)
+pub const SETTING_SELECTED_DEVICE: &str = "audio.selectedDevice";
+
+/// Ordered list of preferred input devices, most preferred first
+///
+/// An empty list means the system default device is always used.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DevicePriority {
+    devices: Vec<String>,
+}
+
+/// Why the capture device was switched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeviceSwitchReason {
+    /// The active device disappeared and the next available one was chosen
+    Fallback,
+    /// A more preferred device became available again
+    Restored,
+}
+
+/// A device switch decided by `DevicePriority::plan_switch`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceSwitch {
+    /// Device to switch to (None = system default)
+    pub device: Option<String>,
+    pub reason: DeviceSwitchReason,
+}
+
+impl DevicePriority {
+    /// Build a priority list, dropping blank and duplicate names
+    pub fn new<I, S>(devices: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut list: Vec<String> = Vec::new();
+        for name in devices {
+            let name = name.into().trim().to_string();
+            if !name.is_empty() && !list.contains(&name) {
+                list.push(name);
+            }
+        }
+        Self { devices: list }
+    }
+
+    /// Load the priority list from settings
+    ///
+    /// Falls back to `audio.selectedDevice` as a one-entry list so existing
+    /// single-device settings keep working.
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let list = settings
+            .get_setting_value(SETTING_DEVICE_PRIORITY)
+            .and_then(|v| serde_json::from_value::<Vec<String>>(v).ok())
+            .map(Self::new)
+            .unwrap_or_default();
+
+        if !list.devices.is_empty() {
+            return list;
+        }
+
+        settings
+            .get_setting(SETTING_SELECTED_DEVICE)
+            .map(|name| Self::new([name]))
+            .unwrap_or_default()
+    }
+
+    /// Device names in preference order
+    pub fn devices(&self) -> &[String] {
+        &self.devices
+    }
+
+    /// Position of a device in the list (lower is more preferred)
+    fn rank(&self, name: &str) -> Option<usize> {
+        self.devices.iter().position(|d| d == name)
+    }
+
+    /// Pick the most preferred device that is currently connected
+    ///
+    /// Returns None (system default) when no listed device is available.
+    pub fn resolve(&self, available: &[AudioInputDevice]) -> Option<String> {
+        self.devices
+            .iter()
+            .find(|name| available.iter().any(|d| &d.name == *name))
+            .cloned()
+    }
+
+    /// Decide whether capture should move to another device
+    ///
+    /// Switches away from the active device only when it is gone, or when a
+    /// more preferred device has become available. A connected device that is
+    /// not on the list was chosen explicitly and is left alone.
+    ///
+    /// # Arguments
+    /// * `available` - Currently connected input devices
+    /// * `current` - Device the engine is using (None = system default)
+    pub fn plan_switch(
+        &self,
+        available: &[AudioInputDevice],
+        current: Option<&str>,
+    ) -> Option<DeviceSwitch> {
+        let target = self.resolve(available);
+        if target.as_deref() == current {
+            return None;
+        }
+
+        let current_connected = current.is_some_and(|c| available.iter().any(|d| d.name == c));
+        let reason = match current {
+            Some(_) if !current_connected => DeviceSwitchReason::Fallback,
+            Some(name) => {
+                let target_rank = target.as_deref().and_then(|t| self.rank(t));
+                match (self.rank(name), target_rank) {
+                    (Some(current_rank), Some(target_rank)) if target_rank < current_rank => {
+                        DeviceSwitchReason::Restored
+                    }
+                    _ => return None,
+                }
+            }
+            None => DeviceSwitchReason::Restored,
+        };
+
+        Some(DeviceSwitch {
+            device: target,
+            reason,
+        })
+    }
+}
+
+/// Pick the input device to capture from according to settings
+///
+/// Returns None (system default) when no preferred device is connected.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub fn preferred_input_device<S: SettingsAccess + ?Sized>(settings: &S) -> Option<String> {
+    let priority = DevicePriority::from_settings(settings);
+    if priority.devices.is_empty() {
+        return None;
+    }
+    priority.resolve(&super::list_input_devices())
+}
+
+#[cfg(test)]
+#[path = "device_priority_test.rs"]
+mod tests;
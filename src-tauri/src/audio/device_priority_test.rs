@@ -0,0 +1,108 @@
+use super::*;
+use tauri::AppHandle;
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+fn devices(names: &[&str]) -> Vec<AudioInputDevice> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| AudioInputDevice {
+            name: name.to_string(),
+            is_default: i == 0,
+        })
+        .collect()
+}
+
+fn priority() -> DevicePriority {
+    DevicePriority::new(["Headset", "USB Mic", "MacBook Pro Microphone"])
+}
+
+#[test]
+fn test_new_drops_blank_and_duplicate_names() {
+    let list = DevicePriority::new(["Headset", " ", "Headset", " USB Mic "]);
+    assert_eq!(list.devices(), ["Headset", "USB Mic"]);
+}
+
+#[test]
+fn test_from_settings_without_app_handle_is_empty() {
+    assert_eq!(
+        DevicePriority::from_settings(&NoAppHandle),
+        DevicePriority::default()
+    );
+}
+
+#[test]
+fn test_resolve_picks_first_available_device() {
+    let available = devices(&["MacBook Pro Microphone", "USB Mic"]);
+    assert_eq!(priority().resolve(&available), Some("USB Mic".to_string()));
+}
+
+#[test]
+fn test_resolve_without_listed_devices_uses_system_default() {
+    let available = devices(&["Webcam"]);
+    assert_eq!(priority().resolve(&available), None);
+}
+
+#[test]
+fn test_plan_switch_falls_back_when_active_device_disappears() {
+    let available = devices(&["MacBook Pro Microphone", "USB Mic"]);
+    assert_eq!(
+        priority().plan_switch(&available, Some("Headset")),
+        Some(DeviceSwitch {
+            device: Some("USB Mic".to_string()),
+            reason: DeviceSwitchReason::Fallback,
+        })
+    );
+}
+
+#[test]
+fn test_plan_switch_restores_preferred_device_when_it_reappears() {
+    let available = devices(&["MacBook Pro Microphone", "Headset"]);
+    assert_eq!(
+        priority().plan_switch(&available, Some("MacBook Pro Microphone")),
+        Some(DeviceSwitch {
+            device: Some("Headset".to_string()),
+            reason: DeviceSwitchReason::Restored,
+        })
+    );
+}
+
+#[test]
+fn test_plan_switch_keeps_current_device_when_nothing_better_appears() {
+    let available = devices(&["MacBook Pro Microphone", "USB Mic"]);
+    assert_eq!(priority().plan_switch(&available, Some("USB Mic")), None);
+}
+
+#[test]
+fn test_plan_switch_leaves_explicitly_chosen_device_alone() {
+    let available = devices(&["Webcam", "Headset"]);
+    assert_eq!(priority().plan_switch(&available, Some("Webcam")), None);
+}
+
+#[test]
+fn test_plan_switch_falls_back_to_system_default() {
+    let available = devices(&["Webcam"]);
+    assert_eq!(
+        priority().plan_switch(&available, Some("Headset")),
+        Some(DeviceSwitch {
+            device: None,
+            reason: DeviceSwitchReason::Fallback,
+        })
+    );
+}
+
+#[test]
+fn test_plan_switch_with_empty_list_does_nothing() {
+    let available = devices(&["Webcam"]);
+    assert_eq!(
+        DevicePriority::default().plan_switch(&available, None),
+        None
+    );
+}
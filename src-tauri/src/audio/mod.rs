@@ -1,7 +1,7 @@
 // Audio capture module for microphone recording
 
 use ringbuf::{
-    traits::{Consumer, Observer, Split},
+    traits::{Consumer, Observer, Producer, Split},
     HeapRb,
 };
 use std::sync::{Arc, Mutex};
@@ -12,6 +12,11 @@ pub use swift_backend::SwiftBackend;
 mod device;
 pub use device::{list_input_devices, AudioInputDevice};
 
+mod device_priority;
+pub use device_priority::{
+    preferred_input_device, DevicePriority, DeviceSwitchReason, SETTING_DEVICE_PRIORITY,
+};
+
 mod error;
 pub use error::AudioDeviceError;
 
@@ -21,8 +26,11 @@ pub use monitor::AudioMonitorHandle;
 pub mod thread;
 pub use thread::AudioThreadHandle;
 
+mod tap;
+pub use tap::{SampleSource, SwiftTapSource};
+
 pub mod wav;
-pub use wav::{encode_wav, parse_duration_from_file, SystemFileWriter};
+pub use wav::{encode_wav, parse_duration_from_file, SystemFileWriter, WavEncodingError};
 
 pub mod diagnostics;
 #[allow(unused_imports)]
@@ -66,6 +74,18 @@ impl AudioBuffer {
         }
     }
 
+    /// Push samples into the ring buffer
+    ///
+    /// Used when samples are captured outside the audio thread (e.g. the
+    /// listening mode tap). Returns the number of samples written; samples
+    /// that do not fit in the ring buffer are dropped.
+    pub fn push_samples(&self, samples: &[f32]) -> usize {
+        match self.producer.lock() {
+            Ok(mut prod) => prod.push_slice(samples),
+            Err(_) => 0,
+        }
+    }
+
     /// Drain available samples from ring buffer into accumulated storage
     ///
     /// Returns a copy of the newly drained samples.
@@ -169,6 +189,8 @@ pub enum CaptureState {
     Idle,
     /// Actively capturing audio
     Capturing,
+    /// Capture suspended; the capture file is kept open for resuming
+    Paused,
     /// Capture stopped (audio data available)
     Stopped,
 }
@@ -221,9 +243,11 @@ pub enum StopReason {
     /// Silence detected after speech (user finished talking)
     #[allow(dead_code)] // Used by silence detection in listening module
     SilenceAfterSpeech,
-    /// No speech detected after wake word (false activation timeout)
+    /// No speech detected after wake phrase (false activation timeout)
     #[allow(dead_code)] // Used by silence detection in listening module
     NoSpeechTimeout,
+    /// Long-form recording reached its configured maximum duration
+    MaxDurationReached,
 }
 
 /// Trait for audio capture backends (allows mocking in tests)
@@ -244,4 +268,10 @@ pub trait AudioCaptureBackend {
 
     /// Stop capturing audio
     fn stop(&mut self) -> Result<(), AudioCaptureError>;
+
+    /// Suspend capture without finalizing the recording
+    fn pause(&mut self) -> Result<(), AudioCaptureError>;
+
+    /// Resume a suspended capture into the same recording
+    fn resume(&mut self) -> Result<(), AudioCaptureError>;
 }
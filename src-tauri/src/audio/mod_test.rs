@@ -10,3 +10,22 @@
 // - test_capture_state_variants: Enum existence verified by type system
 // - test_error_*: Pattern matching on error variants - type system handles this
 
+
+use super::AudioBuffer;
+
+#[test]
+fn test_push_samples_are_drained_and_accumulated() {
+    let buffer = AudioBuffer::with_capacity(8);
+
+    assert_eq!(buffer.push_samples(&[0.1, 0.2, 0.3]), 3);
+    assert_eq!(buffer.drain_samples(), vec![0.1, 0.2, 0.3]);
+    assert_eq!(buffer.accumulated_len(), 3);
+}
+
+#[test]
+fn test_push_samples_drops_overflow() {
+    let buffer = AudioBuffer::with_capacity(4);
+
+    assert_eq!(buffer.push_samples(&[0.0; 6]), 4);
+    assert_eq!(buffer.drain_samples().len(), 4);
+}
@@ -76,6 +76,18 @@ impl SwiftBackend {
     pub fn take_capture_file(&mut self) -> Option<(String, u64)> {
         self.last_capture_file_path.take().map(|path| (path, self.last_duration_ms))
     }
+
+    /// Close the current capture segment and continue capturing into a new one
+    ///
+    /// Returns the path to the finished segment WAV file. Used by long-form
+    /// recordings so that audio never accumulates in memory.
+    pub fn rotate_segment(&mut self) -> Option<String> {
+        if !matches!(self.state, CaptureState::Capturing | CaptureState::Paused) {
+            crate::debug!("Not capturing, nothing to rotate");
+            return None;
+        }
+        swift::audio_engine_rotate_capture()
+    }
 }
 
 impl Default for SwiftBackend {
@@ -169,7 +181,7 @@ impl AudioCaptureBackend for SwiftBackend {
         crate::info!("[STOP] RECORDING SESSION STOPPING (SharedAudioEngine)");
         crate::info!("========================================");
 
-        if self.state != CaptureState::Capturing {
+        if !matches!(self.state, CaptureState::Capturing | CaptureState::Paused) {
             crate::debug!("Not capturing, nothing to stop");
             return Ok(());
         }
@@ -205,6 +217,44 @@ impl AudioCaptureBackend for SwiftBackend {
         crate::info!("[STOP] SharedAudioEngine capture stopped successfully");
         Ok(())
     }
+
+    fn pause(&mut self) -> Result<(), AudioCaptureError> {
+        if self.state != CaptureState::Capturing {
+            crate::debug!("Not capturing, nothing to pause");
+            return Ok(());
+        }
+
+        match swift::audio_engine_pause_capture() {
+            AudioEngineResult::Ok => {
+                crate::info!("[PAUSE] SharedAudioEngine capture paused");
+                self.state = CaptureState::Paused;
+                Ok(())
+            }
+            AudioEngineResult::Failed(error) => {
+                crate::error!("Failed to pause audio capture: {}", error);
+                Err(AudioCaptureError::DeviceError(error))
+            }
+        }
+    }
+
+    fn resume(&mut self) -> Result<(), AudioCaptureError> {
+        if self.state != CaptureState::Paused {
+            crate::debug!("Capture not paused, nothing to resume");
+            return Ok(());
+        }
+
+        match swift::audio_engine_resume_capture() {
+            AudioEngineResult::Ok => {
+                crate::info!("[RESUME] SharedAudioEngine capture resumed");
+                self.state = CaptureState::Capturing;
+                Ok(())
+            }
+            AudioEngineResult::Failed(error) => {
+                crate::error!("Failed to resume audio capture: {}", error);
+                Err(AudioCaptureError::DeviceError(error))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -67,3 +67,19 @@ fn test_start_stop_cycle() {
         }
     }
 }
+
+#[test]
+fn test_rotate_segment_without_capture_returns_none() {
+    let mut backend = SwiftBackend::new();
+    assert!(backend.rotate_segment().is_none());
+}
+
+#[test]
+fn test_pause_resume_without_capture_is_noop() {
+    let mut backend = SwiftBackend::new();
+    // Not capturing - pause and resume should not touch the engine
+    assert!(backend.pause().is_ok());
+    assert_eq!(backend.state, CaptureState::Idle);
+    assert!(backend.resume().is_ok());
+    assert_eq!(backend.state, CaptureState::Idle);
+}
@@ -0,0 +1,34 @@
+// Sample tap sources
+//
+// The shared audio engine can expose its converted 16kHz mono samples
+// independently of file capture. Hands-free modes (wake phrase listening,
+// continuous dictation) read audio through this abstraction.
+
+/// Source of 16kHz mono samples
+pub trait SampleSource: Send {
+    /// Begin collecting samples
+    fn start(&mut self);
+    /// Stop collecting samples and discard anything pending
+    fn stop(&mut self);
+    /// Take all samples collected since the last read
+    fn read(&mut self) -> Vec<f32>;
+}
+
+/// Sample source backed by the shared Swift audio engine tap
+///
+/// The tap is a single stream: only one source should be active at a time.
+pub struct SwiftTapSource;
+
+impl SampleSource for SwiftTapSource {
+    fn start(&mut self) {
+        crate::swift::audio_engine_set_tap_enabled(true);
+    }
+
+    fn stop(&mut self) {
+        crate::swift::audio_engine_set_tap_enabled(false);
+    }
+
+    fn read(&mut self) -> Vec<f32> {
+        crate::swift::audio_engine_drain_tap()
+    }
+}
@@ -38,6 +38,12 @@ pub enum AudioCommand {
     },
     /// Stop capturing audio and return result via channel
     Stop(Option<Sender<StopResult>>),
+    /// Suspend capture without finalizing the recording
+    Pause(Sender<Result<(), AudioCaptureError>>),
+    /// Resume a suspended capture
+    Resume(Sender<Result<(), AudioCaptureError>>),
+    /// Close the current capture segment and keep capturing into a new one
+    RotateSegment(Sender<Option<String>>),
     /// Shutdown the audio thread (used in tests)
     #[allow(dead_code)]
     Shutdown,
@@ -124,6 +130,54 @@ impl AudioThreadHandle {
             })
     }
 
+    /// Pause audio capture, keeping the current recording open
+    ///
+    /// Blocks until the audio thread responds.
+    #[must_use = "this returns a Result that should be handled"]
+    pub fn pause(&self) -> Result<(), AudioThreadError> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.sender
+            .send(AudioCommand::Pause(response_tx))
+            .map_err(|_| AudioThreadError::ThreadDisconnected)?;
+
+        response_rx
+            .recv()
+            .map_err(|_| AudioThreadError::ThreadDisconnected)?
+            .map_err(AudioThreadError::CaptureError)
+    }
+
+    /// Resume a paused audio capture into the same recording
+    ///
+    /// Blocks until the audio thread responds.
+    #[must_use = "this returns a Result that should be handled"]
+    pub fn resume(&self) -> Result<(), AudioThreadError> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.sender
+            .send(AudioCommand::Resume(response_tx))
+            .map_err(|_| AudioThreadError::ThreadDisconnected)?;
+
+        response_rx
+            .recv()
+            .map_err(|_| AudioThreadError::ThreadDisconnected)?
+            .map_err(AudioThreadError::CaptureError)
+    }
+
+    /// Rotate the capture segment for long-form recordings
+    ///
+    /// Returns the path of the finished segment file, or None if nothing is
+    /// being captured. Blocks until the audio thread responds.
+    #[must_use = "this returns a Result that should be handled"]
+    pub fn rotate_segment(&self) -> Result<Option<String>, AudioThreadError> {
+        let (response_tx, response_rx) = mpsc::channel();
+        self.sender
+            .send(AudioCommand::RotateSegment(response_tx))
+            .map_err(|_| AudioThreadError::ThreadDisconnected)?;
+
+        response_rx
+            .recv()
+            .map_err(|_| AudioThreadError::ThreadDisconnected)
+    }
+
     /// Shutdown the audio thread gracefully (used in tests)
     #[allow(dead_code)]
     pub fn shutdown(&self) -> Result<(), AudioThreadError> {
@@ -280,6 +334,18 @@ fn audio_thread_main(receiver: Receiver<AudioCommand>) {
                     let _ = tx.send(StopResult { reason, warnings, raw_audio, capture_file });
                 }
             }
+            AudioCommand::Pause(response_tx) => {
+                crate::debug!("Received PAUSE command");
+                let _ = response_tx.send(backend.pause());
+            }
+            AudioCommand::Resume(response_tx) => {
+                crate::debug!("Received RESUME command");
+                let _ = response_tx.send(backend.resume());
+            }
+            AudioCommand::RotateSegment(response_tx) => {
+                crate::debug!("Received ROTATE_SEGMENT command");
+                let _ = response_tx.send(backend.rotate_segment());
+            }
             AudioCommand::Shutdown => {
                 crate::debug!("Received SHUTDOWN command");
                 let _ = backend.stop();
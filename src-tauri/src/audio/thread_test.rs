@@ -104,3 +104,13 @@ fn test_start_with_device_passes_device_name() {
 }
 
 // test_start_uses_default_device removed: start() method removed (unused convenience wrapper)
+
+/// Pause/resume without an active capture are accepted as no-ops
+#[test]
+fn test_pause_resume_without_capture() {
+    let handle = AudioThreadHandle::spawn();
+    assert!(handle.pause().is_ok());
+    assert!(handle.resume().is_ok());
+    assert_eq!(handle.rotate_segment(), Ok(None));
+    assert!(handle.shutdown().is_ok());
+}
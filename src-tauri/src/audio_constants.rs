@@ -48,35 +48,90 @@ pub const VAD_CHUNK_SIZE_8KHZ: usize = 256;
 /// pauses or soft speech.
 pub const VAD_THRESHOLD_SILENCE: f32 = 0.5;
 
+// =============================================================================
+// ENERGY VAD
+// =============================================================================
+
+/// Level (dBFS) at and below which the energy VAD reports no speech.
+///
+/// Quiet room tone on a laptop microphone sits around -60 to -50 dBFS.
+pub const ENERGY_VAD_FLOOR_DB: f32 = -50.0;
+
+/// Level (dBFS) at and above which the energy VAD reports certain speech.
+///
+/// Normal speech at arm's length peaks around -25 to -15 dBFS. Levels in
+/// between map linearly, so the default 0.5 threshold sits at -38 dBFS.
+pub const ENERGY_VAD_SPEECH_DB: f32 = -26.0;
+
+/// Zero-crossing rate (crossings per sample) above which a chunk is noise-like.
+///
+/// Voiced speech crosses zero well under 0.3 times per sample; broadband
+/// hiss and fans cross about every other sample.
+pub const ENERGY_VAD_MAX_ZERO_CROSSING_RATE: f32 = 0.3;
+
+/// Weight applied to the energy score of noise-like chunks.
+pub const ENERGY_VAD_NOISE_WEIGHT: f32 = 0.25;
+
 // =============================================================================
 // SILENCE DETECTION
 // =============================================================================
 
 /// Duration of silence before stopping recording (milliseconds).
 ///
-/// After speech is detected and followed by this duration of silence,
-/// recording is automatically stopped (SilenceAfterSpeech).
+/// After speech is detected and followed by this duration of silence
+/// (counted once the pause tolerance hangover has expired), recording is
+/// automatically stopped (SilenceAfterSpeech).
 pub const SILENCE_DURATION_MS: u32 = 2000;
 
 /// Duration before canceling if no speech detected (milliseconds).
 ///
-/// If no speech is detected after wake word activation within this
+/// If no speech is detected after wake phrase activation within this
 /// duration, recording is canceled (NoSpeechTimeout / false activation).
 pub const NO_SPEECH_TIMEOUT_MS: u32 = 5000;
 
 /// Duration of pause that doesn't trigger stop (milliseconds).
 ///
-/// Brief pauses in speech below this duration won't trigger silence
-/// detection. Reserved for future pause detection refinement.
-#[allow(dead_code)]
+/// Hangover after the last speech: brief pauses between words below this
+/// duration are still treated as speech and don't start the silence timer.
 pub const PAUSE_TOLERANCE_MS: u32 = 1000;
 
+/// Accepted range for the user-configured silence duration (milliseconds).
+pub const SILENCE_DURATION_MIN_MS: u32 = 300;
+pub const SILENCE_DURATION_MAX_MS: u32 = 10_000;
+
+/// Accepted range for the user-configured no-speech timeout (milliseconds).
+pub const NO_SPEECH_TIMEOUT_MIN_MS: u32 = 1000;
+pub const NO_SPEECH_TIMEOUT_MAX_MS: u32 = 60_000;
+
+/// Upper bound for the user-configured pause tolerance (milliseconds).
+pub const PAUSE_TOLERANCE_MAX_MS: u32 = 5000;
+
+/// Accepted range for the user-configured VAD speech threshold.
+pub const VAD_THRESHOLD_MIN: f32 = 0.1;
+pub const VAD_THRESHOLD_MAX: f32 = 0.95;
+
 /// Minimum speech frames for silence detector VAD.
 ///
 /// Helps filter out brief noise spikes. Setting to 2 catches short
 /// utterances while filtering random pops.
 pub const SILENCE_MIN_SPEECH_FRAMES: usize = 2;
 
+// =============================================================================
+// SILENCE TRIMMING
+// =============================================================================
+
+/// Audio kept before the first detected speech when trimming (milliseconds).
+///
+/// A short lead-in protects soft word onsets (plosives, fricatives) that
+/// the VAD scores below threshold.
+pub const TRIM_LEADING_PADDING_MS: u32 = 200;
+
+/// Audio kept after the last detected speech when trimming (milliseconds).
+///
+/// Slightly longer than the lead-in so trailing syllables are not clipped,
+/// while still dropping the silent tail appended by silence auto-stop.
+pub const TRIM_TRAILING_PADDING_MS: u32 = 300;
+
 // =============================================================================
 // PIPELINE CONFIGURATION
 // =============================================================================
@@ -95,6 +150,13 @@ pub const DETECTION_INTERVAL_MS: u64 = 100;
 /// for the VAD to make accurate decisions.
 pub const MIN_DETECTION_SAMPLES: usize = 1600;
 
+/// Minimum interval between `speech_activity` level updates (milliseconds).
+///
+/// Speech start/end transitions are sent immediately; probability and
+/// countdown updates in between are throttled to keep IPC traffic low
+/// while still animating a countdown smoothly.
+pub const SPEECH_ACTIVITY_INTERVAL_MS: u64 = 200;
+
 /// Chunk size for real-time audio resampling (samples).
 ///
 /// When the audio device doesn't support 16kHz natively, we resample
@@ -142,6 +204,108 @@ pub const HIGHPASS_CUTOFF_HZ: f32 = 80.0;
 #[allow(dead_code)]
 pub const PRE_EMPHASIS_ALPHA: f32 = 0.97;
 
+// =============================================================================
+// LONG-FORM RECORDING
+// =============================================================================
+
+/// Default maximum duration of a long-form recording (seconds).
+///
+/// Long-form sessions spill audio to rotating segment files on disk, so
+/// this limit is about session hygiene rather than memory. 4 hours covers
+/// a full workshop or a day of back-to-back meetings.
+pub const LONG_FORM_MAX_DURATION_SECS: u64 = 4 * 60 * 60;
+
+/// Default duration of each long-form segment file (seconds).
+///
+/// Each closed segment is transcribed independently and appended to the
+/// session document. 5 minutes keeps segments well under the transcription
+/// timeout while avoiding excessive file churn.
+pub const LONG_FORM_SEGMENT_DURATION_SECS: u64 = 5 * 60;
+
+/// Lead time for the max-duration warning event (seconds).
+///
+/// The `recording_duration_warning` event is emitted this long before the
+/// maximum duration is reached, giving the user time to wrap up.
+pub const LONG_FORM_WARNING_LEAD_SECS: u64 = 60;
+
+/// Minimum allowed segment duration (seconds).
+///
+/// Shorter segments give the transcription model too little context.
+pub const LONG_FORM_MIN_SEGMENT_SECS: u64 = 30;
+
+/// Tick interval for the long-form session monitor (milliseconds).
+pub const LONG_FORM_TICK_INTERVAL_MS: u64 = 500;
+
+// =============================================================================
+// LISTENING MODE (WAKE PHRASE)
+// =============================================================================
+
+/// Poll interval for draining the listening mode sample tap (milliseconds).
+pub const LISTENING_POLL_INTERVAL_MS: u64 = 100;
+
+/// Audio kept before detected speech in a wake window (milliseconds).
+///
+/// The VAD needs a few frames to fire, so the onset of the wake phrase
+/// would otherwise be clipped before transcription.
+pub const WAKE_PRE_ROLL_MS: u32 = 300;
+
+/// Silence that ends a candidate wake utterance (milliseconds).
+pub const WAKE_END_SILENCE_MS: u32 = 500;
+
+/// Minimum speech in a candidate wake utterance (milliseconds).
+///
+/// Shorter bursts (clicks, coughs) are not worth transcribing.
+pub const WAKE_MIN_SPEECH_MS: u32 = 200;
+
+/// Maximum length of a wake window sent for transcription (milliseconds).
+///
+/// Wake phrases are short; longer speech is cut at this length so that
+/// ordinary conversation only costs one short transcription.
+pub const WAKE_MAX_WINDOW_MS: u32 = 2500;
+
+/// Default similarity required to accept a wake phrase match (0.0 - 1.0).
+///
+/// Compared against the normalized Levenshtein similarity of the phrase
+/// and the transcript, tolerating small transcription errors
+/// ("hey kat", "hay cat").
+pub const WAKE_MATCH_THRESHOLD: f64 = 0.8;
+
+// =============================================================================
+// SILENCE CALIBRATION
+// =============================================================================
+
+/// Room tone captured at the start of a calibration run (seconds).
+pub const CALIBRATION_ROOM_TONE_SECS: u32 = 3;
+
+/// Speech captured after the room tone during calibration (seconds).
+pub const CALIBRATION_SPEECH_SECS: u32 = 5;
+
+/// Minimum gap between room tone and speech VAD probability.
+///
+/// Below this the two are indistinguishable and no threshold is proposed.
+pub const CALIBRATION_MIN_SEPARATION: f32 = 0.1;
+
+// =============================================================================
+// CONTINUOUS DICTATION
+// =============================================================================
+
+/// Silence that ends an utterance in continuous dictation (milliseconds).
+///
+/// Much shorter than `SILENCE_DURATION_MS`: the session stays open, so a
+/// pause only decides when the text so far is pasted.
+pub const CONTINUOUS_UTTERANCE_SILENCE_MS: u32 = 800;
+
+/// Maximum length of a single dictation utterance (seconds).
+///
+/// Uninterrupted speech is cut at this length so text keeps appearing
+/// and each transcription stays fast.
+pub const CONTINUOUS_MAX_UTTERANCE_SECS: u32 = 30;
+
+/// Audio kept from a silent stretch when dictation discards it (milliseconds).
+///
+/// Protects the onset of the next utterance.
+pub const CONTINUOUS_PRE_ROLL_MS: u32 = 300;
+
 // =============================================================================
 // UTILITY FUNCTIONS
 // =============================================================================
@@ -1,11 +1,12 @@
 //! Audio device commands for Tauri IPC.
 //!
-//! Contains commands for listing devices and monitoring audio levels.
+//! Contains commands for listing devices, managing the device priority list
+//! and monitoring audio levels.
 
 use tauri::{AppHandle, Emitter, State};
 use tauri_plugin_store::StoreExt;
 
-use crate::audio::AudioInputDevice;
+use crate::audio::{AudioInputDevice, DevicePriority, SETTING_DEVICE_PRIORITY};
 use crate::events::event_names;
 
 use super::common::get_settings_file;
@@ -20,6 +21,35 @@ pub fn list_audio_devices() -> Vec<AudioInputDevice> {
     crate::audio::list_input_devices()
 }
 
+/// Get the ordered device preference list
+///
+/// Falls back to the single selected device when no list has been saved.
+#[tauri::command]
+pub fn get_device_priority(app_handle: AppHandle) -> Vec<String> {
+    DevicePriority::from_settings(&app_handle).devices().to_vec()
+}
+
+/// Save the ordered device preference list, most preferred first
+///
+/// The list is applied right away: if a connected device now outranks the
+/// active one, capture switches to it.
+#[tauri::command]
+pub fn set_device_priority(app_handle: AppHandle, devices: Vec<String>) -> Result<(), String> {
+    let priority = DevicePriority::new(devices);
+
+    let settings_file = get_settings_file(&app_handle);
+    let store = app_handle
+        .store(&settings_file)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    store.set(SETTING_DEVICE_PRIORITY, serde_json::json!(priority.devices()));
+    store
+        .save()
+        .map_err(|e| format!("Failed to persist settings: {}", e))?;
+
+    crate::device_handler::apply_device_priority();
+    Ok(())
+}
+
 /// Start audio level monitoring for device testing
 ///
 /// Starts capturing audio from the specified device and emits "audio-level" events
@@ -67,13 +97,8 @@ pub fn init_audio_monitor(
         return Ok(());
     }
 
-    // Read saved device from settings store
-    let settings_file = get_settings_file(&app_handle);
-    let device_name = app_handle
-        .store(&settings_file)
-        .ok()
-        .and_then(|store| store.get("audio.selectedDevice"))
-        .and_then(|v| v.as_str().map(|s| s.to_string()));
+    // Pick the most preferred connected device from settings
+    let device_name = DevicePriority::from_settings(&app_handle).resolve(&devices);
 
     // Pre-initialize the audio engine
     monitor_state.init(device_name)
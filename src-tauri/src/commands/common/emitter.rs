@@ -7,10 +7,11 @@ use tauri::{AppHandle, Emitter};
 
 use crate::emit_or_warn;
 use crate::events::{
-    command_events, event_names, hotkey_events, CommandAmbiguousPayload, CommandEventEmitter,
+    command_events, event_names, hotkey_events, long_form_events, silence_events, speech_activity_events, CommandAmbiguousPayload, CommandConfirmationRequiredPayload, CommandEventEmitter,
     CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload, HotkeyEventEmitter,
+    LongFormEventEmitter,
     RecordingCancelledPayload, RecordingErrorPayload, RecordingEventEmitter,
-    RecordingStartedPayload, RecordingStoppedPayload, TranscriptionCompletedPayload,
+    RecordingPausedPayload, RecordingResumedPayload, RecordingStartedPayload, RecordingStoppedPayload, TranscriptionCompletedPayload,
     TranscriptionErrorPayload, TranscriptionEventEmitter, TranscriptionStartedPayload,
 };
 
@@ -42,9 +43,25 @@ impl RecordingEventEmitter for TauriEventEmitter {
         emit_or_warn!(self.app_handle, event_names::RECORDING_CANCELLED, payload);
     }
 
+    fn emit_recording_paused(&self, payload: RecordingPausedPayload) {
+        emit_or_warn!(self.app_handle, event_names::RECORDING_PAUSED, payload);
+    }
+
+    fn emit_recording_resumed(&self, payload: RecordingResumedPayload) {
+        emit_or_warn!(self.app_handle, event_names::RECORDING_RESUMED, payload);
+    }
+
     fn emit_recording_error(&self, payload: RecordingErrorPayload) {
         emit_or_warn!(self.app_handle, event_names::RECORDING_ERROR, payload);
     }
+
+    fn emit_vad_fallback_active(&self, payload: silence_events::VadFallbackActivePayload) {
+        emit_or_warn!(self.app_handle, silence_events::VAD_FALLBACK_ACTIVE, payload);
+    }
+
+    fn emit_speech_activity(&self, payload: speech_activity_events::SpeechActivityPayload) {
+        emit_or_warn!(self.app_handle, speech_activity_events::SPEECH_ACTIVITY, payload);
+    }
 }
 
 impl TranscriptionEventEmitter for TauriEventEmitter {
@@ -81,6 +98,14 @@ impl CommandEventEmitter for TauriEventEmitter {
     fn emit_command_ambiguous(&self, payload: CommandAmbiguousPayload) {
         emit_or_warn!(self.app_handle, command_events::COMMAND_AMBIGUOUS, payload);
     }
+
+    fn emit_command_confirmation_required(&self, payload: CommandConfirmationRequiredPayload) {
+        emit_or_warn!(
+            self.app_handle,
+            command_events::COMMAND_CONFIRMATION_REQUIRED,
+            payload
+        );
+    }
 }
 
 impl HotkeyEventEmitter for TauriEventEmitter {
@@ -96,6 +121,30 @@ impl HotkeyEventEmitter for TauriEventEmitter {
     }
 }
 
+impl LongFormEventEmitter for TauriEventEmitter {
+    fn emit_recording_duration_warning(
+        &self,
+        payload: long_form_events::RecordingDurationWarningPayload,
+    ) {
+        emit_or_warn!(
+            self.app_handle,
+            long_form_events::RECORDING_DURATION_WARNING,
+            payload
+        );
+    }
+
+    fn emit_long_form_segment_transcribed(
+        &self,
+        payload: long_form_events::LongFormSegmentTranscribedPayload,
+    ) {
+        emit_or_warn!(
+            self.app_handle,
+            long_form_events::LONG_FORM_SEGMENT_TRANSCRIBED,
+            payload
+        );
+    }
+}
+
 #[cfg(test)]
 #[path = "emitter_test.rs"]
 mod tests;
@@ -2,13 +2,13 @@
 //!
 //! This module provides shared infrastructure for commands:
 //! - `emitter`: TauriEventEmitter for production event emission
-//! - `state_access`: Unified settings access helper
+//! - `state_access`: Unified settings and recordings directory access helpers
 
 mod emitter;
 mod state_access;
 
 pub use emitter::TauriEventEmitter;
-pub use state_access::get_settings_file;
+pub use state_access::{get_settings_file, recordings_dir};
 
 /// Helper macro to emit events with error logging.
 ///
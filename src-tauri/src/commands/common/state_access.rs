@@ -1,9 +1,10 @@
 //! Unified settings access for commands.
 //!
-//! Provides helpers to access settings from commands in a consistent way,
-//! handling worktree context.
+//! Provides helpers to access settings and storage locations from commands
+//! in a consistent way, handling worktree context.
 
-use tauri::AppHandle;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
 
 /// Get the settings file name for the current worktree context.
 ///
@@ -13,6 +14,17 @@ pub fn get_settings_file(app_handle: &AppHandle) -> String {
     crate::util::get_settings_file(app_handle)
 }
 
+/// Get the worktree-aware recordings directory.
+///
+/// Falls back to "./heycat/recordings" if the directory can't be resolved.
+pub fn recordings_dir(app_handle: &AppHandle) -> PathBuf {
+    let worktree_context = app_handle
+        .try_state::<crate::worktree::WorktreeState>()
+        .and_then(|s| s.context.clone());
+    crate::paths::get_recordings_dir(worktree_context.as_ref())
+        .unwrap_or_else(|_| PathBuf::from(".").join("heycat").join("recordings"))
+}
+
 #[cfg(test)]
 #[path = "state_access_test.rs"]
 mod tests;
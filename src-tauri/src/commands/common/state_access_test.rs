@@ -10,3 +10,9 @@ fn test_get_settings_file_function_is_callable() {
     fn _takes_fn(_: fn(&tauri::AppHandle) -> String) {}
     _takes_fn(super::get_settings_file);
 }
+
+#[test]
+fn test_recordings_dir_function_is_callable() {
+    fn _takes_fn(_: fn(&tauri::AppHandle) -> std::path::PathBuf) {}
+    _takes_fn(super::recordings_dir);
+}
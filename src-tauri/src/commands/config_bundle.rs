@@ -0,0 +1,49 @@
+//! Configuration bundle commands for Tauri IPC.
+//!
+//! Exports voice commands, dictionary entries and window contexts as one JSON
+//! bundle and imports such bundles, so a command set can be shared.
+
+use tauri::{AppHandle, State};
+
+use crate::turso::{events as turso_events, ConfigBundle, ImportMode, ImportReport};
+
+use super::TursoClientState;
+
+/// Export the current configuration as a JSON bundle
+#[tauri::command]
+pub async fn export_config_bundle(
+    turso_client: State<'_, TursoClientState>,
+) -> Result<String, String> {
+    turso_client
+        .export_config_bundle()
+        .await
+        .and_then(|bundle| bundle.to_json())
+        .map_err(|e| e.to_string())
+}
+
+/// Import a JSON bundle
+///
+/// With `dry_run` set nothing is written; the report shows what would be
+/// added, removed and skipped as a conflict.
+#[tauri::command]
+pub async fn import_config_bundle(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    bundle: String,
+    mode: Option<ImportMode>,
+    dry_run: Option<bool>,
+) -> Result<ImportReport, String> {
+    let bundle = ConfigBundle::from_json(&bundle).map_err(|e| e.to_string())?;
+    let report = turso_client
+        .import_config_bundle(&bundle, mode.unwrap_or_default(), dry_run.unwrap_or(false))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !report.dry_run {
+        turso_events::emit_voice_commands_updated(&app_handle, "sync", "");
+        turso_events::emit_dictionary_updated(&app_handle, "sync", "");
+        turso_events::emit_window_contexts_updated(&app_handle, "sync", "");
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,156 @@
+//! Continuous dictation commands for Tauri IPC.
+//!
+//! A continuous session stays open until stopped. Each utterance is
+//! delivered through the normal transcription pipeline as soon as the
+//! speaker pauses, so text is pasted (or commands run) while the user
+//! keeps talking. Utterances are delivered in the order they were spoken.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::audio::SwiftTapSource;
+use crate::emit_or_warn;
+use crate::events::dictation_events::{
+    self, ContinuousDictationStartedPayload, ContinuousDictationStoppedPayload,
+};
+use crate::recording::{
+    ContinuousConfig, ContinuousDictation, RecordingMetadata, RecordingState, TrimConfig,
+};
+use crate::transcription::DeliveryGate;
+
+use super::common::recordings_dir;
+use super::{DictationState, ListeningState, ProductionState, TranscriptionServiceState};
+
+/// An active continuous dictation session
+pub struct DictationSession {
+    /// Background thread splitting audio into utterances
+    dictation: ContinuousDictation,
+    /// Number of utterances handed to the transcription pipeline
+    utterance_count: Arc<AtomicU64>,
+    /// Whether the wake phrase listener was paused for this session
+    resume_listening: bool,
+}
+
+/// Start a continuous dictation session
+///
+/// Uses the `recording.continuous.*` settings for utterance segmentation.
+/// The wake phrase listener shares the audio tap, so it is paused for the
+/// duration of the session. Does nothing if a session is already running.
+#[tauri::command]
+pub fn start_continuous_dictation(
+    app_handle: AppHandle,
+    dictation_state: State<'_, DictationState>,
+    recording_state: State<'_, ProductionState>,
+    listening_state: State<'_, ListeningState>,
+) -> Result<(), String> {
+    let mut guard = dictation_state
+        .lock()
+        .map_err(|_| "Unable to access dictation state.".to_string())?;
+    if guard.as_ref().is_some_and(|s| s.dictation.is_running()) {
+        return Ok(());
+    }
+
+    let recording_idle = recording_state
+        .lock()
+        .map(|m| m.get_state() == RecordingState::Idle)
+        .unwrap_or(false);
+    if !recording_idle {
+        return Err("Cannot start dictation while a recording is in progress.".to_string());
+    }
+
+    let resume_listening = match listening_state.lock() {
+        Ok(mut listener) => match listener.take() {
+            Some(mut listener) => {
+                listener.stop();
+                true
+            }
+            None => false,
+        },
+        Err(_) => false,
+    };
+
+    let gate = DeliveryGate::new();
+    let utterance_count = Arc::new(AtomicU64::new(0));
+    let on_utterance = {
+        let app_handle = app_handle.clone();
+        let utterance_count = utterance_count.clone();
+        Box::new(move |sequence: u64, metadata: RecordingMetadata| {
+            utterance_count.fetch_add(1, Ordering::SeqCst);
+            crate::storage::store_recording(&app_handle, &metadata, "continuous");
+            let turn = gate.turn(sequence);
+            match app_handle.try_state::<TranscriptionServiceState>() {
+                Some(service) => service.process_utterance(metadata.file_path, turn),
+                None => crate::warn!("[continuous] Transcription service unavailable"),
+            }
+        })
+    };
+
+    *guard = Some(DictationSession {
+        dictation: ContinuousDictation::start(
+            ContinuousConfig::from_settings(&app_handle),
+            TrimConfig::from_settings(&app_handle),
+            SwiftTapSource,
+            recordings_dir(&app_handle),
+            on_utterance,
+        ),
+        utterance_count,
+        resume_listening,
+    });
+    drop(guard);
+
+    emit_or_warn!(
+        app_handle,
+        dictation_events::CONTINUOUS_DICTATION_STARTED,
+        ContinuousDictationStartedPayload {
+            timestamp: crate::events::current_timestamp(),
+        }
+    );
+    Ok(())
+}
+
+/// Stop the continuous dictation session
+///
+/// The utterance in progress is delivered before the session ends. The
+/// wake phrase listener is resumed if the session paused it.
+#[tauri::command]
+pub fn stop_continuous_dictation(
+    app_handle: AppHandle,
+    dictation_state: State<'_, DictationState>,
+) -> Result<(), String> {
+    let session = dictation_state
+        .lock()
+        .map_err(|_| "Unable to access dictation state.".to_string())?
+        .take();
+
+    let Some(mut session) = session else {
+        return Ok(());
+    };
+    session.dictation.stop();
+
+    emit_or_warn!(
+        app_handle,
+        dictation_events::CONTINUOUS_DICTATION_STOPPED,
+        ContinuousDictationStoppedPayload {
+            utterance_count: session.utterance_count.load(Ordering::SeqCst),
+            timestamp: crate::events::current_timestamp(),
+        }
+    );
+
+    if session.resume_listening {
+        if let Err(e) = super::listening::start_wake_listener(&app_handle) {
+            crate::warn!("Failed to resume wake phrase listening: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Check whether a continuous dictation session is running
+#[tauri::command]
+pub fn get_continuous_dictation_status(dictation_state: State<'_, DictationState>) -> bool {
+    dictation_state
+        .lock()
+        .map(|guard| guard.as_ref().is_some_and(|s| s.dictation.is_running()))
+        .unwrap_or(false)
+}
@@ -0,0 +1,204 @@
+//! Listening mode commands for Tauri IPC.
+//!
+//! Listening mode waits for the configured wake phrase and starts a
+//! hands-free recording that stops on silence. It is opt-in: it starts on
+//! launch only when `listening.enabled` is set, or via `start_listening`.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::emit_or_warn;
+use crate::events::listening_events::{
+    self, ListeningStatusChangedPayload, WakePhraseDetectedPayload,
+};
+use crate::events::silence_events::{self, VadFallbackActivePayload};
+use crate::audio::SwiftTapSource;
+use crate::listening::{WakeListener, WakePhraseConfig};
+use crate::parakeet::{SharedTranscriptionModel, TranscriptionState};
+use crate::recording::VadEngine;
+
+use super::{HotkeyIntegrationState, ListeningState, ProductionState};
+
+/// Start listening for the wake phrase
+///
+/// Uses the `listening.*` settings for the phrase and match threshold.
+/// Does nothing if already listening.
+#[tauri::command]
+pub fn start_listening(app_handle: AppHandle) -> Result<(), String> {
+    start_wake_listener(&app_handle)
+}
+
+/// Stop listening for the wake phrase
+#[tauri::command]
+pub fn stop_listening(
+    app_handle: AppHandle,
+    listening_state: State<'_, ListeningState>,
+) -> Result<(), String> {
+    let listener = listening_state
+        .lock()
+        .map_err(|_| "Unable to access listening state.".to_string())?
+        .take();
+
+    if let Some(mut listener) = listener {
+        listener.stop();
+        emit_status(
+            &app_handle,
+            false,
+            &WakePhraseConfig::from_settings(&app_handle),
+        );
+    }
+    Ok(())
+}
+
+/// Check whether the wake phrase listener is running
+#[tauri::command]
+pub fn get_listening_status(listening_state: State<'_, ListeningState>) -> bool {
+    listening_state
+        .lock()
+        .map(|guard| guard.as_ref().is_some_and(|l| l.is_running()))
+        .unwrap_or(false)
+}
+
+/// Start the wake phrase listener if it is not already running
+pub fn start_wake_listener(app_handle: &AppHandle) -> Result<(), String> {
+    let config = WakePhraseConfig::from_settings(app_handle);
+    config.validate().map_err(|e| e.to_string())?;
+
+    let (Some(listening_state), Some(recording_state), Some(model)) = (
+        app_handle.try_state::<ListeningState>(),
+        app_handle.try_state::<ProductionState>(),
+        app_handle.try_state::<Arc<SharedTranscriptionModel>>(),
+    ) else {
+        return Err("Listening mode is not available.".to_string());
+    };
+
+    let mut guard = listening_state
+        .lock()
+        .map_err(|_| "Unable to access listening state.".to_string())?;
+    if guard.as_ref().is_some_and(|l| l.is_running()) {
+        return Ok(());
+    }
+
+    if !model.is_loaded() {
+        crate::warn!("[listening] Transcription model not loaded, wake phrase cannot match yet");
+    }
+
+    let transcriber = {
+        let model = model.inner().clone();
+        Box::new(move |samples: &[f32]| transcribe_window(&model, samples))
+    };
+    let on_wake = {
+        let app_handle = app_handle.clone();
+        Box::new(move |transcript: String| handle_wake_phrase(&app_handle, transcript))
+    };
+
+    let vad_engine = VadEngine::from_settings(app_handle);
+    let listener = WakeListener::start(
+        config.clone(),
+        vad_engine,
+        SwiftTapSource,
+        recording_state.inner().clone(),
+        transcriber,
+        on_wake,
+    );
+    if let Some(reason) = listener.vad_fallback() {
+        emit_or_warn!(
+            app_handle,
+            silence_events::VAD_FALLBACK_ACTIVE,
+            VadFallbackActivePayload {
+                requested_engine: vad_engine,
+                active_engine: listener.vad_engine(),
+                reason: reason.to_string(),
+                timestamp: crate::events::current_timestamp(),
+            }
+        );
+    }
+    *guard = Some(listener);
+    drop(guard);
+
+    emit_status(app_handle, true, &config);
+    Ok(())
+}
+
+/// Start a recording in response to the wake phrase
+fn handle_wake_phrase(app_handle: &AppHandle, transcript: String) -> bool {
+    let recording_started = match (
+        app_handle.try_state::<HotkeyIntegrationState>(),
+        app_handle.try_state::<ProductionState>(),
+    ) {
+        (Some(integration), Some(state)) => integration
+            .lock()
+            .map(|mut integration| integration.handle_wake_phrase(state.as_ref()))
+            .unwrap_or(false),
+        _ => false,
+    };
+
+    emit_or_warn!(
+        app_handle,
+        listening_events::WAKE_PHRASE_DETECTED,
+        WakePhraseDetectedPayload {
+            transcript,
+            recording_started,
+            timestamp: crate::events::current_timestamp(),
+        }
+    );
+
+    recording_started
+}
+
+/// Transcribe a short wake window with the shared model
+///
+/// Windows are skipped while another transcription is running so that
+/// listening never delays a user's recording.
+fn transcribe_window(model: &SharedTranscriptionModel, samples: &[f32]) -> Option<String> {
+    if !model.is_loaded() || model.state() == TranscriptionState::Transcribing {
+        return None;
+    }
+
+    let path = std::env::temp_dir().join(format!("heycat-wake-{}.wav", uuid::Uuid::new_v4()));
+    if let Err(e) = write_window(&path, samples) {
+        crate::warn!("[listening] Failed to write wake window: {}", e);
+        return None;
+    }
+
+    let result = model.transcribe_file(&path.to_string_lossy());
+    let _ = model.reset_to_idle();
+    let _ = std::fs::remove_file(&path);
+
+    match result {
+        Ok(text) => Some(text),
+        Err(e) => {
+            crate::debug!("[listening] Wake window transcription failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Write a 16kHz mono Float32 WAV file
+fn write_window(path: &Path, samples: &[f32]) -> Result<(), hound::Error> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: crate::audio::TARGET_SAMPLE_RATE,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)?;
+    for &sample in samples {
+        writer.write_sample(sample)?;
+    }
+    writer.finalize()
+}
+
+/// Notify the frontend that listening started or stopped
+fn emit_status(app_handle: &AppHandle, listening: bool, config: &WakePhraseConfig) {
+    emit_or_warn!(
+        app_handle,
+        listening_events::LISTENING_STATUS_CHANGED,
+        ListeningStatusChangedPayload {
+            listening,
+            wake_phrase: config.phrase.clone(),
+        }
+    );
+}
@@ -5,7 +5,9 @@ use crate::audio::{parse_duration_from_file, AudioThreadHandle, QualityWarning,
 /// Error identifier for microphone access failures.
 /// Used to detect microphone-related errors without fragile string matching.
 pub const MICROPHONE_ERROR_MARKER: &str = "[MICROPHONE_ACCESS_ERROR]";
-use crate::recording::{AudioData, RecordingManager, RecordingMetadata, RecordingState};
+use crate::recording::{
+    trim_wav_file, AudioData, RecordingManager, RecordingMetadata, RecordingState,
+};
 
 /// Extended result from stop_recording_impl that includes diagnostics
 pub struct StopRecordingResult {
@@ -54,7 +56,7 @@ pub struct RecordingInfo {
 /// Information about the current recording state for frontend consumption
 #[derive(Debug, Clone, Serialize)]
 pub struct RecordingStateInfo {
-    /// Current state (Idle, Recording, Processing)
+    /// Current state (Idle, Recording, Paused, Processing)
     pub state: RecordingState,
 }
 
@@ -187,8 +189,8 @@ pub fn stop_recording_impl_extended(
     // Check current state
     let current_state = manager.get_state();
     crate::debug!("Current recording state: {:?}", current_state);
-    if current_state != RecordingState::Recording {
-        crate::debug!("Stop rejected: not in Recording state");
+    if !manager.is_recording_active() {
+        crate::debug!("Stop rejected: not in Recording or Paused state");
         return Err("No recording in progress. Start a recording first.".to_string());
     }
 
@@ -231,7 +233,8 @@ pub fn stop_recording_impl_extended(
 
     // Move temp file to final location (instant, no I/O - just a rename)
     // Falls back to encoding from buffer if no capture file (for tests)
-    let (file_path, duration_secs, sample_count) = if let Some((temp_path, duration_ms)) = capture_file {
+    let trim_config = manager.trim_config().clone();
+    let (file_path, duration_secs, sample_count, trimmed_duration_secs) = if let Some((temp_path, duration_ms)) = capture_file {
         // Fast path: Rename temp file directly (no re-encoding)
         // Transition to Idle immediately since file rename is instant
         manager
@@ -273,7 +276,18 @@ pub fn stop_recording_impl_extended(
         let sample_count = ((duration_ms as f64 / 1000.0) * TARGET_SAMPLE_RATE as f64) as usize;
         let duration_secs = duration_ms as f64 / 1000.0;
 
-        (final_path_str, duration_secs, sample_count)
+        // Drop leading/trailing silence from the saved file (keeps original on failure)
+        let trimmed = match trim_wav_file(&final_path, &trim_config) {
+            Ok(trimmed) => trimmed,
+            Err(e) => {
+                crate::warn!("Failed to trim silence from recording: {}", e);
+                None
+            }
+        };
+        // Sample count describes the saved file
+        let sample_count = trimmed.map_or(sample_count, |t| t.sample_count);
+
+        (final_path_str, duration_secs, sample_count, trimmed.map(|t| t.duration_secs))
     } else {
         // No capture file - transition to Idle and return empty
         crate::debug!("No capture file available");
@@ -284,7 +298,7 @@ pub fn stop_recording_impl_extended(
                 "Failed to complete recording."
             })?;
         drop(manager);
-        (String::new(), 0.0, 0)
+        (String::new(), 0.0, 0, None)
     };
 
     crate::info!("Recording stopped: {} samples, {:.2}s, stop_reason={:?}, warnings={}",
@@ -296,12 +310,92 @@ pub fn stop_recording_impl_extended(
             file_path,
             sample_count,
             stop_reason,
+            trimmed_duration_secs,
         },
         warnings,
         raw_audio,
     })
 }
 
+/// Implementation of pause_recording
+///
+/// Suspends audio capture and transitions Recording -> Paused. The capture
+/// file stays open so that `resume_recording_impl` continues the same recording.
+///
+/// # Errors
+/// Returns an error string if:
+/// - Not currently recording
+/// - Audio capture fails to pause
+/// - State lock is poisoned
+pub fn pause_recording_impl(
+    state: &Mutex<RecordingManager>,
+    audio_thread: Option<&AudioThreadHandle>,
+) -> Result<(), String> {
+    let mut manager = state.lock().map_err(|_| {
+        crate::error!("Failed to acquire recording state lock in pause_recording_impl");
+        "Unable to access recording state. Please try again or restart the application."
+    })?;
+
+    if manager.get_state() != RecordingState::Recording {
+        crate::debug!("Pause rejected: not in Recording state ({:?})", manager.get_state());
+        return Err("No recording in progress to pause.".to_string());
+    }
+
+    if let Some(audio_thread) = audio_thread {
+        audio_thread.pause().map_err(|e| {
+            crate::error!("Failed to pause audio capture: {:?}", e);
+            "Failed to pause recording."
+        })?;
+    }
+
+    manager.pause_recording().map_err(|e| {
+        crate::error!("Failed to transition to Paused: {:?}", e);
+        "Failed to pause recording."
+    })?;
+
+    crate::info!("Recording paused");
+    Ok(())
+}
+
+/// Implementation of resume_recording
+///
+/// Resumes audio capture and transitions Paused -> Recording.
+///
+/// # Errors
+/// Returns an error string if:
+/// - Recording is not paused
+/// - Audio capture fails to resume
+/// - State lock is poisoned
+pub fn resume_recording_impl(
+    state: &Mutex<RecordingManager>,
+    audio_thread: Option<&AudioThreadHandle>,
+) -> Result<(), String> {
+    let mut manager = state.lock().map_err(|_| {
+        crate::error!("Failed to acquire recording state lock in resume_recording_impl");
+        "Unable to access recording state. Please try again or restart the application."
+    })?;
+
+    if manager.get_state() != RecordingState::Paused {
+        crate::debug!("Resume rejected: not in Paused state ({:?})", manager.get_state());
+        return Err("Recording is not paused.".to_string());
+    }
+
+    if let Some(audio_thread) = audio_thread {
+        audio_thread.resume().map_err(|e| {
+            crate::error!("Failed to resume audio capture: {:?}", e);
+            "Failed to resume recording."
+        })?;
+    }
+
+    manager.resume_recording().map_err(|e| {
+        crate::error!("Failed to transition to Recording: {:?}", e);
+        "Failed to resume recording."
+    })?;
+
+    crate::info!("Recording resumed");
+    Ok(())
+}
+
 /// Implementation of get_recording_state
 ///
 /// # Returns
@@ -0,0 +1,350 @@
+//! Long-form recording commands for Tauri IPC.
+//!
+//! A long-form session records into rotating segment files. Each finished
+//! segment is transcribed in order and appended to a single growing
+//! document in Turso, so meetings of any length never sit in memory.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::mpsc;
+
+use crate::audio::StopReason;
+use crate::emit_or_warn;
+use crate::events::{
+    event_names, long_form_events, LongFormEventEmitter, RecordingStartedPayload,
+    RecordingStoppedPayload,
+};
+use crate::parakeet::SharedTranscriptionModel;
+use crate::recording::{LongFormConfig, LongFormMonitor};
+use crate::turso::{LongFormDocument, TursoClient};
+
+use super::common::recordings_dir;
+use super::logic::{start_recording_impl, stop_recording_impl_extended};
+use super::{
+    AudioThreadState, LongFormState, ProductionState, TauriEventEmitter, TursoClientState,
+};
+
+/// An active long-form session
+pub struct LongFormSession {
+    /// ID of the document segments are appended to
+    pub document_id: String,
+    /// Drives segment rotation and the max duration warning
+    monitor: LongFormMonitor,
+    /// Queue of finished segment files awaiting transcription
+    segment_tx: mpsc::UnboundedSender<PathBuf>,
+}
+
+/// Start a long-form recording session
+///
+/// Creates a new document, starts audio capture and begins rotating segments
+/// according to the `recording.longForm.*` settings.
+///
+/// # Returns
+/// The ID of the long-form document that will receive the transcript.
+#[tauri::command]
+pub async fn start_long_form_recording(
+    app_handle: AppHandle,
+    turso_client: State<'_, TursoClientState>,
+    state: State<'_, ProductionState>,
+    audio_thread: State<'_, AudioThreadState>,
+    long_form_state: State<'_, LongFormState>,
+    title: Option<String>,
+    device_name: Option<String>,
+) -> Result<String, String> {
+    // A session left behind by a cancelled recording is closed out first
+    if !state
+        .lock()
+        .map(|m| m.is_recording_active())
+        .unwrap_or(false)
+    {
+        finish_long_form_session(&app_handle, "");
+    }
+
+    let config = LongFormConfig::from_settings(&app_handle);
+
+    let model_available =
+        match crate::model::check_model_exists_for_type(crate::model::ModelType::ParakeetTDT) {
+            Ok(available) => available,
+            Err(e) => {
+                crate::warn!("Failed to check model status: {}", e);
+                false
+            }
+        };
+
+    start_recording_impl(
+        state.as_ref(),
+        Some(audio_thread.as_ref()),
+        model_available,
+        device_name,
+    )?;
+
+    let document_id = uuid::Uuid::new_v4().to_string();
+    let title = title.unwrap_or_else(|| {
+        format!(
+            "Recording {}",
+            chrono::Local::now().format("%Y-%m-%d %H:%M")
+        )
+    });
+    if let Err(e) = turso_client
+        .create_long_form_document(document_id.clone(), title)
+        .await
+    {
+        crate::error!("Failed to create long-form document: {}", e);
+        let _ = stop_recording_impl_extended(
+            state.as_ref(),
+            Some(audio_thread.as_ref()),
+            false,
+            recordings_dir(&app_handle),
+        );
+        return Err("Failed to create long-form document.".to_string());
+    }
+
+    let (segment_tx, segment_rx) = mpsc::unbounded_channel();
+    tauri::async_runtime::spawn(run_segment_worker(
+        app_handle.clone(),
+        turso_client.inner().clone(),
+        document_id.clone(),
+        segment_rx,
+    ));
+
+    let on_segment = {
+        let segment_tx = segment_tx.clone();
+        let recordings_dir = recordings_dir(&app_handle);
+        Box::new(
+            move |temp_path: String| match move_segment(&temp_path, &recordings_dir) {
+                Ok(path) => {
+                    let _ = segment_tx.send(path);
+                }
+                Err(e) => crate::warn!("Failed to keep long-form segment {}: {}", temp_path, e),
+            },
+        )
+    };
+
+    let on_max_duration = {
+        let app_handle = app_handle.clone();
+        Box::new(move || {
+            tauri::async_runtime::spawn(stop_at_max_duration(app_handle));
+        })
+    };
+
+    let monitor = LongFormMonitor::start(
+        config,
+        state.inner().clone(),
+        audio_thread.inner().clone(),
+        Arc::new(TauriEventEmitter::new(app_handle.clone())),
+        on_segment,
+        on_max_duration,
+    );
+
+    match long_form_state.lock() {
+        Ok(mut guard) => {
+            *guard = Some(LongFormSession {
+                document_id: document_id.clone(),
+                monitor,
+                segment_tx,
+            });
+        }
+        Err(_) => return Err("Unable to access long-form state.".to_string()),
+    }
+
+    emit_or_warn!(
+        app_handle,
+        event_names::RECORDING_STARTED,
+        RecordingStartedPayload {
+            timestamp: crate::events::current_timestamp(),
+        }
+    );
+
+    crate::info!("Long-form recording started (document {})", document_id);
+    Ok(document_id)
+}
+
+/// Get a long-form document by ID
+#[tauri::command]
+pub async fn get_long_form_document(
+    turso_client: State<'_, TursoClientState>,
+    id: String,
+) -> Result<Option<LongFormDocument>, String> {
+    turso_client
+        .get_long_form_document(&id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// List all long-form documents, newest first
+#[tauri::command]
+pub async fn list_long_form_documents(
+    turso_client: State<'_, TursoClientState>,
+) -> Result<Vec<LongFormDocument>, String> {
+    turso_client
+        .list_long_form_documents()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Finish the active long-form session, if any
+///
+/// Called from every stop path with the final capture file. The final file
+/// becomes the last segment and the document is completed once all queued
+/// segments are transcribed.
+///
+/// # Returns
+/// `true` if a long-form session consumed the recording, in which case the
+/// caller must not run the regular single-shot transcription.
+pub fn finish_long_form_session(app_handle: &AppHandle, final_path: &str) -> bool {
+    let Some(long_form_state) = app_handle.try_state::<LongFormState>() else {
+        return false;
+    };
+
+    let session = match long_form_state.lock() {
+        Ok(mut guard) => guard.take(),
+        Err(_) => {
+            crate::error!("Long-form state lock poisoned");
+            None
+        }
+    };
+
+    let Some(mut session) = session else {
+        return false;
+    };
+
+    session.monitor.stop();
+    if !final_path.is_empty() {
+        let _ = session.segment_tx.send(PathBuf::from(final_path));
+    }
+    crate::info!(
+        "Long-form session finished (document {})",
+        session.document_id
+    );
+
+    // Dropping the session closes the queue; the worker completes the document
+    true
+}
+
+/// Stop the recording once the configured max duration is reached
+async fn stop_at_max_duration(app_handle: AppHandle) {
+    let (Some(state), Some(audio_thread)) = (
+        app_handle.try_state::<ProductionState>(),
+        app_handle.try_state::<AudioThreadState>(),
+    ) else {
+        return;
+    };
+
+    let result = stop_recording_impl_extended(
+        state.as_ref(),
+        Some(audio_thread.as_ref()),
+        false,
+        recordings_dir(&app_handle),
+    );
+
+    match result {
+        Ok(stop_result) => {
+            let mut metadata = stop_result.metadata;
+            metadata.stop_reason = Some(StopReason::MaxDurationReached);
+
+            if !metadata.file_path.is_empty() {
+                crate::storage::store_recording(&app_handle, &metadata, "long-form");
+            }
+            finish_long_form_session(&app_handle, &metadata.file_path);
+
+            emit_or_warn!(
+                app_handle,
+                event_names::RECORDING_STOPPED,
+                RecordingStoppedPayload { metadata }
+            );
+        }
+        Err(e) => {
+            crate::warn!("Failed to stop long-form recording at max duration: {}", e);
+            finish_long_form_session(&app_handle, "");
+        }
+    }
+}
+
+/// Transcribe queued segments in order and append them to the document
+async fn run_segment_worker(
+    app_handle: AppHandle,
+    turso_client: Arc<TursoClient>,
+    document_id: String,
+    mut segment_rx: mpsc::UnboundedReceiver<PathBuf>,
+) {
+    let emitter = TauriEventEmitter::new(app_handle.clone());
+    let mut segment_index: u32 = 0;
+
+    while let Some(path) = segment_rx.recv().await {
+        let text = transcribe_segment(&app_handle, &path)
+            .await
+            .unwrap_or_default();
+
+        match turso_client
+            .append_long_form_segment(&document_id, &text)
+            .await
+        {
+            Ok(_) => {
+                emitter.emit_long_form_segment_transcribed(
+                    long_form_events::LongFormSegmentTranscribedPayload {
+                        document_id: document_id.clone(),
+                        segment_index,
+                        text,
+                    },
+                );
+            }
+            Err(e) => crate::error!("Failed to append long-form segment: {}", e),
+        }
+        segment_index += 1;
+    }
+
+    if let Err(e) = turso_client.complete_long_form_document(&document_id).await {
+        crate::error!("Failed to complete long-form document: {}", e);
+    }
+    crate::info!(
+        "Long-form document {} completed with {} segments",
+        document_id,
+        segment_index
+    );
+}
+
+/// Transcribe a single segment file with the shared model
+async fn transcribe_segment(app_handle: &AppHandle, path: &Path) -> Option<String> {
+    let model = app_handle
+        .try_state::<Arc<SharedTranscriptionModel>>()?
+        .inner()
+        .clone();
+    if !model.is_loaded() {
+        crate::warn!("Transcription model not loaded, skipping long-form segment");
+        return None;
+    }
+
+    let file_path = path.to_string_lossy().to_string();
+    let transcriber = model.clone();
+    let result = tokio::task::spawn_blocking(move || transcriber.transcribe_file(&file_path)).await;
+
+    if let Err(e) = model.reset_to_idle() {
+        crate::warn!("Failed to reset transcription state: {}", e);
+    }
+
+    match result {
+        Ok(Ok(text)) => Some(text),
+        Ok(Err(e)) => {
+            crate::warn!("Long-form segment transcription failed: {}", e);
+            None
+        }
+        Err(e) => {
+            crate::error!("Long-form segment transcription task panicked: {}", e);
+            None
+        }
+    }
+}
+
+/// Move a finished temp segment into the recordings directory
+fn move_segment(temp_path: &str, recordings_dir: &Path) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(recordings_dir)?;
+    let filename = format!(
+        "long-form-{}.wav",
+        chrono::Utc::now().format("%Y-%m-%d-%H%M%S")
+    );
+    let final_path = recordings_dir.join(filename);
+    std::fs::rename(temp_path, &final_path)?;
+    Ok(final_path)
+}
@@ -9,6 +9,12 @@
 //! - `transcription`: Transcription commands
 //! - `audio`: Audio device commands
 //! - `hotkey`: Hotkey management commands
+//! - `long_form`: Long-form recording session commands
+//! - `pipeline_log`: Pipeline outcome log queries
+//! - `config_bundle`: Configuration bundle export and import
+//! - `listening`: Wake phrase listening mode commands
+//! - `dictation`: Continuous dictation session commands
+//! - `silence`: Silence detection settings and calibration commands
 //! - `dictionary`: Dictionary management commands
 //! - `window_context`: Window context commands
 //! - `common`: Shared utilities (TauriEventEmitter)
@@ -18,10 +24,16 @@
 
 pub mod audio;
 pub mod common;
+pub mod config_bundle;
+pub mod dictation;
 pub mod dictionary;
 pub mod hotkey;
+pub mod listening;
 pub mod logic;
+pub mod long_form;
+pub mod pipeline_log;
 pub mod recording;
+pub mod silence;
 pub mod transcription;
 pub mod window;
 pub mod window_context;
@@ -31,8 +43,9 @@ pub use common::TauriEventEmitter;
 
 // Re-export state type aliases from app::state for backward compatibility
 pub use crate::app::state::{
-    AudioMonitorState, AudioThreadState, HotkeyIntegrationState, HotkeyServiceState,
-    KeyboardCaptureState, ProductionState, TranscriptionServiceState, TursoClientState,
+    AudioMonitorState, AudioThreadState, DictationState, HotkeyIntegrationState,
+    HotkeyServiceState, KeyboardCaptureState, ListeningState, LongFormState, ProductionState,
+    TranscriptionServiceState, TursoClientState,
 };
 
 // Worktree commands
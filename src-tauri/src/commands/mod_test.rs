@@ -3,8 +3,8 @@
 
 use super::logic::{
     clear_last_recording_buffer_impl, get_last_recording_buffer_impl, get_recording_state_impl,
-    list_recordings_impl, start_recording_impl, stop_recording_impl, PaginatedRecordingsResponse,
-    RecordingInfo, RecordingStateInfo,
+    list_recordings_impl, pause_recording_impl, resume_recording_impl, start_recording_impl,
+    stop_recording_impl, PaginatedRecordingsResponse, RecordingInfo, RecordingStateInfo,
 };
 use crate::audio::TARGET_SAMPLE_RATE;
 use crate::recording::{RecordingManager, RecordingState};
@@ -180,6 +180,66 @@ fn test_multiple_start_stop_cycles() {
     );
 }
 
+// =============================================================================
+// pause_recording_impl / resume_recording_impl Tests
+// =============================================================================
+
+#[test]
+fn test_pause_recording_returns_error_when_not_recording() {
+    let state = create_test_state();
+    let result = pause_recording_impl(&state, None);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("No recording in progress"));
+}
+
+#[test]
+fn test_resume_recording_returns_error_when_not_paused() {
+    let state = create_test_state();
+    start_recording_impl(&state, None, true, None).unwrap();
+    let result = resume_recording_impl(&state, None);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("not paused"));
+}
+
+#[test]
+fn test_pause_resume_stop_cycle() {
+    let state = create_test_state();
+    start_recording_impl(&state, None, true, None).unwrap();
+
+    assert!(pause_recording_impl(&state, None).is_ok());
+    assert_eq!(
+        get_recording_state_impl(&state).unwrap().state,
+        RecordingState::Paused
+    );
+
+    assert!(resume_recording_impl(&state, None).is_ok());
+    assert_eq!(
+        get_recording_state_impl(&state).unwrap().state,
+        RecordingState::Recording
+    );
+
+    assert!(stop_recording_impl(&state, None, false, test_recordings_dir()).is_ok());
+    assert_eq!(
+        get_recording_state_impl(&state).unwrap().state,
+        RecordingState::Idle
+    );
+}
+
+#[test]
+fn test_stop_recording_while_paused() {
+    let state = create_test_state();
+    start_recording_impl(&state, None, true, None).unwrap();
+    pause_recording_impl(&state, None).unwrap();
+
+    assert!(stop_recording_impl(&state, None, false, test_recordings_dir()).is_ok());
+    assert_eq!(
+        get_recording_state_impl(&state).unwrap().state,
+        RecordingState::Idle
+    );
+}
+
 // =============================================================================
 // get_last_recording_buffer_impl Tests
 // =============================================================================
@@ -0,0 +1,38 @@
+//! Pipeline log commands for Tauri IPC.
+//!
+//! Exposes the pipeline_event table, which records what happened to each
+//! transcription (pasted, expanded or matched to a command) and how commands
+//! run from speech or the UI turned out.
+
+use tauri::State;
+
+use crate::turso::{PipelineEvent, PipelineEventFilter};
+
+use super::TursoClientState;
+
+/// List pipeline events, newest first
+///
+/// All filter fields are optional; without a filter every event is returned.
+#[tauri::command]
+pub async fn list_pipeline_events(
+    turso_client: State<'_, TursoClientState>,
+    filter: Option<PipelineEventFilter>,
+) -> Result<Vec<PipelineEvent>, String> {
+    turso_client
+        .list_pipeline_events(&filter.unwrap_or_default())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete the whole pipeline log
+///
+/// Returns the number of events deleted.
+#[tauri::command]
+pub async fn clear_pipeline_events(
+    turso_client: State<'_, TursoClientState>,
+) -> Result<u64, String> {
+    turso_client
+        .clear_pipeline_events()
+        .await
+        .map_err(|e| e.to_string())
+}
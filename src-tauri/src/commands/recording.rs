@@ -2,18 +2,22 @@
 //!
 //! Contains commands for starting, stopping, and managing recordings.
 
-use tauri::{AppHandle, Emitter, Manager, State};
+use tauri::{AppHandle, Emitter, State};
 
 use crate::audio::{encode_wav, AudioDeviceError, SystemFileWriter, StopReason};
 use crate::emit_or_warn;
-use crate::events::{event_names, RecordingStartedPayload, RecordingStoppedPayload};
-use crate::recording::{AudioData, RecordingMetadata};
+use crate::events::{
+    event_names, RecordingPausedPayload, RecordingResumedPayload, RecordingStartedPayload,
+    RecordingStoppedPayload,
+};
+use crate::recording::{AudioData, RecordingMetadata, TrimConfig};
 use crate::turso::events as turso_events;
 
+use super::common::recordings_dir;
 use super::logic::{
     clear_last_recording_buffer_impl, delete_recording_impl, get_last_recording_buffer_impl,
-    get_recording_state_impl, list_recordings_impl, start_recording_impl,
-    stop_recording_impl_extended, PaginatedRecordingsResponse, RecordingContextData,
+    get_recording_state_impl, list_recordings_impl, pause_recording_impl, resume_recording_impl,
+    start_recording_impl, stop_recording_impl_extended, PaginatedRecordingsResponse, RecordingContextData,
     RecordingStateInfo, MICROPHONE_ERROR_MARKER,
 };
 use super::{AudioMonitorState, AudioThreadState, ProductionState, TranscriptionServiceState, TursoClientState};
@@ -101,12 +105,7 @@ pub async fn stop_recording(
     audio_thread: State<'_, AudioThreadState>,
     transcription_service: State<'_, TranscriptionServiceState>,
 ) -> Result<RecordingMetadata, String> {
-    // Get worktree-aware recordings directory
-    let worktree_context = app_handle
-        .try_state::<crate::worktree::WorktreeState>()
-        .and_then(|s| s.context.clone());
-    let recordings_dir = crate::paths::get_recordings_dir(worktree_context.as_ref())
-        .unwrap_or_else(|_| std::path::PathBuf::from(".").join("heycat").join("recordings"));
+    let recordings_dir = recordings_dir(&app_handle);
 
     let result = stop_recording_impl_extended(
         state.as_ref(),
@@ -176,8 +175,11 @@ pub async fn stop_recording(
             }
         );
 
-        // Trigger transcription via TranscriptionService
-        if !metadata.file_path.is_empty() {
+        // Long-form sessions transcribe the final segment into their document;
+        // otherwise trigger transcription via TranscriptionService
+        let handled_by_long_form =
+            super::long_form::finish_long_form_session(&app_handle, &metadata.file_path);
+        if !handled_by_long_form && !metadata.file_path.is_empty() {
             transcription_service.process_recording(metadata.file_path.clone());
         }
     }
@@ -185,6 +187,45 @@ pub async fn stop_recording(
     result.map(|r| r.metadata)
 }
 
+/// Pause the current recording without finalizing it
+///
+/// Audio capture is suspended and silence detection timing is frozen until
+/// `resume_recording` is called. Stopping while paused finalizes the recording.
+#[tauri::command]
+pub fn pause_recording(
+    app_handle: AppHandle,
+    state: State<'_, ProductionState>,
+    audio_thread: State<'_, AudioThreadState>,
+) -> Result<(), String> {
+    pause_recording_impl(state.as_ref(), Some(audio_thread.as_ref()))?;
+    emit_or_warn!(
+        app_handle,
+        event_names::RECORDING_PAUSED,
+        RecordingPausedPayload {
+            timestamp: crate::events::current_timestamp(),
+        }
+    );
+    Ok(())
+}
+
+/// Resume a paused recording, continuing the same recording file
+#[tauri::command]
+pub fn resume_recording(
+    app_handle: AppHandle,
+    state: State<'_, ProductionState>,
+    audio_thread: State<'_, AudioThreadState>,
+) -> Result<(), String> {
+    resume_recording_impl(state.as_ref(), Some(audio_thread.as_ref()))?;
+    emit_or_warn!(
+        app_handle,
+        event_names::RECORDING_RESUMED,
+        RecordingResumedPayload {
+            timestamp: crate::events::current_timestamp(),
+        }
+    );
+    Ok(())
+}
+
 /// Get the current recording state
 #[tauri::command]
 pub fn get_recording_state(state: State<'_, ProductionState>) -> Result<RecordingStateInfo, String> {
@@ -215,12 +256,7 @@ pub async fn list_recordings(
     limit: Option<usize>,
     offset: Option<usize>,
 ) -> Result<PaginatedRecordingsResponse, String> {
-    // Get worktree-aware recordings directory
-    let worktree_context = app_handle
-        .try_state::<crate::worktree::WorktreeState>()
-        .and_then(|s| s.context.clone());
-    let recordings_dir = crate::paths::get_recordings_dir(worktree_context.as_ref())
-        .unwrap_or_else(|_| std::path::PathBuf::from(".").join("heycat").join("recordings"));
+    let recordings_dir = recordings_dir(&app_handle);
 
     // Fetch recording context from Turso
     let mut recording_context: std::collections::HashMap<String, RecordingContextData> =
@@ -280,3 +316,16 @@ pub async fn delete_recording(
 
     delete_recording_impl(&file_path)
 }
+
+/// Update the silence trimming configuration
+///
+/// Called by the frontend after the `recording.trim.*` settings change.
+/// Takes effect for the next recording that is saved.
+#[tauri::command]
+pub fn set_trim_config(state: State<'_, ProductionState>, config: TrimConfig) -> Result<(), String> {
+    let mut manager = state
+        .lock()
+        .map_err(|_| "Unable to access recording state.".to_string())?;
+    manager.set_trim_config(config);
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! Silence detection settings commands for Tauri IPC.
+//!
+//! Exposes the auto-stop thresholds as persisted settings and a calibration
+//! run that proposes values for the current room and speaker.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter, State};
+use tauri_plugin_store::StoreExt;
+
+use crate::audio::{SampleSource, SwiftTapSource, TARGET_SAMPLE_RATE};
+use crate::audio_constants::{
+    CALIBRATION_ROOM_TONE_SECS, CALIBRATION_SPEECH_SECS, OPTIMAL_CHUNK_DURATION_MS,
+};
+use crate::emit_or_warn;
+use crate::events::silence_events::{self, SilenceCalibrationPhasePayload};
+use crate::recording::{
+    propose_config, vad_probabilities, CalibrationResult, RecordingDetectors, SilenceConfig,
+};
+
+use super::common::get_settings_file;
+use super::{DictationState, ListeningState};
+
+/// Get the silence detection settings used for auto-stop
+#[tauri::command]
+pub fn get_silence_settings(app_handle: AppHandle) -> SilenceConfig {
+    SilenceConfig::from_settings(&app_handle)
+}
+
+/// Validate and save silence detection settings
+///
+/// Applies to recordings started after the call.
+#[tauri::command]
+pub fn set_silence_settings(
+    app_handle: AppHandle,
+    detectors: State<'_, Arc<Mutex<RecordingDetectors>>>,
+    config: SilenceConfig,
+) -> Result<(), String> {
+    config.validate().map_err(|e| e.to_string())?;
+
+    let settings_file = get_settings_file(&app_handle);
+    let store = app_handle
+        .store(&settings_file)
+        .map_err(|e| format!("Failed to open settings: {}", e))?;
+    for (key, value) in config.to_settings() {
+        store.set(key, value);
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to persist settings: {}", e))?;
+
+    detectors
+        .lock()
+        .map_err(|_| "Unable to access recording detectors.".to_string())?
+        .set_silence_config(config);
+    Ok(())
+}
+
+/// Record room tone and speech, then propose silence detection settings
+///
+/// The user should stay quiet during the "roomTone" phase and talk normally
+/// during the "speech" phase; a `silence_calibration_phase` event marks the
+/// start of each. The proposal is returned but not saved.
+#[tauri::command]
+pub async fn calibrate_silence_detection(
+    app_handle: AppHandle,
+    listening_state: State<'_, ListeningState>,
+    dictation_state: State<'_, DictationState>,
+) -> Result<CalibrationResult, String> {
+    // Listening and dictation read the same sample tap
+    let tap_in_use = listening_state
+        .lock()
+        .map(|l| l.as_ref().is_some_and(|l| l.is_running()))
+        .unwrap_or(true)
+        || dictation_state.lock().map(|d| d.is_some()).unwrap_or(true);
+    if tap_in_use {
+        return Err("Stop listening mode and dictation before calibrating.".to_string());
+    }
+
+    let base = SilenceConfig::from_settings(&app_handle);
+    let emitter = app_handle.clone();
+    let (room_tone, speech) = tauri::async_runtime::spawn_blocking(move || {
+        let mut source = SwiftTapSource;
+        source.start();
+        // Discard anything buffered before the run started
+        let _ = source.read();
+
+        let room_tone = record_phase(
+            &emitter,
+            &mut source,
+            "roomTone",
+            CALIBRATION_ROOM_TONE_SECS,
+        );
+        let speech = record_phase(&emitter, &mut source, "speech", CALIBRATION_SPEECH_SECS);
+        source.stop();
+        (room_tone, speech)
+    })
+    .await
+    .map_err(|e| format!("Calibration task failed: {}", e))?;
+
+    let room_tone = vad_probabilities(&room_tone, TARGET_SAMPLE_RATE, base.vad_engine);
+    let speech = vad_probabilities(&speech, TARGET_SAMPLE_RATE, base.vad_engine);
+    let result = propose_config(&room_tone, &speech, OPTIMAL_CHUNK_DURATION_MS, &base)
+        .map_err(|e| e.to_string())?;
+
+    crate::info!(
+        "[silence] Calibration: noise={:.2} speech={:.2} longest pause={}ms -> {:?}",
+        result.noise_level,
+        result.speech_level,
+        result.longest_pause_ms,
+        result.proposed
+    );
+    Ok(result)
+}
+
+/// Announce a calibration phase and collect its samples
+fn record_phase(
+    app_handle: &AppHandle,
+    source: &mut SwiftTapSource,
+    phase: &str,
+    duration_secs: u32,
+) -> Vec<f32> {
+    emit_or_warn!(
+        app_handle,
+        silence_events::SILENCE_CALIBRATION_PHASE,
+        SilenceCalibrationPhasePayload {
+            phase: phase.to_string(),
+            duration_secs,
+        }
+    );
+    std::thread::sleep(Duration::from_secs(duration_secs as u64));
+    source.read()
+}
@@ -7,12 +7,14 @@ use tauri::{AppHandle, State};
 use tauri::Emitter;
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
+use crate::app::state::OutputHistoryState;
 use crate::emit_or_warn;
 use crate::events::{
     event_names, TranscriptionCompletedPayload, TranscriptionErrorPayload,
     TranscriptionStartedPayload,
 };
 use crate::parakeet::SharedTranscriptionModel;
+use crate::transcription::scratch_last_output;
 use crate::turso::events as turso_events;
 
 use super::logic::transcribe_file_impl;
@@ -171,3 +173,17 @@ pub async fn get_transcriptions_by_recording(
         })
         .map_err(|e| format!("Failed to get transcriptions: {}", e))
 }
+
+/// Remove the last text pasted or typed into the frontmost app ("scratch that")
+///
+/// Returns the number of characters deleted.
+#[tauri::command]
+pub async fn scratch_that(
+    app_handle: AppHandle,
+    output_history: State<'_, OutputHistoryState>,
+) -> Result<u32, String> {
+    scratch_last_output(&app_handle, &output_history)
+        .await
+        .map(|output| output.backspaces())
+        .map_err(|e| e.to_string())
+}
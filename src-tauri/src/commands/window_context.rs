@@ -6,8 +6,8 @@
 
 use crate::turso::{events as turso_events, TursoClient};
 use crate::window_context::{
-    get_active_window, get_running_applications, ActiveWindowInfo, OverrideMode,
-    RunningApplication, WindowContext, WindowContextStoreError, WindowMatcher,
+    get_active_window, get_running_applications, ActiveWindowInfo, CommandPrefixMode,
+    OverrideMode, RunningApplication, WindowContext, WindowContextStoreError, WindowMatcher,
 };
 use std::sync::Arc;
 use tauri::{AppHandle, State};
@@ -79,6 +79,7 @@ pub async fn add_window_context(
     dictionary_entry_ids: Option<Vec<String>>,
     enabled: Option<bool>,
     priority: Option<i32>,
+    command_prefix: Option<String>,
 ) -> Result<WindowContext, String> {
     // Validate: name cannot be empty
     if name.trim().is_empty() {
@@ -108,6 +109,7 @@ pub async fn add_window_context(
     let dictionary_entry_ids_val = dictionary_entry_ids.clone().unwrap_or_default();
     let enabled_val = enabled.unwrap_or(true);
     let priority_val = priority.unwrap_or(0);
+    let command_prefix_val = parse_command_prefix(command_prefix.as_deref());
 
     // Add context to Turso
     let context = turso_client
@@ -120,6 +122,7 @@ pub async fn add_window_context(
             dictionary_entry_ids_val,
             enabled_val,
             priority_val,
+            command_prefix_val,
         )
         .await
         .map_err(to_user_error)?;
@@ -150,6 +153,7 @@ pub async fn update_window_context(
     dictionary_entry_ids: Option<Vec<String>>,
     enabled: Option<bool>,
     priority: Option<i32>,
+    command_prefix: Option<String>,
 ) -> Result<(), String> {
     // Validate: name cannot be empty
     if name.trim().is_empty() {
@@ -188,6 +192,7 @@ pub async fn update_window_context(
         dictionary_entry_ids: dictionary_entry_ids.unwrap_or_default(),
         enabled: enabled.unwrap_or(true),
         priority: priority.unwrap_or(0),
+        command_prefix: parse_command_prefix(command_prefix.as_deref()),
     };
 
     // Update context in Turso
@@ -235,3 +240,12 @@ fn parse_override_mode(mode: Option<&str>) -> OverrideMode {
         _ => OverrideMode::Merge,
     }
 }
+
+/// Parse command prefix mode from string
+fn parse_command_prefix(mode: Option<&str>) -> CommandPrefixMode {
+    match mode {
+        Some("required") => CommandPrefixMode::Required,
+        Some("off") => CommandPrefixMode::Off,
+        _ => CommandPrefixMode::Inherit,
+    }
+}
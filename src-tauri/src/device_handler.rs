@@ -1,8 +1,10 @@
 //! Audio device change handler for macOS.
 //!
 //! This module handles audio device connection/disconnection notifications from
-//! Core Audio and coordinates restarting the audio engine to ensure fresh
-//! hardware connection after device changes.
+//! Core Audio. When the active device disappears, capture falls back to the next
+//! device in the user's priority list (and returns when a preferred device comes
+//! back), without interrupting a recording in progress. Otherwise the audio
+//! engine is restarted to ensure a fresh hardware connection.
 //!
 //! Includes coordination with user-initiated device changes to prevent race
 //! conditions when both the user and automatic handler try to control the
@@ -11,8 +13,13 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::OnceLock;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
 use tokio::sync::Mutex as TokioMutex;
 
+use crate::audio::{list_input_devices, DevicePriority};
+use crate::emit_or_warn;
+use crate::events::audio_device_events::{self, AudioDeviceSwitchedPayload};
+
 /// Static storage for the device change handler state.
 /// Uses OnceLock for safe, one-time initialization.
 static DEVICE_HANDLER: OnceLock<DeviceHandlerState> = OnceLock::new();
@@ -32,20 +39,18 @@ const DEBOUNCE_DELAY_MS: u64 = 300;
 static RESTART_DEBOUNCE: OnceLock<TokioMutex<Option<tokio::task::JoinHandle<()>>>> = OnceLock::new();
 
 /// State required for handling device change events.
-/// Note: This struct only contains a `bool` which is inherently Send + Sync,
-/// so no manual unsafe impl is needed.
-#[derive(Debug)]
+/// Note: AppHandle is Send + Sync, so no manual unsafe impl is needed.
 struct DeviceHandlerState {
-    /// Marker to indicate handler is initialized (no actual state needed)
-    _initialized: bool,
+    /// Used to read the device priority list and emit switch events
+    app_handle: AppHandle,
 }
 
 /// Initialize the device change handler.
 ///
 /// This should be called once during app setup, typically in lib.rs.
-/// The handler will restart the audio engine when devices connect/disconnect.
-pub fn init_device_change_handler() {
-    let state = DeviceHandlerState { _initialized: true };
+/// The handler will switch or restart the audio engine when devices connect/disconnect.
+pub fn init_device_change_handler(app_handle: AppHandle) {
+    let state = DeviceHandlerState { app_handle };
 
     if DEVICE_HANDLER.set(state).is_err() {
         crate::warn!("Device change handler already initialized");
@@ -133,13 +138,113 @@ extern "C" fn on_device_change() {
                 return;
             }
 
-            restart_audio_engine_async().await;
+            handle_device_change_async().await;
         });
 
         *guard = Some(handle);
     });
 }
 
+/// Re-apply the device priority list to the running engine.
+///
+/// Call this after the priority list changes so a newly preferred device
+/// that is already connected takes effect immediately.
+pub fn apply_device_priority() {
+    tauri::async_runtime::spawn(handle_device_change_async());
+}
+
+/// Async task that reacts to a device change.
+///
+/// Switches to the device chosen by the priority list when the active device
+/// disappeared or a more preferred one appeared; otherwise restarts the
+/// engine for a fresh hardware connection.
+async fn handle_device_change_async() {
+    let Some(state) = DEVICE_HANDLER.get() else {
+        crate::error!("Device change handler not initialized - cannot apply device change");
+        return;
+    };
+
+    let audio_running = tauri::async_runtime::spawn_blocking(crate::swift::audio_engine_is_running)
+        .await
+        .unwrap_or(false);
+    if !audio_running {
+        crate::debug!("Audio engine was not running - no device change needed");
+        return;
+    }
+
+    let priority = DevicePriority::from_settings(&state.app_handle);
+    let devices = tauri::async_runtime::spawn_blocking(|| {
+        let available = list_input_devices();
+        (available, crate::swift::audio_engine_current_device())
+    })
+    .await;
+    let Ok((available, current)) = devices else {
+        crate::warn!("Failed to query audio devices after device change");
+        return;
+    };
+
+    match priority.plan_switch(&available, current.as_deref()) {
+        Some(switch) => {
+            switch_audio_device(&state.app_handle, current, switch.device, switch.reason).await
+        }
+        None => restart_audio_engine_async(current).await,
+    }
+}
+
+/// Move the running engine to another input device.
+///
+/// The Swift engine keeps its capture file across a device switch, so a
+/// recording in progress continues on the new device.
+async fn switch_audio_device(
+    app_handle: &AppHandle,
+    previous_device: Option<String>,
+    device: Option<String>,
+    reason: crate::audio::DeviceSwitchReason,
+) {
+    let recording_active =
+        tauri::async_runtime::spawn_blocking(crate::swift::audio_engine_is_capturing)
+            .await
+            .unwrap_or(false);
+
+    crate::info!(
+        "Switching audio device ({:?}): {:?} -> {:?} (recording active: {})",
+        reason,
+        previous_device,
+        device,
+        recording_active
+    );
+
+    // The switch itself fires device notifications; don't react to our own change
+    mark_user_device_change();
+    let target = device.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        crate::swift::audio_engine_set_device(target.as_deref())
+    })
+    .await;
+
+    match result {
+        Ok(crate::swift::AudioEngineResult::Ok) => {
+            emit_or_warn!(
+                app_handle,
+                audio_device_events::AUDIO_DEVICE_SWITCHED,
+                AudioDeviceSwitchedPayload {
+                    previous_device,
+                    device,
+                    reason,
+                    recording_active,
+                    timestamp: crate::events::current_timestamp(),
+                }
+            );
+        }
+        Ok(crate::swift::AudioEngineResult::Failed(e)) => {
+            crate::error!("Failed to switch audio device: {}", e);
+        }
+        Err(e) => {
+            crate::error!("Audio device switch task panicked: {}", e);
+        }
+    }
+}
+
 /// Async task that restarts the audio engine if it was running.
 ///
 /// This ensures a fresh hardware connection after device changes.
@@ -148,8 +253,8 @@ extern "C" fn on_device_change() {
 /// 2. Check if audio engine is running
 /// 3. Stop the audio engine
 /// 4. Wait 200ms for Core Audio cleanup
-/// 5. Start the audio engine with default device
-async fn restart_audio_engine_async() {
+/// 5. Start the audio engine with the device it was using
+async fn restart_audio_engine_async(device: Option<String>) {
     // Check if audio CAPTURE is in progress - if so, skip auto-restart to preserve recording
     // The Swift side will handle device switching while preserving capture state
     let is_capturing =
@@ -183,9 +288,11 @@ async fn restart_audio_engine_async() {
     // Wait 200ms for Core Audio cleanup
     tokio::time::sleep(std::time::Duration::from_millis(200)).await;
 
-    // Start the audio engine with default device
-    let start_result =
-        tauri::async_runtime::spawn_blocking(|| crate::swift::audio_engine_start(None)).await;
+    // Start the audio engine with the same device
+    let start_result = tauri::async_runtime::spawn_blocking(move || {
+        crate::swift::audio_engine_start(device.as_deref())
+    })
+    .await;
 
     match start_result {
         Ok(crate::swift::AudioEngineResult::Ok) => {
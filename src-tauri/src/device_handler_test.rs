@@ -29,7 +29,7 @@ fn test_suppression_window_is_1000ms() {
 
 #[test]
 fn test_device_handler_state_is_send_sync() {
-    // DeviceHandlerState only contains a `bool` which is inherently Send + Sync.
+    // DeviceHandlerState only contains an AppHandle, which is Send + Sync.
     // No manual unsafe impl is needed - the compiler auto-derives these traits.
     // This test documents the requirement: if DeviceHandlerState ever gains
     // non-Send/Sync fields, compilation will fail because OnceLock<T> requires
@@ -10,6 +10,8 @@ pub mod event_names {
     pub const RECORDING_STARTED: &str = "recording_started";
     pub const RECORDING_STOPPED: &str = "recording_stopped";
     pub const RECORDING_CANCELLED: &str = "recording_cancelled";
+    pub const RECORDING_PAUSED: &str = "recording_paused";
+    pub const RECORDING_RESUMED: &str = "recording_resumed";
     pub const RECORDING_ERROR: &str = "recording_error";
     pub const AUDIO_DEVICE_ERROR: &str = "audio_device_error";
     pub const AUDIO_LEVEL: &str = "audio-level";
@@ -26,6 +28,7 @@ pub mod command_events {
     pub const COMMAND_EXECUTED: &str = "command_executed";
     pub const COMMAND_FAILED: &str = "command_failed";
     pub const COMMAND_AMBIGUOUS: &str = "command_ambiguous";
+    pub const COMMAND_CONFIRMATION_REQUIRED: &str = "command_confirmation_required";
 }
 
 /// Hotkey-related event names
@@ -50,6 +53,194 @@ pub trait HotkeyEventEmitter: Send + Sync {
     fn emit_key_blocking_unavailable(&self, payload: hotkey_events::KeyBlockingUnavailablePayload);
 }
 
+/// Long-form recording event names
+pub mod long_form_events {
+    pub const RECORDING_DURATION_WARNING: &str = "recording_duration_warning";
+    pub const LONG_FORM_SEGMENT_TRANSCRIBED: &str = "long_form_segment_transcribed";
+
+    /// Payload for recording_duration_warning event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct RecordingDurationWarningPayload {
+        /// Seconds left before the recording is stopped automatically
+        pub remaining_secs: u64,
+        /// Configured maximum duration in seconds
+        pub max_duration_secs: u64,
+        /// ISO 8601 timestamp when the warning was raised
+        pub timestamp: String,
+    }
+
+    /// Payload for long_form_segment_transcribed event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LongFormSegmentTranscribedPayload {
+        /// ID of the long-form document the segment was appended to
+        pub document_id: String,
+        /// Zero-based index of the segment within the session
+        pub segment_index: u32,
+        /// Transcribed text of this segment
+        pub text: String,
+    }
+}
+
+/// Trait for emitting long-form recording events
+/// Allows mocking in tests while using real Tauri AppHandle in production
+pub trait LongFormEventEmitter: Send + Sync {
+    /// Emit recording_duration_warning event
+    fn emit_recording_duration_warning(
+        &self,
+        payload: long_form_events::RecordingDurationWarningPayload,
+    );
+
+    /// Emit long_form_segment_transcribed event
+    fn emit_long_form_segment_transcribed(
+        &self,
+        payload: long_form_events::LongFormSegmentTranscribedPayload,
+    );
+}
+
+/// Audio device event names
+pub mod audio_device_events {
+    use crate::audio::DeviceSwitchReason;
+
+    pub const AUDIO_DEVICE_SWITCHED: &str = "audio_device_switched";
+
+    /// Payload for audio_device_switched event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AudioDeviceSwitchedPayload {
+        /// Device used before the switch (None = system default)
+        pub previous_device: Option<String>,
+        /// Device used after the switch (None = system default)
+        pub device: Option<String>,
+        /// Why the switch happened: "fallback" or "restored"
+        pub reason: DeviceSwitchReason,
+        /// Whether a recording continued across the switch
+        pub recording_active: bool,
+        /// ISO 8601 timestamp of the switch
+        pub timestamp: String,
+    }
+}
+
+/// Silence detection event names
+pub mod silence_events {
+    use crate::recording::VadEngine;
+
+    pub const SILENCE_CALIBRATION_PHASE: &str = "silence_calibration_phase";
+
+    /// Payload for silence_calibration_phase event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SilenceCalibrationPhasePayload {
+        /// Phase now being recorded: "roomTone" or "speech"
+        pub phase: String,
+        /// How long this phase records for (seconds)
+        pub duration_secs: u32,
+    }
+
+    pub const VAD_FALLBACK_ACTIVE: &str = "vad_fallback_active";
+
+    /// Payload for vad_fallback_active event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct VadFallbackActivePayload {
+        /// Engine the settings asked for
+        pub requested_engine: VadEngine,
+        /// Engine actually detecting speech
+        pub active_engine: VadEngine,
+        /// Why the requested engine could not be used
+        pub reason: String,
+        /// ISO 8601 timestamp of the fallback
+        pub timestamp: String,
+    }
+}
+
+/// Live speech activity event names
+pub mod speech_activity_events {
+    pub const SPEECH_ACTIVITY: &str = "speech_activity";
+
+    /// What a speech_activity event reports
+    #[derive(Debug, Clone, Copy, serde::Serialize, PartialEq, Eq)]
+    #[serde(rename_all = "camelCase")]
+    pub enum SpeechActivityKind {
+        /// The VAD started hearing speech
+        SpeechStarted,
+        /// The VAD stopped hearing speech
+        SpeechEnded,
+        /// Periodic probability and countdown update
+        Update,
+    }
+
+    /// Payload for speech_activity event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SpeechActivityPayload {
+        /// What changed
+        pub kind: SpeechActivityKind,
+        /// Highest VAD speech probability in the last analysed frame (0.0 - 1.0)
+        pub probability: f32,
+        /// Whether the last analysed frame contained speech
+        pub is_speech: bool,
+        /// Milliseconds until silence auto-stops the recording (None while speaking
+        /// or before any speech)
+        pub stop_in_ms: Option<u64>,
+        /// ISO 8601 timestamp of the event
+        pub timestamp: String,
+    }
+}
+
+/// Listening mode (wake phrase) event names
+pub mod listening_events {
+    pub const LISTENING_STATUS_CHANGED: &str = "listening_status_changed";
+    pub const WAKE_PHRASE_DETECTED: &str = "wake_phrase_detected";
+
+    /// Payload for listening_status_changed event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ListeningStatusChangedPayload {
+        /// Whether the wake phrase listener is running
+        pub listening: bool,
+        /// Configured wake phrase
+        pub wake_phrase: String,
+    }
+
+    /// Payload for wake_phrase_detected event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct WakePhraseDetectedPayload {
+        /// Transcript of the window that matched the wake phrase
+        pub transcript: String,
+        /// Whether a recording was started in response
+        pub recording_started: bool,
+        /// ISO 8601 timestamp of the detection
+        pub timestamp: String,
+    }
+}
+
+/// Continuous dictation event names
+pub mod dictation_events {
+    pub const CONTINUOUS_DICTATION_STARTED: &str = "continuous_dictation_started";
+    pub const CONTINUOUS_DICTATION_STOPPED: &str = "continuous_dictation_stopped";
+
+    /// Payload for continuous_dictation_started event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ContinuousDictationStartedPayload {
+        /// ISO 8601 timestamp when the session started
+        pub timestamp: String,
+    }
+
+    /// Payload for continuous_dictation_stopped event
+    #[derive(Debug, Clone, serde::Serialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ContinuousDictationStoppedPayload {
+        /// Number of utterances delivered during the session
+        pub utterance_count: u64,
+        /// ISO 8601 timestamp when the session stopped
+        pub timestamp: String,
+    }
+}
+
 /// Dictionary-related event names
 pub mod dictionary_events {
     pub const DICTIONARY_UPDATED: &str = "dictionary_updated";
@@ -164,6 +355,20 @@ pub struct RecordingCancelledPayload {
     pub timestamp: String,
 }
 
+/// Payload for recording_paused event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RecordingPausedPayload {
+    /// ISO 8601 timestamp when recording was paused
+    pub timestamp: String,
+}
+
+/// Payload for recording_resumed event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RecordingResumedPayload {
+    /// ISO 8601 timestamp when recording was resumed
+    pub timestamp: String,
+}
+
 /// Payload for transcription_started event
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct TranscriptionStartedPayload {
@@ -218,6 +423,21 @@ pub struct CommandAmbiguousPayload {
     pub transcription: String,
     /// List of candidate commands
     pub candidates: Vec<CommandCandidate>,
+    /// How long a follow-up choice is accepted, in milliseconds
+    pub timeout_ms: u64,
+}
+
+/// Payload for command_confirmation_required event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CommandConfirmationRequiredPayload {
+    /// ID of the command waiting for confirmation
+    pub command_id: String,
+    /// Trigger phrase
+    pub trigger: String,
+    /// The transcribed text that matched the command
+    pub transcription: String,
+    /// How long the command waits for confirmation, in milliseconds
+    pub timeout_ms: u64,
 }
 
 /// Payload for command_executed event
@@ -229,6 +449,8 @@ pub struct CommandExecutedPayload {
     pub trigger: String,
     /// Result message
     pub message: String,
+    /// Additional result data from the action (e.g. per-step macro results)
+    pub data: Option<serde_json::Value>,
 }
 
 /// Payload for command_failed event
@@ -242,6 +464,8 @@ pub struct CommandFailedPayload {
     pub error_code: String,
     /// Error message
     pub error_message: String,
+    /// Additional error data from the action (e.g. per-step macro results)
+    pub data: Option<serde_json::Value>,
 }
 
 /// Trait for emitting recording events
@@ -256,8 +480,20 @@ pub trait RecordingEventEmitter: Send + Sync {
     /// Emit recording_cancelled event
     fn emit_recording_cancelled(&self, payload: RecordingCancelledPayload);
 
+    /// Emit recording_paused event
+    fn emit_recording_paused(&self, payload: RecordingPausedPayload);
+
+    /// Emit recording_resumed event
+    fn emit_recording_resumed(&self, payload: RecordingResumedPayload);
+
     /// Emit recording_error event
     fn emit_recording_error(&self, payload: RecordingErrorPayload);
+
+    /// Emit vad_fallback_active event
+    fn emit_vad_fallback_active(&self, payload: silence_events::VadFallbackActivePayload);
+
+    /// Emit speech_activity event
+    fn emit_speech_activity(&self, payload: speech_activity_events::SpeechActivityPayload);
 }
 
 /// Trait for emitting transcription events
@@ -287,6 +523,9 @@ pub trait CommandEventEmitter: Send + Sync {
 
     /// Emit command_ambiguous event
     fn emit_command_ambiguous(&self, payload: CommandAmbiguousPayload);
+
+    /// Emit command_confirmation_required event
+    fn emit_command_confirmation_required(&self, payload: CommandConfirmationRequiredPayload);
 }
 
 /// Get the current timestamp in ISO 8601 format
@@ -7,6 +7,8 @@ pub struct MockEventEmitter {
     pub started_events: Arc<Mutex<Vec<RecordingStartedPayload>>>,
     pub stopped_events: Arc<Mutex<Vec<RecordingStoppedPayload>>>,
     pub cancelled_events: Arc<Mutex<Vec<RecordingCancelledPayload>>>,
+    pub paused_events: Arc<Mutex<Vec<RecordingPausedPayload>>>,
+    pub resumed_events: Arc<Mutex<Vec<RecordingResumedPayload>>>,
     pub error_events: Arc<Mutex<Vec<RecordingErrorPayload>>>,
     pub transcription_started_events: Arc<Mutex<Vec<TranscriptionStartedPayload>>>,
     pub transcription_completed_events: Arc<Mutex<Vec<TranscriptionCompletedPayload>>>,
@@ -15,8 +17,12 @@ pub struct MockEventEmitter {
     pub command_executed_events: Arc<Mutex<Vec<CommandExecutedPayload>>>,
     pub command_failed_events: Arc<Mutex<Vec<CommandFailedPayload>>>,
     pub command_ambiguous_events: Arc<Mutex<Vec<CommandAmbiguousPayload>>>,
+    pub command_confirmation_required_events:
+        Arc<Mutex<Vec<CommandConfirmationRequiredPayload>>>,
     pub key_blocking_unavailable_events:
         Arc<Mutex<Vec<hotkey_events::KeyBlockingUnavailablePayload>>>,
+    pub vad_fallback_active_events: Arc<Mutex<Vec<silence_events::VadFallbackActivePayload>>>,
+    pub speech_activity_events: Arc<Mutex<Vec<speech_activity_events::SpeechActivityPayload>>>,
 }
 
 impl MockEventEmitter {
@@ -38,9 +44,28 @@ impl RecordingEventEmitter for MockEventEmitter {
         self.cancelled_events.lock().unwrap().push(payload);
     }
 
+    fn emit_recording_paused(&self, payload: RecordingPausedPayload) {
+        self.paused_events.lock().unwrap().push(payload);
+    }
+
+    fn emit_recording_resumed(&self, payload: RecordingResumedPayload) {
+        self.resumed_events.lock().unwrap().push(payload);
+    }
+
     fn emit_recording_error(&self, payload: RecordingErrorPayload) {
         self.error_events.lock().unwrap().push(payload);
     }
+
+    fn emit_vad_fallback_active(&self, payload: silence_events::VadFallbackActivePayload) {
+        self.vad_fallback_active_events
+            .lock()
+            .unwrap()
+            .push(payload);
+    }
+
+    fn emit_speech_activity(&self, payload: speech_activity_events::SpeechActivityPayload) {
+        self.speech_activity_events.lock().unwrap().push(payload);
+    }
 }
 
 impl TranscriptionEventEmitter for MockEventEmitter {
@@ -82,6 +107,13 @@ impl CommandEventEmitter for MockEventEmitter {
     fn emit_command_ambiguous(&self, payload: CommandAmbiguousPayload) {
         self.command_ambiguous_events.lock().unwrap().push(payload);
     }
+
+    fn emit_command_confirmation_required(&self, payload: CommandConfirmationRequiredPayload) {
+        self.command_confirmation_required_events
+            .lock()
+            .unwrap()
+            .push(payload);
+    }
 }
 
 impl HotkeyEventEmitter for MockEventEmitter {
@@ -143,6 +175,7 @@ fn test_mock_emitter_records_recording_events() {
             file_path: "/tmp/test.wav".to_string(),
             sample_count: 48000,
             stop_reason: None,
+            trimmed_duration_secs: None,
         },
     });
     emitter.emit_recording_error(RecordingErrorPayload {
@@ -198,22 +231,39 @@ fn test_mock_emitter_records_command_events() {
         command_id: "1".to_string(),
         trigger: "open slack".to_string(),
         message: "Opened".to_string(),
+        data: None,
     });
     emitter.emit_command_failed(CommandFailedPayload {
         command_id: "1".to_string(),
         trigger: "test".to_string(),
         error_code: "ERR".to_string(),
         error_message: "error".to_string(),
+        data: None,
     });
     emitter.emit_command_ambiguous(CommandAmbiguousPayload {
         transcription: "open".to_string(),
         candidates: vec![],
+        timeout_ms: 8_000,
+    });
+    emitter.emit_command_confirmation_required(CommandConfirmationRequiredPayload {
+        command_id: "1".to_string(),
+        trigger: "delete everything".to_string(),
+        transcription: "delete everything".to_string(),
+        timeout_ms: 8_000,
     });
 
     assert_eq!(emitter.command_matched_events.lock().unwrap().len(), 1);
     assert_eq!(emitter.command_executed_events.lock().unwrap().len(), 1);
     assert_eq!(emitter.command_failed_events.lock().unwrap().len(), 1);
     assert_eq!(emitter.command_ambiguous_events.lock().unwrap().len(), 1);
+    assert_eq!(
+        emitter
+            .command_confirmation_required_events
+            .lock()
+            .unwrap()
+            .len(),
+        1
+    );
 }
 
 #[test]
@@ -246,3 +296,102 @@ fn test_mock_emitter_records_hotkey_events() {
     let payload = &emitter.key_blocking_unavailable_events.lock().unwrap()[0];
     assert_eq!(payload.reason, "Accessibility permission denied");
 }
+
+#[test]
+fn test_recording_duration_warning_payload_serializes_camel_case() {
+    use super::long_form_events::RecordingDurationWarningPayload;
+    let payload = RecordingDurationWarningPayload {
+        remaining_secs: 60,
+        max_duration_secs: 3600,
+        timestamp: current_timestamp(),
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("remainingSecs"));
+    assert!(json.contains("maxDurationSecs"));
+}
+
+#[test]
+fn test_wake_phrase_detected_payload_serializes_camel_case() {
+    use super::listening_events::WakePhraseDetectedPayload;
+    let payload = WakePhraseDetectedPayload {
+        transcript: "hey cat".to_string(),
+        recording_started: true,
+        timestamp: current_timestamp(),
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("recordingStarted"));
+}
+
+#[test]
+fn test_continuous_dictation_stopped_payload_serialization() {
+    use super::dictation_events::ContinuousDictationStoppedPayload;
+    let payload = ContinuousDictationStoppedPayload {
+        utterance_count: 3,
+        timestamp: current_timestamp(),
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("\"utteranceCount\":3"));
+}
+
+#[test]
+fn test_audio_device_switched_payload_serialization() {
+    use super::audio_device_events::AudioDeviceSwitchedPayload;
+    use crate::audio::DeviceSwitchReason;
+    let payload = AudioDeviceSwitchedPayload {
+        previous_device: Some("Headset".to_string()),
+        device: None,
+        reason: DeviceSwitchReason::Fallback,
+        recording_active: true,
+        timestamp: current_timestamp(),
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("\"previousDevice\":\"Headset\""));
+    assert!(json.contains("\"device\":null"));
+    assert!(json.contains("\"reason\":\"fallback\""));
+    assert!(json.contains("\"recordingActive\":true"));
+}
+
+#[test]
+fn test_silence_calibration_phase_payload_serialization() {
+    use super::silence_events::SilenceCalibrationPhasePayload;
+    let payload = SilenceCalibrationPhasePayload {
+        phase: "roomTone".to_string(),
+        duration_secs: 3,
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("\"phase\":\"roomTone\""));
+    assert!(json.contains("\"durationSecs\":3"));
+}
+
+#[test]
+fn test_vad_fallback_active_payload_serialization() {
+    use super::silence_events::VadFallbackActivePayload;
+    use crate::recording::VadEngine;
+    let payload = VadFallbackActivePayload {
+        requested_engine: VadEngine::Silero,
+        active_engine: VadEngine::Energy,
+        reason: "VAD initialization failed: model missing".to_string(),
+        timestamp: current_timestamp(),
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("\"requestedEngine\":\"silero\""));
+    assert!(json.contains("\"activeEngine\":\"energy\""));
+    assert!(json.contains("\"reason\":\"VAD initialization failed: model missing\""));
+}
+
+#[test]
+fn test_speech_activity_payload_serialization() {
+    use super::speech_activity_events::{SpeechActivityKind, SpeechActivityPayload};
+    let payload = SpeechActivityPayload {
+        kind: SpeechActivityKind::SpeechEnded,
+        probability: 0.25,
+        is_speech: false,
+        stop_in_ms: Some(1200),
+        timestamp: current_timestamp(),
+    };
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(json.contains("\"kind\":\"speechEnded\""));
+    assert!(json.contains("\"probability\":0.25"));
+    assert!(json.contains("\"isSpeech\":false"));
+    assert!(json.contains("\"stopInMs\":1200"));
+}
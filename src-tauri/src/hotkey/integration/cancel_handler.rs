@@ -32,7 +32,7 @@ where
     /// * `true` if cancellation was successful
     /// * `false` if not in recording state or an error occurred
     pub fn cancel_recording(&mut self, state: &Mutex<RecordingManager>, reason: &str) -> bool {
-        // Check current state - can only cancel from Recording state
+        // Check current state - can only cancel from Recording or Paused state
         let current_state = match state.lock() {
             Ok(guard) => guard.get_state(),
             Err(e) => {
@@ -45,7 +45,7 @@ where
             }
         };
 
-        if current_state != RecordingState::Recording {
+        if !matches!(current_state, RecordingState::Recording | RecordingState::Paused) {
             crate::debug!(
                 "Cancel ignored - not in recording state (current: {:?})",
                 current_state
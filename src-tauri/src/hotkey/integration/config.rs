@@ -9,6 +9,8 @@ use crate::hotkey::{NullShortcutBackend, ShortcutBackend};
 use crate::parakeet::SharedTranscriptionModel;
 use crate::recording::SilenceConfig;
 use crate::turso::TursoClient;
+use crate::voice_commands::confirmation::ConfirmationGate;
+use crate::voice_commands::disambiguation::Disambiguator;
 use crate::voice_commands::executor::ActionDispatcher;
 use crate::voice_commands::matcher::CommandMatcher;
 use std::sync::Arc;
@@ -79,6 +81,10 @@ pub struct VoiceCommandConfig<C: CommandEventEmitter> {
     pub matcher: Arc<CommandMatcher>,
     /// Dispatcher for executing matched command actions
     pub dispatcher: Arc<ActionDispatcher>,
+    /// Pending choice for ambiguous matches, shared with the transcription service
+    pub disambiguator: Arc<Disambiguator>,
+    /// Command waiting for confirmation, shared with the transcription service
+    pub confirmations: Arc<ConfirmationGate>,
     /// Event emitter for command events (matched, executed, failed, ambiguous)
     /// Optional to support incremental builder pattern
     pub emitter: Option<Arc<C>>,
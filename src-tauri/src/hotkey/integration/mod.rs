@@ -9,8 +9,10 @@
 //! - `toggle_handler`: Handle toggle mode (press once to start, again to stop)
 //! - `ptt_handler`: Handle push-to-talk mode (hold to record, release to stop)
 //! - `cancel_handler`: Handle recording cancellation via double-tap Escape
+//! - `pause_handler`: Handle pause/resume of an active recording via the pause hotkey
 //! - `transcription_runner`: Core transcription execution and voice command matching
 //! - `silence_handler`: Silence detection for auto-stop recordings
+//! - `wake_phrase_handler`: Start hands-free recordings from the listening mode
 //! - `escape_handler`: Escape key listener registration/unregistration
 //! - `clipboard_helper`: Clipboard and paste simulation utilities
 
@@ -18,17 +20,23 @@ mod cancel_handler;
 mod clipboard_helper;
 pub mod config;
 mod escape_handler;
+mod pause_handler;
 mod ptt_handler;
 mod silence_handler;
 mod toggle_handler;
 mod transcription_runner;
+mod wake_phrase_handler;
 
 #[cfg(test)]
 mod cancel_handler_test;
 #[cfg(test)]
+mod pause_handler_test;
+#[cfg(test)]
 mod ptt_handler_test;
 #[cfg(test)]
 mod toggle_handler_test;
+#[cfg(test)]
+mod wake_phrase_handler_test;
 
 pub use config::{
     EscapeKeyConfig, SilenceDetectionConfig, TranscriptionConfig, VoiceCommandConfig,
@@ -42,6 +50,8 @@ use crate::hotkey::{RecordingMode, ShortcutBackend};
 use crate::parakeet::SharedTranscriptionModel;
 use crate::recording::{RecordingDetectors, RecordingManager, SilenceConfig};
 use crate::turso::TursoClient;
+use crate::voice_commands::confirmation::ConfirmationGate;
+use crate::voice_commands::disambiguation::Disambiguator;
 use crate::voice_commands::executor::ActionDispatcher;
 use crate::voice_commands::matcher::CommandMatcher;
 use std::sync::atomic::AtomicBool;
@@ -166,7 +176,7 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
         self
     }
 
-    /// Get the selected audio device from persistent settings store
+    /// Get the most preferred connected audio device from persistent settings store
     pub(crate) fn get_selected_audio_device(&self) -> Option<String> {
         use crate::util::SettingsAccess;
         struct OptionalAppHandle<'a>(&'a Option<AppHandle>);
@@ -175,7 +185,7 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
                 self.0.as_ref()
             }
         }
-        OptionalAppHandle(&self.app_handle).get_setting("audio.selectedDevice")
+        crate::audio::preferred_input_device(&OptionalAppHandle(&self.app_handle))
     }
 
     /// Add an audio thread handle (builder pattern)
@@ -244,6 +254,8 @@ impl<R: RecordingEventEmitter, T: TranscriptionEventEmitter + 'static, C: Comman
                 turso_client,
                 matcher: Arc::new(CommandMatcher::new()),
                 dispatcher: Arc::new(ActionDispatcher::new()),
+                disambiguator: Arc::new(Disambiguator::new()),
+                confirmations: Arc::new(ConfirmationGate::new()),
                 emitter: None,
             });
         }
@@ -0,0 +1,84 @@
+//! Pause/resume handler for HotkeyIntegration.
+//!
+//! Handles the pause hotkey, which suspends an active recording without
+//! finalizing it and resumes it into the same recording on the next press.
+
+use crate::commands::logic::{pause_recording_impl, resume_recording_impl};
+use crate::events::{
+    current_timestamp, RecordingErrorPayload, RecordingEventEmitter, RecordingPausedPayload,
+    RecordingResumedPayload,
+};
+use crate::recording::{RecordingManager, RecordingState};
+use std::sync::Mutex;
+
+use super::HotkeyIntegration;
+
+impl<R, T, C> HotkeyIntegration<R, T, C>
+where
+    R: RecordingEventEmitter,
+    T: crate::events::TranscriptionEventEmitter + 'static,
+    C: crate::events::CommandEventEmitter + 'static,
+{
+    /// Handle the pause hotkey
+    ///
+    /// Toggles between Recording and Paused. Presses while Idle or Processing
+    /// are ignored.
+    ///
+    /// Returns true if the recording was paused or resumed, false otherwise
+    pub fn handle_pause_toggle(&mut self, state: &Mutex<RecordingManager>) -> bool {
+        let current_state = match state.lock() {
+            Ok(guard) => guard.get_state(),
+            Err(e) => {
+                crate::error!("Failed to acquire lock: {}", e);
+                self.recording_emitter
+                    .emit_recording_error(RecordingErrorPayload {
+                        message: "Internal error: state lock poisoned".to_string(),
+                    });
+                return false;
+            }
+        };
+
+        crate::debug!("Pause hotkey received, current state: {:?}", current_state);
+
+        match current_state {
+            RecordingState::Recording => {
+                match pause_recording_impl(state, self.audio_thread.as_deref()) {
+                    Ok(()) => {
+                        self.recording_emitter
+                            .emit_recording_paused(RecordingPausedPayload {
+                                timestamp: current_timestamp(),
+                            });
+                        true
+                    }
+                    Err(e) => {
+                        crate::error!("Failed to pause recording: {}", e);
+                        self.recording_emitter
+                            .emit_recording_error(RecordingErrorPayload { message: e });
+                        false
+                    }
+                }
+            }
+            RecordingState::Paused => {
+                match resume_recording_impl(state, self.audio_thread.as_deref()) {
+                    Ok(()) => {
+                        self.recording_emitter
+                            .emit_recording_resumed(RecordingResumedPayload {
+                                timestamp: current_timestamp(),
+                            });
+                        true
+                    }
+                    Err(e) => {
+                        crate::error!("Failed to resume recording: {}", e);
+                        self.recording_emitter
+                            .emit_recording_error(RecordingErrorPayload { message: e });
+                        false
+                    }
+                }
+            }
+            RecordingState::Idle | RecordingState::Processing => {
+                crate::debug!("Pause hotkey ignored - no active recording");
+                false
+            }
+        }
+    }
+}
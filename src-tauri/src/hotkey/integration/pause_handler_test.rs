@@ -0,0 +1,58 @@
+//! Pause/resume tests for hotkey-to-recording integration.
+
+use super::HotkeyIntegration;
+use crate::recording::{RecordingManager, RecordingState};
+use crate::test_utils::{ensure_test_model_files, MockEmitter};
+use std::sync::Mutex;
+
+/// Type alias for HotkeyIntegration with MockEmitter for all parameters
+type TestIntegration = HotkeyIntegration<MockEmitter, MockEmitter, MockEmitter>;
+
+#[test]
+fn test_pause_ignored_when_idle() {
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::new(emitter.clone());
+    let state = Mutex::new(RecordingManager::new());
+
+    assert!(!integration.handle_pause_toggle(&state));
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Idle);
+    assert_eq!(emitter.paused_count(), 0);
+}
+
+#[test]
+fn test_pause_then_resume_same_recording() {
+    ensure_test_model_files();
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0);
+    let state = Mutex::new(RecordingManager::new());
+
+    integration.handle_toggle(&state);
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Recording);
+
+    assert!(integration.handle_pause_toggle(&state));
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Paused);
+    assert_eq!(emitter.paused_count(), 1);
+
+    assert!(integration.handle_pause_toggle(&state));
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Recording);
+    assert_eq!(emitter.resumed_count(), 1);
+
+    // Only one recording was started and nothing was stopped along the way
+    assert_eq!(emitter.started_count(), 1);
+    assert_eq!(emitter.stopped_count(), 0);
+}
+
+#[test]
+fn test_toggle_while_paused_stops_recording() {
+    ensure_test_model_files();
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0);
+    let state = Mutex::new(RecordingManager::new());
+
+    integration.handle_toggle(&state);
+    integration.handle_pause_toggle(&state);
+
+    assert!(integration.handle_toggle(&state));
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Idle);
+    assert_eq!(emitter.stopped_count(), 1);
+}
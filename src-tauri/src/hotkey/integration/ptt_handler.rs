@@ -90,7 +90,7 @@ where
                     }
                 }
             }
-            RecordingState::Recording => {
+            RecordingState::Recording | RecordingState::Paused => {
                 // Already recording - ignore (user might have double-pressed)
                 crate::debug!("PTT press ignored - already recording");
                 false
@@ -126,7 +126,7 @@ where
         crate::debug!("PTT release received, current state: {:?}", current_state);
 
         match current_state {
-            RecordingState::Recording => {
+            RecordingState::Recording | RecordingState::Paused => {
                 crate::info!("PTT: Stopping recording on key release...");
 
                 // Unregister Escape key listener
@@ -164,8 +164,17 @@ where
                             .emit_recording_stopped(RecordingStoppedPayload { metadata });
                         crate::debug!("PTT: Emitted recording_stopped event");
 
-                        // Auto-transcribe
-                        self.spawn_transcription(file_path_for_transcription);
+                        // Auto-transcribe unless a long-form session owns the recording
+                        let handled_by_long_form =
+                            self.app_handle.as_ref().is_some_and(|app_handle| {
+                                crate::commands::long_form::finish_long_form_session(
+                                    app_handle,
+                                    &file_path_for_transcription,
+                                )
+                            });
+                        if !handled_by_long_form {
+                            self.spawn_transcription(file_path_for_transcription);
+                        }
 
                         true
                     }
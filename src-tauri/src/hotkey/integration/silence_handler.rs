@@ -35,6 +35,14 @@ where
             return;
         }
 
+        self.start_silence_monitoring(recording_state);
+    }
+
+    /// Start silence monitoring regardless of the silence detection setting
+    ///
+    /// Used directly by wake phrase recordings, which have no hotkey press to
+    /// end them and must always stop on silence.
+    pub(crate) fn start_silence_monitoring(&self, recording_state: &Mutex<RecordingManager>) {
         // Check for required components
         let detectors = match &self.recording_detectors {
             Some(d) => d.clone(),
@@ -85,7 +93,6 @@ where
         };
 
         // Create transcription callback that calls spawn_transcription
-        // This is the same pattern used in wake word flow
         // Extract components from transcription config
         let shared_model = transcription_config.shared_model.clone();
         let transcription_emitter_for_callback = transcription_config.emitter.clone();
@@ -26,6 +26,7 @@ where
     /// Handle hotkey toggle - debounces rapid presses
     ///
     /// Toggles recording state (Idle → Recording → Idle) and emits events.
+    /// A paused recording is stopped and finalized like an active one.
     /// Delegates to unified command implementations for start/stop logic.
     ///
     /// Returns true if the toggle was accepted, false if debounced or busy
@@ -64,7 +65,7 @@ where
 
         match current_state {
             RecordingState::Idle => self.start_recording_toggle(state),
-            RecordingState::Recording => self.stop_recording_toggle(state),
+            RecordingState::Recording | RecordingState::Paused => self.stop_recording_toggle(state),
             RecordingState::Processing => {
                 // In Processing state - ignore toggle (busy)
                 crate::debug!("Toggle ignored - already processing");
@@ -74,7 +75,7 @@ where
     }
 
     /// Start recording in toggle mode
-    fn start_recording_toggle(&mut self, state: &Mutex<RecordingManager>) -> bool {
+    pub(super) fn start_recording_toggle(&mut self, state: &Mutex<RecordingManager>) -> bool {
         crate::info!("Starting recording from Idle state...");
 
         // Check model availability (TDT for batch transcription)
@@ -159,8 +160,17 @@ where
                     .emit_recording_stopped(RecordingStoppedPayload { metadata });
                 crate::debug!("Emitted recording_stopped event");
 
-                // Auto-transcribe if transcription manager is configured
-                self.spawn_transcription(file_path_for_transcription);
+                // Long-form sessions transcribe the final segment into their
+                // document; otherwise auto-transcribe if configured
+                let handled_by_long_form = self.app_handle.as_ref().is_some_and(|app_handle| {
+                    crate::commands::long_form::finish_long_form_session(
+                        app_handle,
+                        &file_path_for_transcription,
+                    )
+                });
+                if !handled_by_long_form {
+                    self.spawn_transcription(file_path_for_transcription);
+                }
 
                 true
             }
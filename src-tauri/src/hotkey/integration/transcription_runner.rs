@@ -4,12 +4,13 @@
 
 use crate::events::{
     current_timestamp, CommandAmbiguousPayload, CommandCandidate, CommandEventEmitter,
-    CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload,
     TranscriptionCompletedPayload, TranscriptionErrorPayload, TranscriptionEventEmitter,
     TranscriptionStartedPayload,
 };
 use crate::parakeet::{SharedTranscriptionModel, TranscriptionService};
 use crate::recording::RecordingManager;
+use crate::voice_commands::confirmation::{handle_follow_up, run_or_confirm};
+use crate::voice_commands::disambiguation::{PendingCandidate, Resolution};
 use crate::voice_commands::matcher::MatchResult;
 use crate::voice_commands::registry::CommandDefinition;
 use std::sync::{Arc, Mutex};
@@ -137,7 +138,7 @@ where
     /// Uses Tauri's async runtime for bounded async execution.
     /// No-op if transcription manager or transcription emitter is not configured.
     ///
-    /// This method is public so it can be called from the wake word recording flow
+    /// This method is public so it can be called from the wake phrase recording flow
     /// (via the coordinator) in addition to the hotkey recording flow.
     ///
     /// When a transcription_callback is configured (via with_transcription_callback),
@@ -191,17 +192,25 @@ where
         }
 
         // Optional voice command components from voice_commands config
-        let (turso_client, command_matcher, action_dispatcher, command_emitter) =
-            if let Some(ref vc) = self.voice_commands {
-                (
-                    Some(vc.turso_client.clone()),
-                    Some(vc.matcher.clone()),
-                    Some(vc.dispatcher.clone()),
-                    vc.emitter.clone(),
-                )
-            } else {
-                (None, None, None, None)
-            };
+        let (
+            turso_client,
+            command_matcher,
+            action_dispatcher,
+            command_emitter,
+            disambiguator,
+            confirmations,
+        ) = if let Some(ref vc) = self.voice_commands {
+            (
+                Some(vc.turso_client.clone()),
+                Some(vc.matcher.clone()),
+                Some(vc.dispatcher.clone()),
+                vc.emitter.clone(),
+                Some(vc.disambiguator.clone()),
+                Some(vc.confirmations.clone()),
+            )
+        } else {
+            (None, None, None, None, None, None)
+        };
 
         // Clone app_handle for clipboard access
         let app_handle = self.app_handle.clone();
@@ -244,9 +253,10 @@ where
                     cmd: CommandDefinition,
                     trigger: String,
                     confidence: f64,
+                    parameters: std::collections::HashMap<String, String>,
                 },
                 Ambiguous {
-                    candidates: Vec<CommandCandidate>,
+                    candidates: Vec<PendingCandidate>,
                 },
                 NoMatch,
             }
@@ -261,14 +271,37 @@ where
                 }
             };
 
-            let command_handled = if let (
+            // A waiting confirmation or ambiguous match takes the next utterance as its answer
+            let choice_handled = match (&confirmations, &action_dispatcher, &command_emitter) {
+                (Some(confirmations), Some(dispatcher), Some(emitter)) => {
+                    handle_follow_up(
+                        &text,
+                        dispatcher,
+                        emitter.as_ref(),
+                        confirmations,
+                        disambiguator.as_deref(),
+                    )
+                    .await
+                    .is_some()
+                }
+                _ => false,
+            };
+
+            let command_handled = if choice_handled {
+                true
+            } else if let (
                 Some(client),
                 Some(matcher),
                 Some(dispatcher),
                 Some(emitter),
-            ) =
-                (&turso_client, &command_matcher, &action_dispatcher, &command_emitter)
-            {
+                Some(confirmations),
+            ) = (
+                &turso_client,
+                &command_matcher,
+                &action_dispatcher,
+                &command_emitter,
+                &confirmations,
+            ) {
                 // Fetch all commands from Turso
                 let all_commands = match client.list_voice_commands().await {
                     Ok(commands) => commands,
@@ -291,38 +324,44 @@ where
                 let outcome = match match_result {
                     MatchResult::Exact {
                         command: matched_cmd,
-                        ..
+                        parameters,
                     } => match commands_by_id.get(&matched_cmd.id) {
                         Some(cmd) => MatchOutcome::Matched {
                             cmd: (*cmd).clone(),
                             trigger: matched_cmd.trigger.clone(),
                             confidence: 1.0,
+                            parameters,
                         },
                         None => MatchOutcome::NoMatch,
                     },
                     MatchResult::Fuzzy {
                         command: matched_cmd,
                         score,
-                        ..
+                        parameters,
                     } => match commands_by_id.get(&matched_cmd.id) {
                         Some(cmd) => MatchOutcome::Matched {
                             cmd: (*cmd).clone(),
                             trigger: matched_cmd.trigger.clone(),
                             confidence: score,
+                            parameters,
                         },
                         None => MatchOutcome::NoMatch,
                     },
                     MatchResult::Ambiguous { candidates } => {
-                        let candidate_data: Vec<_> = candidates
-                            .iter()
-                            .map(|c| CommandCandidate {
-                                id: c.command.id.to_string(),
-                                trigger: c.command.trigger.clone(),
-                                confidence: c.score,
+                        let pending: Vec<_> = candidates
+                            .into_iter()
+                            .filter_map(|c| {
+                                commands_by_id
+                                    .get(&c.command.id)
+                                    .map(|cmd| PendingCandidate {
+                                        command: (*cmd).clone(),
+                                        parameters: c.parameters,
+                                        confidence: c.score,
+                                    })
                             })
                             .collect();
                         MatchOutcome::Ambiguous {
-                            candidates: candidate_data,
+                            candidates: pending,
                         }
                     }
                     MatchResult::NoMatch => MatchOutcome::NoMatch,
@@ -333,6 +372,7 @@ where
                         cmd,
                         trigger,
                         confidence,
+                        parameters,
                     } => {
                         crate::info!(
                             "Command matched: {} (confidence: {:.2})",
@@ -340,43 +380,43 @@ where
                             confidence
                         );
 
-                        // Emit command_matched event
-                        emitter.emit_command_matched(CommandMatchedPayload {
+                        // Emit command_matched, then execute directly using await
+                        // (no new runtime needed!) or wait for confirmation
+                        let resolution = Resolution {
+                            candidate: PendingCandidate {
+                                command: cmd,
+                                parameters,
+                                confidence,
+                            },
                             transcription: text.clone(),
-                            command_id: cmd.id.to_string(),
-                            trigger: trigger.clone(),
-                            confidence,
-                        });
-
-                        // Execute command directly using await (no new runtime needed!)
-                        match dispatcher.execute(&cmd).await {
-                            Ok(action_result) => {
-                                crate::info!("Command executed: {}", action_result.message);
-                                emitter.emit_command_executed(CommandExecutedPayload {
-                                    command_id: cmd.id.to_string(),
-                                    trigger: trigger.clone(),
-                                    message: action_result.message,
-                                });
-                            }
-                            Err(action_error) => {
-                                crate::error!("Command execution failed: {}", action_error);
-                                emitter.emit_command_failed(CommandFailedPayload {
-                                    command_id: cmd.id.to_string(),
-                                    trigger: trigger.clone(),
-                                    error_code: action_error.code.to_string(),
-                                    error_message: action_error.message,
-                                });
-                            }
-                        }
+                        };
+                        run_or_confirm(dispatcher, emitter.as_ref(), confirmations, resolution)
+                            .await;
                         true // Command was handled
                     }
                     MatchOutcome::Ambiguous { candidates } => {
                         crate::info!("Ambiguous match: {} candidates", candidates.len());
+                        let candidate_data = candidates
+                            .iter()
+                            .map(|c| CommandCandidate {
+                                id: c.command.id.to_string(),
+                                trigger: c.command.trigger.clone(),
+                                confidence: c.confidence,
+                            })
+                            .collect();
+                        let timeout_ms = match &disambiguator {
+                            Some(disambiguator) => {
+                                disambiguator.begin(&text, candidates);
+                                disambiguator.timeout().as_millis() as u64
+                            }
+                            None => 0,
+                        };
 
                         // Emit command_ambiguous event for disambiguation UI
                         emitter.emit_command_ambiguous(CommandAmbiguousPayload {
                             transcription: text.clone(),
-                            candidates,
+                            candidates: candidate_data,
+                            timeout_ms,
                         });
                         true // Command matching was handled (ambiguous)
                     }
@@ -0,0 +1,56 @@
+//! Wake phrase handler for HotkeyIntegration.
+//!
+//! Starts a hands-free recording when the listening mode hears the wake
+//! phrase. The recording is identical to a toggle recording, except that it
+//! always stops on silence.
+
+use crate::events::{RecordingErrorPayload, RecordingEventEmitter};
+use crate::recording::{RecordingManager, RecordingState};
+use std::sync::Mutex;
+
+use super::HotkeyIntegration;
+
+impl<R, T, C> HotkeyIntegration<R, T, C>
+where
+    R: RecordingEventEmitter,
+    T: crate::events::TranscriptionEventEmitter + 'static,
+    C: crate::events::CommandEventEmitter + 'static,
+{
+    /// Handle a detected wake phrase
+    ///
+    /// Starts recording from Idle; any other state is left untouched.
+    /// The hotkey can still stop or cancel the recording as usual.
+    ///
+    /// Returns true if a recording was started
+    pub fn handle_wake_phrase(&mut self, state: &Mutex<RecordingManager>) -> bool {
+        let current_state = match state.lock() {
+            Ok(guard) => guard.get_state(),
+            Err(e) => {
+                crate::error!("Failed to acquire lock: {}", e);
+                self.recording_emitter
+                    .emit_recording_error(RecordingErrorPayload {
+                        message: "Internal error: state lock poisoned".to_string(),
+                    });
+                return false;
+            }
+        };
+
+        if current_state != RecordingState::Idle {
+            crate::debug!("Wake phrase ignored, current state: {:?}", current_state);
+            return false;
+        }
+
+        crate::info!("Starting recording from wake phrase...");
+        if !self.start_recording_toggle(state) {
+            return false;
+        }
+
+        // Hands-free recordings have no key press to end them, so silence
+        // monitoring runs even when auto-stop is disabled for hotkey recordings
+        if !self.silence.enabled {
+            self.start_silence_monitoring(state);
+        }
+
+        true
+    }
+}
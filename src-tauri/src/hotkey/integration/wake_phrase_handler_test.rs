@@ -0,0 +1,47 @@
+//! Wake phrase tests for hotkey-to-recording integration.
+
+use super::HotkeyIntegration;
+use crate::recording::{RecordingManager, RecordingState};
+use crate::test_utils::{ensure_test_model_files, MockEmitter};
+use std::sync::Mutex;
+
+/// Type alias for HotkeyIntegration with MockEmitter for all parameters
+type TestIntegration = HotkeyIntegration<MockEmitter, MockEmitter, MockEmitter>;
+
+#[test]
+fn test_wake_phrase_starts_recording_from_idle() {
+    ensure_test_model_files();
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::new(emitter.clone());
+    let state = Mutex::new(RecordingManager::new());
+
+    assert!(integration.handle_wake_phrase(&state));
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Recording);
+    assert_eq!(emitter.started_count(), 1);
+}
+
+#[test]
+fn test_wake_phrase_ignored_while_recording() {
+    ensure_test_model_files();
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0);
+    let state = Mutex::new(RecordingManager::new());
+
+    integration.handle_toggle(&state);
+    assert!(!integration.handle_wake_phrase(&state));
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Recording);
+    assert_eq!(emitter.started_count(), 1);
+}
+
+#[test]
+fn test_wake_phrase_recording_stops_with_toggle() {
+    ensure_test_model_files();
+    let emitter = MockEmitter::new();
+    let mut integration: TestIntegration = HotkeyIntegration::with_debounce(emitter.clone(), 0);
+    let state = Mutex::new(RecordingManager::new());
+
+    assert!(integration.handle_wake_phrase(&state));
+    assert!(integration.handle_toggle(&state));
+    assert_eq!(state.lock().unwrap().get_state(), RecordingState::Idle);
+    assert_eq!(emitter.stopped_count(), 1);
+}
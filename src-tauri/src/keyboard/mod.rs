@@ -2,6 +2,7 @@
 // Uses Core Graphics on macOS (for consistency with paste simulation)
 // Uses enigo crate for Windows and Linux support
 
+pub mod shortcut;
 pub mod synth;
 
 #[cfg(not(target_os = "macos"))]
@@ -0,0 +1,229 @@
+// Shortcut syntax for sending key combinations
+//
+// A shortcut is a list of key strokes separated by spaces or commas:
+//
+//   stroke   := combo ["*" count]
+//   combo    := (modifier "+")* key
+//   modifier := ctrl | control | shift | alt | option | opt
+//             | cmd | command | meta | super | win | mod
+//   key      := a single letter, digit or punctuation character (not ',')
+//             | enter | return | tab | space | esc | escape | backspace
+//             | delete | del | up | down | left | right | home | end
+//             | pageup | pgup | pagedown | pgdn | f1 .. f20
+//
+// Names are case-insensitive. `mod` is Cmd on macOS and Ctrl elsewhere, so
+// one command works on every platform. Examples:
+//
+//   "mod+s"          save file
+//   "mod+w"          close tab
+//   "ctrl+tab"       next tab
+//   "ctrl+shift+t"   reopen closed tab
+//   "down*3 enter"   move down three times, then press Enter
+//   "ctrl+k, ctrl+s" a chord of two strokes
+
+use serde::Serialize;
+
+/// Most times a single stroke may be repeated
+pub const MAX_STROKE_REPEAT: u32 = 50;
+/// Most key presses one shortcut may send, after repeats
+pub const MAX_KEY_PRESSES: u32 = 100;
+
+/// Errors from parsing a shortcut
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ShortcutError {
+    /// Nothing to press
+    #[error("Shortcut is empty")]
+    Empty,
+    /// Unknown key or modifier name
+    #[error("Unknown key '{0}'")]
+    UnknownKey(String),
+    /// A combo made only of modifiers, e.g. "ctrl+shift"
+    #[error("'{0}' has no key after its modifiers")]
+    MissingKey(String),
+    /// Repeat count that isn't a number in range
+    #[error("Repeat count in '{stroke}' must be between 1 and {max}")]
+    InvalidRepeat { stroke: String, max: u32 },
+    /// Total presses above the limit
+    #[error("Shortcut sends {count} key presses (max {max})")]
+    TooManyPresses { count: u32, max: u32 },
+}
+
+/// Modifier key held during a stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Modifier {
+    Control,
+    Shift,
+    Alt,
+    /// Cmd on macOS, the Windows/Super key elsewhere
+    Meta,
+}
+
+/// Non-character keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NamedKey {
+    Enter,
+    Tab,
+    Space,
+    Escape,
+    Backspace,
+    Delete,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    /// Function key F1-F20
+    F(u8),
+}
+
+/// Key pressed by a stroke
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Key {
+    /// Letter (always lowercase), digit or punctuation
+    Char(char),
+    Named(NamedKey),
+}
+
+/// Key plus the modifiers held while pressing it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct KeyCombo {
+    /// Sorted and without duplicates
+    pub modifiers: Vec<Modifier>,
+    pub key: Key,
+}
+
+/// One entry of a shortcut: a combo pressed `repeat` times
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct KeyStroke {
+    pub combo: KeyCombo,
+    pub repeat: u32,
+}
+
+/// Platform's primary shortcut modifier
+pub fn primary_modifier() -> Modifier {
+    if cfg!(target_os = "macos") {
+        Modifier::Meta
+    } else {
+        Modifier::Control
+    }
+}
+
+fn parse_modifier(name: &str) -> Option<Modifier> {
+    match name {
+        "ctrl" | "control" => Some(Modifier::Control),
+        "shift" => Some(Modifier::Shift),
+        "alt" | "option" | "opt" => Some(Modifier::Alt),
+        "cmd" | "command" | "meta" | "super" | "win" => Some(Modifier::Meta),
+        "mod" => Some(primary_modifier()),
+        _ => None,
+    }
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    let named = match name {
+        "enter" | "return" => NamedKey::Enter,
+        "tab" => NamedKey::Tab,
+        "space" => NamedKey::Space,
+        "esc" | "escape" => NamedKey::Escape,
+        "backspace" => NamedKey::Backspace,
+        "delete" | "del" => NamedKey::Delete,
+        "up" => NamedKey::Up,
+        "down" => NamedKey::Down,
+        "left" => NamedKey::Left,
+        "right" => NamedKey::Right,
+        "home" => NamedKey::Home,
+        "end" => NamedKey::End,
+        "pageup" | "pgup" => NamedKey::PageUp,
+        "pagedown" | "pgdn" => NamedKey::PageDown,
+        _ => {
+            if let Some(n) = name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                return (1..=20).contains(&n).then_some(Key::Named(NamedKey::F(n)));
+            }
+            let mut chars = name.chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_graphic() => Some(Key::Char(c)),
+                _ => None,
+            };
+        }
+    };
+    Some(Key::Named(named))
+}
+
+fn parse_combo(text: &str) -> Result<KeyCombo, ShortcutError> {
+    // "+" is a key of its own when it's the last part ("ctrl++")
+    let (head, key_name) = match text.strip_suffix("++") {
+        Some(head) => (head, "+"),
+        None if text == "+" => ("", "+"),
+        None => match text.rsplit_once('+') {
+            Some((head, key)) => (head, key),
+            None => ("", text),
+        },
+    };
+    if key_name.is_empty() || parse_modifier(key_name).is_some() {
+        return Err(ShortcutError::MissingKey(text.to_string()));
+    }
+
+    let mut modifiers = Vec::new();
+    for name in head.split('+').filter(|n| !n.is_empty()) {
+        let modifier =
+            parse_modifier(name).ok_or_else(|| ShortcutError::UnknownKey(name.to_string()))?;
+        modifiers.push(modifier);
+    }
+    modifiers.sort();
+    modifiers.dedup();
+
+    let key = parse_key(key_name).ok_or_else(|| ShortcutError::UnknownKey(key_name.to_string()))?;
+    Ok(KeyCombo { modifiers, key })
+}
+
+/// Parse a shortcut into its key strokes
+pub fn parse_shortcut(text: &str) -> Result<Vec<KeyStroke>, ShortcutError> {
+    let lowered = text.to_lowercase();
+    let mut strokes = Vec::new();
+    let mut presses: u32 = 0;
+
+    for stroke in lowered
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+    {
+        let (combo, repeat) = match stroke.rsplit_once('*') {
+            Some((combo, count)) if !combo.is_empty() && !count.is_empty() => {
+                let repeat = count
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| (1..=MAX_STROKE_REPEAT).contains(n))
+                    .ok_or_else(|| ShortcutError::InvalidRepeat {
+                        stroke: stroke.to_string(),
+                        max: MAX_STROKE_REPEAT,
+                    })?;
+                (combo, repeat)
+            }
+            _ => (stroke, 1),
+        };
+        let combo = parse_combo(combo)?;
+
+        presses += repeat;
+        if presses > MAX_KEY_PRESSES {
+            return Err(ShortcutError::TooManyPresses {
+                count: presses,
+                max: MAX_KEY_PRESSES,
+            });
+        }
+        strokes.push(KeyStroke { combo, repeat });
+    }
+
+    if strokes.is_empty() {
+        return Err(ShortcutError::Empty);
+    }
+    Ok(strokes)
+}
+
+#[cfg(test)]
+#[path = "shortcut_test.rs"]
+mod tests;
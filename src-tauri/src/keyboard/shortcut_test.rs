@@ -0,0 +1,117 @@
+use super::*;
+
+fn combo(modifiers: &[Modifier], key: Key) -> KeyCombo {
+    KeyCombo {
+        modifiers: modifiers.to_vec(),
+        key,
+    }
+}
+
+fn single(text: &str) -> KeyCombo {
+    let strokes = parse_shortcut(text).unwrap();
+    assert_eq!(strokes.len(), 1, "expected one stroke for {:?}", text);
+    assert_eq!(strokes[0].repeat, 1);
+    strokes[0].combo.clone()
+}
+
+#[test]
+fn test_parses_modifier_combos() {
+    assert_eq!(
+        single("ctrl+shift+t"),
+        combo(&[Modifier::Control, Modifier::Shift], Key::Char('t'))
+    );
+    // Order, case and duplicates don't matter
+    assert_eq!(single("Shift+CTRL+ctrl+T"), single("ctrl+shift+t"));
+    assert_eq!(
+        single("cmd+option+f5"),
+        combo(&[Modifier::Alt, Modifier::Meta], Key::Named(NamedKey::F(5)))
+    );
+}
+
+#[test]
+fn test_mod_is_the_platform_primary_modifier() {
+    assert_eq!(
+        single("mod+s"),
+        combo(&[primary_modifier()], Key::Char('s'))
+    );
+}
+
+#[test]
+fn test_parses_named_keys() {
+    let cases = [
+        ("enter", NamedKey::Enter),
+        ("return", NamedKey::Enter),
+        ("esc", NamedKey::Escape),
+        ("pgdn", NamedKey::PageDown),
+        ("left", NamedKey::Left),
+        ("f20", NamedKey::F(20)),
+    ];
+    for (text, key) in cases {
+        assert_eq!(single(text), combo(&[], Key::Named(key)), "{}", text);
+    }
+}
+
+#[test]
+fn test_plus_and_star_can_be_keys() {
+    assert_eq!(
+        single("ctrl++"),
+        combo(&[Modifier::Control], Key::Char('+'))
+    );
+    assert_eq!(single("shift+*"), combo(&[Modifier::Shift], Key::Char('*')));
+    assert_eq!(
+        single("mod+]"),
+        combo(&[primary_modifier()], Key::Char(']'))
+    );
+}
+
+#[test]
+fn test_parses_sequences_with_repeats() {
+    let strokes = parse_shortcut("down*3 enter, ctrl+k").unwrap();
+
+    assert_eq!(strokes.len(), 3);
+    assert_eq!(strokes[0].combo.key, Key::Named(NamedKey::Down));
+    assert_eq!(strokes[0].repeat, 3);
+    assert_eq!(strokes[1].combo.key, Key::Named(NamedKey::Enter));
+    assert_eq!(strokes[2].combo.modifiers, vec![Modifier::Control]);
+}
+
+#[test]
+fn test_rejects_invalid_shortcuts() {
+    assert_eq!(parse_shortcut("  , "), Err(ShortcutError::Empty));
+    assert_eq!(
+        parse_shortcut("hyper+x"),
+        Err(ShortcutError::UnknownKey("hyper".to_string()))
+    );
+    assert_eq!(
+        parse_shortcut("ctrl+banana"),
+        Err(ShortcutError::UnknownKey("banana".to_string()))
+    );
+    assert_eq!(
+        parse_shortcut("f21"),
+        Err(ShortcutError::UnknownKey("f21".to_string()))
+    );
+    assert_eq!(
+        parse_shortcut("ctrl+shift"),
+        Err(ShortcutError::MissingKey("ctrl+shift".to_string()))
+    );
+    assert!(matches!(
+        parse_shortcut("tab*0"),
+        Err(ShortcutError::InvalidRepeat { .. })
+    ));
+    assert!(matches!(
+        parse_shortcut("tab*lots"),
+        Err(ShortcutError::InvalidRepeat { .. })
+    ));
+}
+
+#[test]
+fn test_limits_total_presses() {
+    assert!(parse_shortcut("down*50 up*50").is_ok());
+    assert_eq!(
+        parse_shortcut("down*50 up*50 enter"),
+        Err(ShortcutError::TooManyPresses {
+            count: 101,
+            max: MAX_KEY_PRESSES
+        })
+    );
+}
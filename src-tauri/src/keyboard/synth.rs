@@ -1,5 +1,6 @@
 #[cfg(target_os = "macos")]
 mod macos {
+    use crate::keyboard::shortcut::{Key, KeyStroke, Modifier, NamedKey};
     use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGKeyCode};
     use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
     use std::sync::{Mutex, MutexGuard};
@@ -167,16 +168,257 @@ mod macos {
 
         Ok(())
     }
+
+    /// macOS virtual key code (ANSI layout) for a shortcut key
+    fn key_code(key: &Key) -> Option<CGKeyCode> {
+        let code = match key {
+            Key::Named(named) => match named {
+                NamedKey::Enter => 36,
+                NamedKey::Tab => 48,
+                NamedKey::Space => 49,
+                NamedKey::Escape => 53,
+                NamedKey::Backspace => 51,
+                NamedKey::Delete => 117,
+                NamedKey::Up => 126,
+                NamedKey::Down => 125,
+                NamedKey::Left => 123,
+                NamedKey::Right => 124,
+                NamedKey::Home => 115,
+                NamedKey::End => 119,
+                NamedKey::PageUp => 116,
+                NamedKey::PageDown => 121,
+                NamedKey::F(n) => {
+                    const F_KEYS: [CGKeyCode; 20] = [
+                        122, 120, 99, 118, 96, 97, 98, 100, 101, 109, 103, 111, 105, 107, 113, 106,
+                        64, 79, 80, 90,
+                    ];
+                    return F_KEYS.get((*n as usize).checked_sub(1)?).copied();
+                }
+            },
+            // Characters sit at the index of their key code; '\0' marks gaps
+            Key::Char(c) => {
+                const ANSI_KEYS: &str = "asdfhgzxcv\0bqweryt123465=97-80]ou[ip\0lj'k;\\,/nm.\0\0`";
+                return ANSI_KEYS.find(*c).map(|index| index as CGKeyCode);
+            }
+        };
+        Some(code)
+    }
+
+    fn modifier_flags(modifiers: &[Modifier]) -> CGEventFlags {
+        modifiers
+            .iter()
+            .fold(CGEventFlags::empty(), |flags, modifier| {
+                flags
+                    | match modifier {
+                        Modifier::Control => CGEventFlags::CGEventFlagControl,
+                        Modifier::Shift => CGEventFlags::CGEventFlagShift,
+                        Modifier::Alt => CGEventFlags::CGEventFlagAlternate,
+                        Modifier::Meta => CGEventFlags::CGEventFlagCommand,
+                    }
+            })
+    }
+
+    /// Send parsed key strokes on macOS.
+    ///
+    /// Every key is resolved before anything is posted, so an unsupported key
+    /// never leaves a shortcut half-sent. Like the other functions, each
+    /// key-down is always followed by its key-up; shutdown is only checked
+    /// between presses.
+    pub fn send_keystrokes(strokes: &[KeyStroke], delay_ms: u64) -> Result<(), String> {
+        // 20ms recommended by rdev for macOS to register keystroke
+        send_held_keystrokes(strokes, delay_ms, 20)
+    }
+
+    /// Send a long run of key strokes quickly.
+    ///
+    /// Keys are held for 1ms like typed text rather than 20ms, so hundreds of
+    /// presses (e.g. selecting text with shift+left) take a moment instead of
+    /// many seconds.
+    pub fn send_keystrokes_batched(strokes: &[KeyStroke]) -> Result<(), String> {
+        send_held_keystrokes(strokes, 0, 1)
+    }
+
+    fn send_held_keystrokes(
+        strokes: &[KeyStroke],
+        delay_ms: u64,
+        hold_ms: u64,
+    ) -> Result<(), String> {
+        let resolved = strokes
+            .iter()
+            .map(|stroke| {
+                key_code(&stroke.combo.key)
+                    .map(|code| (code, modifier_flags(&stroke.combo.modifiers), stroke.repeat))
+                    .ok_or_else(|| format!("Key {:?} is not supported on macOS", stroke.combo.key))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if crate::shutdown::is_shutting_down() {
+            return Ok(());
+        }
+
+        let _guard = lock_synth();
+
+        if crate::shutdown::is_shutting_down() {
+            return Ok(());
+        }
+
+        let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+            .map_err(|_| "Failed to create event source")?;
+
+        for (code, flags, repeat) in resolved {
+            for _ in 0..repeat {
+                if crate::shutdown::is_shutting_down() {
+                    return Ok(());
+                }
+
+                let event_down = CGEvent::new_keyboard_event(source.clone(), code, true)
+                    .map_err(|_| "Failed to create key down event")?;
+                let event_up = CGEvent::new_keyboard_event(source.clone(), code, false)
+                    .map_err(|_| "Failed to create key up event")?;
+                event_down.set_flags(flags);
+                event_up.set_flags(flags);
+
+                event_down.post(CGEventTapLocation::Session);
+                std::thread::sleep(Duration::from_millis(hold_ms));
+                event_up.post(CGEventTapLocation::Session);
+
+                std::thread::sleep(Duration::from_millis(delay_ms));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(target_os = "macos")]
-pub use macos::{simulate_cmd_v_paste, simulate_enter_keypress, type_unicode_text};
+pub use macos::{
+    send_keystrokes, send_keystrokes_batched, simulate_cmd_v_paste, simulate_enter_keypress,
+    type_unicode_text,
+};
 
 #[cfg(not(target_os = "macos"))]
 pub fn simulate_cmd_v_paste() -> Result<(), String> {
     Err("Paste simulation only supported on macOS".to_string())
 }
 
+/// Send parsed key strokes with enigo.
+///
+/// Modifiers are pressed before and released after each key, and are always
+/// released even when the key itself fails. Shutdown is only checked between
+/// presses.
+#[cfg(not(target_os = "macos"))]
+pub fn send_keystrokes(
+    strokes: &[crate::keyboard::shortcut::KeyStroke],
+    delay_ms: u64,
+) -> Result<(), String> {
+    use crate::keyboard::shortcut::{Key as ShortcutKey, Modifier, NamedKey};
+    use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+    use std::sync::Mutex;
+
+    /// Serializes enigo sequences the way the macOS path does
+    static ENIGO_SYNTH_MUTEX: Mutex<()> = Mutex::new(());
+
+    fn key(key: &ShortcutKey) -> Key {
+        match key {
+            ShortcutKey::Char(c) => Key::Unicode(*c),
+            ShortcutKey::Named(named) => match named {
+                NamedKey::Enter => Key::Return,
+                NamedKey::Tab => Key::Tab,
+                NamedKey::Space => Key::Space,
+                NamedKey::Escape => Key::Escape,
+                NamedKey::Backspace => Key::Backspace,
+                NamedKey::Delete => Key::Delete,
+                NamedKey::Up => Key::UpArrow,
+                NamedKey::Down => Key::DownArrow,
+                NamedKey::Left => Key::LeftArrow,
+                NamedKey::Right => Key::RightArrow,
+                NamedKey::Home => Key::Home,
+                NamedKey::End => Key::End,
+                NamedKey::PageUp => Key::PageUp,
+                NamedKey::PageDown => Key::PageDown,
+                NamedKey::F(n) => [
+                    Key::F1,
+                    Key::F2,
+                    Key::F3,
+                    Key::F4,
+                    Key::F5,
+                    Key::F6,
+                    Key::F7,
+                    Key::F8,
+                    Key::F9,
+                    Key::F10,
+                    Key::F11,
+                    Key::F12,
+                    Key::F13,
+                    Key::F14,
+                    Key::F15,
+                    Key::F16,
+                    Key::F17,
+                    Key::F18,
+                    Key::F19,
+                    Key::F20,
+                ][(*n as usize).clamp(1, 20) - 1],
+            },
+        }
+    }
+
+    fn modifier(modifier: &Modifier) -> Key {
+        match modifier {
+            Modifier::Control => Key::Control,
+            Modifier::Shift => Key::Shift,
+            Modifier::Alt => Key::Alt,
+            Modifier::Meta => Key::Meta,
+        }
+    }
+
+    if crate::shutdown::is_shutting_down() {
+        return Ok(());
+    }
+    let _guard = ENIGO_SYNTH_MUTEX
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut enigo = Enigo::new(&Settings::default())
+        .map_err(|e| format!("Failed to create keyboard simulator: {}", e))?;
+
+    for stroke in strokes {
+        for _ in 0..stroke.repeat {
+            if crate::shutdown::is_shutting_down() {
+                return Ok(());
+            }
+
+            let mut result = Ok(());
+            let mut pressed = Vec::new();
+            for m in &stroke.combo.modifiers {
+                result = enigo.key(modifier(m), Direction::Press);
+                if result.is_err() {
+                    break;
+                }
+                pressed.push(m);
+            }
+            if result.is_ok() {
+                result = enigo.key(key(&stroke.combo.key), Direction::Click);
+            }
+            for m in pressed.into_iter().rev() {
+                let _ = enigo.key(modifier(m), Direction::Release);
+            }
+            result.map_err(|e| format!("Failed to send key: {}", e))?;
+
+            std::thread::sleep(std::time::Duration::from_millis(delay_ms));
+        }
+    }
+
+    Ok(())
+}
+
+/// Send a long run of key strokes quickly (enigo clicks are not held, so
+/// this is `send_keystrokes` without a delay)
+#[cfg(not(target_os = "macos"))]
+pub fn send_keystrokes_batched(
+    strokes: &[crate::keyboard::shortcut::KeyStroke],
+) -> Result<(), String> {
+    send_keystrokes(strokes, 0)
+}
+
 #[cfg(not(target_os = "macos"))]
 pub fn type_unicode_text(_text: &str, _delay_ms: u64) -> Result<(), String> {
     Err("Text input is only supported on macOS".to_string())
@@ -13,6 +13,7 @@ mod events;
 mod hotkey;
 mod keyboard;
 mod keyboard_capture;
+mod listening;
 mod model;
 mod parakeet;
 mod paths;
@@ -84,6 +85,23 @@ pub fn run() {
             // Recording commands
             commands::recording::start_recording,
             commands::recording::stop_recording,
+            commands::recording::pause_recording,
+            commands::recording::resume_recording,
+            commands::recording::set_trim_config,
+            commands::long_form::start_long_form_recording,
+            commands::long_form::get_long_form_document,
+            commands::long_form::list_long_form_documents,
+            commands::pipeline_log::list_pipeline_events,
+            commands::pipeline_log::clear_pipeline_events,
+            commands::listening::start_listening,
+            commands::listening::stop_listening,
+            commands::listening::get_listening_status,
+            commands::dictation::start_continuous_dictation,
+            commands::dictation::stop_continuous_dictation,
+            commands::dictation::get_continuous_dictation_status,
+            commands::silence::get_silence_settings,
+            commands::silence::set_silence_settings,
+            commands::silence::calibrate_silence_detection,
             commands::recording::get_recording_state,
             commands::recording::get_last_recording_buffer,
             commands::recording::clear_last_recording_buffer,
@@ -93,8 +111,11 @@ pub fn run() {
             commands::transcription::transcribe_file,
             commands::transcription::list_transcriptions,
             commands::transcription::get_transcriptions_by_recording,
+            commands::transcription::scratch_that,
             // Audio commands
             commands::audio::list_audio_devices,
+            commands::audio::get_device_priority,
+            commands::audio::set_device_priority,
             commands::audio::start_audio_monitor,
             commands::audio::stop_audio_monitor,
             commands::audio::init_audio_monitor,
@@ -107,6 +128,11 @@ pub fn run() {
             voice_commands::update_command,
             voice_commands::remove_command,
             voice_commands::executor::test_command,
+            voice_commands::resolve_ambiguous_command,
+            voice_commands::dismiss_ambiguous_command,
+            voice_commands::confirm_command,
+            voice_commands::cancel_command_confirmation,
+            voice_commands::explain_match,
             // Hotkey commands
             commands::hotkey::suspend_recording_shortcut,
             commands::hotkey::resume_recording_shortcut,
@@ -131,6 +157,9 @@ pub fn run() {
             commands::window_context::add_window_context,
             commands::window_context::update_window_context,
             commands::window_context::delete_window_context,
+            // Configuration bundle commands
+            commands::config_bundle::export_config_bundle,
+            commands::config_bundle::import_config_bundle,
             // Window commands
             commands::window::show_main_window
         ])
@@ -0,0 +1,251 @@
+// Wake phrase listener thread
+//
+// Drains a sample source while listening is enabled. When idle, speech is
+// segmented into short windows, transcribed, and checked for the wake phrase.
+// After a wake-initiated recording starts, samples are forwarded into the
+// recording buffer so the silence detector can stop it.
+
+use super::segmenter::{SegmenterConfig, UtteranceSegmenter};
+use super::wake_phrase::{WakePhraseConfig, WakePhraseMatcher};
+use crate::audio::{SampleSource, TARGET_SAMPLE_RATE};
+use crate::audio_constants::{
+    chunk_size_for_sample_rate, LISTENING_POLL_INTERVAL_MS, VAD_THRESHOLD_SILENCE,
+};
+use crate::recording::{
+    create_voice_activity, RecordingManager, RecordingState, VadConfig, VadEngine, VadError,
+    VoiceActivity,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Transcribes a window of 16kHz samples, returning None on failure
+pub type WindowTranscriber = Box<dyn FnMut(&[f32]) -> Option<String> + Send + 'static>;
+
+/// Called with the transcript when the wake phrase is heard.
+/// Returns true if a recording was started.
+pub type WakeCallback = Box<dyn Fn(String) -> bool + Send + 'static>;
+
+/// Detects the wake phrase in a stream of samples
+pub(crate) struct WakeDetector {
+    segmenter: UtteranceSegmenter,
+    matcher: WakePhraseMatcher,
+    pending: Vec<f32>,
+    chunk_size: usize,
+}
+
+impl WakeDetector {
+    pub(crate) fn new(config: &WakePhraseConfig, sample_rate: u32) -> Self {
+        Self {
+            segmenter: UtteranceSegmenter::new(SegmenterConfig::wake(sample_rate)),
+            matcher: WakePhraseMatcher::new(config),
+            pending: Vec::new(),
+            chunk_size: chunk_size_for_sample_rate(sample_rate),
+        }
+    }
+
+    /// Feed samples, returning the transcript if the wake phrase was heard
+    ///
+    /// Samples after a match are discarded.
+    pub(crate) fn feed(
+        &mut self,
+        samples: &[f32],
+        is_speech: &mut dyn FnMut(&[f32]) -> bool,
+        transcribe: &mut dyn FnMut(&[f32]) -> Option<String>,
+    ) -> Option<String> {
+        self.pending.extend_from_slice(samples);
+
+        let mut offset = 0;
+        let mut detected = None;
+        while self.pending.len() - offset >= self.chunk_size {
+            let chunk = &self.pending[offset..offset + self.chunk_size];
+            offset += self.chunk_size;
+
+            let speech = is_speech(chunk);
+            let Some(window) = self.segmenter.push(chunk, speech) else {
+                continue;
+            };
+            let Some(transcript) = transcribe(&window) else {
+                continue;
+            };
+            crate::debug!("[listening] Candidate window: {:?}", transcript);
+            if self.matcher.matches(&transcript) {
+                detected = Some(transcript);
+                break;
+            }
+        }
+
+        if detected.is_some() {
+            self.reset();
+        } else {
+            self.pending.drain(..offset);
+        }
+        detected
+    }
+
+    /// Drop all buffered audio
+    pub(crate) fn reset(&mut self) {
+        self.pending.clear();
+        self.segmenter.reset();
+    }
+}
+
+/// Background thread that listens for the wake phrase
+pub struct WakeListener {
+    thread: Option<JoinHandle<()>>,
+    should_stop: Arc<AtomicBool>,
+    vad_engine: VadEngine,
+    vad_fallback: Option<VadError>,
+}
+
+impl WakeListener {
+    /// Start listening
+    ///
+    /// # Arguments
+    /// * `config` - Validated wake phrase configuration
+    /// * `vad_engine` - Preferred VAD engine; the energy VAD is used if it
+    ///   can't be created and `vad_fallback()` reports why
+    /// * `source` - Source of 16kHz mono samples
+    /// * `recording_manager` - Used to observe state and reach the recording buffer
+    /// * `transcriber` - Transcribes candidate wake windows
+    /// * `on_wake` - Called when the wake phrase is heard while idle
+    pub fn start<S: SampleSource + 'static>(
+        config: WakePhraseConfig,
+        vad_engine: VadEngine,
+        source: S,
+        recording_manager: Arc<Mutex<RecordingManager>>,
+        transcriber: WindowTranscriber,
+        on_wake: WakeCallback,
+    ) -> Self {
+        let should_stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = should_stop.clone();
+
+        let (vad, vad_fallback) = create_voice_activity(&VadConfig {
+            sample_rate: TARGET_SAMPLE_RATE,
+            engine: vad_engine,
+            ..VadConfig::default()
+        });
+        let active_engine = vad.engine();
+
+        crate::info!("[listening] Starting listener for {:?}", config.phrase);
+
+        let thread = thread::spawn(move || {
+            listen_loop(
+                config,
+                vad,
+                source,
+                recording_manager,
+                stop_flag,
+                transcriber,
+                on_wake,
+            );
+        });
+
+        Self {
+            thread: Some(thread),
+            should_stop,
+            vad_engine: active_engine,
+            vad_fallback,
+        }
+    }
+
+    /// Engine currently detecting speech
+    pub fn vad_engine(&self) -> VadEngine {
+        self.vad_engine
+    }
+
+    /// Why the preferred VAD engine is not in use, if the fallback is active
+    pub fn vad_fallback(&self) -> Option<&VadError> {
+        self.vad_fallback.as_ref()
+    }
+
+    /// Check if the listener thread is still running
+    pub fn is_running(&self) -> bool {
+        self.thread.as_ref().is_some_and(|h| !h.is_finished())
+    }
+
+    /// Stop listening and wait for the thread to exit
+    pub fn stop(&mut self) {
+        self.should_stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.thread.take() {
+            if handle.thread().id() != thread::current().id() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+impl Drop for WakeListener {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Main loop of the listener thread
+fn listen_loop<S: SampleSource>(
+    config: WakePhraseConfig,
+    mut vad: Box<dyn VoiceActivity>,
+    mut source: S,
+    recording_manager: Arc<Mutex<RecordingManager>>,
+    should_stop: Arc<AtomicBool>,
+    mut transcriber: WindowTranscriber,
+    on_wake: WakeCallback,
+) {
+    let mut is_speech = |chunk: &[f32]| vad.speech_probability(chunk) >= VAD_THRESHOLD_SILENCE;
+
+    let mut detector = WakeDetector::new(&config, TARGET_SAMPLE_RATE);
+    // Whether the current recording was started by the wake phrase
+    let mut forwarding = false;
+    let poll = Duration::from_millis(LISTENING_POLL_INTERVAL_MS);
+
+    source.start();
+
+    loop {
+        thread::sleep(poll);
+        if should_stop.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let samples = source.read();
+        let (state, buffer) = match recording_manager.lock() {
+            Ok(manager) => (manager.get_state(), manager.get_audio_buffer().ok()),
+            Err(_) => {
+                crate::error!("[listening] Recording state lock poisoned, stopping listener");
+                break;
+            }
+        };
+
+        match state {
+            RecordingState::Idle => forwarding = false,
+            RecordingState::Recording if forwarding => {
+                if let Some(buffer) = buffer {
+                    buffer.push_samples(&samples);
+                }
+                continue;
+            }
+            // Recordings started elsewhere are captured by the engine;
+            // paused and processing audio is never listened to
+            _ => {
+                detector.reset();
+                continue;
+            }
+        }
+
+        if samples.is_empty() {
+            continue;
+        }
+
+        if let Some(transcript) = detector.feed(&samples, &mut is_speech, &mut *transcriber) {
+            crate::info!("[listening] Wake phrase detected: {:?}", transcript);
+            forwarding = on_wake(transcript);
+        }
+    }
+
+    source.stop();
+    crate::info!("[listening] Listener stopped");
+}
+
+#[cfg(test)]
+#[path = "listener_test.rs"]
+mod tests;
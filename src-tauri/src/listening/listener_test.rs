@@ -0,0 +1,137 @@
+use super::*;
+use std::sync::atomic::AtomicUsize;
+
+const RATE: u32 = 16000;
+const CHUNK: usize = 512;
+
+fn config() -> WakePhraseConfig {
+    WakePhraseConfig {
+        enabled: true,
+        ..WakePhraseConfig::default()
+    }
+}
+
+/// Chunks with a positive first sample count as speech
+fn loud_is_speech(chunk: &[f32]) -> bool {
+    chunk[0] > 0.5
+}
+
+/// One second of speech followed by one second of silence
+fn utterance() -> Vec<f32> {
+    let mut samples = vec![1.0; RATE as usize];
+    samples.extend(vec![0.0; RATE as usize]);
+    samples
+}
+
+#[test]
+fn test_detector_returns_transcript_on_match() {
+    let mut detector = WakeDetector::new(&config(), RATE);
+    let mut transcribe = |_: &[f32]| Some("Hey cat".to_string());
+
+    let result = detector.feed(&utterance(), &mut loud_is_speech, &mut transcribe);
+    assert_eq!(result, Some("Hey cat".to_string()));
+}
+
+#[test]
+fn test_detector_ignores_other_speech() {
+    let mut detector = WakeDetector::new(&config(), RATE);
+    let calls = AtomicUsize::new(0);
+    let mut transcribe = |_: &[f32]| {
+        calls.fetch_add(1, Ordering::SeqCst);
+        Some("what's the weather".to_string())
+    };
+
+    assert_eq!(
+        detector.feed(&utterance(), &mut loud_is_speech, &mut transcribe),
+        None
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_detector_skips_transcription_for_silence() {
+    let mut detector = WakeDetector::new(&config(), RATE);
+    let mut transcribe = |_: &[f32]| -> Option<String> { panic!("nothing to transcribe") };
+
+    let silence = vec![0.0; RATE as usize * 2];
+    assert_eq!(
+        detector.feed(&silence, &mut loud_is_speech, &mut transcribe),
+        None
+    );
+}
+
+#[test]
+fn test_detector_handles_samples_split_across_reads() {
+    let mut detector = WakeDetector::new(&config(), RATE);
+    let mut transcribe = |_: &[f32]| Some("hey cat".to_string());
+
+    let samples = utterance();
+    let mut detected = None;
+    // Reads that are not a multiple of the VAD chunk size
+    for part in samples.chunks(CHUNK * 3 + 17) {
+        if let Some(t) = detector.feed(part, &mut loud_is_speech, &mut transcribe) {
+            detected = Some(t);
+        }
+    }
+    assert_eq!(detected, Some("hey cat".to_string()));
+}
+
+struct SilentSource {
+    started: Arc<AtomicBool>,
+}
+
+impl SampleSource for SilentSource {
+    fn start(&mut self) {
+        self.started.store(true, Ordering::SeqCst);
+    }
+
+    fn stop(&mut self) {
+        self.started.store(false, Ordering::SeqCst);
+    }
+
+    fn read(&mut self) -> Vec<f32> {
+        vec![0.0; CHUNK * 4]
+    }
+}
+
+#[test]
+fn test_listener_starts_and_stops_source() {
+    let started = Arc::new(AtomicBool::new(false));
+    let manager = Arc::new(Mutex::new(RecordingManager::new()));
+
+    let mut listener = WakeListener::start(
+        config(),
+        VadEngine::Energy,
+        SilentSource {
+            started: started.clone(),
+        },
+        manager,
+        Box::new(|_: &[f32]| -> Option<String> { panic!("silence should not be transcribed") }),
+        Box::new(|_: String| -> bool { panic!("wake not expected") }),
+    );
+
+    thread::sleep(Duration::from_millis(LISTENING_POLL_INTERVAL_MS * 3));
+    assert!(listener.is_running());
+    assert!(started.load(Ordering::SeqCst));
+
+    listener.stop();
+    assert!(!listener.is_running());
+    assert!(!started.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_listener_uses_the_requested_vad_engine() {
+    let listener = WakeListener::start(
+        config(),
+        VadEngine::Energy,
+        SilentSource {
+            started: Arc::new(AtomicBool::new(false)),
+        },
+        Arc::new(Mutex::new(RecordingManager::new())),
+        Box::new(|_: &[f32]| -> Option<String> { None }),
+        Box::new(|_: String| -> bool { false }),
+    );
+
+    assert_eq!(listener.vad_engine(), VadEngine::Energy);
+    assert!(listener.vad_fallback().is_none());
+}
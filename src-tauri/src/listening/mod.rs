@@ -0,0 +1,13 @@
+// Hands-free listening mode
+//
+// While listening is enabled, the shared audio engine's sample tap is run
+// through VAD. Short utterances are transcribed and compared against the
+// configured wake phrase; a match starts a recording that is stopped by
+// silence detection.
+
+mod listener;
+mod segmenter;
+mod wake_phrase;
+
+pub use listener::WakeListener;
+pub use wake_phrase::WakePhraseConfig;
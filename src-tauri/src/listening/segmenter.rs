@@ -0,0 +1,150 @@
+// Utterance segmentation from per-chunk VAD decisions
+//
+// Pure state machine: callers classify each fixed-size chunk as speech or
+// silence and the segmenter returns a complete utterance once speech is
+// followed by enough silence (or the utterance reaches its maximum length).
+
+use crate::audio_constants::{
+    WAKE_END_SILENCE_MS, WAKE_MAX_WINDOW_MS, WAKE_MIN_SPEECH_MS, WAKE_PRE_ROLL_MS,
+};
+use std::collections::VecDeque;
+
+/// Timing configuration for the utterance segmenter
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmenterConfig {
+    /// Sample rate of the incoming audio
+    pub sample_rate: u32,
+    /// Audio kept from before speech onset (milliseconds)
+    pub pre_roll_ms: u32,
+    /// Silence that ends an utterance (milliseconds)
+    pub end_silence_ms: u32,
+    /// Utterances with less speech than this are dropped (milliseconds)
+    pub min_speech_ms: u32,
+    /// Utterances are cut at this length (milliseconds)
+    pub max_utterance_ms: u32,
+}
+
+impl SegmenterConfig {
+    /// Configuration for short wake phrase windows
+    pub fn wake(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            pre_roll_ms: WAKE_PRE_ROLL_MS,
+            end_silence_ms: WAKE_END_SILENCE_MS,
+            min_speech_ms: WAKE_MIN_SPEECH_MS,
+            max_utterance_ms: WAKE_MAX_WINDOW_MS,
+        }
+    }
+
+    fn samples(&self, ms: u32) -> usize {
+        (ms as u64 * self.sample_rate as u64 / 1000) as usize
+    }
+}
+
+/// Splits a stream of classified chunks into utterances
+pub struct UtteranceSegmenter {
+    pre_roll: VecDeque<f32>,
+    current: Vec<f32>,
+    in_speech: bool,
+    /// Cut at max length; ignore speech until the next silence gap
+    discarding: bool,
+    speech_samples: usize,
+    silence_samples: usize,
+    pre_roll_samples: usize,
+    end_silence_samples: usize,
+    min_speech_samples: usize,
+    max_utterance_samples: usize,
+}
+
+impl UtteranceSegmenter {
+    pub fn new(config: SegmenterConfig) -> Self {
+        Self {
+            pre_roll: VecDeque::new(),
+            current: Vec::new(),
+            in_speech: false,
+            discarding: false,
+            speech_samples: 0,
+            silence_samples: 0,
+            pre_roll_samples: config.samples(config.pre_roll_ms),
+            end_silence_samples: config.samples(config.end_silence_ms),
+            min_speech_samples: config.samples(config.min_speech_ms),
+            max_utterance_samples: config.samples(config.max_utterance_ms),
+        }
+    }
+
+    /// Feed one classified chunk
+    ///
+    /// Returns the utterance samples (including pre-roll and trailing
+    /// silence) when an utterance ends.
+    pub fn push(&mut self, chunk: &[f32], is_speech: bool) -> Option<Vec<f32>> {
+        if self.discarding {
+            self.track_silence(chunk.len(), is_speech);
+            if self.silence_samples >= self.end_silence_samples {
+                self.discarding = false;
+                self.silence_samples = 0;
+            }
+            self.push_pre_roll(chunk);
+            return None;
+        }
+
+        if !self.in_speech {
+            if !is_speech {
+                self.push_pre_roll(chunk);
+                return None;
+            }
+            self.in_speech = true;
+            self.current = self.pre_roll.drain(..).collect();
+            self.speech_samples = 0;
+            self.silence_samples = 0;
+        }
+
+        self.current.extend_from_slice(chunk);
+        self.track_silence(chunk.len(), is_speech);
+
+        if self.silence_samples >= self.end_silence_samples {
+            self.silence_samples = 0;
+            return self.finish();
+        }
+        if self.current.len() >= self.max_utterance_samples {
+            self.discarding = true;
+            self.silence_samples = 0;
+            return self.finish();
+        }
+        None
+    }
+
+    /// Drop any in-progress utterance and buffered pre-roll
+    pub fn reset(&mut self) {
+        self.pre_roll.clear();
+        self.current.clear();
+        self.in_speech = false;
+        self.discarding = false;
+        self.speech_samples = 0;
+        self.silence_samples = 0;
+    }
+
+    fn track_silence(&mut self, len: usize, is_speech: bool) {
+        if is_speech {
+            self.speech_samples += len;
+            self.silence_samples = 0;
+        } else {
+            self.silence_samples += len;
+        }
+    }
+
+    fn push_pre_roll(&mut self, chunk: &[f32]) {
+        self.pre_roll.extend(chunk.iter().copied());
+        let excess = self.pre_roll.len().saturating_sub(self.pre_roll_samples);
+        self.pre_roll.drain(..excess);
+    }
+
+    fn finish(&mut self) -> Option<Vec<f32>> {
+        self.in_speech = false;
+        let utterance = std::mem::take(&mut self.current);
+        (self.speech_samples >= self.min_speech_samples).then_some(utterance)
+    }
+}
+
+#[cfg(test)]
+#[path = "segmenter_test.rs"]
+mod tests;
@@ -0,0 +1,82 @@
+use super::*;
+
+const CHUNK: usize = 160; // 10ms at 16kHz
+
+fn config() -> SegmenterConfig {
+    SegmenterConfig {
+        sample_rate: 16000,
+        pre_roll_ms: 20,
+        end_silence_ms: 30,
+        min_speech_ms: 20,
+        max_utterance_ms: 200,
+    }
+}
+
+/// Feed `count` chunks, returning every completed utterance
+fn feed(segmenter: &mut UtteranceSegmenter, count: usize, is_speech: bool) -> Vec<Vec<f32>> {
+    let value = if is_speech { 1.0 } else { 0.0 };
+    (0..count)
+        .filter_map(|_| segmenter.push(&[value; CHUNK], is_speech))
+        .collect()
+}
+
+#[test]
+fn test_utterance_ends_after_silence_with_pre_roll() {
+    let mut segmenter = UtteranceSegmenter::new(config());
+
+    assert!(feed(&mut segmenter, 5, false).is_empty());
+    assert!(feed(&mut segmenter, 4, true).is_empty());
+    let utterances = feed(&mut segmenter, 3, false);
+
+    assert_eq!(utterances.len(), 1);
+    // 2 chunks pre-roll + 4 speech + 3 silence
+    assert_eq!(utterances[0].len(), 9 * CHUNK);
+    assert_eq!(utterances[0][0], 0.0);
+    assert_eq!(utterances[0][2 * CHUNK], 1.0);
+}
+
+#[test]
+fn test_short_bursts_are_dropped() {
+    let mut segmenter = UtteranceSegmenter::new(config());
+
+    assert!(feed(&mut segmenter, 1, true).is_empty());
+    assert!(feed(&mut segmenter, 3, false).is_empty());
+}
+
+#[test]
+fn test_brief_pause_does_not_split_utterance() {
+    let mut segmenter = UtteranceSegmenter::new(config());
+
+    feed(&mut segmenter, 3, true);
+    assert!(feed(&mut segmenter, 2, false).is_empty());
+    feed(&mut segmenter, 3, true);
+    let utterances = feed(&mut segmenter, 3, false);
+
+    assert_eq!(utterances.len(), 1);
+    assert_eq!(utterances[0].len(), 11 * CHUNK);
+}
+
+#[test]
+fn test_long_speech_is_cut_then_ignored_until_silence() {
+    let mut segmenter = UtteranceSegmenter::new(config());
+
+    let utterances = feed(&mut segmenter, 30, true);
+    assert_eq!(utterances.len(), 1);
+    assert_eq!(utterances[0].len(), 20 * CHUNK);
+
+    // Trailing speech after the cut and the silence that ends it yield nothing
+    assert!(feed(&mut segmenter, 3, false).is_empty());
+
+    // A new utterance is picked up afterwards
+    feed(&mut segmenter, 3, true);
+    assert_eq!(feed(&mut segmenter, 3, false).len(), 1);
+}
+
+#[test]
+fn test_reset_drops_in_progress_utterance() {
+    let mut segmenter = UtteranceSegmenter::new(config());
+
+    feed(&mut segmenter, 3, true);
+    segmenter.reset();
+    assert!(feed(&mut segmenter, 3, false).is_empty());
+}
@@ -0,0 +1,136 @@
+// Wake phrase configuration and fuzzy matching
+//
+// Transcripts of short utterances are compared word-window by word-window
+// against the configured phrase, so small transcription errors ("hey kat")
+// and filler before the phrase ("okay hey cat") still match.
+
+use crate::audio_constants::WAKE_MATCH_THRESHOLD;
+use crate::util::{normalize_words, SettingsAccess};
+use serde::{Deserialize, Serialize};
+use strsim::normalized_levenshtein;
+
+/// Settings key for enabling listening mode on startup
+pub const SETTING_LISTENING_ENABLED: &str = "listening.enabled";
+/// Settings key for the wake phrase
+pub const SETTING_WAKE_PHRASE: &str = "listening.wakePhrase";
+/// Settings key for the wake phrase match threshold (0.0 - 1.0)
+pub const SETTING_WAKE_MATCH_THRESHOLD: &str = "listening.matchThreshold";
+
+/// Wake phrase used when none is configured
+pub const DEFAULT_WAKE_PHRASE: &str = "hey cat";
+
+/// Errors from validating a wake phrase configuration
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum WakePhraseConfigError {
+    /// The phrase has no words after normalization
+    #[error("Wake phrase must contain at least one word")]
+    EmptyPhrase,
+    /// Threshold outside (0, 1]
+    #[error("Match threshold must be greater than 0 and at most 1 (got {0})")]
+    InvalidThreshold(f64),
+}
+
+/// Configuration for wake phrase listening
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WakePhraseConfig {
+    /// Whether listening mode starts automatically
+    pub enabled: bool,
+    /// Phrase that activates recording
+    pub phrase: String,
+    /// Minimum similarity for a transcript to count as the wake phrase
+    pub match_threshold: f64,
+}
+
+impl Default for WakePhraseConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            phrase: DEFAULT_WAKE_PHRASE.to_string(),
+            match_threshold: WAKE_MATCH_THRESHOLD,
+        }
+    }
+}
+
+impl WakePhraseConfig {
+    /// Check that the configuration can be used for matching
+    pub fn validate(&self) -> Result<(), WakePhraseConfigError> {
+        if normalize_words(&self.phrase).is_empty() {
+            return Err(WakePhraseConfigError::EmptyPhrase);
+        }
+        if !(self.match_threshold > 0.0 && self.match_threshold <= 1.0) {
+            return Err(WakePhraseConfigError::InvalidThreshold(
+                self.match_threshold,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Load the configuration from settings, falling back to defaults per key
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+
+        Self {
+            enabled: settings
+                .get_setting_value(SETTING_LISTENING_ENABLED)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(defaults.enabled),
+            phrase: settings
+                .get_setting(SETTING_WAKE_PHRASE)
+                .filter(|p| !normalize_words(p).is_empty())
+                .unwrap_or(defaults.phrase),
+            match_threshold: settings
+                .get_setting_value(SETTING_WAKE_MATCH_THRESHOLD)
+                .and_then(|v| v.as_f64())
+                .unwrap_or(defaults.match_threshold),
+        }
+    }
+}
+
+/// Fuzzy matcher for a single wake phrase
+#[derive(Debug, Clone)]
+pub struct WakePhraseMatcher {
+    phrase: String,
+    word_count: usize,
+    threshold: f64,
+}
+
+impl WakePhraseMatcher {
+    /// Create a matcher from a validated configuration
+    pub fn new(config: &WakePhraseConfig) -> Self {
+        let words = normalize_words(&config.phrase);
+        Self {
+            phrase: words.join(" "),
+            word_count: words.len(),
+            threshold: config.match_threshold,
+        }
+    }
+
+    /// Best similarity between the phrase and any word window of the transcript
+    ///
+    /// Windows of one word shorter and longer than the phrase are included,
+    /// since transcription may merge ("heycat") or split ("hey ca t") words.
+    pub fn similarity(&self, transcript: &str) -> f64 {
+        let words = normalize_words(transcript);
+        if words.is_empty() || self.word_count == 0 {
+            return 0.0;
+        }
+
+        let min_len = self.word_count.saturating_sub(1).max(1);
+        let max_len = (self.word_count + 1).min(words.len());
+
+        (min_len..=max_len)
+            .flat_map(|len| words.windows(len))
+            .map(|window| normalized_levenshtein(&self.phrase, &window.join(" ")))
+            .fold(0.0, f64::max)
+    }
+
+    /// Check whether the transcript contains the wake phrase
+    pub fn matches(&self, transcript: &str) -> bool {
+        self.similarity(transcript) >= self.threshold
+    }
+}
+
+#[cfg(test)]
+#[path = "wake_phrase_test.rs"]
+mod tests;
@@ -0,0 +1,78 @@
+use super::*;
+use tauri::AppHandle;
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+fn matcher(phrase: &str) -> WakePhraseMatcher {
+    WakePhraseMatcher::new(&WakePhraseConfig {
+        phrase: phrase.to_string(),
+        ..WakePhraseConfig::default()
+    })
+}
+
+#[test]
+fn test_default_config_is_valid_and_disabled() {
+    let config = WakePhraseConfig::default();
+    assert!(config.validate().is_ok());
+    assert!(!config.enabled);
+    assert_eq!(config.phrase, DEFAULT_WAKE_PHRASE);
+}
+
+#[test]
+fn test_validate_rejects_bad_values() {
+    let empty = WakePhraseConfig {
+        phrase: " ?! ".to_string(),
+        ..WakePhraseConfig::default()
+    };
+    assert_eq!(empty.validate(), Err(WakePhraseConfigError::EmptyPhrase));
+
+    let zero = WakePhraseConfig {
+        match_threshold: 0.0,
+        ..WakePhraseConfig::default()
+    };
+    assert_eq!(
+        zero.validate(),
+        Err(WakePhraseConfigError::InvalidThreshold(0.0))
+    );
+}
+
+#[test]
+fn test_from_settings_without_app_handle_uses_defaults() {
+    assert_eq!(
+        WakePhraseConfig::from_settings(&NoAppHandle),
+        WakePhraseConfig::default()
+    );
+}
+
+#[test]
+fn test_matches_ignores_case_and_punctuation() {
+    assert!(matcher("Hey Cat").matches("Hey, cat!"));
+}
+
+#[test]
+fn test_matches_phrase_inside_transcript() {
+    let m = matcher("hey cat");
+    assert!(m.matches("okay hey cat"));
+    assert!(m.matches("hey cat open the browser"));
+}
+
+#[test]
+fn test_matches_tolerates_small_transcription_errors() {
+    let m = matcher("hey cat");
+    assert!(m.matches("hey kat"));
+    assert!(m.matches("heycat"));
+}
+
+#[test]
+fn test_rejects_unrelated_speech() {
+    let m = matcher("hey cat");
+    assert!(!m.matches("hey there"));
+    assert!(!m.matches("what time is it"));
+    assert!(!m.matches(""));
+}
@@ -9,4 +9,4 @@ pub use shared::SharedTranscriptionModel;
 // TranscribingGuard exported for public API (RAII state management)
 #[allow(unused_imports)]
 pub use shared::TranscribingGuard;
-pub use types::TranscriptionService;
+pub use types::{TranscriptionService, TranscriptionState};
@@ -167,7 +167,7 @@ impl Drop for TranscribingGuard {
 /// Shared transcription model wrapper for ParakeetTDT
 ///
 /// This struct provides thread-safe access to a single Parakeet model instance
-/// that can be shared between all transcription consumers and WakeWordDetector.
+/// that can be shared between all transcription consumers and the wake phrase listener.
 /// Previously, each component loaded its own ~3GB model, wasting memory.
 ///
 /// Usage:
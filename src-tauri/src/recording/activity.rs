@@ -0,0 +1,70 @@
+// Live speech activity reporting for the recording overlay
+//
+// The detection loop analyses audio every 100ms. Turning every frame into
+// an event would flood the frontend, so transitions between speech and
+// silence are reported immediately and level/countdown updates in between
+// are throttled.
+
+use crate::events::current_timestamp;
+use crate::events::speech_activity_events::{SpeechActivityKind, SpeechActivityPayload};
+use std::time::{Duration, Instant};
+
+/// Decides which detector frames become `speech_activity` events
+#[derive(Debug)]
+pub(crate) struct SpeechActivityTracker {
+    /// Minimum time between two `Update` events
+    interval: Duration,
+    /// Whether the last reported frame was speech
+    speaking: bool,
+    /// When the last event was produced
+    last_emit: Option<Instant>,
+}
+
+impl SpeechActivityTracker {
+    pub(crate) fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            speaking: false,
+            last_emit: None,
+        }
+    }
+
+    /// Record the state after a detector frame
+    ///
+    /// Returns the event to emit, if any: `SpeechStarted`/`SpeechEnded` on a
+    /// transition, otherwise an `Update` once `interval` has passed since the
+    /// previous event.
+    pub(crate) fn observe(
+        &mut self,
+        now: Instant,
+        is_speech: bool,
+        probability: f32,
+        stop_in: Option<Duration>,
+    ) -> Option<SpeechActivityPayload> {
+        let kind = match (self.speaking, is_speech) {
+            (false, true) => SpeechActivityKind::SpeechStarted,
+            (true, false) => SpeechActivityKind::SpeechEnded,
+            _ if self
+                .last_emit
+                .is_some_and(|last| now.duration_since(last) < self.interval) =>
+            {
+                return None
+            }
+            _ => SpeechActivityKind::Update,
+        };
+
+        self.speaking = is_speech;
+        self.last_emit = Some(now);
+        Some(SpeechActivityPayload {
+            kind,
+            probability,
+            is_speech,
+            stop_in_ms: stop_in.map(|d| d.as_millis() as u64),
+            timestamp: current_timestamp(),
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "activity_test.rs"]
+mod tests;
@@ -0,0 +1,67 @@
+use super::*;
+
+const INTERVAL: Duration = Duration::from_millis(200);
+
+fn at(start: Instant, ms: u64) -> Instant {
+    start + Duration::from_millis(ms)
+}
+
+fn stop_in(ms: u64) -> Option<Duration> {
+    Some(Duration::from_millis(ms))
+}
+
+#[test]
+fn test_first_frame_reports_update() {
+    let start = Instant::now();
+    let mut tracker = SpeechActivityTracker::new(INTERVAL);
+
+    let event = tracker.observe(start, false, 0.1, None).unwrap();
+    assert_eq!(event.kind, SpeechActivityKind::Update);
+    assert!(!event.is_speech);
+}
+
+#[test]
+fn test_transitions_are_reported_immediately() {
+    let start = Instant::now();
+    let mut tracker = SpeechActivityTracker::new(INTERVAL);
+    tracker.observe(start, false, 0.1, None);
+
+    let started = tracker.observe(at(start, 100), true, 0.9, None).unwrap();
+    assert_eq!(started.kind, SpeechActivityKind::SpeechStarted);
+
+    let ended = tracker
+        .observe(at(start, 150), false, 0.2, stop_in(3000))
+        .unwrap();
+    assert_eq!(ended.kind, SpeechActivityKind::SpeechEnded);
+    assert_eq!(ended.stop_in_ms, Some(3000));
+}
+
+#[test]
+fn test_updates_are_throttled() {
+    let start = Instant::now();
+    let mut tracker = SpeechActivityTracker::new(INTERVAL);
+    tracker.observe(start, true, 0.9, None);
+
+    assert!(tracker.observe(at(start, 100), true, 0.8, None).is_none());
+
+    let update = tracker.observe(at(start, 200), true, 0.7, None).unwrap();
+    assert_eq!(update.kind, SpeechActivityKind::Update);
+    assert!((update.probability - 0.7).abs() < 1e-6);
+}
+
+#[test]
+fn test_countdown_updates_during_silence() {
+    let start = Instant::now();
+    let mut tracker = SpeechActivityTracker::new(INTERVAL);
+    tracker.observe(start, true, 0.9, None);
+    tracker.observe(at(start, 100), false, 0.1, stop_in(2000));
+
+    assert!(tracker
+        .observe(at(start, 200), false, 0.1, stop_in(1900))
+        .is_none());
+    let update = tracker
+        .observe(at(start, 300), false, 0.1, stop_in(1800))
+        .unwrap();
+    assert_eq!(update.kind, SpeechActivityKind::Update);
+    assert_eq!(update.stop_in_ms, Some(1800));
+}
@@ -0,0 +1,151 @@
+// Noise-floor calibration for silence detection
+//
+// A calibration run records a few seconds of room tone followed by a few
+// seconds of speech. Comparing VAD probabilities of the two gives a speech
+// threshold that sits above the room's noise, and the pauses in the speech
+// give durations that don't cut off the speaker.
+
+use super::silence::SilenceConfig;
+use super::vad::{create_voice_activity, VadConfig, VadEngine};
+use crate::audio_constants::{
+    chunk_size_for_sample_rate, CALIBRATION_MIN_SEPARATION, PAUSE_TOLERANCE_MAX_MS,
+    SILENCE_DURATION_MAX_MS, VAD_THRESHOLD_MAX, VAD_THRESHOLD_MIN,
+};
+use serde::Serialize;
+
+/// Shortest silence duration a calibration will propose (milliseconds)
+const MIN_PROPOSED_SILENCE_MS: u32 = 1000;
+
+/// Errors from a calibration run
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum CalibrationError {
+    /// Too little audio was captured to analyse
+    #[error("Not enough audio was captured for calibration")]
+    NotEnoughAudio,
+    /// No speech was found in the speech part of the run
+    #[error("No speech was detected, please speak during calibration")]
+    NoSpeechDetected,
+    /// Speech is not measurably louder than the room
+    #[error("Speech could not be told apart from background noise (noise {noise:.2}, speech {speech:.2})")]
+    Indistinguishable { noise: f32, speech: f32 },
+}
+
+/// Outcome of a calibration run
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalibrationResult {
+    /// Proposed silence detection settings (not saved automatically)
+    pub proposed: SilenceConfig,
+    /// VAD probability of the room tone (95th percentile)
+    pub noise_level: f32,
+    /// VAD probability of the speech (75th percentile)
+    pub speech_level: f32,
+    /// Longest pause between words in the speech (milliseconds)
+    pub longest_pause_ms: u32,
+}
+
+/// Run the VAD over samples, returning one speech probability per chunk
+///
+/// Calibrates the engine that will detect speech: if Silero is requested
+/// but unavailable, the energy VAD that stands in for it is measured.
+pub fn vad_probabilities(samples: &[f32], sample_rate: u32, engine: VadEngine) -> Vec<f32> {
+    let vad_config = VadConfig {
+        sample_rate,
+        engine,
+        ..VadConfig::default()
+    };
+    let (mut vad, _) = create_voice_activity(&vad_config);
+
+    let chunk_size = chunk_size_for_sample_rate(sample_rate);
+    samples
+        .chunks_exact(chunk_size)
+        .map(|chunk| vad.speech_probability(chunk))
+        .collect()
+}
+
+/// Propose silence settings from room tone and speech VAD probabilities
+///
+/// # Arguments
+/// * `room_tone` - Per-chunk probabilities while the room was quiet
+/// * `speech` - Per-chunk probabilities while the user was speaking
+/// * `chunk_ms` - Duration of one chunk in milliseconds
+/// * `base` - Current settings; values calibration can't measure are kept
+pub fn propose_config(
+    room_tone: &[f32],
+    speech: &[f32],
+    chunk_ms: u32,
+    base: &SilenceConfig,
+) -> Result<CalibrationResult, CalibrationError> {
+    if room_tone.is_empty() || speech.is_empty() {
+        return Err(CalibrationError::NotEnoughAudio);
+    }
+
+    let noise_level = percentile(room_tone, 0.95);
+    let speech_level = percentile(speech, 0.75);
+    if speech_level - noise_level < CALIBRATION_MIN_SEPARATION {
+        return Err(CalibrationError::Indistinguishable {
+            noise: noise_level,
+            speech: speech_level,
+        });
+    }
+
+    // Midway between the room and the voice, on a 0.05 grid
+    let threshold = (((noise_level + speech_level) / 2.0) * 20.0).round() / 20.0;
+    let threshold = threshold.clamp(VAD_THRESHOLD_MIN, VAD_THRESHOLD_MAX);
+
+    let longest_pause_ms = longest_pause(speech, threshold)
+        .ok_or(CalibrationError::NoSpeechDetected)? as u32
+        * chunk_ms;
+
+    let pause_tolerance_ms = round_up_100(longest_pause_ms).min(PAUSE_TOLERANCE_MAX_MS);
+    let silence_duration_ms =
+        round_up_100(longest_pause_ms).clamp(MIN_PROPOSED_SILENCE_MS, SILENCE_DURATION_MAX_MS);
+
+    Ok(CalibrationResult {
+        proposed: SilenceConfig {
+            vad_speech_threshold: threshold,
+            silence_duration_ms,
+            pause_tolerance_ms,
+            ..base.clone()
+        },
+        noise_level,
+        speech_level,
+        longest_pause_ms,
+    })
+}
+
+/// Value below which `fraction` of the values fall
+fn percentile(values: &[f32], fraction: f32) -> f32 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let index = ((sorted.len() - 1) as f32 * fraction).round() as usize;
+    sorted[index]
+}
+
+/// Longest run of non-speech chunks between the first and last speech chunk
+///
+/// Returns None when no chunk reaches the threshold.
+fn longest_pause(probabilities: &[f32], threshold: f32) -> Option<usize> {
+    let first = probabilities.iter().position(|&p| p >= threshold)?;
+    let last = probabilities.iter().rposition(|&p| p >= threshold)?;
+
+    let mut longest = 0;
+    let mut current = 0;
+    for &p in &probabilities[first..=last] {
+        if p >= threshold {
+            current = 0;
+        } else {
+            current += 1;
+            longest = longest.max(current);
+        }
+    }
+    Some(longest)
+}
+
+fn round_up_100(ms: u32) -> u32 {
+    ms.div_ceil(100) * 100
+}
+
+#[cfg(test)]
+#[path = "calibration_test.rs"]
+mod tests;
@@ -0,0 +1,78 @@
+use super::*;
+
+const CHUNK_MS: u32 = 32;
+
+#[test]
+fn test_propose_config_sets_threshold_between_noise_and_speech() {
+    let room_tone = vec![0.2; 50];
+    let speech = vec![0.9; 50];
+
+    let result = propose_config(&room_tone, &speech, CHUNK_MS, &SilenceConfig::default()).unwrap();
+
+    assert!((result.noise_level - 0.2).abs() < 1e-6);
+    assert!((result.speech_level - 0.9).abs() < 1e-6);
+    assert!((result.proposed.vad_speech_threshold - 0.55).abs() < 1e-6);
+}
+
+#[test]
+fn test_propose_config_sizes_durations_from_longest_pause() {
+    let room_tone = vec![0.05; 50];
+    // Two words separated by a 40-chunk (1280ms) pause, trailing silence ignored
+    let mut speech = vec![0.9; 10];
+    speech.extend(vec![0.1; 40]);
+    speech.extend(vec![0.9; 10]);
+    speech.extend(vec![0.1; 10]);
+
+    let result = propose_config(&room_tone, &speech, CHUNK_MS, &SilenceConfig::default()).unwrap();
+
+    assert_eq!(result.longest_pause_ms, 1280);
+    assert_eq!(result.proposed.pause_tolerance_ms, 1300);
+    assert_eq!(result.proposed.silence_duration_ms, 1300);
+    assert!(result.proposed.validate().is_ok());
+}
+
+#[test]
+fn test_propose_config_keeps_unmeasured_settings() {
+    let base = SilenceConfig {
+        no_speech_timeout_ms: 8000,
+        ..Default::default()
+    };
+    let result = propose_config(&[0.1; 10], &[0.9; 10], CHUNK_MS, &base).unwrap();
+
+    assert_eq!(result.proposed.no_speech_timeout_ms, 8000);
+    assert_eq!(result.proposed.silence_duration_ms, MIN_PROPOSED_SILENCE_MS);
+    assert_eq!(result.proposed.pause_tolerance_ms, 0);
+}
+
+#[test]
+fn test_propose_config_rejects_noise_as_loud_as_speech() {
+    let result = propose_config(&[0.6; 20], &[0.65; 20], CHUNK_MS, &SilenceConfig::default());
+    assert!(matches!(
+        result,
+        Err(CalibrationError::Indistinguishable { .. })
+    ));
+}
+
+#[test]
+fn test_propose_config_requires_audio() {
+    assert_eq!(
+        propose_config(&[], &[0.9; 10], CHUNK_MS, &SilenceConfig::default()),
+        Err(CalibrationError::NotEnoughAudio)
+    );
+}
+
+#[test]
+fn test_vad_probabilities_of_silence_are_low() {
+    let probabilities = vad_probabilities(&vec![0.0; 16000], 16000, VadEngine::Silero);
+    assert_eq!(probabilities.len(), 16000 / 512);
+    assert!(probabilities.iter().all(|&p| p < 0.5));
+}
+
+#[test]
+fn test_vad_probabilities_use_requested_engine() {
+    let tone: Vec<f32> = (0..16000)
+        .map(|i| 0.3 * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16000.0).sin())
+        .collect();
+    let probabilities = vad_probabilities(&tone, 16000, VadEngine::Energy);
+    assert!(probabilities.iter().all(|&p| p > 0.9));
+}
@@ -0,0 +1,332 @@
+// Continuous dictation sessions
+//
+// A continuous session stays open until explicitly ended. Audio from the
+// engine's sample tap is split into utterances with the silence detector;
+// each utterance is trimmed, saved and handed off for transcription as soon
+// as the speaker pauses.
+
+use super::silence::{SilenceConfig, SilenceDetectionResult, SilenceDetector, SilenceStopReason};
+use super::trim::trim_range;
+use super::{RecordingMetadata, TrimConfig};
+use crate::audio::{encode_wav, FileWriter, SampleSource, WavEncodingError, TARGET_SAMPLE_RATE};
+use crate::audio_constants::{
+    CONTINUOUS_MAX_UTTERANCE_SECS, CONTINUOUS_PRE_ROLL_MS, CONTINUOUS_UTTERANCE_SILENCE_MS,
+    DETECTION_INTERVAL_MS, MIN_DETECTION_SAMPLES,
+};
+use crate::util::SettingsAccess;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Settings key for the silence that ends an utterance (milliseconds)
+pub const SETTING_UTTERANCE_SILENCE_MS: &str = "recording.continuous.utteranceSilenceMs";
+/// Settings key for the maximum utterance length (seconds)
+pub const SETTING_MAX_UTTERANCE_SECS: &str = "recording.continuous.maxUtteranceSecs";
+
+/// Configuration for continuous dictation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinuousConfig {
+    /// Silence that ends an utterance (milliseconds)
+    pub utterance_silence_ms: u32,
+    /// Uninterrupted speech is cut at this length (seconds)
+    pub max_utterance_secs: u32,
+}
+
+impl Default for ContinuousConfig {
+    fn default() -> Self {
+        Self {
+            utterance_silence_ms: CONTINUOUS_UTTERANCE_SILENCE_MS,
+            max_utterance_secs: CONTINUOUS_MAX_UTTERANCE_SECS,
+        }
+    }
+}
+
+impl ContinuousConfig {
+    /// Load the configuration from settings, falling back to defaults per key
+    ///
+    /// Zero values are ignored since they would cut every chunk.
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+        let read = |key: &str, default: u32| {
+            settings
+                .get_setting_value(key)
+                .and_then(|v| v.as_u64())
+                .filter(|&v| v > 0)
+                .map(|v| v.min(u32::MAX as u64) as u32)
+                .unwrap_or(default)
+        };
+
+        Self {
+            utterance_silence_ms: read(SETTING_UTTERANCE_SILENCE_MS, defaults.utterance_silence_ms),
+            max_utterance_secs: read(SETTING_MAX_UTTERANCE_SECS, defaults.max_utterance_secs),
+        }
+    }
+}
+
+/// Splits a continuous stream into utterances using the silence detector
+pub(crate) struct UtteranceSplitter {
+    detector: SilenceDetector,
+    /// Samples of the utterance in progress
+    utterance: Vec<f32>,
+    /// Samples not yet passed to the detector
+    unchecked: Vec<f32>,
+    max_samples: usize,
+    pre_roll_samples: usize,
+}
+
+impl UtteranceSplitter {
+    pub(crate) fn new(config: &ContinuousConfig) -> Self {
+        let silence_config = SilenceConfig {
+            silence_duration_ms: config.utterance_silence_ms,
+            // The utterance silence is the whole pause budget
+            pause_tolerance_ms: 0,
+            sample_rate: TARGET_SAMPLE_RATE,
+            ..SilenceConfig::default()
+        };
+
+        Self {
+            detector: SilenceDetector::with_config(silence_config),
+            utterance: Vec::new(),
+            unchecked: Vec::new(),
+            max_samples: config.max_utterance_secs as usize * TARGET_SAMPLE_RATE as usize,
+            pre_roll_samples: (CONTINUOUS_PRE_ROLL_MS * TARGET_SAMPLE_RATE / 1000) as usize,
+        }
+    }
+
+    /// Feed samples, returning a finished utterance when the speaker pauses
+    pub(crate) fn push(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+        self.utterance.extend_from_slice(samples);
+        self.unchecked.extend_from_slice(samples);
+        if self.unchecked.len() < MIN_DETECTION_SAMPLES {
+            return None;
+        }
+
+        let result = self.detector.process_samples(&self.unchecked);
+        self.unchecked.clear();
+
+        match result {
+            SilenceDetectionResult::Stop(SilenceStopReason::SilenceAfterSpeech) => self.cut(),
+            SilenceDetectionResult::Stop(SilenceStopReason::NoSpeechTimeout) => {
+                // Nothing said for a while: drop the silence but keep a short
+                // tail so the next utterance's onset is not clipped
+                let excess = self.utterance.len().saturating_sub(self.pre_roll_samples);
+                self.utterance.drain(..excess);
+                self.detector.reset();
+                None
+            }
+            SilenceDetectionResult::Continue => {
+                if self.utterance.len() >= self.max_samples && self.detector.has_detected_speech() {
+                    self.cut()
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Take the utterance in progress, if it contains speech
+    pub(crate) fn flush(&mut self) -> Option<Vec<f32>> {
+        if !self.unchecked.is_empty() {
+            let _ = self.detector.process_samples(&self.unchecked);
+            self.unchecked.clear();
+        }
+        if self.detector.has_detected_speech() {
+            self.cut()
+        } else {
+            None
+        }
+    }
+
+    fn cut(&mut self) -> Option<Vec<f32>> {
+        self.detector.reset();
+        self.unchecked.clear();
+        Some(std::mem::take(&mut self.utterance))
+    }
+}
+
+/// File writer naming utterance files by session order
+struct UtteranceFileWriter<'a> {
+    recordings_dir: &'a Path,
+    sequence: u64,
+}
+
+impl FileWriter for UtteranceFileWriter<'_> {
+    fn output_dir(&self) -> PathBuf {
+        self.recordings_dir.to_path_buf()
+    }
+
+    fn generate_filename(&self) -> String {
+        let now = chrono::Utc::now();
+        format!(
+            "dictation-{}-{:04}.wav",
+            now.format("%Y-%m-%d-%H%M%S"),
+            self.sequence
+        )
+    }
+
+    fn create_dir_all(&self, path: &Path) -> Result<(), std::io::Error> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn path_exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+}
+
+/// Trim and save one utterance as a WAV file
+pub(crate) fn save_utterance(
+    samples: &[f32],
+    recordings_dir: &Path,
+    sequence: u64,
+    trim_config: &TrimConfig,
+) -> Result<RecordingMetadata, WavEncodingError> {
+    let range = trim_range(samples, TARGET_SAMPLE_RATE, trim_config);
+    let kept = &samples[range];
+    let writer = UtteranceFileWriter {
+        recordings_dir,
+        sequence,
+    };
+    let file_path = encode_wav(kept, TARGET_SAMPLE_RATE, &writer)?;
+
+    let duration_secs = samples.len() as f64 / TARGET_SAMPLE_RATE as f64;
+    Ok(RecordingMetadata {
+        duration_secs,
+        file_path,
+        sample_count: kept.len(),
+        stop_reason: None,
+        trimmed_duration_secs: (kept.len() != samples.len())
+            .then(|| kept.len() as f64 / TARGET_SAMPLE_RATE as f64),
+    })
+}
+
+/// Called with the sequence number and saved file of each utterance
+pub type UtteranceCallback = Box<dyn Fn(u64, RecordingMetadata) + Send + 'static>;
+
+/// Background thread running a continuous dictation session
+pub struct ContinuousDictation {
+    thread: Option<JoinHandle<()>>,
+    should_stop: Arc<AtomicBool>,
+}
+
+impl ContinuousDictation {
+    /// Start a continuous dictation session
+    ///
+    /// # Arguments
+    /// * `config` - Utterance segmentation settings
+    /// * `trim_config` - Silence trimming applied to each utterance
+    /// * `source` - Source of 16kHz mono samples
+    /// * `recordings_dir` - Directory utterance files are saved to
+    /// * `on_utterance` - Called in spoken order for each saved utterance
+    pub fn start<S: SampleSource + 'static>(
+        config: ContinuousConfig,
+        trim_config: TrimConfig,
+        source: S,
+        recordings_dir: PathBuf,
+        on_utterance: UtteranceCallback,
+    ) -> Self {
+        let should_stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = should_stop.clone();
+
+        crate::info!(
+            "[continuous] Starting dictation (utterance silence={}ms)",
+            config.utterance_silence_ms
+        );
+
+        let thread = thread::spawn(move || {
+            dictation_loop(
+                config,
+                trim_config,
+                source,
+                recordings_dir,
+                stop_flag,
+                on_utterance,
+            );
+        });
+
+        Self {
+            thread: Some(thread),
+            should_stop,
+        }
+    }
+
+    /// Check if the dictation thread is still running
+    pub fn is_running(&self) -> bool {
+        self.thread.as_ref().is_some_and(|h| !h.is_finished())
+    }
+
+    /// End the session, delivering any utterance in progress first
+    pub fn stop(&mut self) {
+        self.should_stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.thread.take() {
+            if handle.thread().id() != thread::current().id() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+impl Drop for ContinuousDictation {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Main loop of the dictation thread
+fn dictation_loop<S: SampleSource>(
+    config: ContinuousConfig,
+    trim_config: TrimConfig,
+    mut source: S,
+    recordings_dir: PathBuf,
+    should_stop: Arc<AtomicBool>,
+    on_utterance: UtteranceCallback,
+) {
+    let mut splitter = UtteranceSplitter::new(&config);
+    let mut sequence: u64 = 0;
+    let poll = Duration::from_millis(DETECTION_INTERVAL_MS);
+
+    let mut deliver = |samples: Vec<f32>| {
+        let saved = save_utterance(&samples, &recordings_dir, sequence, &trim_config);
+        match saved {
+            Ok(metadata) => {
+                crate::info!(
+                    "[continuous] Utterance {} saved: {}",
+                    sequence,
+                    metadata.file_path
+                );
+                on_utterance(sequence, metadata);
+                sequence += 1;
+            }
+            Err(e) => crate::error!("[continuous] Failed to save utterance: {:?}", e),
+        }
+    };
+
+    source.start();
+
+    loop {
+        thread::sleep(poll);
+        let stopping = should_stop.load(Ordering::SeqCst);
+
+        let samples = source.read();
+        if let Some(utterance) = splitter.push(&samples) {
+            deliver(utterance);
+        }
+
+        if stopping {
+            if let Some(utterance) = splitter.flush() {
+                deliver(utterance);
+            }
+            break;
+        }
+    }
+
+    source.stop();
+    crate::info!("[continuous] Dictation ended after {} utterances", sequence);
+}
+
+#[cfg(test)]
+#[path = "continuous_test.rs"]
+mod tests;
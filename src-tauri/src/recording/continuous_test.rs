@@ -0,0 +1,104 @@
+use super::*;
+use std::sync::Mutex;
+use tauri::AppHandle;
+use tempfile::TempDir;
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+/// Source that yields silence and records start/stop calls
+struct SilentSource {
+    events: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl SampleSource for SilentSource {
+    fn start(&mut self) {
+        self.events.lock().unwrap().push("start");
+    }
+
+    fn stop(&mut self) {
+        self.events.lock().unwrap().push("stop");
+    }
+
+    fn read(&mut self) -> Vec<f32> {
+        vec![0.0; MIN_DETECTION_SAMPLES]
+    }
+}
+
+#[test]
+fn test_from_settings_without_app_handle_uses_defaults() {
+    assert_eq!(
+        ContinuousConfig::from_settings(&NoAppHandle),
+        ContinuousConfig::default()
+    );
+}
+
+#[test]
+fn test_config_serializes_camel_case() {
+    let json = serde_json::to_string(&ContinuousConfig::default()).unwrap();
+    assert!(json.contains("utteranceSilenceMs"));
+    assert!(json.contains("maxUtteranceSecs"));
+}
+
+#[test]
+fn test_splitter_ignores_silence() {
+    let mut splitter = UtteranceSplitter::new(&ContinuousConfig::default());
+
+    for _ in 0..10 {
+        assert_eq!(splitter.push(&[0.0; MIN_DETECTION_SAMPLES]), None);
+    }
+    assert_eq!(splitter.flush(), None);
+}
+
+#[test]
+fn test_splitter_buffers_partial_chunks() {
+    let mut splitter = UtteranceSplitter::new(&ContinuousConfig::default());
+
+    assert_eq!(splitter.push(&[0.0; 100]), None);
+    assert_eq!(splitter.utterance.len(), 100);
+    assert_eq!(splitter.unchecked.len(), 100);
+}
+
+#[test]
+fn test_save_utterance_writes_sequenced_file() {
+    let dir = TempDir::new().unwrap();
+    let samples = vec![0.0; TARGET_SAMPLE_RATE as usize];
+
+    let metadata = save_utterance(&samples, dir.path(), 7, &TrimConfig::default()).unwrap();
+
+    assert!(metadata.file_path.ends_with("-0007.wav"));
+    assert!(Path::new(&metadata.file_path).exists());
+    assert_eq!(metadata.sample_count, samples.len());
+    assert!((metadata.duration_secs - 1.0).abs() < f64::EPSILON);
+    // Silence alone is never trimmed away
+    assert_eq!(metadata.trimmed_duration_secs, None);
+}
+
+#[test]
+fn test_dictation_stop_releases_source_without_utterances() {
+    let dir = TempDir::new().unwrap();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let source = SilentSource {
+        events: events.clone(),
+    };
+
+    let mut dictation = ContinuousDictation::start(
+        ContinuousConfig::default(),
+        TrimConfig::default(),
+        source,
+        dir.path().to_path_buf(),
+        Box::new(|_: u64, _: RecordingMetadata| panic!("no utterances expected")),
+    );
+
+    thread::sleep(Duration::from_millis(DETECTION_INTERVAL_MS * 3));
+    assert!(dictation.is_running());
+    dictation.stop();
+
+    assert!(!dictation.is_running());
+    assert_eq!(*events.lock().unwrap(), vec!["start", "stop"]);
+}
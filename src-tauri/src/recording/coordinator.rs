@@ -1,16 +1,21 @@
 // Recording detection coordinator
 // Manages silence detection during recording phase
 
+use super::activity::SpeechActivityTracker;
 use super::silence::{SilenceConfig, SilenceDetectionResult, SilenceDetector, SilenceStopReason};
+use super::trim::trim_range;
 use super::{RecordingManager, RecordingMetadata, RecordingState};
 use crate::audio::{encode_wav, AudioBuffer, SystemFileWriter, TARGET_SAMPLE_RATE};
-use crate::audio_constants::{DETECTION_INTERVAL_MS, MIN_DETECTION_SAMPLES};
-use crate::events::{RecordingEventEmitter, RecordingStoppedPayload};
+use crate::audio_constants::{
+    DETECTION_INTERVAL_MS, MIN_DETECTION_SAMPLES, SPEECH_ACTIVITY_INTERVAL_MS,
+};
+use crate::events::silence_events::VadFallbackActivePayload;
+use crate::events::{current_timestamp, RecordingEventEmitter, RecordingStoppedPayload};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Coordinator for silence detection during recording
 ///
@@ -67,6 +72,19 @@ impl RecordingDetectors {
         }
     }
 
+    /// Get the silence detection configuration
+    pub fn silence_config(&self) -> &SilenceConfig {
+        &self.silence_config
+    }
+
+    /// Replace the silence detection configuration
+    ///
+    /// Takes effect from the next recording; a running detection keeps the
+    /// configuration it was started with.
+    pub fn set_silence_config(&mut self, config: SilenceConfig) {
+        self.silence_config = config;
+    }
+
     /// Check if detection is currently running
     ///
     /// Returns true only if the detection thread exists AND is still actively running.
@@ -120,6 +138,14 @@ impl RecordingDetectors {
         // Create silence detector
         let mut silence_detector = SilenceDetector::with_config(self.silence_config.clone());
         silence_detector.reset();
+        if let Some(reason) = silence_detector.vad_fallback() {
+            emitter.emit_vad_fallback_active(VadFallbackActivePayload {
+                requested_engine: self.silence_config.vad_engine,
+                active_engine: silence_detector.vad_engine(),
+                reason: reason.to_string(),
+                timestamp: current_timestamp(),
+            });
+        }
 
         let should_stop = self.should_stop.clone();
         let recordings_dir = self.recordings_dir.clone();
@@ -191,6 +217,10 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
     let mut samples_since_last_check: Vec<f32> = Vec::new();
     let mut loop_count: u64 = 0;
 
+    // Throttles speech_activity events for the overlay
+    let mut activity =
+        SpeechActivityTracker::new(Duration::from_millis(SPEECH_ACTIVITY_INTERVAL_MS));
+
     loop {
         loop_count += 1;
 
@@ -201,15 +231,32 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
         }
 
         // Check if still recording
-        let is_recording = recording_manager
+        let current_state = recording_manager
             .lock()
-            .map(|m| m.get_state() == RecordingState::Recording)
-            .unwrap_or(false);
+            .map(|m| m.get_state())
+            .unwrap_or(RecordingState::Idle);
 
-        if !is_recording {
-            // Recording stopped by other means (hotkey, timeout, etc.)
-            crate::debug!("[coordinator] No longer in Recording state, exiting loop");
-            break;
+        match current_state {
+            RecordingState::Recording => {
+                if silence_detector.is_paused() {
+                    silence_detector.resume();
+                }
+            }
+            RecordingState::Paused => {
+                // Capture is suspended - freeze silence timing without finalizing
+                if !silence_detector.is_paused() {
+                    crate::debug!("[coordinator] Recording paused, suspending detection");
+                    silence_detector.pause();
+                    samples_since_last_check.clear();
+                }
+                thread::sleep(interval);
+                continue;
+            }
+            _ => {
+                // Recording stopped by other means (hotkey, timeout, etc.)
+                crate::debug!("[coordinator] No longer in Recording state, exiting loop");
+                break;
+            }
         }
 
         // Drain NEW samples from ring buffer (lock-free read)
@@ -237,6 +284,17 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
             // Feed to silence detector
             let silence_result = silence_detector.process_samples(&samples_since_last_check);
 
+            if silence_result == SilenceDetectionResult::Continue {
+                if let Some(payload) = activity.observe(
+                    Instant::now(),
+                    silence_detector.is_speech(),
+                    silence_detector.speech_probability(),
+                    silence_detector.time_until_stop(),
+                ) {
+                    emitter.emit_speech_activity(payload);
+                }
+            }
+
             match silence_result {
                 SilenceDetectionResult::Stop(reason) => {
                     crate::info!(
@@ -245,8 +303,13 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                         samples_since_last_check.len()
                     );
 
-                    // Stop audio capture
-                    let _ = audio_thread.stop();
+                    // Stop audio capture. The recording is saved from the buffer
+                    // below, so the engine's own capture file is not needed.
+                    if let Ok(stop_result) = audio_thread.stop() {
+                        if let Some((capture_path, _)) = stop_result.capture_file {
+                            let _ = std::fs::remove_file(capture_path);
+                        }
+                    }
 
                     // Transition to appropriate state
                     if let Ok(mut manager) = recording_manager.lock() {
@@ -263,33 +326,40 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
 
                                 // 2. Get samples and encode WAV
                                 let sample_rate = manager.get_sample_rate().unwrap_or(TARGET_SAMPLE_RATE);
-                                let (file_path, sample_count, duration_secs) = match manager.get_audio_buffer() {
+                                let trim_config = manager.trim_config().clone();
+                                let (file_path, sample_count, duration_secs, trimmed_duration_secs) = match manager.get_audio_buffer() {
                                     Ok(buf) => {
                                         match buf.lock() {
                                             Ok(samples) => {
                                                 let count = samples.len();
                                                 let duration = count as f64 / sample_rate as f64;
+                                                // Drop leading silence and the silent tail that triggered the stop
+                                                let range = trim_range(&samples, sample_rate, &trim_config);
+                                                let kept = range.len();
+                                                let trimmed = (kept != count)
+                                                    .then(|| kept as f64 / sample_rate as f64);
                                                 let writer = SystemFileWriter::new(recordings_dir.clone());
-                                                match encode_wav(&samples, sample_rate, &writer) {
+                                                match encode_wav(&samples[range], sample_rate, &writer) {
                                                     Ok(path) => {
                                                         crate::info!("[coordinator] WAV saved to: {}", path);
-                                                        (path, count, duration)
+                                                        // Sample count describes the saved file
+                                                        (path, kept, duration, trimmed)
                                                     }
                                                     Err(e) => {
                                                         crate::error!("[coordinator] WAV encoding failed: {:?}", e);
-                                                        (String::new(), count, duration)
+                                                        (String::new(), count, duration, None)
                                                     }
                                                 }
                                             }
                                             Err(e) => {
                                                 crate::error!("[coordinator] Buffer lock failed: {:?}", e);
-                                                (String::new(), 0, 0.0)
+                                                (String::new(), 0, 0.0, None)
                                             }
                                         }
                                     }
                                     Err(e) => {
                                         crate::error!("[coordinator] No audio buffer: {:?}", e);
-                                        (String::new(), 0, 0.0)
+                                        (String::new(), 0, 0.0, None)
                                     }
                                 };
 
@@ -299,6 +369,7 @@ fn detection_loop<E: RecordingEventEmitter + 'static>(
                                     file_path: file_path.clone(),
                                     sample_count,
                                     stop_reason: None,
+                                    trimmed_duration_secs,
                                 };
                                 emitter.emit_recording_stopped(RecordingStoppedPayload {
                                     metadata: metadata.clone(),
@@ -17,3 +17,14 @@ fn test_stop_without_start() {
     detectors.stop_monitoring();
     assert!(!detectors.is_running());
 }
+
+#[test]
+fn test_set_silence_config_replaces_config() {
+    let mut detectors = RecordingDetectors::new();
+    let config = SilenceConfig {
+        silence_duration_ms: 4000,
+        ..Default::default()
+    };
+    detectors.set_silence_config(config.clone());
+    assert_eq!(detectors.silence_config(), &config);
+}
@@ -0,0 +1,364 @@
+// Long-form recording session support
+//
+// Long-form recordings never accumulate audio in memory: the Swift capture
+// writes to a temp file that is rotated into fixed-length segments. Each
+// closed segment is handed off for incremental transcription, a warning is
+// emitted shortly before the configured max duration, and the session is
+// stopped once it is reached.
+
+use super::{RecordingManager, RecordingState};
+use crate::audio::AudioThreadHandle;
+use crate::audio_constants::{
+    LONG_FORM_MAX_DURATION_SECS, LONG_FORM_MIN_SEGMENT_SECS, LONG_FORM_SEGMENT_DURATION_SECS,
+    LONG_FORM_TICK_INTERVAL_MS, LONG_FORM_WARNING_LEAD_SECS,
+};
+use crate::events::{long_form_events, LongFormEventEmitter};
+use crate::util::SettingsAccess;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Settings key for the long-form max duration (seconds)
+pub const SETTING_MAX_DURATION_SECS: &str = "recording.longForm.maxDurationSecs";
+/// Settings key for the long-form segment duration (seconds)
+pub const SETTING_SEGMENT_DURATION_SECS: &str = "recording.longForm.segmentDurationSecs";
+/// Settings key for the long-form warning lead time (seconds)
+pub const SETTING_WARNING_LEAD_SECS: &str = "recording.longForm.warningLeadSecs";
+
+/// Errors from validating a long-form configuration
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum LongFormConfigError {
+    /// Max duration must be positive
+    #[error("Max duration must be greater than zero")]
+    ZeroMaxDuration,
+    /// Segments shorter than the minimum give the model too little context
+    #[error("Segment duration must be at least {min}s (got {actual}s)")]
+    SegmentTooShort { min: u64, actual: u64 },
+    /// Warning would fire before (or at) the start of the recording
+    #[error("Warning lead time ({lead}s) must be shorter than the max duration ({max}s)")]
+    WarningLeadTooLong { lead: u64, max: u64 },
+}
+
+/// Configuration for a long-form recording session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongFormConfig {
+    /// Recording is stopped automatically after this many seconds
+    pub max_duration_secs: u64,
+    /// Length of each segment handed off for transcription
+    pub segment_duration_secs: u64,
+    /// How long before the max duration the warning event is emitted
+    pub warning_lead_secs: u64,
+}
+
+impl Default for LongFormConfig {
+    fn default() -> Self {
+        Self {
+            max_duration_secs: LONG_FORM_MAX_DURATION_SECS,
+            segment_duration_secs: LONG_FORM_SEGMENT_DURATION_SECS,
+            warning_lead_secs: LONG_FORM_WARNING_LEAD_SECS,
+        }
+    }
+}
+
+impl LongFormConfig {
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), LongFormConfigError> {
+        if self.max_duration_secs == 0 {
+            return Err(LongFormConfigError::ZeroMaxDuration);
+        }
+        if self.segment_duration_secs < LONG_FORM_MIN_SEGMENT_SECS {
+            return Err(LongFormConfigError::SegmentTooShort {
+                min: LONG_FORM_MIN_SEGMENT_SECS,
+                actual: self.segment_duration_secs,
+            });
+        }
+        if self.warning_lead_secs >= self.max_duration_secs {
+            return Err(LongFormConfigError::WarningLeadTooLong {
+                lead: self.warning_lead_secs,
+                max: self.max_duration_secs,
+            });
+        }
+        Ok(())
+    }
+
+    /// Load the configuration from settings, falling back to defaults
+    ///
+    /// Missing keys use their default value. An invalid combination is logged
+    /// and replaced by the default configuration as a whole.
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+        let read = |key: &str, default: u64| {
+            settings
+                .get_setting_value(key)
+                .and_then(|v| v.as_u64())
+                .unwrap_or(default)
+        };
+
+        let config = Self {
+            max_duration_secs: read(SETTING_MAX_DURATION_SECS, defaults.max_duration_secs),
+            segment_duration_secs: read(
+                SETTING_SEGMENT_DURATION_SECS,
+                defaults.segment_duration_secs,
+            ),
+            warning_lead_secs: read(SETTING_WARNING_LEAD_SECS, defaults.warning_lead_secs),
+        };
+
+        match config.validate() {
+            Ok(()) => config,
+            Err(e) => {
+                crate::warn!(
+                    "[long_form] Invalid long-form settings ({}), using defaults",
+                    e
+                );
+                defaults
+            }
+        }
+    }
+}
+
+/// Action requested by the tracker after time advances
+#[derive(Debug, Clone, PartialEq)]
+pub enum LongFormAction {
+    /// Close the current segment and start a new one
+    RotateSegment,
+    /// Max duration is approaching
+    WarnMaxDuration { remaining_secs: u64 },
+    /// Max duration reached, the recording must stop
+    StopMaxDuration,
+}
+
+/// Tracks elapsed recording time and decides when to rotate, warn and stop
+///
+/// Pure bookkeeping with no I/O, so callers decide what counts as elapsed
+/// time (paused intervals are simply not passed to `advance`).
+#[derive(Debug, Clone)]
+pub struct LongFormTracker {
+    config: LongFormConfig,
+    elapsed: Duration,
+    segment_elapsed: Duration,
+    warned: bool,
+    stopped: bool,
+}
+
+impl LongFormTracker {
+    /// Create a tracker for a new session
+    pub fn new(config: LongFormConfig) -> Self {
+        Self {
+            config,
+            elapsed: Duration::ZERO,
+            segment_elapsed: Duration::ZERO,
+            warned: false,
+            stopped: false,
+        }
+    }
+
+    /// Total recorded time so far
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Advance recorded time and return the actions that became due
+    ///
+    /// Once `StopMaxDuration` has been returned, further calls return nothing.
+    pub fn advance(&mut self, delta: Duration) -> Vec<LongFormAction> {
+        if self.stopped {
+            return Vec::new();
+        }
+
+        self.elapsed += delta;
+        self.segment_elapsed += delta;
+
+        let max = Duration::from_secs(self.config.max_duration_secs);
+        if self.elapsed >= max {
+            self.stopped = true;
+            return vec![LongFormAction::StopMaxDuration];
+        }
+
+        let mut actions = Vec::new();
+
+        let segment = Duration::from_secs(self.config.segment_duration_secs);
+        if self.segment_elapsed >= segment {
+            self.segment_elapsed = Duration::ZERO;
+            actions.push(LongFormAction::RotateSegment);
+        }
+
+        let lead = Duration::from_secs(self.config.warning_lead_secs);
+        if !self.warned && self.elapsed + lead >= max {
+            self.warned = true;
+            let remaining_secs = (max - self.elapsed).as_secs_f64().ceil() as u64;
+            actions.push(LongFormAction::WarnMaxDuration { remaining_secs });
+        }
+
+        actions
+    }
+}
+
+/// Background monitor that drives a long-form session
+///
+/// Ticks while the recording is active, rotating segments and emitting the
+/// duration warning. Exits when the recording leaves the Recording/Paused
+/// states, when `stop()` is called, or after the max duration is reached.
+pub struct LongFormMonitor {
+    thread: Option<JoinHandle<()>>,
+    should_stop: Arc<AtomicBool>,
+}
+
+impl LongFormMonitor {
+    /// Start monitoring a long-form session
+    ///
+    /// # Arguments
+    /// * `config` - Validated long-form configuration
+    /// * `recording_manager` - Used to observe Recording/Paused state
+    /// * `audio_thread` - Handle used to rotate capture segments
+    /// * `emitter` - Emits the max duration warning
+    /// * `on_segment` - Called with the path of each finished segment
+    /// * `on_max_duration` - Called once when the max duration is reached
+    pub fn start<E: LongFormEventEmitter + 'static>(
+        config: LongFormConfig,
+        recording_manager: Arc<Mutex<RecordingManager>>,
+        audio_thread: Arc<AudioThreadHandle>,
+        emitter: Arc<E>,
+        on_segment: Box<dyn Fn(String) + Send + 'static>,
+        on_max_duration: Box<dyn FnOnce() + Send + 'static>,
+    ) -> Self {
+        let should_stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = should_stop.clone();
+
+        crate::info!(
+            "[long_form] Starting monitor (max={}s, segment={}s)",
+            config.max_duration_secs,
+            config.segment_duration_secs
+        );
+
+        let thread = thread::spawn(move || {
+            monitor_loop(
+                config,
+                recording_manager,
+                audio_thread,
+                emitter,
+                stop_flag,
+                on_segment,
+                on_max_duration,
+            );
+        });
+
+        Self {
+            thread: Some(thread),
+            should_stop,
+        }
+    }
+
+    /// Check if the monitor thread is still running
+    #[allow(dead_code)] // Utility method for status checks
+    pub fn is_running(&self) -> bool {
+        self.thread.as_ref().is_some_and(|h| !h.is_finished())
+    }
+
+    /// Stop the monitor and wait for its thread to exit
+    ///
+    /// Safe to call from the monitor's own callbacks: the thread is not
+    /// joined from itself.
+    pub fn stop(&mut self) {
+        self.should_stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.thread.take() {
+            if handle.thread().id() != thread::current().id() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+impl Drop for LongFormMonitor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Main loop of the long-form monitor thread
+fn monitor_loop<E: LongFormEventEmitter>(
+    config: LongFormConfig,
+    recording_manager: Arc<Mutex<RecordingManager>>,
+    audio_thread: Arc<AudioThreadHandle>,
+    emitter: Arc<E>,
+    should_stop: Arc<AtomicBool>,
+    on_segment: Box<dyn Fn(String) + Send + 'static>,
+    on_max_duration: Box<dyn FnOnce() + Send + 'static>,
+) {
+    let max_duration_secs = config.max_duration_secs;
+    let mut tracker = LongFormTracker::new(config);
+    let mut on_max_duration = Some(on_max_duration);
+    let tick = Duration::from_millis(LONG_FORM_TICK_INTERVAL_MS);
+    let mut last_tick = Instant::now();
+
+    loop {
+        thread::sleep(tick);
+        if should_stop.load(Ordering::SeqCst) {
+            break;
+        }
+
+        let now = Instant::now();
+        let delta = now - last_tick;
+        last_tick = now;
+
+        let state = match recording_manager.lock() {
+            Ok(manager) => manager.get_state(),
+            Err(_) => {
+                crate::error!("[long_form] Recording state lock poisoned, stopping monitor");
+                break;
+            }
+        };
+
+        match state {
+            RecordingState::Recording => {}
+            // Paused time does not count towards segments or max duration
+            RecordingState::Paused => continue,
+            _ => {
+                crate::debug!(
+                    "[long_form] Recording no longer active ({:?}), exiting",
+                    state
+                );
+                break;
+            }
+        }
+
+        for action in tracker.advance(delta) {
+            match action {
+                LongFormAction::RotateSegment => match audio_thread.rotate_segment() {
+                    Ok(Some(path)) => {
+                        crate::info!("[long_form] Segment closed: {}", path);
+                        on_segment(path);
+                    }
+                    Ok(None) => crate::warn!("[long_form] Segment rotation produced no file"),
+                    Err(e) => crate::warn!("[long_form] Segment rotation failed: {:?}", e),
+                },
+                LongFormAction::WarnMaxDuration { remaining_secs } => {
+                    crate::info!("[long_form] Max duration in {}s", remaining_secs);
+                    emitter.emit_recording_duration_warning(
+                        long_form_events::RecordingDurationWarningPayload {
+                            remaining_secs,
+                            max_duration_secs,
+                            timestamp: crate::events::current_timestamp(),
+                        },
+                    );
+                }
+                LongFormAction::StopMaxDuration => {
+                    crate::info!(
+                        "[long_form] Max duration reached after {:?}, stopping",
+                        tracker.elapsed()
+                    );
+                    if let Some(callback) = on_max_duration.take() {
+                        callback();
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "long_form_test.rs"]
+mod tests;
@@ -0,0 +1,122 @@
+use super::*;
+use crate::test_utils::MockEmitter;
+use tauri::AppHandle;
+
+fn config(max: u64, segment: u64, lead: u64) -> LongFormConfig {
+    LongFormConfig {
+        max_duration_secs: max,
+        segment_duration_secs: segment,
+        warning_lead_secs: lead,
+    }
+}
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+#[test]
+fn test_default_config_is_valid() {
+    assert!(LongFormConfig::default().validate().is_ok());
+}
+
+#[test]
+fn test_validate_rejects_bad_values() {
+    assert_eq!(
+        config(0, 60, 0).validate(),
+        Err(LongFormConfigError::ZeroMaxDuration)
+    );
+    assert!(matches!(
+        config(3600, 5, 60).validate(),
+        Err(LongFormConfigError::SegmentTooShort { .. })
+    ));
+    assert!(matches!(
+        config(600, 60, 600).validate(),
+        Err(LongFormConfigError::WarningLeadTooLong { .. })
+    ));
+}
+
+#[test]
+fn test_from_settings_without_app_handle_uses_defaults() {
+    assert_eq!(
+        LongFormConfig::from_settings(&NoAppHandle),
+        LongFormConfig::default()
+    );
+}
+
+#[test]
+fn test_config_serializes_camel_case() {
+    let json = serde_json::to_string(&LongFormConfig::default()).unwrap();
+    assert!(json.contains("maxDurationSecs"));
+    assert!(json.contains("segmentDurationSecs"));
+    assert!(json.contains("warningLeadSecs"));
+}
+
+#[test]
+fn test_tracker_rotates_each_segment() {
+    let mut tracker = LongFormTracker::new(config(3600, 60, 30));
+
+    assert!(tracker.advance(Duration::from_secs(59)).is_empty());
+    assert_eq!(
+        tracker.advance(Duration::from_secs(1)),
+        vec![LongFormAction::RotateSegment]
+    );
+    assert!(tracker.advance(Duration::from_secs(30)).is_empty());
+    assert_eq!(
+        tracker.advance(Duration::from_secs(30)),
+        vec![LongFormAction::RotateSegment]
+    );
+}
+
+#[test]
+fn test_tracker_warns_once_before_max_duration() {
+    let mut tracker = LongFormTracker::new(config(120, 300, 30));
+
+    assert!(tracker.advance(Duration::from_secs(89)).is_empty());
+    assert_eq!(
+        tracker.advance(Duration::from_millis(500)),
+        Vec::<LongFormAction>::new()
+    );
+    assert_eq!(
+        tracker.advance(Duration::from_millis(500)),
+        vec![LongFormAction::WarnMaxDuration { remaining_secs: 30 }]
+    );
+    assert!(tracker.advance(Duration::from_secs(10)).is_empty());
+}
+
+#[test]
+fn test_tracker_stops_at_max_duration_and_then_goes_quiet() {
+    let mut tracker = LongFormTracker::new(config(120, 60, 30));
+
+    tracker.advance(Duration::from_secs(100));
+    assert_eq!(
+        tracker.advance(Duration::from_secs(20)),
+        vec![LongFormAction::StopMaxDuration]
+    );
+    assert!(tracker.advance(Duration::from_secs(60)).is_empty());
+    assert_eq!(tracker.elapsed(), Duration::from_secs(120));
+}
+
+#[test]
+fn test_monitor_exits_when_not_recording() {
+    let manager = Arc::new(Mutex::new(RecordingManager::new()));
+    let audio_thread = Arc::new(AudioThreadHandle::spawn());
+    let emitter = Arc::new(MockEmitter::new());
+
+    let mut monitor = LongFormMonitor::start(
+        LongFormConfig::default(),
+        manager,
+        audio_thread,
+        emitter.clone(),
+        Box::new(|_: String| panic!("no segments expected")),
+        Box::new(|| panic!("max duration not expected")),
+    );
+
+    thread::sleep(Duration::from_millis(LONG_FORM_TICK_INTERVAL_MS * 3));
+    assert!(!monitor.is_running());
+    assert_eq!(emitter.duration_warning_count(), 0);
+    monitor.stop();
+}
@@ -1,13 +1,23 @@
 // Recording module for managing recording state
 
+mod activity;
+mod calibration;
+mod continuous;
 mod coordinator;
+mod long_form;
 mod silence;
 mod state;
+mod trim;
 mod vad;
 
+pub use continuous::{ContinuousConfig, ContinuousDictation};
 pub use coordinator::RecordingDetectors;
+pub use long_form::{LongFormConfig, LongFormMonitor};
+pub use calibration::{propose_config, vad_probabilities, CalibrationResult};
 pub use silence::SilenceConfig;
 pub use state::{AudioData, RecordingManager, RecordingMetadata, RecordingState};
+pub use trim::{trim_wav_file, TrimConfig};
+pub use vad::{create_voice_activity, VadConfig, VadEngine, VadError, VoiceActivity};
 
 #[cfg(test)]
 pub use state::RecordingStateError;
@@ -1,13 +1,45 @@
 // Silence detection for automatic recording stop
 // Uses VAD (Voice Activity Detection) to identify end of speech
 
-use super::vad::{create_vad, VadConfig};
+use super::vad::{
+    create_voice_activity, VadConfig, VadEngine, VadError, VoiceActivity, SETTING_VAD_ENGINE,
+};
 use crate::audio_constants::{
-    DEFAULT_SAMPLE_RATE, NO_SPEECH_TIMEOUT_MS, PAUSE_TOLERANCE_MS, SILENCE_DURATION_MS,
-    SILENCE_MIN_SPEECH_FRAMES, VAD_CHUNK_SIZE_16KHZ, VAD_THRESHOLD_SILENCE,
+    DEFAULT_SAMPLE_RATE, NO_SPEECH_TIMEOUT_MAX_MS, NO_SPEECH_TIMEOUT_MIN_MS, NO_SPEECH_TIMEOUT_MS,
+    PAUSE_TOLERANCE_MAX_MS, PAUSE_TOLERANCE_MS, SILENCE_DURATION_MAX_MS, SILENCE_DURATION_MIN_MS,
+    SILENCE_DURATION_MS, SILENCE_MIN_SPEECH_FRAMES, VAD_CHUNK_SIZE_16KHZ, VAD_THRESHOLD_MAX,
+    VAD_THRESHOLD_MIN, VAD_THRESHOLD_SILENCE,
 };
-use std::time::Instant;
-use voice_activity_detector::VoiceActivityDetector;
+use crate::util::SettingsAccess;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::{Duration, Instant};
+
+/// Settings key for the VAD speech threshold
+pub const SETTING_VAD_THRESHOLD: &str = "recording.silence.vadThreshold";
+/// Settings key for the silence duration before auto-stop (milliseconds)
+pub const SETTING_SILENCE_DURATION_MS: &str = "recording.silence.silenceDurationMs";
+/// Settings key for the no-speech timeout (milliseconds)
+pub const SETTING_NO_SPEECH_TIMEOUT_MS: &str = "recording.silence.noSpeechTimeoutMs";
+/// Settings key for the pause tolerance hangover (milliseconds)
+pub const SETTING_PAUSE_TOLERANCE_MS: &str = "recording.silence.pauseToleranceMs";
+
+/// Errors from validating a silence detection configuration
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SilenceConfigError {
+    /// Threshold outside the range the VAD gives useful results in
+    #[error("VAD threshold must be between {min} and {max} (got {actual})")]
+    InvalidThreshold { min: f32, max: f32, actual: f32 },
+    /// Silence duration too short to survive normal speech or too long to be useful
+    #[error("Silence duration must be between {min}ms and {max}ms (got {actual}ms)")]
+    SilenceDurationOutOfRange { min: u32, max: u32, actual: u32 },
+    /// No-speech timeout outside the accepted range
+    #[error("No-speech timeout must be between {min}ms and {max}ms (got {actual}ms)")]
+    NoSpeechTimeoutOutOfRange { min: u32, max: u32, actual: u32 },
+    /// Pause tolerance longer than the accepted maximum
+    #[error("Pause tolerance must be at most {max}ms (got {actual}ms)")]
+    PauseToleranceTooLong { max: u32, actual: u32 },
+}
 
 /// Reason why recording was automatically stopped due to silence detection
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -19,7 +51,8 @@ pub enum SilenceStopReason {
 }
 
 /// Configuration for silence detection
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct SilenceConfig {
     /// VAD speech probability threshold (0.0 - 1.0, default: 0.5)
     pub vad_speech_threshold: f32,
@@ -27,10 +60,12 @@ pub struct SilenceConfig {
     pub silence_duration_ms: u32,
     /// Duration before canceling if no speech detected in milliseconds (default: 5000)
     pub no_speech_timeout_ms: u32,
-    /// Duration of pause that doesn't trigger stop in milliseconds (default: 1000)
-    #[allow(dead_code)] // Reserved for future pause detection refinement
+    /// Hangover after speech before the silence timer starts in milliseconds (default: 1000)
     pub pause_tolerance_ms: u32,
+    /// VAD engine used to detect speech (default: Silero, energy VAD as fallback)
+    pub vad_engine: VadEngine,
     /// Sample rate for VAD processing (default: 16000)
+    #[serde(skip)]
     pub sample_rate: u32,
 }
 
@@ -41,11 +76,99 @@ impl Default for SilenceConfig {
             silence_duration_ms: SILENCE_DURATION_MS,
             no_speech_timeout_ms: NO_SPEECH_TIMEOUT_MS,
             pause_tolerance_ms: PAUSE_TOLERANCE_MS,
+            vad_engine: VadEngine::default(),
             sample_rate: DEFAULT_SAMPLE_RATE,
         }
     }
 }
 
+impl SilenceConfig {
+    /// Validate the user-tunable values
+    pub fn validate(&self) -> Result<(), SilenceConfigError> {
+        if !(VAD_THRESHOLD_MIN..=VAD_THRESHOLD_MAX).contains(&self.vad_speech_threshold) {
+            return Err(SilenceConfigError::InvalidThreshold {
+                min: VAD_THRESHOLD_MIN,
+                max: VAD_THRESHOLD_MAX,
+                actual: self.vad_speech_threshold,
+            });
+        }
+        if !(SILENCE_DURATION_MIN_MS..=SILENCE_DURATION_MAX_MS).contains(&self.silence_duration_ms)
+        {
+            return Err(SilenceConfigError::SilenceDurationOutOfRange {
+                min: SILENCE_DURATION_MIN_MS,
+                max: SILENCE_DURATION_MAX_MS,
+                actual: self.silence_duration_ms,
+            });
+        }
+        if !(NO_SPEECH_TIMEOUT_MIN_MS..=NO_SPEECH_TIMEOUT_MAX_MS)
+            .contains(&self.no_speech_timeout_ms)
+        {
+            return Err(SilenceConfigError::NoSpeechTimeoutOutOfRange {
+                min: NO_SPEECH_TIMEOUT_MIN_MS,
+                max: NO_SPEECH_TIMEOUT_MAX_MS,
+                actual: self.no_speech_timeout_ms,
+            });
+        }
+        if self.pause_tolerance_ms > PAUSE_TOLERANCE_MAX_MS {
+            return Err(SilenceConfigError::PauseToleranceTooLong {
+                max: PAUSE_TOLERANCE_MAX_MS,
+                actual: self.pause_tolerance_ms,
+            });
+        }
+        Ok(())
+    }
+
+    /// Load the configuration from settings, falling back to defaults
+    ///
+    /// Missing keys use their default value. An invalid combination is logged
+    /// and replaced by the default configuration as a whole.
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+        let read_ms = |key: &str, default: u32| {
+            settings
+                .get_setting_value(key)
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(u32::MAX as u64) as u32)
+                .unwrap_or(default)
+        };
+
+        let config = Self {
+            vad_speech_threshold: settings
+                .get_setting_value(SETTING_VAD_THRESHOLD)
+                .and_then(|v| v.as_f64())
+                .map(|v| v as f32)
+                .unwrap_or(defaults.vad_speech_threshold),
+            silence_duration_ms: read_ms(SETTING_SILENCE_DURATION_MS, defaults.silence_duration_ms),
+            no_speech_timeout_ms: read_ms(
+                SETTING_NO_SPEECH_TIMEOUT_MS,
+                defaults.no_speech_timeout_ms,
+            ),
+            pause_tolerance_ms: read_ms(SETTING_PAUSE_TOLERANCE_MS, defaults.pause_tolerance_ms),
+            vad_engine: VadEngine::from_settings(settings),
+            ..defaults
+        };
+
+        match config.validate() {
+            Ok(()) => config,
+            Err(e) => {
+                crate::warn!("[silence] Invalid silence settings ({}), using defaults", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Settings keys and values to persist this configuration
+    pub fn to_settings(&self) -> [(&'static str, serde_json::Value); 5] {
+        [
+            (SETTING_VAD_THRESHOLD, json!(self.vad_speech_threshold)),
+            (SETTING_SILENCE_DURATION_MS, json!(self.silence_duration_ms)),
+            (SETTING_NO_SPEECH_TIMEOUT_MS, json!(self.no_speech_timeout_ms)),
+            (SETTING_PAUSE_TOLERANCE_MS, json!(self.pause_tolerance_ms)),
+            (SETTING_VAD_ENGINE, json!(self.vad_engine)),
+        ]
+    }
+}
+
 /// Result of processing audio samples
 #[derive(Debug, Clone, PartialEq)]
 pub enum SilenceDetectionResult {
@@ -66,10 +189,20 @@ pub struct SilenceDetector {
     has_detected_speech: bool,
     /// When the current silence period started (if currently silent)
     silence_start: Option<Instant>,
+    /// When speech was last detected (start of the pause tolerance hangover)
+    last_speech_at: Option<Instant>,
     /// When recording started (for no-speech timeout)
     recording_start: Instant,
     /// Voice activity detector for speech detection
-    vad: Option<VoiceActivityDetector>,
+    vad: Box<dyn VoiceActivity>,
+    /// Why the configured VAD engine is not in use, if the fallback is active
+    vad_fallback: Option<VadError>,
+    /// When the recording was paused (timers are frozen while set)
+    paused_at: Option<Instant>,
+    /// Highest speech probability in the last processed frame
+    last_probability: f32,
+    /// Whether the last processed frame contained speech
+    last_frame_speech: bool,
 }
 
 impl SilenceDetector {
@@ -80,29 +213,45 @@ impl SilenceDetector {
 
     /// Create a new silence detector with custom configuration
     ///
-    /// Uses the unified VadConfig with silence preset for optimal precision.
+    /// Uses the configured VAD engine; if Silero can't be created the energy
+    /// VAD is used instead and `vad_fallback()` reports why.
     pub fn with_config(config: SilenceConfig) -> Self {
-        // Initialize VAD using unified factory
-        let vad_config = VadConfig {
-            speech_threshold: config.vad_speech_threshold,
-            sample_rate: config.sample_rate,
-            min_speech_frames: SILENCE_MIN_SPEECH_FRAMES,
-        };
-
-        let vad = create_vad(&vad_config).ok();
+        let (vad, vad_fallback) = create_voice_activity(&Self::vad_config(&config));
+        crate::debug!(
+            "[silence] VAD initialized (engine={:?}, threshold={})",
+            vad.engine(),
+            config.vad_speech_threshold
+        );
 
-        if vad.is_some() {
-            crate::debug!("[silence] VAD initialized (threshold={})", config.vad_speech_threshold);
-        } else {
-            crate::debug!("[silence] VAD initialization failed, speech detection will be disabled");
-        }
+        let mut detector = Self::with_vad(config, vad);
+        detector.vad_fallback = vad_fallback;
+        detector
+    }
 
+    /// Create a silence detector around an existing voice activity detector
+    ///
+    /// Useful for running deterministic VADs on synthetic signals in tests.
+    pub fn with_vad(config: SilenceConfig, vad: Box<dyn VoiceActivity>) -> Self {
         Self {
             config,
             has_detected_speech: false,
             silence_start: None,
+            last_speech_at: None,
             recording_start: Instant::now(),
             vad,
+            vad_fallback: None,
+            paused_at: None,
+            last_probability: 0.0,
+            last_frame_speech: false,
+        }
+    }
+
+    fn vad_config(config: &SilenceConfig) -> VadConfig {
+        VadConfig {
+            speech_threshold: config.vad_speech_threshold,
+            sample_rate: config.sample_rate,
+            min_speech_frames: SILENCE_MIN_SPEECH_FRAMES,
+            engine: config.vad_engine,
         }
     }
 
@@ -111,15 +260,19 @@ impl SilenceDetector {
         crate::debug!("[silence] Detector reset for new recording session");
         self.has_detected_speech = false;
         self.silence_start = None;
+        self.last_speech_at = None;
         self.recording_start = Instant::now();
+        self.paused_at = None;
+        self.last_probability = 0.0;
+        self.last_frame_speech = false;
 
-        // Reinitialize VAD for fresh state using unified factory
-        let vad_config = VadConfig {
-            speech_threshold: self.config.vad_speech_threshold,
-            sample_rate: self.config.sample_rate,
-            min_speech_frames: SILENCE_MIN_SPEECH_FRAMES,
-        };
-        self.vad = create_vad(&vad_config).ok();
+        // Silero carries state between chunks, so start from a fresh model.
+        // The energy VAD is stateless and is kept as is.
+        if self.vad.engine() == VadEngine::Silero {
+            let (vad, vad_fallback) = create_voice_activity(&Self::vad_config(&self.config));
+            self.vad = vad;
+            self.vad_fallback = vad_fallback;
+        }
     }
 
     /// Get the configuration
@@ -128,40 +281,110 @@ impl SilenceDetector {
         &self.config
     }
 
+    /// Engine currently detecting speech
+    pub fn vad_engine(&self) -> VadEngine {
+        self.vad.engine()
+    }
+
+    /// Why the configured VAD engine is not in use, if the fallback is active
+    pub fn vad_fallback(&self) -> Option<&VadError> {
+        self.vad_fallback.as_ref()
+    }
+
     /// Check if speech has been detected
-    #[allow(dead_code)] // Utility method for status checks
     pub fn has_detected_speech(&self) -> bool {
         self.has_detected_speech
     }
 
+    /// Suspend timing while the recording is paused
+    ///
+    /// The paused interval is excluded from both the silence and no-speech
+    /// timers once `resume()` is called. Calling this twice is a no-op.
+    pub fn pause(&mut self) {
+        if self.paused_at.is_none() {
+            crate::debug!("[silence] Timing suspended (recording paused)");
+            self.paused_at = Some(Instant::now());
+        }
+    }
+
+    /// Resume timing after a pause
+    ///
+    /// Shifts the timer origins forward by the paused duration so the pause
+    /// does not count towards silence or no-speech timeouts.
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take() {
+            let paused_for = paused_at.elapsed();
+            crate::debug!("[silence] Timing resumed after {:?} pause", paused_for);
+            self.recording_start += paused_for;
+            if let Some(start) = self.silence_start.as_mut() {
+                *start += paused_for;
+            }
+            if let Some(last_speech) = self.last_speech_at.as_mut() {
+                *last_speech += paused_for;
+            }
+        }
+    }
+
+    /// Check if timing is currently suspended
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Highest VAD speech probability in the last processed frame
+    pub fn speech_probability(&self) -> f32 {
+        self.last_probability
+    }
+
+    /// Whether the last processed frame contained speech
+    pub fn is_speech(&self) -> bool {
+        self.last_frame_speech
+    }
+
+    /// Time left until silence after speech stops the recording
+    ///
+    /// None while paused, while the user is speaking, or before any speech
+    /// (the no-speech timeout is a cancellation, not an auto-stop). During
+    /// the pause tolerance hangover the full silence duration is still ahead.
+    pub fn time_until_stop(&self) -> Option<Duration> {
+        if self.is_paused() || !self.has_detected_speech || self.last_frame_speech {
+            return None;
+        }
+
+        let silence_duration = Duration::from_millis(self.config.silence_duration_ms as u64);
+        match self.silence_start {
+            Some(start) => Some(silence_duration.saturating_sub(start.elapsed())),
+            None => {
+                let tolerance = Duration::from_millis(self.config.pause_tolerance_ms as u64);
+                let hangover_left = self
+                    .last_speech_at
+                    .map_or(Duration::ZERO, |last| tolerance.saturating_sub(last.elapsed()));
+                Some(hangover_left + silence_duration)
+            }
+        }
+    }
+
     /// Check if speech is present using VAD
     ///
     /// Processes audio in 512-sample chunks (required by Silero VAD at 16kHz).
     /// Returns true if any chunk has speech probability above threshold.
     fn check_vad(&mut self, samples: &[f32]) -> bool {
-        let vad = match &mut self.vad {
-            Some(v) => v,
-            None => {
-                crate::trace!("[silence] VAD not available, assuming no speech");
-                return false;
-            }
-        };
-
         // Process in VAD_CHUNK_SIZE_16KHZ chunks (required by Silero VAD at 16kHz)
         let chunk_size = VAD_CHUNK_SIZE_16KHZ;
         let mut max_probability: f32 = 0.0;
 
         for chunk in samples.chunks(chunk_size) {
             if chunk.len() == chunk_size {
-                let probability = vad.predict(chunk.to_vec());
+                let probability = self.vad.speech_probability(chunk);
                 max_probability = max_probability.max(probability);
                 if probability >= self.config.vad_speech_threshold {
+                    self.last_probability = probability;
                     return true; // Speech detected
                 }
             }
         }
 
         crate::trace!("[silence] VAD max_probability={:.3}, threshold={}", max_probability, self.config.vad_speech_threshold);
+        self.last_probability = max_probability;
         false
     }
 
@@ -170,13 +393,25 @@ impl SilenceDetector {
     /// Call this periodically with frames of audio (e.g., 100ms chunks).
     /// Returns whether to continue recording or stop (with reason).
     pub fn process_samples(&mut self, samples: &[f32]) -> SilenceDetectionResult {
+        // Samples arriving while paused are not part of the recording
+        if self.is_paused() {
+            return SilenceDetectionResult::Continue;
+        }
+
         let now = Instant::now();
 
         // Use VAD to detect speech
         let has_speech = self.check_vad(samples);
+        self.last_frame_speech = has_speech;
         let is_silent = !has_speech;
 
         if is_silent {
+            // Pauses shorter than the tolerance are still part of speech
+            if self.in_pause_hangover() {
+                crate::trace!("[silence] Pause within tolerance, treating as speech");
+                return SilenceDetectionResult::Continue;
+            }
+
             // Audio is silent (no speech detected by VAD)
             if self.silence_start.is_none() {
                 // Start tracking silence period
@@ -226,10 +461,18 @@ impl SilenceDetector {
             }
             self.has_detected_speech = true;
             self.silence_start = None;
+            self.last_speech_at = Some(now);
         }
 
         SilenceDetectionResult::Continue
     }
+
+    /// Check if the last speech is recent enough to bridge a pause
+    fn in_pause_hangover(&self) -> bool {
+        self.last_speech_at.is_some_and(|last_speech| {
+            last_speech.elapsed().as_millis() < self.config.pause_tolerance_ms as u128
+        })
+    }
 }
 
 impl Default for SilenceDetector {
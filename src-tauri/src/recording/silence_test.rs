@@ -59,3 +59,257 @@ fn test_continues_while_waiting() {
     assert_eq!(result, SilenceDetectionResult::Continue);
     assert!(!detector.has_detected_speech());
 }
+
+#[test]
+fn test_pause_suspends_no_speech_timeout() {
+    let config = SilenceConfig {
+        no_speech_timeout_ms: 100,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_config(config);
+    let silent_samples = vec![0.0; 512];
+
+    detector.pause();
+    assert!(detector.is_paused());
+
+    // Time spent paused must not count towards the timeout
+    thread::sleep(Duration::from_millis(120));
+    assert_eq!(detector.process_samples(&silent_samples), SilenceDetectionResult::Continue);
+
+    detector.resume();
+    assert!(!detector.is_paused());
+    assert_eq!(detector.process_samples(&silent_samples), SilenceDetectionResult::Continue);
+}
+
+#[test]
+fn test_pause_shifts_silence_start() {
+    let config = SilenceConfig {
+        silence_duration_ms: 100,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_config(config);
+    let silent_samples = vec![0.0; 512];
+    detector.has_detected_speech = true;
+
+    // Start silence tracking, then pause longer than the silence threshold
+    let _ = detector.process_samples(&silent_samples);
+    detector.pause();
+    thread::sleep(Duration::from_millis(120));
+    detector.resume();
+
+    assert_eq!(detector.process_samples(&silent_samples), SilenceDetectionResult::Continue);
+}
+
+#[test]
+fn test_pause_within_tolerance_does_not_start_silence_timer() {
+    let config = SilenceConfig {
+        silence_duration_ms: 50,
+        pause_tolerance_ms: 200,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_config(config);
+    let silent_samples = vec![0.0; 512];
+
+    // Simulate speech that just ended
+    detector.has_detected_speech = true;
+    detector.last_speech_at = Some(Instant::now());
+
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(detector.process_samples(&silent_samples), SilenceDetectionResult::Continue);
+    assert!(detector.silence_start.is_none());
+}
+
+#[test]
+fn test_silence_timer_starts_after_pause_tolerance() {
+    let config = SilenceConfig {
+        silence_duration_ms: 50,
+        pause_tolerance_ms: 50,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_config(config);
+    let silent_samples = vec![0.0; 512];
+
+    detector.has_detected_speech = true;
+    detector.last_speech_at = Some(Instant::now());
+
+    // Hangover expires, silence timer starts
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(detector.process_samples(&silent_samples), SilenceDetectionResult::Continue);
+    assert!(detector.silence_start.is_some());
+
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(
+        detector.process_samples(&silent_samples),
+        SilenceDetectionResult::Stop(SilenceStopReason::SilenceAfterSpeech)
+    );
+}
+
+#[test]
+fn test_default_config_is_valid() {
+    assert!(SilenceConfig::default().validate().is_ok());
+}
+
+#[test]
+fn test_validate_rejects_out_of_range_values() {
+    let invalid = |config: SilenceConfig| config.validate().unwrap_err();
+
+    assert!(matches!(
+        invalid(SilenceConfig { vad_speech_threshold: 1.0, ..Default::default() }),
+        SilenceConfigError::InvalidThreshold { .. }
+    ));
+    assert!(matches!(
+        invalid(SilenceConfig { silence_duration_ms: 100, ..Default::default() }),
+        SilenceConfigError::SilenceDurationOutOfRange { .. }
+    ));
+    assert!(matches!(
+        invalid(SilenceConfig { no_speech_timeout_ms: 120_000, ..Default::default() }),
+        SilenceConfigError::NoSpeechTimeoutOutOfRange { .. }
+    ));
+    assert!(matches!(
+        invalid(SilenceConfig { pause_tolerance_ms: 10_000, ..Default::default() }),
+        SilenceConfigError::PauseToleranceTooLong { .. }
+    ));
+}
+
+#[test]
+fn test_config_serializes_camel_case_without_sample_rate() {
+    let json = serde_json::to_string(&SilenceConfig::default()).unwrap();
+    assert!(json.contains("vadSpeechThreshold"));
+    assert!(json.contains("pauseToleranceMs"));
+    assert!(!json.contains("sampleRate"));
+
+    let parsed: SilenceConfig = serde_json::from_str(r#"{"silenceDurationMs":3000}"#).unwrap();
+    assert_eq!(parsed.silence_duration_ms, 3000);
+    assert_eq!(parsed.sample_rate, DEFAULT_SAMPLE_RATE);
+}
+
+#[test]
+fn test_to_settings_covers_all_tunable_keys() {
+    let config = SilenceConfig {
+        silence_duration_ms: 2500,
+        ..Default::default()
+    };
+    let settings = config.to_settings();
+    let keys: Vec<&str> = settings.iter().map(|(key, _)| *key).collect();
+
+    assert_eq!(
+        keys,
+        [
+            SETTING_VAD_THRESHOLD,
+            SETTING_SILENCE_DURATION_MS,
+            SETTING_NO_SPEECH_TIMEOUT_MS,
+            SETTING_PAUSE_TOLERANCE_MS,
+            SETTING_VAD_ENGINE
+        ]
+    );
+    assert_eq!(settings[1].1, serde_json::json!(2500));
+}
+
+#[test]
+fn test_energy_engine_detects_speech_in_synthetic_signal() {
+    let config = SilenceConfig {
+        vad_engine: VadEngine::Energy,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_config(config);
+    let tone: Vec<f32> = (0..512)
+        .map(|i| 0.3 * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16000.0).sin())
+        .collect();
+
+    assert_eq!(detector.vad_engine(), VadEngine::Energy);
+    assert!(detector.vad_fallback().is_none());
+    detector.process_samples(&vec![0.0; 512]);
+    assert!(!detector.has_detected_speech());
+    detector.process_samples(&tone);
+    assert!(detector.has_detected_speech());
+}
+
+#[test]
+fn test_falls_back_to_energy_vad_instead_of_disabling_detection() {
+    // Silero only supports 8kHz and 16kHz
+    let config = SilenceConfig {
+        sample_rate: 44100,
+        ..Default::default()
+    };
+    let detector = SilenceDetector::with_config(config);
+
+    assert_eq!(detector.vad_engine(), VadEngine::Energy);
+    assert!(detector.vad_fallback().is_some());
+}
+
+/// VAD that reports speech for loud chunks, independent of any model
+struct ThresholdVad;
+
+impl VoiceActivity for ThresholdVad {
+    fn speech_probability(&mut self, chunk: &[f32]) -> f32 {
+        if chunk.iter().any(|s| s.abs() > 0.1) { 1.0 } else { 0.0 }
+    }
+
+    fn engine(&self) -> VadEngine {
+        VadEngine::Energy
+    }
+}
+
+#[test]
+fn test_with_vad_uses_injected_detector_across_reset() {
+    let config = SilenceConfig {
+        silence_duration_ms: 50,
+        pause_tolerance_ms: 0,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_vad(config, Box::new(ThresholdVad));
+    detector.reset();
+
+    assert_eq!(detector.process_samples(&[0.5; 512]), SilenceDetectionResult::Continue);
+    assert!(detector.has_detected_speech());
+
+    let _ = detector.process_samples(&[0.0; 512]);
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(
+        detector.process_samples(&[0.0; 512]),
+        SilenceDetectionResult::Stop(SilenceStopReason::SilenceAfterSpeech)
+    );
+}
+
+#[test]
+fn test_time_until_stop_counts_down_after_speech() {
+    let config = SilenceConfig {
+        silence_duration_ms: 2000,
+        pause_tolerance_ms: 0,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_vad(config, Box::new(ThresholdVad));
+
+    // Nothing to count down before speech or while speaking
+    detector.process_samples(&[0.0; 512]);
+    assert_eq!(detector.time_until_stop(), None);
+    detector.process_samples(&[0.5; 512]);
+    assert!(detector.is_speech());
+    assert_eq!(detector.speech_probability(), 1.0);
+    assert_eq!(detector.time_until_stop(), None);
+
+    detector.process_samples(&[0.0; 512]);
+    thread::sleep(Duration::from_millis(50));
+    let remaining = detector.time_until_stop().unwrap();
+    assert!(remaining <= Duration::from_millis(1950));
+    assert!(remaining > Duration::from_millis(1500));
+}
+
+#[test]
+fn test_time_until_stop_includes_pause_tolerance_hangover() {
+    let config = SilenceConfig {
+        silence_duration_ms: 2000,
+        pause_tolerance_ms: 1000,
+        ..Default::default()
+    };
+    let mut detector = SilenceDetector::with_vad(config, Box::new(ThresholdVad));
+    detector.process_samples(&[0.5; 512]);
+    detector.process_samples(&[0.0; 512]);
+
+    let remaining = detector.time_until_stop().unwrap();
+    assert!(remaining > Duration::from_millis(2500));
+    assert!(remaining <= Duration::from_millis(3000));
+
+    detector.pause();
+    assert_eq!(detector.time_until_stop(), None);
+}
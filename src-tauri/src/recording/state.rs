@@ -1,5 +1,6 @@
 // Recording state management for Tauri application
 
+use super::trim::TrimConfig;
 use crate::audio::{AudioBuffer, StopReason, TARGET_SAMPLE_RATE};
 use serde::Serialize;
 
@@ -10,6 +11,8 @@ pub enum RecordingState {
     Idle,
     /// Actively recording audio
     Recording,
+    /// Recording suspended by the user; capture resumes into the same recording
+    Paused,
     /// Recording stopped, processing audio (encoding, saving)
     Processing,
 }
@@ -65,10 +68,22 @@ pub struct RecordingMetadata {
     pub duration_secs: f64,
     /// Path to the saved WAV file
     pub file_path: String,
-    /// Number of audio samples recorded
+    /// Number of audio samples in the saved file
     pub sample_count: usize,
     /// Why recording stopped (None = user initiated, Some = auto-stopped)
     pub stop_reason: Option<StopReason>,
+    /// Duration of the saved file after silence trimming (None = not trimmed)
+    pub trimmed_duration_secs: Option<f64>,
+}
+
+impl RecordingMetadata {
+    /// Duration of the audio actually saved to disk
+    ///
+    /// This is the trimmed duration when silence trimming removed audio,
+    /// otherwise the captured duration.
+    pub fn saved_duration_secs(&self) -> f64 {
+        self.trimmed_duration_secs.unwrap_or(self.duration_secs)
+    }
 }
 
 /// Retained recording data from the last completed recording
@@ -94,6 +109,8 @@ pub struct RecordingManager {
     active_recording: Option<ActiveRecording>,
     /// Retained audio data from the last recording for transcription
     last_recording: Option<LastRecording>,
+    /// Silence trimming applied when a recording is saved
+    trim_config: TrimConfig,
 }
 
 impl RecordingManager {
@@ -104,6 +121,7 @@ impl RecordingManager {
             audio_buffer: None,
             active_recording: None,
             last_recording: None,
+            trim_config: TrimConfig::default(),
         }
     }
 
@@ -112,6 +130,18 @@ impl RecordingManager {
         self.state
     }
 
+    /// Get the silence trimming configuration
+    pub fn trim_config(&self) -> &TrimConfig {
+        &self.trim_config
+    }
+
+    /// Replace the silence trimming configuration
+    ///
+    /// Takes effect for the next recording that is saved.
+    pub fn set_trim_config(&mut self, config: TrimConfig) {
+        self.trim_config = config;
+    }
+
     /// Start recording with the given sample rate
     ///
     /// Transitions from Idle to Recording state and creates the audio buffer.
@@ -146,13 +176,59 @@ impl RecordingManager {
         }
     }
 
+    /// Pause the current recording
+    ///
+    /// Transitions from Recording to Paused. The audio buffer and sample rate
+    /// are kept so that `resume_recording()` continues the same recording.
+    ///
+    /// # Errors
+    /// Returns error if not in Recording state
+    #[must_use = "this returns a Result that should be handled"]
+    pub fn pause_recording(&mut self) -> Result<(), RecordingStateError> {
+        if self.state != RecordingState::Recording {
+            return Err(RecordingStateError::InvalidTransition {
+                from: self.state,
+                to: RecordingState::Paused,
+            });
+        }
+
+        self.state = RecordingState::Paused;
+        Ok(())
+    }
+
+    /// Resume a paused recording
+    ///
+    /// Transitions from Paused back to Recording, reusing the existing buffer.
+    ///
+    /// # Errors
+    /// Returns error if not in Paused state
+    #[must_use = "this returns a Result that should be handled"]
+    pub fn resume_recording(&mut self) -> Result<(), RecordingStateError> {
+        if self.state != RecordingState::Paused {
+            return Err(RecordingStateError::InvalidTransition {
+                from: self.state,
+                to: RecordingState::Recording,
+            });
+        }
+
+        self.state = RecordingState::Recording;
+        Ok(())
+    }
+
+    /// Check whether a recording session is active (Recording or Paused)
+    pub fn is_recording_active(&self) -> bool {
+        matches!(self.state, RecordingState::Recording | RecordingState::Paused)
+    }
+
     /// Transition to a new state with validation
     ///
     /// Valid transitions:
     /// - Recording -> Processing (stops recording, keeps buffer)
+    /// - Paused -> Processing (stops a paused recording, keeps buffer)
     /// - Processing -> Idle (clears buffer, retains samples for transcription)
     ///
     /// Note: Use `start_recording(sample_rate)` for Idle -> Recording transition
+    /// and `pause_recording()`/`resume_recording()` for Recording <-> Paused
     ///
     /// Returns error for invalid transitions
     #[must_use = "this returns a Result that should be handled"]
@@ -160,6 +236,7 @@ impl RecordingManager {
         let valid = matches!(
             (self.state, new_state),
             (RecordingState::Recording, RecordingState::Processing)
+                | (RecordingState::Paused, RecordingState::Processing)
                 | (RecordingState::Processing, RecordingState::Idle)
         );
 
@@ -265,8 +342,8 @@ impl RecordingManager {
 
     /// Abort the current recording without saving
     ///
-    /// Transitions from Recording to Idle, discarding the audio buffer
-    /// without retaining it.
+    /// Transitions from Recording (or Paused) to Idle, discarding the audio
+    /// buffer without retaining it.
     ///
     /// This is used for cancellation scenarios where the user wants to abort
     /// the recording. The partial recording is discarded.
@@ -276,15 +353,15 @@ impl RecordingManager {
     ///
     /// # Returns
     /// * `Ok(())` if abort was successful
-    /// * `Err(RecordingStateError::InvalidTransition)` if not in Recording state
-    ///   or if target state is not Idle
+    /// * `Err(RecordingStateError::InvalidTransition)` if not in Recording or
+    ///   Paused state, or if target state is not Idle
     #[must_use = "this returns a Result that should be handled"]
     pub fn abort_recording(
         &mut self,
         target_state: RecordingState,
     ) -> Result<(), RecordingStateError> {
-        // Can only abort from Recording state
-        if self.state != RecordingState::Recording {
+        // Can only abort an active recording (Recording or Paused)
+        if !self.is_recording_active() {
             return Err(RecordingStateError::InvalidTransition {
                 from: self.state,
                 to: target_state,
@@ -60,6 +60,68 @@ fn test_abort_discards_recording() {
     assert!(manager.get_last_recording_buffer().is_err());
 }
 
+/// Test pause/resume continues the same recording
+/// Samples captured before and after the pause end up in one recording
+#[test]
+fn test_pause_resume_keeps_same_recording() {
+    let mut manager = RecordingManager::new();
+    let buffer = manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    buffer.lock().unwrap().extend_from_slice(&[0.1, 0.2]);
+
+    manager.pause_recording().unwrap();
+    assert_eq!(manager.get_state(), RecordingState::Paused);
+    assert!(manager.is_recording_active());
+
+    // Buffer and sample rate survive the pause
+    assert!(manager.get_audio_buffer().is_ok());
+    assert_eq!(manager.get_sample_rate(), Some(TARGET_SAMPLE_RATE));
+
+    manager.resume_recording().unwrap();
+    assert_eq!(manager.get_state(), RecordingState::Recording);
+    manager
+        .get_audio_buffer()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .extend_from_slice(&[0.3]);
+
+    manager.transition_to(RecordingState::Processing).unwrap();
+    manager.transition_to(RecordingState::Idle).unwrap();
+    let last = manager.get_last_recording_buffer().unwrap();
+    assert_eq!(last.samples, vec![0.1, 0.2, 0.3]);
+}
+
+/// Test that a paused recording can be stopped or aborted directly
+/// and that pause/resume reject invalid source states
+#[test]
+fn test_paused_transitions() {
+    let mut manager = RecordingManager::new();
+
+    // Nothing to pause or resume while idle
+    assert!(manager.pause_recording().is_err());
+    assert!(manager.resume_recording().is_err());
+
+    manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    // Cannot resume a recording that is not paused
+    assert!(manager.resume_recording().is_err());
+
+    manager.pause_recording().unwrap();
+    // Cannot pause twice
+    assert!(manager.pause_recording().is_err());
+    assert_eq!(manager.get_state(), RecordingState::Paused);
+
+    // Stopping while paused goes straight to Processing
+    manager.transition_to(RecordingState::Processing).unwrap();
+    manager.transition_to(RecordingState::Idle).unwrap();
+
+    // Abort works from Paused as well
+    manager.start_recording(TARGET_SAMPLE_RATE).unwrap();
+    manager.pause_recording().unwrap();
+    manager.abort_recording(RecordingState::Idle).unwrap();
+    assert_eq!(manager.get_state(), RecordingState::Idle);
+    assert!(!manager.is_recording_active());
+}
+
 /// Test that invalid operations don't corrupt state
 /// After invalid transitions, manager remains in valid state
 #[test]
@@ -155,3 +217,33 @@ fn test_audio_duration_calculation() {
     let audio = manager.get_last_recording_buffer().unwrap();
     assert!((audio.duration_secs - 1.0).abs() < 0.001);
 }
+
+/// Test trim config can be replaced and defaults to enabled
+#[test]
+fn test_trim_config_defaults_and_updates() {
+    let mut manager = RecordingManager::new();
+    assert!(manager.trim_config().enabled);
+
+    let disabled = crate::recording::TrimConfig {
+        enabled: false,
+        ..Default::default()
+    };
+    manager.set_trim_config(disabled.clone());
+    assert_eq!(manager.trim_config(), &disabled);
+}
+
+/// Test saved duration prefers the trimmed duration
+#[test]
+fn test_saved_duration_prefers_trimmed() {
+    let mut metadata = RecordingMetadata {
+        duration_secs: 5.0,
+        file_path: "/tmp/test.wav".to_string(),
+        sample_count: 80000,
+        stop_reason: None,
+        trimmed_duration_secs: None,
+    };
+    assert_eq!(metadata.saved_duration_secs(), 5.0);
+
+    metadata.trimmed_duration_secs = Some(3.5);
+    assert_eq!(metadata.saved_duration_secs(), 3.5);
+}
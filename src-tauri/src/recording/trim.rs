@@ -0,0 +1,238 @@
+// Silence trimming for saved recordings
+// Uses VAD to drop leading and trailing silence before a recording is kept
+
+use super::vad::{create_voice_activity, VadConfig};
+use crate::audio::WavEncodingError;
+use crate::audio_constants::{
+    chunk_size_for_sample_rate, TRIM_LEADING_PADDING_MS, TRIM_TRAILING_PADDING_MS,
+    VAD_THRESHOLD_SILENCE,
+};
+use crate::util::SettingsAccess;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::path::Path;
+
+/// Settings key for enabling silence trimming
+pub const SETTING_TRIM_ENABLED: &str = "recording.trim.enabled";
+/// Settings key for the padding kept before speech (milliseconds)
+pub const SETTING_TRIM_LEADING_PADDING_MS: &str = "recording.trim.leadingPaddingMs";
+/// Settings key for the padding kept after speech (milliseconds)
+pub const SETTING_TRIM_TRAILING_PADDING_MS: &str = "recording.trim.trailingPaddingMs";
+
+/// Configuration for silence trimming
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimConfig {
+    /// Whether trimming is applied at all
+    pub enabled: bool,
+    /// Audio kept before the first detected speech (milliseconds)
+    pub leading_padding_ms: u32,
+    /// Audio kept after the last detected speech (milliseconds)
+    pub trailing_padding_ms: u32,
+}
+
+impl Default for TrimConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            leading_padding_ms: TRIM_LEADING_PADDING_MS,
+            trailing_padding_ms: TRIM_TRAILING_PADDING_MS,
+        }
+    }
+}
+
+impl TrimConfig {
+    /// Load the configuration from settings, falling back to defaults per key
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+        let read_ms = |key: &str, default: u32| {
+            settings
+                .get_setting_value(key)
+                .and_then(|v| v.as_u64())
+                .map(|v| v.min(u32::MAX as u64) as u32)
+                .unwrap_or(default)
+        };
+
+        Self {
+            enabled: settings
+                .get_setting_value(SETTING_TRIM_ENABLED)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(defaults.enabled),
+            leading_padding_ms: read_ms(
+                SETTING_TRIM_LEADING_PADDING_MS,
+                defaults.leading_padding_ms,
+            ),
+            trailing_padding_ms: read_ms(
+                SETTING_TRIM_TRAILING_PADDING_MS,
+                defaults.trailing_padding_ms,
+            ),
+        }
+    }
+}
+
+/// Length of a recording after silence was trimmed from it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrimmedLength {
+    /// Number of samples kept
+    pub sample_count: usize,
+    /// Duration of the kept samples in seconds
+    pub duration_secs: f64,
+}
+
+/// Compute the range of samples to keep from per-chunk speech flags
+///
+/// Returns the full range when no chunk contains speech, so that a
+/// recording is never trimmed to nothing.
+pub fn speech_range(
+    speech_flags: &[bool],
+    chunk_size: usize,
+    sample_count: usize,
+    sample_rate: u32,
+    config: &TrimConfig,
+) -> Range<usize> {
+    let first = speech_flags.iter().position(|&s| s);
+    let last = speech_flags.iter().rposition(|&s| s);
+
+    let (first, last) = match (first, last) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return 0..sample_count,
+    };
+
+    let ms_to_samples = |ms: u32| (ms as u64 * sample_rate as u64 / 1000) as usize;
+
+    let speech_start = first * chunk_size;
+    let speech_end = ((last + 1) * chunk_size).min(sample_count);
+
+    let start = speech_start.saturating_sub(ms_to_samples(config.leading_padding_ms));
+    let end = speech_end
+        .saturating_add(ms_to_samples(config.trailing_padding_ms))
+        .min(sample_count);
+
+    start..end
+}
+
+/// Find the range of samples to keep after trimming silence
+///
+/// Runs VAD over the samples in fixed-size chunks. Sample rates Silero
+/// does not support are trimmed with the energy VAD instead; a disabled
+/// config leaves the samples untouched.
+pub fn trim_range(samples: &[f32], sample_rate: u32, config: &TrimConfig) -> Range<usize> {
+    if !config.enabled || samples.is_empty() {
+        return 0..samples.len();
+    }
+
+    let vad_config = VadConfig {
+        sample_rate,
+        ..VadConfig::default()
+    };
+    let (mut vad, _) = create_voice_activity(&vad_config);
+
+    let chunk_size = chunk_size_for_sample_rate(sample_rate);
+    let speech_flags: Vec<bool> = samples
+        .chunks(chunk_size)
+        .map(|chunk| {
+            // The trailing partial chunk is padded with silence for the VAD
+            let mut chunk = chunk.to_vec();
+            chunk.resize(chunk_size, 0.0);
+            vad.speech_probability(&chunk) >= VAD_THRESHOLD_SILENCE
+        })
+        .collect();
+
+    speech_range(&speech_flags, chunk_size, samples.len(), sample_rate, config)
+}
+
+/// Trim leading and trailing silence from a WAV file in place
+///
+/// # Returns
+/// * `Ok(Some(length))` - Length of the file after trimming
+/// * `Ok(None)` - Nothing was trimmed (disabled, no speech, or already tight)
+/// * `Err(WavEncodingError)` - If the file cannot be read or rewritten
+pub fn trim_wav_file(
+    path: &Path,
+    config: &TrimConfig,
+) -> Result<Option<TrimmedLength>, WavEncodingError> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    let mut reader = hound::WavReader::open(path)
+        .map_err(|e| WavEncodingError::IoError(e.to_string()))?;
+    let spec = reader.spec();
+
+    if spec.channels != 1 || spec.sample_rate == 0 {
+        crate::debug!("[trim] Unsupported WAV layout {:?}, skipping trim", spec);
+        return Ok(None);
+    }
+
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<_, _>>()
+            .map_err(|e| WavEncodingError::EncodingError(e.to_string()))?,
+        hound::SampleFormat::Int => {
+            let scale = (1_i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / scale))
+                .collect::<Result<_, _>>()
+                .map_err(|e| WavEncodingError::EncodingError(e.to_string()))?
+        }
+    };
+    drop(reader);
+
+    let range = trim_range(&samples, spec.sample_rate, config);
+    if range.start == 0 && range.end == samples.len() {
+        return Ok(None);
+    }
+
+    let kept = &samples[range];
+    let tmp_path = path.with_extension("trim.wav");
+    write_samples(&tmp_path, spec, kept)?;
+    std::fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp_path);
+        WavEncodingError::IoError(e.to_string())
+    })?;
+
+    let duration_secs = kept.len() as f64 / spec.sample_rate as f64;
+    crate::info!(
+        "[trim] Trimmed {} -> {:.2}s ({} samples removed)",
+        path.display(),
+        duration_secs,
+        samples.len() - kept.len()
+    );
+    Ok(Some(TrimmedLength {
+        sample_count: kept.len(),
+        duration_secs,
+    }))
+}
+
+/// Write samples with the same spec as the source file
+fn write_samples(
+    path: &Path,
+    spec: hound::WavSpec,
+    samples: &[f32],
+) -> Result<(), WavEncodingError> {
+    let encoding_error = |e: hound::Error| WavEncodingError::EncodingError(e.to_string());
+    let mut writer = hound::WavWriter::create(path, spec).map_err(encoding_error)?;
+
+    match spec.sample_format {
+        hound::SampleFormat::Float => {
+            for &sample in samples {
+                writer.write_sample(sample).map_err(encoding_error)?;
+            }
+        }
+        hound::SampleFormat::Int => {
+            let max = ((1_i64 << (spec.bits_per_sample - 1)) - 1) as f32;
+            for &sample in samples {
+                let value = (sample.clamp(-1.0, 1.0) * max) as i32;
+                writer.write_sample(value).map_err(encoding_error)?;
+            }
+        }
+    }
+
+    writer.finalize().map_err(encoding_error)
+}
+
+#[cfg(test)]
+#[path = "trim_test.rs"]
+mod tests;
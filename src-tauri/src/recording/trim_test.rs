@@ -0,0 +1,153 @@
+use super::*;
+use tauri::AppHandle;
+use tempfile::TempDir;
+
+const RATE: u32 = 16000;
+const CHUNK: usize = 512;
+
+fn config(leading_ms: u32, trailing_ms: u32) -> TrimConfig {
+    TrimConfig {
+        enabled: true,
+        leading_padding_ms: leading_ms,
+        trailing_padding_ms: trailing_ms,
+    }
+}
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+fn write_silent_wav(path: &Path, samples: usize) {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: RATE,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    for _ in 0..samples {
+        writer.write_sample(0.0_f32).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+#[test]
+fn test_from_settings_without_app_handle_uses_defaults() {
+    assert_eq!(TrimConfig::from_settings(&NoAppHandle), TrimConfig::default());
+}
+
+#[test]
+fn test_speech_range_applies_padding() {
+    // Speech in chunks 10..=19 of 40
+    let flags: Vec<bool> = (0..40).map(|i| (10..20).contains(&i)).collect();
+    let len = 40 * CHUNK;
+
+    // 200ms = 3200 samples before, 300ms = 4800 samples after
+    let range = speech_range(&flags, CHUNK, len, RATE, &config(200, 300));
+    assert_eq!(range, (10 * CHUNK - 3200)..(20 * CHUNK + 4800));
+}
+
+#[test]
+fn test_speech_range_clamps_padding_to_bounds() {
+    let flags = [true, false, false, true];
+    let len = 3 * CHUNK + 100; // partial last chunk
+
+    let range = speech_range(&flags, CHUNK, len, RATE, &config(1000, 1000));
+    assert_eq!(range, 0..len);
+}
+
+#[test]
+fn test_speech_range_without_speech_keeps_everything() {
+    let flags = [false; 8];
+    let range = speech_range(&flags, CHUNK, 8 * CHUNK, RATE, &config(0, 0));
+    assert_eq!(range, 0..8 * CHUNK);
+}
+
+#[test]
+fn test_trim_range_disabled_keeps_everything() {
+    let samples = vec![0.0; 4 * CHUNK];
+    let disabled = TrimConfig {
+        enabled: false,
+        ..TrimConfig::default()
+    };
+    assert_eq!(trim_range(&samples, RATE, &disabled), 0..samples.len());
+}
+
+#[test]
+fn test_trim_range_unsupported_sample_rate_uses_energy_vad() {
+    let rate = 48000;
+    let silence = vec![0.0; 4800];
+    assert_eq!(
+        trim_range(&silence, rate, &TrimConfig::default()),
+        0..silence.len()
+    );
+
+    // One second of silence, half a second of tone, one second of silence
+    let second = rate as usize;
+    let samples: Vec<f32> = (0..second * 5 / 2)
+        .map(|i| {
+            if (second..second * 3 / 2).contains(&i) {
+                0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / rate as f32).sin()
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let range = trim_range(&samples, rate, &config(0, 0));
+    assert!(range.start > 0 && range.end < samples.len());
+    assert!(range.start <= second && range.end >= second * 3 / 2);
+}
+
+#[test]
+fn test_trim_wav_file_leaves_silent_file_untouched() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("silent.wav");
+    write_silent_wav(&path, RATE as usize);
+
+    let result = trim_wav_file(&path, &TrimConfig::default()).unwrap();
+    assert_eq!(result, None);
+    assert_eq!(hound::WavReader::open(&path).unwrap().duration(), RATE);
+}
+
+#[test]
+fn test_trim_wav_file_reports_trimmed_length() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("speech.wav");
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: 48000,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+    // One second of silence, then half a second of tone
+    for i in 0..72000 {
+        let sample = if i < 48000 {
+            0.0
+        } else {
+            0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 48000.0).sin()
+        };
+        writer.write_sample(sample).unwrap();
+    }
+    writer.finalize().unwrap();
+
+    let trimmed = trim_wav_file(&path, &config(0, 0)).unwrap().unwrap();
+
+    assert!(trimmed.sample_count < 72000);
+    assert_eq!(
+        hound::WavReader::open(&path).unwrap().duration() as usize,
+        trimmed.sample_count
+    );
+    assert!((trimmed.duration_secs - trimmed.sample_count as f64 / 48000.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_trim_wav_file_missing_file_errors() {
+    let dir = TempDir::new().unwrap();
+    let result = trim_wav_file(&dir.path().join("missing.wav"), &TrimConfig::default());
+    assert!(matches!(result, Err(WavEncodingError::IoError(_))));
+}
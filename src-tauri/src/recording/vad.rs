@@ -2,10 +2,17 @@
 // Used by silence detection during recording
 
 use crate::audio_constants::{
-    chunk_size_for_sample_rate, DEFAULT_SAMPLE_RATE, VAD_THRESHOLD_SILENCE,
+    chunk_size_for_sample_rate, DEFAULT_SAMPLE_RATE, ENERGY_VAD_FLOOR_DB,
+    ENERGY_VAD_MAX_ZERO_CROSSING_RATE, ENERGY_VAD_NOISE_WEIGHT, ENERGY_VAD_SPEECH_DB,
+    VAD_THRESHOLD_SILENCE,
 };
+use crate::util::SettingsAccess;
+use serde::{Deserialize, Serialize};
 use voice_activity_detector::VoiceActivityDetector;
 
+/// Settings key for the preferred VAD engine
+pub const SETTING_VAD_ENGINE: &str = "recording.vadEngine";
+
 /// Error type for VAD operations
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum VadError {
@@ -56,6 +63,9 @@ pub struct VadConfig {
     /// short utterances like "hello" while filtering random pops.
     #[allow(dead_code)]
     pub min_speech_frames: usize,
+
+    /// Engine to use; the energy VAD stands in if Silero can't be created
+    pub engine: VadEngine,
 }
 
 impl Default for VadConfig {
@@ -65,6 +75,7 @@ impl Default for VadConfig {
             speech_threshold: VAD_THRESHOLD_SILENCE,
             sample_rate: DEFAULT_SAMPLE_RATE,
             min_speech_frames: 2,
+            engine: VadEngine::default(),
         }
     }
 }
@@ -123,6 +134,112 @@ pub fn create_vad(config: &VadConfig) -> Result<VoiceActivityDetector, VadError>
         .map_err(|e| VadError::InitializationFailed(e.to_string()))
 }
 
+/// Available voice activity detection engines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VadEngine {
+    /// Silero neural VAD (ONNX model, 8kHz or 16kHz only)
+    #[default]
+    Silero,
+    /// Energy and zero-crossing heuristic, works at any sample rate
+    Energy,
+}
+
+impl VadEngine {
+    /// Load the preferred engine from settings, defaulting to Silero
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        settings
+            .get_setting_value(SETTING_VAD_ENGINE)
+            .and_then(|v| serde_json::from_value(v).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// A source of per-chunk speech probabilities
+///
+/// Implemented by the Silero model and by [`EnergyVad`], so detectors can
+/// run on either (or on a scripted VAD in tests).
+pub trait VoiceActivity: Send {
+    /// Speech probability (0.0 - 1.0) of one chunk of samples
+    fn speech_probability(&mut self, chunk: &[f32]) -> f32;
+
+    /// Engine behind this detector
+    fn engine(&self) -> VadEngine;
+}
+
+impl VoiceActivity for VoiceActivityDetector {
+    fn speech_probability(&mut self, chunk: &[f32]) -> f32 {
+        self.predict(chunk.to_vec())
+    }
+
+    fn engine(&self) -> VadEngine {
+        VadEngine::Silero
+    }
+}
+
+/// Lightweight VAD based on signal level and zero-crossing rate
+///
+/// Needs no model, so it is always available. Loud chunks score high unless
+/// they cross zero as often as broadband noise does. It can't tell speech
+/// from other loud tonal sounds (music, a TV), so Silero is preferred.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnergyVad;
+
+impl EnergyVad {
+    /// Create an energy VAD
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl VoiceActivity for EnergyVad {
+    fn speech_probability(&mut self, chunk: &[f32]) -> f32 {
+        if chunk.len() < 2 {
+            return 0.0;
+        }
+
+        let mean_square = chunk.iter().map(|s| s * s).sum::<f32>() / chunk.len() as f32;
+        let level_db = 10.0 * mean_square.max(1e-12).log10();
+        let energy = ((level_db - ENERGY_VAD_FLOOR_DB)
+            / (ENERGY_VAD_SPEECH_DB - ENERGY_VAD_FLOOR_DB))
+            .clamp(0.0, 1.0);
+
+        let crossings = chunk
+            .windows(2)
+            .filter(|pair| (pair[0] >= 0.0) != (pair[1] >= 0.0))
+            .count();
+        let zero_crossing_rate = crossings as f32 / (chunk.len() - 1) as f32;
+
+        if zero_crossing_rate > ENERGY_VAD_MAX_ZERO_CROSSING_RATE {
+            energy * ENERGY_VAD_NOISE_WEIGHT
+        } else {
+            energy
+        }
+    }
+
+    fn engine(&self) -> VadEngine {
+        VadEngine::Energy
+    }
+}
+
+/// Create the configured VAD, falling back to the energy VAD
+///
+/// Speech detection must keep working when the Silero model can't be
+/// loaded, so this never fails. The second value carries the Silero error
+/// when the fallback is in use; callers should surface it to the user.
+pub fn create_voice_activity(config: &VadConfig) -> (Box<dyn VoiceActivity>, Option<VadError>) {
+    match config.engine {
+        VadEngine::Energy => (Box::new(EnergyVad::new()), None),
+        VadEngine::Silero => match create_vad(config) {
+            Ok(vad) => (Box::new(vad), None),
+            Err(e) => {
+                crate::warn!("[vad] Silero VAD unavailable, using energy VAD: {}", e);
+                (Box::new(EnergyVad::new()), Some(e))
+            }
+        },
+    }
+}
+
 #[cfg(test)]
 #[path = "vad_test.rs"]
 mod tests;
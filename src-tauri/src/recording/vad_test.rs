@@ -45,3 +45,62 @@ fn test_sample_rate_error_message_mentions_supported_rates() {
     let msg = format!("{}", err);
     assert!(msg.contains("8000") && msg.contains("16000"));
 }
+
+fn sine(amplitude: f32, frequency: f32, len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| amplitude * (2.0 * std::f32::consts::PI * frequency * i as f32 / 16000.0).sin())
+        .collect()
+}
+
+#[test]
+fn test_energy_vad_scores_silence_and_voiced_tone() {
+    let mut vad = EnergyVad::new();
+
+    assert_eq!(vad.speech_probability(&[0.0; 512]), 0.0);
+    assert!(vad.speech_probability(&sine(0.001, 220.0, 512)) < 0.1);
+    assert!(vad.speech_probability(&sine(0.3, 220.0, 512)) > 0.9);
+}
+
+#[test]
+fn test_energy_vad_discounts_noise_like_signals() {
+    let mut vad = EnergyVad::new();
+    // Alternating samples cross zero every sample, like broadband hiss
+    let hiss: Vec<f32> = (0..512).map(|i| if i % 2 == 0 { 0.3 } else { -0.3 }).collect();
+
+    assert!(vad.speech_probability(&hiss) < VAD_THRESHOLD_SILENCE);
+}
+
+#[test]
+fn test_energy_vad_ignores_too_short_chunks() {
+    assert_eq!(EnergyVad::new().speech_probability(&[0.5]), 0.0);
+}
+
+#[test]
+fn test_create_voice_activity_uses_requested_engine() {
+    let config = VadConfig { engine: VadEngine::Energy, ..Default::default() };
+    let (vad, fallback) = create_voice_activity(&config);
+    assert_eq!(vad.engine(), VadEngine::Energy);
+    assert!(fallback.is_none());
+
+    let (vad, fallback) = create_voice_activity(&VadConfig::default());
+    assert_eq!(vad.engine(), VadEngine::Silero);
+    assert!(fallback.is_none());
+}
+
+#[test]
+fn test_create_voice_activity_falls_back_when_silero_unavailable() {
+    let config = VadConfig { sample_rate: 44100, ..Default::default() };
+    let (vad, fallback) = create_voice_activity(&config);
+
+    assert_eq!(vad.engine(), VadEngine::Energy);
+    assert!(matches!(fallback, Some(VadError::ConfigurationInvalid(_))));
+}
+
+#[test]
+fn test_vad_engine_serializes_lowercase() {
+    assert_eq!(serde_json::to_string(&VadEngine::Energy).unwrap(), "\"energy\"");
+    assert_eq!(
+        serde_json::from_str::<VadEngine>("\"silero\"").unwrap(),
+        VadEngine::Silero
+    );
+}
@@ -68,7 +68,7 @@ impl RecordingStorage {
             .add_recording(
                 recording_id.clone(),
                 metadata.file_path.clone(),
-                metadata.duration_secs,
+                metadata.saved_duration_secs(),
                 metadata.sample_count as u64,
                 metadata.stop_reason.clone(),
                 window_context.app_name,
@@ -104,7 +104,7 @@ pub fn store_recording(app_handle: &AppHandle, metadata: &RecordingMetadata, flo
 
         let recording_id = uuid::Uuid::new_v4().to_string();
         let file_path = metadata.file_path.clone();
-        let duration_secs = metadata.duration_secs;
+        let duration_secs = metadata.saved_duration_secs();
         let sample_count = metadata.sample_count as u64;
         let stop_reason = metadata.stop_reason.clone();
         let client = client.inner().clone();
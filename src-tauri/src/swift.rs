@@ -3,7 +3,7 @@
 //! This module provides safe Rust wrappers around Swift functions
 //! compiled via swift-rs.
 
-use swift_rs::SRString;
+use swift_rs::{SRData, SRString};
 
 // Define the FFI function signatures
 // SRString is the Swift-Rs string type that can be safely passed across FFI
@@ -51,11 +51,20 @@ swift_rs::swift!(fn swift_audio_engine_start(device_name: &SRString) -> bool);
 swift_rs::swift!(fn swift_audio_engine_stop());
 swift_rs::swift!(fn swift_audio_engine_set_device(device_name: &SRString) -> bool);
 swift_rs::swift!(fn swift_audio_engine_is_running() -> bool);
+// Returns the active device name (or empty string for the system default)
+swift_rs::swift!(fn swift_audio_engine_get_device() -> SRString);
 swift_rs::swift!(fn swift_audio_engine_get_level() -> u8);
 swift_rs::swift!(fn swift_audio_engine_start_capture() -> bool);
 // Returns file path containing captured samples (or empty string on error)
 swift_rs::swift!(fn swift_audio_engine_stop_capture() -> SRString);
 swift_rs::swift!(fn swift_audio_engine_is_capturing() -> bool);
+swift_rs::swift!(fn swift_audio_engine_pause_capture() -> bool);
+swift_rs::swift!(fn swift_audio_engine_resume_capture() -> bool);
+// Returns file path of the finished segment (or empty string if not capturing)
+swift_rs::swift!(fn swift_audio_engine_rotate_capture() -> SRString);
+// Sample tap for listening mode; drained data is native-endian Float32 bytes
+swift_rs::swift!(fn swift_audio_engine_set_tap_enabled(enabled: bool));
+swift_rs::swift!(fn swift_audio_engine_drain_tap() -> SRData);
 swift_rs::swift!(fn swift_audio_engine_get_duration_ms() -> i64);
 swift_rs::swift!(fn swift_audio_engine_get_sample_count() -> i64);
 swift_rs::swift!(fn swift_audio_engine_get_error() -> SRString);
@@ -188,6 +197,13 @@ pub fn audio_engine_is_running() -> bool {
     unsafe { swift_audio_engine_is_running() }
 }
 
+/// Get the device the engine is using.
+/// Returns None when the engine uses the system default device.
+pub fn audio_engine_current_device() -> Option<String> {
+    let name = unsafe { swift_audio_engine_get_device() }.to_string();
+    (!name.is_empty()).then_some(name)
+}
+
 /// Get the current audio level (0-100).
 /// Available whenever engine is running.
 pub fn audio_engine_get_level() -> u8 {
@@ -210,6 +226,72 @@ pub fn audio_engine_start_capture() -> AudioEngineResult {
     }
 }
 
+/// Pause audio capture. The capture file stays open and paused time is
+/// excluded from the reported duration.
+pub fn audio_engine_pause_capture() -> AudioEngineResult {
+    unsafe {
+        if swift_audio_engine_pause_capture() {
+            AudioEngineResult::Ok
+        } else {
+            let error = swift_audio_engine_get_error().to_string();
+            AudioEngineResult::Failed(if error.is_empty() {
+                "Unknown error pausing capture".to_string()
+            } else {
+                error
+            })
+        }
+    }
+}
+
+/// Resume a paused audio capture, appending to the same capture file.
+pub fn audio_engine_resume_capture() -> AudioEngineResult {
+    unsafe {
+        if swift_audio_engine_resume_capture() {
+            AudioEngineResult::Ok
+        } else {
+            let error = swift_audio_engine_get_error().to_string();
+            AudioEngineResult::Failed(if error.is_empty() {
+                "Unknown error resuming capture".to_string()
+            } else {
+                error
+            })
+        }
+    }
+}
+
+/// Rotate the capture file, returning the path of the finished segment.
+/// Capture continues uninterrupted into a new temp file.
+/// Returns None if not capturing or if the new segment file could not be created.
+pub fn audio_engine_rotate_capture() -> Option<String> {
+    unsafe {
+        let file_path = swift_audio_engine_rotate_capture().to_string();
+        if file_path.is_empty() {
+            let error = swift_audio_engine_get_error().to_string();
+            crate::warn!("Capture rotation failed: {}", error);
+            None
+        } else {
+            crate::debug!("Capture segment ready: {}", file_path);
+            Some(file_path)
+        }
+    }
+}
+
+/// Enable or disable the 16kHz mono sample tap.
+/// The tap runs independently of capture; disabling it discards pending samples.
+pub fn audio_engine_set_tap_enabled(enabled: bool) {
+    unsafe { swift_audio_engine_set_tap_enabled(enabled) }
+}
+
+/// Drain all samples collected by the tap since the last call.
+/// Returns an empty vector if the tap is disabled or the engine is not running.
+pub fn audio_engine_drain_tap() -> Vec<f32> {
+    let data = unsafe { swift_audio_engine_drain_tap() };
+    data.as_slice()
+        .chunks_exact(4)
+        .map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
 /// Stop audio capture and return path to the temp WAV file.
 /// The Swift side writes audio to a temp WAV file to avoid dropped samples.
 /// The caller should move/rename this file to the final location (instant, no I/O).
@@ -1,9 +1,10 @@
 //! Mock event emitters for testing.
 
 use crate::events::{
-    CommandAmbiguousPayload, CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload,
-    RecordingCancelledPayload, RecordingErrorPayload, RecordingStartedPayload,
-    RecordingStoppedPayload, TranscriptionCompletedPayload, TranscriptionErrorPayload,
+    CommandAmbiguousPayload, CommandConfirmationRequiredPayload, CommandExecutedPayload,
+    CommandFailedPayload, CommandMatchedPayload,
+    RecordingCancelledPayload, RecordingErrorPayload, RecordingPausedPayload,
+    RecordingResumedPayload, RecordingStartedPayload, RecordingStoppedPayload, TranscriptionCompletedPayload, TranscriptionErrorPayload,
     TranscriptionStartedPayload,
 };
 use std::sync::{Arc, Mutex};
@@ -14,6 +15,8 @@ pub struct MockEmitter {
     pub started: Arc<Mutex<Vec<RecordingStartedPayload>>>,
     pub stopped: Arc<Mutex<Vec<RecordingStoppedPayload>>>,
     pub cancelled: Arc<Mutex<Vec<RecordingCancelledPayload>>>,
+    pub paused: Arc<Mutex<Vec<RecordingPausedPayload>>>,
+    pub resumed: Arc<Mutex<Vec<RecordingResumedPayload>>>,
     pub errors: Arc<Mutex<Vec<RecordingErrorPayload>>>,
     pub transcription_started: Arc<Mutex<Vec<TranscriptionStartedPayload>>>,
     pub transcription_completed: Arc<Mutex<Vec<TranscriptionCompletedPayload>>>,
@@ -22,8 +25,16 @@ pub struct MockEmitter {
     pub command_executed: Arc<Mutex<Vec<CommandExecutedPayload>>>,
     pub command_failed: Arc<Mutex<Vec<CommandFailedPayload>>>,
     pub command_ambiguous: Arc<Mutex<Vec<CommandAmbiguousPayload>>>,
+    pub command_confirmation_required: Arc<Mutex<Vec<CommandConfirmationRequiredPayload>>>,
     pub key_blocking_unavailable:
         Arc<Mutex<Vec<crate::events::hotkey_events::KeyBlockingUnavailablePayload>>>,
+    pub duration_warnings:
+        Arc<Mutex<Vec<crate::events::long_form_events::RecordingDurationWarningPayload>>>,
+    pub segments_transcribed:
+        Arc<Mutex<Vec<crate::events::long_form_events::LongFormSegmentTranscribedPayload>>>,
+    pub vad_fallbacks: Arc<Mutex<Vec<crate::events::silence_events::VadFallbackActivePayload>>>,
+    pub speech_activity:
+        Arc<Mutex<Vec<crate::events::speech_activity_events::SpeechActivityPayload>>>,
 }
 
 impl MockEmitter {
@@ -43,6 +54,18 @@ impl MockEmitter {
         self.cancelled.lock().unwrap().len()
     }
 
+    pub fn paused_count(&self) -> usize {
+        self.paused.lock().unwrap().len()
+    }
+
+    pub fn resumed_count(&self) -> usize {
+        self.resumed.lock().unwrap().len()
+    }
+
+    pub fn duration_warning_count(&self) -> usize {
+        self.duration_warnings.lock().unwrap().len()
+    }
+
     pub fn last_cancelled(&self) -> Option<RecordingCancelledPayload> {
         self.cancelled.lock().unwrap().last().cloned()
     }
@@ -65,9 +88,31 @@ impl crate::events::RecordingEventEmitter for MockEmitter {
         self.cancelled.lock().unwrap().push(payload);
     }
 
+    fn emit_recording_paused(&self, payload: RecordingPausedPayload) {
+        self.paused.lock().unwrap().push(payload);
+    }
+
+    fn emit_recording_resumed(&self, payload: RecordingResumedPayload) {
+        self.resumed.lock().unwrap().push(payload);
+    }
+
     fn emit_recording_error(&self, payload: RecordingErrorPayload) {
         self.errors.lock().unwrap().push(payload);
     }
+
+    fn emit_vad_fallback_active(
+        &self,
+        payload: crate::events::silence_events::VadFallbackActivePayload,
+    ) {
+        self.vad_fallbacks.lock().unwrap().push(payload);
+    }
+
+    fn emit_speech_activity(
+        &self,
+        payload: crate::events::speech_activity_events::SpeechActivityPayload,
+    ) {
+        self.speech_activity.lock().unwrap().push(payload);
+    }
 }
 
 impl crate::events::TranscriptionEventEmitter for MockEmitter {
@@ -100,6 +145,10 @@ impl crate::events::CommandEventEmitter for MockEmitter {
     fn emit_command_ambiguous(&self, payload: CommandAmbiguousPayload) {
         self.command_ambiguous.lock().unwrap().push(payload);
     }
+
+    fn emit_command_confirmation_required(&self, payload: CommandConfirmationRequiredPayload) {
+        self.command_confirmation_required.lock().unwrap().push(payload);
+    }
 }
 
 impl crate::events::HotkeyEventEmitter for MockEmitter {
@@ -110,3 +159,19 @@ impl crate::events::HotkeyEventEmitter for MockEmitter {
         self.key_blocking_unavailable.lock().unwrap().push(payload);
     }
 }
+
+impl crate::events::LongFormEventEmitter for MockEmitter {
+    fn emit_recording_duration_warning(
+        &self,
+        payload: crate::events::long_form_events::RecordingDurationWarningPayload,
+    ) {
+        self.duration_warnings.lock().unwrap().push(payload);
+    }
+
+    fn emit_long_form_segment_transcribed(
+        &self,
+        payload: crate::events::long_form_events::LongFormSegmentTranscribedPayload,
+    ) {
+        self.segments_transcribed.lock().unwrap().push(payload);
+    }
+}
@@ -0,0 +1,256 @@
+// Inline editing phrases inside dictated text
+//
+// Control phrases spoken in the middle of a dictation ("new line", "comma",
+// "delete last word", ...) are turned into edits before the text is pasted.
+// Whole-utterance voice commands are matched first, so this only ever sees
+// text that is about to be delivered. Saying the escape phrase before a
+// control phrase ("literal new line") dictates the words themselves.
+//
+// Off by default: phrases like "period" and "comma" are ordinary words in
+// prose ("the trial period ended"), so users opt in.
+
+use crate::util::{normalize_word, normalize_words, SettingsAccess};
+use serde::{Deserialize, Serialize};
+
+/// Settings key for turning inline editing phrases on or off
+pub const SETTING_INLINE_EDITS_ENABLED: &str = "dictation.inlineEdits.enabled";
+/// Settings key for the escape phrase
+pub const SETTING_INLINE_EDITS_ESCAPE: &str = "dictation.inlineEdits.escapePhrase";
+/// Settings key for the phrase set (array of `InlinePhrase`), replacing the defaults
+pub const SETTING_INLINE_EDITS_PHRASES: &str = "dictation.inlineEdits.phrases";
+
+/// Escape phrase used when none is configured
+pub const DEFAULT_ESCAPE_PHRASE: &str = "literal";
+
+/// Edit performed when a control phrase is spoken
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "text", rename_all = "snake_case")]
+pub enum InlineEdit {
+    /// Start a new line
+    NewLine,
+    /// Start a new paragraph (blank line in between)
+    NewParagraph,
+    /// Attach punctuation to the previous word
+    Punctuation(String),
+    /// Remove the last word written so far
+    DeleteLastWord,
+    /// Uppercase the following words
+    CapsOn,
+    /// Stop uppercasing
+    CapsOff,
+}
+
+/// A spoken control phrase and the edit it performs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlinePhrase {
+    pub phrase: String,
+    pub edit: InlineEdit,
+}
+
+impl InlinePhrase {
+    fn new(phrase: &str, edit: InlineEdit) -> Self {
+        Self {
+            phrase: phrase.to_string(),
+            edit,
+        }
+    }
+}
+
+/// Configuration for inline editing phrases
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlineEditConfig {
+    /// Whether control phrases are turned into edits (default: false)
+    pub enabled: bool,
+    /// Phrase that makes the following control phrase literal text
+    pub escape_phrase: String,
+    /// Recognised control phrases
+    pub phrases: Vec<InlinePhrase>,
+}
+
+impl Default for InlineEditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            escape_phrase: DEFAULT_ESCAPE_PHRASE.to_string(),
+            phrases: default_phrases(),
+        }
+    }
+}
+
+/// The built-in phrase set
+pub fn default_phrases() -> Vec<InlinePhrase> {
+    vec![
+        InlinePhrase::new("new line", InlineEdit::NewLine),
+        InlinePhrase::new("new paragraph", InlineEdit::NewParagraph),
+        InlinePhrase::new("period", InlineEdit::Punctuation(".".to_string())),
+        InlinePhrase::new("comma", InlineEdit::Punctuation(",".to_string())),
+        InlinePhrase::new("question mark", InlineEdit::Punctuation("?".to_string())),
+        InlinePhrase::new("delete last word", InlineEdit::DeleteLastWord),
+        InlinePhrase::new("caps on", InlineEdit::CapsOn),
+        InlinePhrase::new("caps off", InlineEdit::CapsOff),
+    ]
+}
+
+impl InlineEditConfig {
+    /// Load the configuration from settings, falling back to defaults per key
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+
+        Self {
+            enabled: settings
+                .get_setting_value(SETTING_INLINE_EDITS_ENABLED)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(defaults.enabled),
+            escape_phrase: settings
+                .get_setting(SETTING_INLINE_EDITS_ESCAPE)
+                .filter(|p| !normalize_words(p).is_empty())
+                .unwrap_or(defaults.escape_phrase),
+            phrases: settings
+                .get_setting_value(SETTING_INLINE_EDITS_PHRASES)
+                .and_then(|v| serde_json::from_value(v).ok())
+                .unwrap_or(defaults.phrases),
+        }
+    }
+
+    /// Apply the control phrases in `text`, returning the edited text
+    ///
+    /// Text without any control phrase is returned unchanged.
+    pub fn apply(&self, text: &str) -> String {
+        if !self.enabled {
+            return text.to_string();
+        }
+
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let normalized: Vec<String> = words.iter().map(|w| normalize_word(w)).collect();
+        let escape = normalize_words(&self.escape_phrase);
+        // Longest phrases first so "new paragraph" is not cut short by a shorter phrase
+        let mut phrases: Vec<(Vec<String>, &InlineEdit)> = self
+            .phrases
+            .iter()
+            .map(|p| (normalize_words(&p.phrase), &p.edit))
+            .filter(|(words, _)| !words.is_empty())
+            .collect();
+        phrases.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+
+        let phrase_at = |i: usize| {
+            phrases
+                .iter()
+                .find(|(p, _)| normalized[i..].starts_with(p))
+                .map(|(p, edit)| (p.len(), *edit))
+        };
+
+        let mut writer = EditWriter::default();
+        let mut edited = false;
+        let mut i = 0;
+        while i < words.len() {
+            if !escape.is_empty() && normalized[i..].starts_with(&escape) {
+                if let Some((len, _)) = phrase_at(i + escape.len()) {
+                    let start = i + escape.len();
+                    for word in &words[start..start + len] {
+                        writer.word(word);
+                    }
+                    edited = true;
+                    i = start + len;
+                    continue;
+                }
+            }
+
+            match phrase_at(i) {
+                Some((len, edit)) => {
+                    writer.edit(edit);
+                    edited = true;
+                    i += len;
+                }
+                None => {
+                    writer.word(words[i]);
+                    i += 1;
+                }
+            }
+        }
+
+        if edited {
+            writer.out
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Builds the output text word by word
+#[derive(Default)]
+struct EditWriter {
+    out: String,
+    caps: bool,
+    capitalize_next: bool,
+}
+
+impl EditWriter {
+    fn word(&mut self, word: &str) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push(' ');
+        }
+        if self.caps {
+            self.out.push_str(&word.to_uppercase());
+        } else if std::mem::take(&mut self.capitalize_next) {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                self.out.extend(first.to_uppercase());
+                self.out.push_str(chars.as_str());
+            }
+        } else {
+            self.out.push_str(word);
+        }
+    }
+
+    fn edit(&mut self, edit: &InlineEdit) {
+        match edit {
+            InlineEdit::NewLine => {
+                self.trim_spaces();
+                self.out.push('\n');
+            }
+            InlineEdit::NewParagraph => {
+                self.trim_spaces();
+                self.out.push_str("\n\n");
+            }
+            InlineEdit::Punctuation(mark) => {
+                // The transcriber often punctuates on its own ("hello, comma");
+                // the spoken mark replaces whatever it put there
+                self.trim_spaces();
+                while self.out.ends_with(is_auto_punctuation) {
+                    self.out.pop();
+                }
+                self.out.push_str(mark);
+                self.capitalize_next = mark.ends_with(['.', '?', '!']);
+            }
+            InlineEdit::DeleteLastWord => {
+                let trimmed = self.out.trim_end().len();
+                self.out.truncate(trimmed);
+                let start = self
+                    .out
+                    .rfind(char::is_whitespace)
+                    .map(|i| i + 1)
+                    .unwrap_or(0);
+                self.out.truncate(start);
+                self.trim_spaces();
+            }
+            InlineEdit::CapsOn => self.caps = true,
+            InlineEdit::CapsOff => self.caps = false,
+        }
+    }
+
+    /// Drop trailing spaces, keeping line breaks
+    fn trim_spaces(&mut self) {
+        let trimmed = self.out.trim_end_matches([' ', '\t']).len();
+        self.out.truncate(trimmed);
+    }
+}
+
+fn is_auto_punctuation(c: char) -> bool {
+    matches!(c, '.' | ',' | '?' | '!' | ';' | ':')
+}
+
+#[cfg(test)]
+#[path = "inline_edit_test.rs"]
+mod tests;
@@ -0,0 +1,120 @@
+use super::*;
+use tauri::AppHandle;
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+fn enabled() -> InlineEditConfig {
+    InlineEditConfig {
+        enabled: true,
+        ..InlineEditConfig::default()
+    }
+}
+
+fn apply(text: &str) -> String {
+    enabled().apply(text)
+}
+
+#[test]
+fn test_text_without_control_phrases_is_unchanged() {
+    assert_eq!(apply("Hello  there, world."), "Hello  there, world.");
+}
+
+#[test]
+fn test_new_line_and_new_paragraph() {
+    assert_eq!(apply("Dear Sam new line thanks"), "Dear Sam\nthanks");
+    assert_eq!(
+        apply("First point. New paragraph. Second point."),
+        "First point.\n\nSecond point."
+    );
+}
+
+#[test]
+fn test_punctuation_attaches_to_previous_word_and_replaces_auto_punctuation() {
+    assert_eq!(
+        apply("Hello comma how are you question mark"),
+        "Hello, how are you?"
+    );
+    assert_eq!(apply("Hello, comma, world period."), "Hello, world.");
+    assert_eq!(apply("done period next"), "done. Next");
+}
+
+#[test]
+fn test_delete_last_word() {
+    assert_eq!(
+        apply("send it tomorrow delete last word today"),
+        "send it today"
+    );
+    assert_eq!(apply("one new line two delete last word"), "one\n");
+    assert_eq!(apply("delete last word hi"), "hi");
+}
+
+#[test]
+fn test_caps_on_and_off() {
+    assert_eq!(
+        apply("this is caps on very important caps off ok"),
+        "this is VERY IMPORTANT ok"
+    );
+}
+
+#[test]
+fn test_escape_phrase_dictates_the_words() {
+    assert_eq!(apply("type literal new line here"), "type new line here");
+    // The escape phrase on its own is an ordinary word
+    assert_eq!(apply("literal meaning new line"), "literal meaning\n");
+}
+
+#[test]
+fn test_custom_phrases_and_disabled_config() {
+    let config = InlineEditConfig {
+        escape_phrase: "verbatim".to_string(),
+        phrases: vec![InlinePhrase::new(
+            "full stop",
+            InlineEdit::Punctuation(".".to_string()),
+        )],
+        ..enabled()
+    };
+    assert_eq!(config.apply("done full stop"), "done.");
+    assert_eq!(
+        config.apply("done verbatim full stop period"),
+        "done full stop period"
+    );
+
+    let disabled = InlineEditConfig {
+        enabled: false,
+        ..enabled()
+    };
+    assert_eq!(disabled.apply("a new line b"), "a new line b");
+}
+
+#[test]
+fn test_default_settings_leave_prose_unchanged() {
+    let config = InlineEditConfig::from_settings(&NoAppHandle);
+    assert!(!config.enabled);
+    assert_eq!(
+        config.apply("The trial period ended, comma splices aside, on time."),
+        "The trial period ended, comma splices aside, on time."
+    );
+}
+
+#[test]
+fn test_phrases_round_trip_through_settings_json() {
+    let json = serde_json::to_value(default_phrases()).unwrap();
+    assert_eq!(json[2]["edit"]["kind"], "punctuation");
+    assert_eq!(json[2]["edit"]["text"], ".");
+    let parsed: Vec<InlinePhrase> = serde_json::from_value(json).unwrap();
+    assert_eq!(parsed, default_phrases());
+}
+
+#[test]
+fn test_from_settings_without_app_handle_uses_defaults() {
+    assert_eq!(
+        InlineEditConfig::from_settings(&NoAppHandle),
+        InlineEditConfig::default()
+    );
+}
@@ -1,6 +1,11 @@
 // Transcription service module
-// Provides unified transcription flow for all recording triggers (hotkey, UI button, wake word)
+// Provides unified transcription flow for all recording triggers (hotkey, UI button, wake phrase)
 
+mod inline_edit;
+mod ordering;
 mod service;
+mod undo;
 
+pub use ordering::{DeliveryGate, DeliveryTurn};
 pub use service::RecordingTranscriptionService;
+pub use undo::{scratch_last_output, InjectedOutput, OutputHistory};
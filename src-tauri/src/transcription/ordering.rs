@@ -0,0 +1,99 @@
+// Ordered delivery of concurrently transcribed utterances
+//
+// Continuous dictation transcribes utterances as soon as they end, so a
+// short utterance can finish before a longer one spoken earlier. Each
+// utterance holds a DeliveryTurn and waits for its turn before pasting.
+// A turn that is dropped without delivering (e.g. transcription failed)
+// is skipped so later utterances are never blocked.
+
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// Hands out delivery turns in sequence order
+#[derive(Debug, Default)]
+pub struct DeliveryGate {
+    state: Mutex<GateState>,
+    notify: Notify,
+}
+
+#[derive(Debug, Default)]
+struct GateState {
+    /// Sequence number whose turn it currently is
+    next: u64,
+    /// Finished sequence numbers that are ahead of `next`
+    finished: BTreeSet<u64>,
+}
+
+impl DeliveryGate {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Create the turn for the given sequence number (starting at 0)
+    pub fn turn(self: &Arc<Self>, sequence: u64) -> DeliveryTurn {
+        DeliveryTurn {
+            gate: self.clone(),
+            sequence,
+        }
+    }
+
+    fn is_turn(&self, sequence: u64) -> bool {
+        self.state
+            .lock()
+            .map(|s| s.next >= sequence)
+            .unwrap_or(true)
+    }
+
+    fn finish(&self, sequence: u64) {
+        if let Ok(mut state) = self.state.lock() {
+            state.finished.insert(sequence);
+            loop {
+                let next = state.next;
+                if !state.finished.remove(&next) {
+                    break;
+                }
+                state.next += 1;
+            }
+        }
+        self.notify.notify_waiters();
+    }
+}
+
+/// A single utterance's place in the delivery order
+///
+/// Dropping the turn marks it as delivered.
+#[derive(Debug)]
+pub struct DeliveryTurn {
+    gate: Arc<DeliveryGate>,
+    sequence: u64,
+}
+
+impl DeliveryTurn {
+    /// Sequence number of this turn
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Wait until all earlier turns have been delivered or dropped
+    pub async fn wait(&self) {
+        loop {
+            // Register before checking so a concurrent finish is not missed
+            let notified = self.gate.notify.notified();
+            if self.gate.is_turn(self.sequence) {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Drop for DeliveryTurn {
+    fn drop(&mut self) {
+        self.gate.finish(self.sequence);
+    }
+}
+
+#[cfg(test)]
+#[path = "ordering_test.rs"]
+mod tests;
@@ -0,0 +1,69 @@
+use super::*;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_first_turn_does_not_wait() {
+    let gate = DeliveryGate::new();
+    let turn = gate.turn(0);
+
+    tokio::time::timeout(Duration::from_millis(100), turn.wait())
+        .await
+        .expect("turn 0 should not wait");
+}
+
+#[tokio::test]
+async fn test_later_turn_waits_for_earlier_delivery() {
+    let gate = DeliveryGate::new();
+    let first = gate.turn(0);
+    let second = gate.turn(1);
+
+    assert!(
+        tokio::time::timeout(Duration::from_millis(50), second.wait())
+            .await
+            .is_err(),
+        "turn 1 must wait for turn 0"
+    );
+
+    drop(first);
+    tokio::time::timeout(Duration::from_millis(100), second.wait())
+        .await
+        .expect("turn 1 should proceed once turn 0 is delivered");
+}
+
+#[tokio::test]
+async fn test_out_of_order_completion_is_delivered_in_order() {
+    let gate = DeliveryGate::new();
+    let delivered = Arc::new(Mutex::new(Vec::new()));
+
+    // Spawn in reverse so later utterances are ready first
+    let mut handles = Vec::new();
+    for sequence in (0..4).rev() {
+        let turn = gate.turn(sequence);
+        let delivered = delivered.clone();
+        handles.push(tokio::spawn(async move {
+            turn.wait().await;
+            delivered.lock().unwrap().push(turn.sequence());
+        }));
+    }
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    assert_eq!(*delivered.lock().unwrap(), vec![0, 1, 2, 3]);
+}
+
+#[tokio::test]
+async fn test_dropped_turn_is_skipped() {
+    let gate = DeliveryGate::new();
+    let first = gate.turn(0);
+    let second = gate.turn(1);
+    let third = gate.turn(2);
+
+    // Turn 1 fails before it is ever delivered, ahead of turn 0
+    drop(second);
+    drop(first);
+
+    tokio::time::timeout(Duration::from_millis(100), third.wait())
+        .await
+        .expect("turn 2 should not wait for the dropped turn 1");
+}
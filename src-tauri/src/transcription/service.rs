@@ -2,22 +2,31 @@
 // Handles: WAV transcription → command matching → clipboard fallback
 //
 // This service decouples transcription from HotkeyIntegration, enabling
-// button-initiated recordings and wake word flows to share the same logic.
+// button-initiated recordings and wake phrase flows to share the same logic.
 
+use super::inline_edit::InlineEditConfig;
+use super::ordering::DeliveryTurn;
+use super::undo::{is_scratch_phrase, scratch_last_output, InjectedOutput};
+use crate::app::state::OutputHistoryState;
 use crate::dictionary::{DictionaryEntry, DictionaryExpander, ExpansionResult};
 use crate::events::{
     current_timestamp, CommandAmbiguousPayload, CommandCandidate, CommandEventEmitter,
-    CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload,
     TranscriptionCompletedPayload, TranscriptionErrorPayload, TranscriptionEventEmitter,
     TranscriptionStartedPayload,
 };
 use crate::parakeet::{SharedTranscriptionModel, TranscriptionService as TranscriptionServiceTrait};
 use crate::recording::RecordingManager;
-use crate::turso::TursoClient;
+use crate::storage::WindowContext;
+use crate::turso::{PipelineMatch, PipelineOutcome, TursoClient};
+use crate::voice_commands::command_prefix::CommandPrefixConfig;
+use crate::voice_commands::confirmation::{handle_follow_up, run_or_confirm, ConfirmationGate};
+use crate::voice_commands::disambiguation::{Disambiguator, PendingCandidate, Resolution};
 use crate::voice_commands::executor::ActionDispatcher;
 use crate::voice_commands::matcher::{CommandMatcher, MatchResult};
+use crate::voice_commands::pipeline_log::PipelineRecord;
 use crate::voice_commands::registry::CommandDefinition;
-use crate::window_context::ContextResolver;
+use crate::window_context::{CommandPrefixMode, ContextResolver};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
@@ -58,7 +67,7 @@ fn simulate_paste() -> Result<(), String> {
 /// This service provides a unified transcription flow that can be used by:
 /// - Hotkey-triggered recordings
 /// - Button-initiated recordings (via stop_recording command)
-/// - Wake word recordings
+/// - Wake phrase recordings
 ///
 /// The flow is: WAV transcription → command matching → clipboard fallback
 pub struct RecordingTranscriptionService<T, C>
@@ -90,6 +99,12 @@ where
     dictionary_expander: Arc<RwLock<Option<DictionaryExpander>>>,
     /// Optional context resolver for window-aware command/dictionary resolution
     context_resolver: Option<Arc<ContextResolver>>,
+    /// Optional disambiguator for follow-up choices after ambiguous matches
+    disambiguator: Option<Arc<Disambiguator>>,
+    /// Holds commands that need confirmation before they run
+    confirmation_gate: Arc<ConfirmationGate>,
+    /// Set by the command hotkey; the next recording is matched without the prefix
+    command_hotkey_armed: Arc<AtomicBool>,
 }
 
 impl<T, C> RecordingTranscriptionService<T, C>
@@ -117,6 +132,9 @@ where
             transcription_timeout: Duration::from_secs(DEFAULT_TRANSCRIPTION_TIMEOUT_SECS),
             dictionary_expander: Arc::new(RwLock::new(None)),
             context_resolver: None,
+            disambiguator: None,
+            confirmation_gate: Arc::new(ConfirmationGate::new()),
+            command_hotkey_armed: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -163,6 +181,18 @@ where
         self
     }
 
+    /// Add disambiguator for resolving ambiguous matches by voice (builder pattern)
+    pub fn with_disambiguator(mut self, disambiguator: Arc<Disambiguator>) -> Self {
+        self.disambiguator = Some(disambiguator);
+        self
+    }
+
+    /// Share the confirmation gate with the hotkey flow and UI commands (builder pattern)
+    pub fn with_confirmation_gate(mut self, gate: Arc<ConfirmationGate>) -> Self {
+        self.confirmation_gate = gate;
+        self
+    }
+
     /// Update the dictionary expander with new entries at runtime
     ///
     /// This method is called when dictionary entries are added, updated, or deleted
@@ -202,6 +232,42 @@ where
     /// This method is non-blocking - it spawns the transcription as an async task.
     #[cfg_attr(coverage_nightly, coverage(off))]
     pub fn process_recording(&self, file_path: String) {
+        let from_command_hotkey = self.command_hotkey_armed.swap(false, Ordering::SeqCst);
+        self.spawn_processing(file_path, None, from_command_hotkey);
+    }
+
+    /// Mark the next recording as started from the command hotkey
+    ///
+    /// It is matched against voice commands even when the command prefix is
+    /// required and wasn't said.
+    pub fn arm_command_hotkey(&self) {
+        self.command_hotkey_armed.store(true, Ordering::SeqCst);
+    }
+
+    /// Forget a command hotkey press that didn't lead to a recording
+    pub fn disarm_command_hotkey(&self) {
+        self.command_hotkey_armed.store(false, Ordering::SeqCst);
+    }
+
+    /// Process one utterance of a continuous dictation session
+    ///
+    /// Runs the same pipeline as `process_recording`, but waits for `turn`
+    /// before matching commands or pasting so utterances are delivered in
+    /// the order they were spoken. Utterances queue for a transcription slot
+    /// instead of being dropped when too many are in flight.
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    pub fn process_utterance(&self, file_path: String, turn: DeliveryTurn) {
+        self.spawn_processing(file_path, Some(turn), false);
+    }
+
+    /// Spawn the transcription pipeline, optionally ordered by a delivery turn
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    fn spawn_processing(
+        &self,
+        file_path: String,
+        turn: Option<DeliveryTurn>,
+        from_command_hotkey: bool,
+    ) {
         // Check if model is loaded
         if !self.shared_transcription_model.is_loaded() {
             crate::info!("Transcription skipped: transcription model not loaded");
@@ -221,13 +287,21 @@ where
         let timeout_duration = self.transcription_timeout;
         let dictionary_expander = self.dictionary_expander.clone();
         let context_resolver = self.context_resolver.clone();
+        let disambiguator = self.disambiguator.clone();
+        let confirmation_gate = self.confirmation_gate.clone();
 
         crate::info!("Spawning transcription task for: {}", file_path);
 
         // Spawn async task using Tauri's async runtime
         tauri::async_runtime::spawn(async move {
+            // Utterances are not backed by the recording buffer
+            let owns_recording_buffer = turn.is_none();
+
             // Helper to clear recording buffer - call this in all exit paths to prevent memory leaks
             let clear_recording_buffer = || {
+                if !owns_recording_buffer {
+                    return;
+                }
                 if let Ok(mut manager) = recording_state.lock() {
                     manager.clear_last_recording();
                     crate::debug!("Cleared recording buffer");
@@ -235,9 +309,13 @@ where
             };
 
             // Acquire semaphore permit to limit concurrent transcriptions
-            let _permit = match semaphore.try_acquire() {
-                Ok(permit) => permit,
-                Err(_) => {
+            let permit = match turn {
+                Some(_) => semaphore.acquire().await.ok(),
+                None => semaphore.try_acquire().ok(),
+            };
+            let permit = match permit {
+                Some(permit) => permit,
+                None => {
                     crate::warn!("Too many concurrent transcriptions, skipping this one");
                     transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
                         error: "Too many transcriptions in progress. Please wait and try again."
@@ -316,8 +394,9 @@ where
             );
 
             // Store transcription in Turso using storage abstraction (async since we're in async context)
+            let mut transcription_id = None;
             if let Some(turso) = app_handle.try_state::<TursoClientState>() {
-                if let Err(e) = crate::storage::TranscriptionStorage::store(
+                match crate::storage::TranscriptionStorage::store(
                     &turso,
                     &file_path_for_storage,
                     &text,
@@ -326,7 +405,8 @@ where
                 )
                 .await
                 {
-                    crate::warn!("Failed to store transcription: {}", e);
+                    Ok(id) => transcription_id = Some(id),
+                    Err(e) => crate::warn!("Failed to store transcription: {}", e),
                 }
             }
 
@@ -416,7 +496,7 @@ where
                             } else {
                                 crate::debug!("[DictionaryExpansion] No global expander configured");
                                 ExpansionResult {
-                                    expanded_text: text,
+                                    expanded_text: text.clone(),
                                     should_press_enter: false,
                                 }
                             }
@@ -424,7 +504,7 @@ where
                         Err(e) => {
                             crate::warn!("[DictionaryExpansion] Failed to acquire dictionary expander lock: {}", e);
                             ExpansionResult {
-                                expanded_text: text,
+                                expanded_text: text.clone(),
                                 should_press_enter: false,
                             }
                         }
@@ -432,25 +512,52 @@ where
                 }
             };
             let expanded_text = expansion_result.expanded_text;
+            let was_expanded = expanded_text != text;
+
+            // Deliver utterances in the order they were spoken. The transcription
+            // slot is released first so a later utterance holding it can never
+            // block an earlier one from transcribing.
+            if let Some(ref turn) = turn {
+                drop(permit);
+                turn.wait().await;
+            }
 
-            // Try voice command matching if configured (using expanded text)
-            let command_handled =
-                Self::try_command_matching(&expanded_text, &turso_client, &command_matcher, &action_dispatcher, &command_emitter, &transcription_emitter, &context_resolver)
-                    .await;
+            // "Scratch that" removes the last output instead of being delivered,
+            // otherwise try voice command matching if configured (using expanded text)
+            let mut pipeline_record = if is_scratch_phrase(&text) {
+                Some(Self::scratch_that(&app_handle).await)
+            } else {
+                // Commands may need the prefix or the command hotkey; anything else is dictation
+                let prefix_mode = match context_resolver {
+                    Some(ref resolver) => resolver.get_command_prefix_mode_async().await,
+                    None => CommandPrefixMode::Inherit,
+                };
+                let command_text = CommandPrefixConfig::from_settings(&app_handle)
+                    .command_text(&expanded_text, prefix_mode, from_command_hotkey);
+                Self::try_command_matching(&expanded_text, command_text.as_deref(), &turso_client, &command_matcher, &action_dispatcher, &command_emitter, &transcription_emitter, &context_resolver, &disambiguator, &confirmation_gate)
+                    .await
+            };
 
             // Fallback to clipboard if no command was handled (using expanded text)
             // Safety check: don't paste during shutdown
-            if !command_handled && !crate::shutdown::is_shutting_down() {
-                if let Err(e) = app_handle.clipboard().write_text(&expanded_text) {
+            if pipeline_record.is_none() && !crate::shutdown::is_shutting_down() {
+                // Inline phrases ("new line", "comma", ...) become edits in dictated text
+                let output_text = InlineEditConfig::from_settings(&app_handle).apply(&expanded_text);
+                // Kept so "scratch that" can put the clipboard back
+                let previous_clipboard = app_handle.clipboard().read_text().ok();
+                let delivered = if let Err(e) = app_handle.clipboard().write_text(&output_text) {
                     crate::warn!("Failed to copy to clipboard: {}", e);
+                    PipelineOutcome::PasteFailed
                 } else {
                     crate::debug!("Transcribed text copied to clipboard");
                     if let Err(e) = simulate_paste() {
                         crate::warn!("Failed to auto-paste: {}", e);
+                        PipelineOutcome::PasteFailed
                     } else {
                         crate::debug!("Auto-pasted transcribed text");
 
                         // Simulate Enter keypress if auto_enter was triggered
+                        let mut pressed_enter = false;
                         if expansion_result.should_press_enter {
                             crate::debug!("Auto-enter triggered, simulating Enter keypress");
                             match crate::keyboard::KeyboardSimulator::new() {
@@ -459,6 +566,7 @@ where
                                         crate::warn!("Failed to simulate enter keypress: {}", e);
                                     } else {
                                         crate::debug!("Successfully simulated Enter keypress");
+                                        pressed_enter = true;
                                     }
                                 }
                                 Err(e) => {
@@ -466,8 +574,32 @@ where
                                 }
                             }
                         }
+
+                        if let Some(history) = app_handle.try_state::<OutputHistoryState>() {
+                            let app = WindowContext::capture().app_name;
+                            history.record(
+                                app.as_deref(),
+                                InjectedOutput::pasted(output_text.clone(), previous_clipboard, pressed_enter),
+                            );
+                        }
+
+                        if was_expanded {
+                            PipelineOutcome::Expanded
+                        } else {
+                            PipelineOutcome::Pasted
+                        }
                     }
-                }
+                };
+                pipeline_record = Some(PipelineRecord::delivered(delivered));
+            }
+
+            // Record what happened to the utterance in the pipeline log
+            if let (Some(record), Some(turso)) =
+                (pipeline_record, app_handle.try_state::<TursoClientState>())
+            {
+                record
+                    .write(&turso, transcription_id, start_time.elapsed())
+                    .await;
             }
 
             // Always emit transcription_completed with expanded text (whether command handled or not)
@@ -487,19 +619,42 @@ where
         });
     }
 
+    /// Remove the last output from the frontmost app ("scratch that")
+    #[cfg_attr(coverage_nightly, coverage(off))]
+    async fn scratch_that(app_handle: &AppHandle) -> PipelineRecord {
+        let Some(history) = app_handle.try_state::<OutputHistoryState>() else {
+            return PipelineRecord::built_in(PipelineOutcome::Failed);
+        };
+        match scratch_last_output(app_handle, &history).await {
+            Ok(_) => PipelineRecord::built_in(PipelineOutcome::Undone),
+            Err(e) => {
+                crate::info!("Scratch that: {}", e);
+                PipelineRecord::built_in(PipelineOutcome::Failed)
+            }
+        }
+    }
+
     /// Try to match the transcribed text against voice commands
     ///
-    /// Returns true if a command was matched and handled, false otherwise.
+    /// Returns what was done when a command was matched and handled, None otherwise.
     /// When a context_resolver is provided, uses context-resolved commands for matching.
+    /// The text is first tried as the answer to a command waiting for confirmation
+    /// or, when a disambiguator is provided, as the choice for a pending ambiguous match.
+    /// Otherwise `command_text` (the text with any command prefix stripped) is matched;
+    /// None means the utterance is dictation.
+    #[allow(clippy::too_many_arguments)]
     async fn try_command_matching(
         text: &str,
+        command_text: Option<&str>,
         turso_client: &Option<Arc<TursoClient>>,
         command_matcher: &Option<Arc<CommandMatcher>>,
         action_dispatcher: &Option<Arc<ActionDispatcher>>,
         command_emitter: &Option<Arc<C>>,
         transcription_emitter: &Arc<T>,
         context_resolver: &Option<Arc<ContextResolver>>,
-    ) -> bool {
+        disambiguator: &Option<Arc<Disambiguator>>,
+        confirmation_gate: &Arc<ConfirmationGate>,
+    ) -> Option<PipelineRecord> {
         // Check if all voice command components are configured
         let (client, matcher, dispatcher, emitter) = match (
             turso_client,
@@ -510,10 +665,28 @@ where
             (Some(c), Some(m), Some(d), Some(e)) => (c, m, d, e),
             _ => {
                 crate::debug!("Voice commands not configured, skipping command matching");
-                return false;
+                return None;
             }
         };
 
+        // A waiting confirmation or ambiguous match takes the next utterance as its answer
+        if let Some(record) = handle_follow_up(
+            text,
+            dispatcher,
+            emitter.as_ref(),
+            confirmation_gate,
+            disambiguator.as_deref(),
+        )
+        .await
+        {
+            return Some(record);
+        }
+
+        let Some(text) = command_text else {
+            crate::debug!("Command prefix required but not said, treating as dictation");
+            return None;
+        };
+
         // Fetch all commands from Turso
         let all_commands = match client.list_voice_commands().await {
             Ok(commands) => commands,
@@ -522,19 +695,21 @@ where
                 transcription_emitter.emit_transcription_error(TranscriptionErrorPayload {
                     error: "Failed to load voice commands. Please try again.".to_string(),
                 });
-                return false;
+                return None;
             }
         };
 
         // Local enum to capture match results
         enum MatchOutcome {
             Matched {
+                kind: PipelineMatch,
                 cmd: CommandDefinition,
                 trigger: String,
                 confidence: f64,
+                parameters: std::collections::HashMap<String, String>,
             },
             Ambiguous {
-                candidates: Vec<CommandCandidate>,
+                candidates: Vec<PendingCandidate>,
             },
             NoMatch,
         }
@@ -564,38 +739,46 @@ where
         let outcome = match match_result {
             MatchResult::Exact {
                 command: matched_cmd,
-                ..
+                parameters,
             } => match commands_by_id.get(&matched_cmd.id) {
                 Some(cmd) => MatchOutcome::Matched {
+                    kind: PipelineMatch::Exact,
                     cmd: (*cmd).clone(),
                     trigger: matched_cmd.trigger.clone(),
                     confidence: 1.0,
+                    parameters,
                 },
                 None => MatchOutcome::NoMatch,
             },
             MatchResult::Fuzzy {
                 command: matched_cmd,
                 score,
-                ..
+                parameters,
             } => match commands_by_id.get(&matched_cmd.id) {
                 Some(cmd) => MatchOutcome::Matched {
+                    kind: PipelineMatch::Fuzzy,
                     cmd: (*cmd).clone(),
                     trigger: matched_cmd.trigger.clone(),
                     confidence: score,
+                    parameters,
                 },
                 None => MatchOutcome::NoMatch,
             },
             MatchResult::Ambiguous { candidates } => {
-                let candidate_data: Vec<_> = candidates
-                    .iter()
-                    .map(|c| CommandCandidate {
-                        id: c.command.id.to_string(),
-                        trigger: c.command.trigger.clone(),
-                        confidence: c.score,
+                let pending: Vec<_> = candidates
+                    .into_iter()
+                    .filter_map(|c| {
+                        commands_by_id
+                            .get(&c.command.id)
+                            .map(|cmd| PendingCandidate {
+                                command: (*cmd).clone(),
+                                parameters: c.parameters,
+                                confidence: c.score,
+                            })
                     })
                     .collect();
                 MatchOutcome::Ambiguous {
-                    candidates: candidate_data,
+                    candidates: pending,
                 }
             }
             MatchResult::NoMatch => MatchOutcome::NoMatch,
@@ -603,58 +786,61 @@ where
 
         match outcome {
             MatchOutcome::Matched {
+                kind,
                 cmd,
                 trigger,
                 confidence,
+                parameters,
             } => {
                 crate::info!(
                     "Command matched: {} (confidence: {:.2})",
                     trigger, confidence
                 );
 
-                // Emit command_matched event
-                emitter.emit_command_matched(CommandMatchedPayload {
+                // Emit command_matched, then execute or wait for confirmation
+                let resolution = Resolution {
+                    candidate: PendingCandidate {
+                        command: cmd,
+                        parameters,
+                        confidence,
+                    },
                     transcription: text.to_string(),
-                    command_id: cmd.id.to_string(),
-                    trigger: trigger.clone(),
-                    confidence,
-                });
-
-                // Execute command
-                match dispatcher.execute(&cmd).await {
-                    Ok(action_result) => {
-                        crate::info!("Command executed: {}", action_result.message);
-                        emitter.emit_command_executed(CommandExecutedPayload {
-                            command_id: cmd.id.to_string(),
-                            trigger: trigger.clone(),
-                            message: action_result.message,
-                        });
-                    }
-                    Err(action_error) => {
-                        crate::error!("Command execution failed: {}", action_error);
-                        emitter.emit_command_failed(CommandFailedPayload {
-                            command_id: cmd.id.to_string(),
-                            trigger: trigger.clone(),
-                            error_code: action_error.code.to_string(),
-                            error_message: action_error.message,
-                        });
-                    }
-                }
-                true // Command was handled
+                };
+                let candidate = resolution.candidate.clone();
+                let result =
+                    run_or_confirm(dispatcher, emitter.as_ref(), confirmation_gate, resolution).await;
+                Some(PipelineRecord::ran(kind, &candidate, result.as_ref()))
             }
             MatchOutcome::Ambiguous { candidates } => {
                 crate::info!("Ambiguous match: {} candidates", candidates.len());
+                let record = PipelineRecord::awaiting_choice(&candidates);
+                let candidate_data = candidates
+                    .iter()
+                    .map(|c| CommandCandidate {
+                        id: c.command.id.to_string(),
+                        trigger: c.command.trigger.clone(),
+                        confidence: c.confidence,
+                    })
+                    .collect();
+                let timeout_ms = match disambiguator {
+                    Some(disambiguator) => {
+                        disambiguator.begin(text, candidates);
+                        disambiguator.timeout().as_millis() as u64
+                    }
+                    None => 0,
+                };
 
                 // Emit command_ambiguous event for disambiguation UI
                 emitter.emit_command_ambiguous(CommandAmbiguousPayload {
                     transcription: text.to_string(),
-                    candidates,
+                    candidates: candidate_data,
+                    timeout_ms,
                 });
-                true // Command matching was handled (ambiguous)
+                Some(record) // Command matching was handled (ambiguous)
             }
             MatchOutcome::NoMatch => {
                 crate::debug!("No command match for: {}", text);
-                false // Fall through to clipboard
+                None // Fall through to clipboard
             }
         }
     }
@@ -1,8 +1,8 @@
 use super::*;
 use crate::dictionary::DictionaryEntry;
 use crate::events::{
-    CommandAmbiguousPayload, CommandExecutedPayload, CommandFailedPayload,
-    CommandMatchedPayload, TranscriptionCompletedPayload, TranscriptionErrorPayload,
+    CommandAmbiguousPayload, CommandConfirmationRequiredPayload, CommandExecutedPayload,
+    CommandFailedPayload, CommandMatchedPayload, TranscriptionCompletedPayload, TranscriptionErrorPayload,
     TranscriptionStartedPayload,
 };
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -46,6 +46,7 @@ impl CommandEventEmitter for MockCommandEmitter {
     fn emit_command_executed(&self, _payload: CommandExecutedPayload) {}
     fn emit_command_failed(&self, _payload: CommandFailedPayload) {}
     fn emit_command_ambiguous(&self, _payload: CommandAmbiguousPayload) {}
+    fn emit_command_confirmation_required(&self, _payload: CommandConfirmationRequiredPayload) {}
 }
 
 #[test]
@@ -0,0 +1,311 @@
+// Scratch that - removes the last text the pipeline put into an app
+//
+// Every paste and every TypeText command output is recorded against the
+// frontmost app in a bounded history. "Scratch that" (spoken, or via the
+// scratch hotkey) takes the newest entry for the current app, deletes it with
+// backspaces (or, for long outputs, selects it and deletes the selection) and,
+// for pastes, puts back the clipboard the paste replaced.
+// Saying it again walks further back through the history. An entry is only
+// dropped once it has been deleted, so a failed scratch can be retried.
+
+use crate::keyboard::shortcut::{Key, KeyCombo, KeyStroke, Modifier, NamedKey, MAX_STROKE_REPEAT};
+use crate::keyboard_capture::permissions::check_accessibility_permission;
+use crate::storage::WindowContext;
+use crate::util::spoken_phrase;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+/// How many outputs are remembered per app
+pub const DEFAULT_HISTORY_PER_APP: usize = 20;
+
+/// Phrases that remove the last output instead of being delivered
+const SCRATCH_PHRASES: &[&str] = &["scratch that", "undo that", "delete that", "strike that"];
+
+/// How an output got into the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InjectionKind {
+    /// Copied to the clipboard and pasted
+    Pasted,
+    /// Typed key by key by a TypeText command
+    Typed,
+}
+
+/// Text the pipeline put into an app
+#[derive(Debug, Clone, PartialEq)]
+pub struct InjectedOutput {
+    pub text: String,
+    pub kind: InjectionKind,
+    /// Clipboard text a paste replaced
+    pub previous_clipboard: Option<String>,
+    /// Enter was pressed after the text
+    pub pressed_enter: bool,
+}
+
+impl InjectedOutput {
+    /// Text pasted over `previous_clipboard`
+    pub fn pasted(text: String, previous_clipboard: Option<String>, pressed_enter: bool) -> Self {
+        Self {
+            text,
+            kind: InjectionKind::Pasted,
+            previous_clipboard,
+            pressed_enter,
+        }
+    }
+
+    /// Text typed by a command
+    pub fn typed(text: String) -> Self {
+        Self {
+            text,
+            kind: InjectionKind::Typed,
+            previous_clipboard: None,
+            pressed_enter: false,
+        }
+    }
+
+    /// Backspaces needed to remove the output
+    pub fn backspaces(&self) -> u32 {
+        backspace_count(&self.text) + u32::from(self.pressed_enter)
+    }
+
+    /// Clipboard text to put back, if the clipboard still holds this paste
+    ///
+    /// Anything the user copied after the paste is left alone.
+    pub fn clipboard_to_restore(&self, current_clipboard: Option<&str>) -> Option<&str> {
+        match (self.kind, current_clipboard) {
+            (InjectionKind::Pasted, Some(current)) if current == self.text => {
+                self.previous_clipboard.as_deref()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Errors from scratching the last output
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ScratchError {
+    /// No output is recorded for the frontmost app
+    #[error("Nothing to scratch in this app")]
+    NothingToScratch,
+    /// Backspaces can't be sent without Accessibility permission
+    #[error("Accessibility permission not granted. Please enable it in System Preferences > Security & Privacy > Privacy > Accessibility")]
+    PermissionDenied,
+    /// Sending the backspaces failed
+    #[error("Failed to delete text: {0}")]
+    Keyboard(String),
+}
+
+/// Recent outputs, newest last, for each app
+pub struct OutputHistory {
+    entries: Mutex<HashMap<String, VecDeque<InjectedOutput>>>,
+    per_app: usize,
+}
+
+impl Default for OutputHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutputHistory {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_HISTORY_PER_APP)
+    }
+
+    /// Create a history remembering up to `per_app` outputs per app
+    pub fn with_capacity(per_app: usize) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            per_app: per_app.max(1),
+        }
+    }
+
+    /// Remember an output put into `app` (None when the app is unknown)
+    pub fn record(&self, app: Option<&str>, output: InjectedOutput) {
+        if output.text.is_empty() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            let history = entries.entry(app_key(app)).or_default();
+            if history.len() == self.per_app {
+                history.pop_front();
+            }
+            history.push_back(output);
+        }
+    }
+
+    /// Take the newest output put into `app`
+    pub fn take_last(&self, app: Option<&str>) -> Option<InjectedOutput> {
+        self.entries.lock().ok()?.get_mut(&app_key(app))?.pop_back()
+    }
+
+    /// Newest output put into `app`, left in the history
+    pub fn last(&self, app: Option<&str>) -> Option<InjectedOutput> {
+        self.entries
+            .lock()
+            .ok()?
+            .get(&app_key(app))?
+            .back()
+            .cloned()
+    }
+
+    /// Forget `output` once it has been deleted from `app`
+    ///
+    /// Outputs recorded after it are kept.
+    pub fn remove(&self, app: Option<&str>, output: &InjectedOutput) {
+        if let Ok(mut entries) = self.entries.lock() {
+            if let Some(history) = entries.get_mut(&app_key(app)) {
+                if let Some(index) = history.iter().rposition(|entry| entry == output) {
+                    history.remove(index);
+                }
+            }
+        }
+    }
+
+    /// Number of outputs remembered for `app`
+    pub fn len(&self, app: Option<&str>) -> usize {
+        self.entries
+            .lock()
+            .ok()
+            .and_then(|entries| entries.get(&app_key(app)).map(VecDeque::len))
+            .unwrap_or(0)
+    }
+}
+
+fn app_key(app: Option<&str>) -> String {
+    app.unwrap_or_default().to_string()
+}
+
+/// Whether an utterance is a request to scratch the last output
+pub fn is_scratch_phrase(text: &str) -> bool {
+    SCRATCH_PHRASES.contains(&spoken_phrase(text).as_str())
+}
+
+/// Characters that attach to the previous one instead of taking a backspace
+fn is_extending(c: char) -> bool {
+    matches!(c,
+        '\u{0300}'..='\u{036F}'      // combining diacritics
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{20D0}'..='\u{20FF}'
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{200D}'                 // zero width joiner
+        | '\u{FE00}'..='\u{FE0F}'    // variation selectors
+        | '\u{1F3FB}'..='\u{1F3FF}'  // skin tones
+        | '\u{E0020}'..='\u{E007F}'  // emoji tags
+    )
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+/// Backspaces needed to delete `text`
+///
+/// Apps delete a whole visible character per backspace, so combining marks,
+/// joined emoji, flags and "\r\n" each count once.
+pub fn backspace_count(text: &str) -> u32 {
+    let mut count = 0;
+    let mut prev: Option<char> = None;
+    let mut open_flag = false;
+    for c in text.chars() {
+        let attaches = match prev {
+            Some('\u{200D}') => true,
+            Some('\r') => c == '\n',
+            _ => false,
+        } || is_extending(c)
+            || (open_flag && is_regional_indicator(c));
+
+        if attaches {
+            open_flag = false;
+        } else {
+            count += 1;
+            open_flag = is_regional_indicator(c);
+        }
+        prev = Some(c);
+    }
+    count
+}
+
+/// Key strokes that delete `count` characters before the cursor
+///
+/// Up to `MAX_STROKE_REPEAT` characters get a backspace each. Longer outputs
+/// are selected with shift+left, in strokes of at most `MAX_STROKE_REPEAT`
+/// presses, and removed with a single backspace.
+pub fn deletion_strokes(count: u32) -> Vec<KeyStroke> {
+    let stroke = |modifiers: Vec<Modifier>, key: NamedKey, repeat: u32| KeyStroke {
+        combo: KeyCombo {
+            modifiers,
+            key: Key::Named(key),
+        },
+        repeat,
+    };
+    if count <= MAX_STROKE_REPEAT {
+        return vec![stroke(Vec::new(), NamedKey::Backspace, count)];
+    }
+
+    let mut strokes = Vec::new();
+    let mut remaining = count;
+    while remaining > 0 {
+        let repeat = remaining.min(MAX_STROKE_REPEAT);
+        strokes.push(stroke(vec![Modifier::Shift], NamedKey::Left, repeat));
+        remaining -= repeat;
+    }
+    strokes.push(stroke(Vec::new(), NamedKey::Backspace, 1));
+    strokes
+}
+
+/// Delete the newest output put into the frontmost app
+///
+/// Removes the output's characters (see `deletion_strokes`) and, for a paste
+/// whose text is still on the clipboard, restores the clipboard the paste
+/// replaced.
+#[cfg_attr(coverage_nightly, coverage(off))]
+pub async fn scratch_last_output(
+    app_handle: &AppHandle,
+    history: &OutputHistory,
+) -> Result<InjectedOutput, ScratchError> {
+    let app = WindowContext::capture().app_name;
+    let output = history
+        .last(app.as_deref())
+        .ok_or(ScratchError::NothingToScratch)?;
+
+    let has_permission = tokio::task::spawn_blocking(check_accessibility_permission)
+        .await
+        .unwrap_or(false);
+    if !has_permission {
+        return Err(ScratchError::PermissionDenied);
+    }
+
+    let count = output.backspaces();
+    let strokes = deletion_strokes(count);
+    tokio::task::spawn_blocking(move || {
+        if count <= MAX_STROKE_REPEAT {
+            crate::keyboard::synth::send_keystrokes(&strokes, 0)
+        } else {
+            crate::keyboard::synth::send_keystrokes_batched(&strokes)
+        }
+    })
+    .await
+    .map_err(|e| ScratchError::Keyboard(e.to_string()))?
+    .map_err(ScratchError::Keyboard)?;
+    history.remove(app.as_deref(), &output);
+
+    let current_clipboard = app_handle.clipboard().read_text().ok();
+    if let Some(previous) = output.clipboard_to_restore(current_clipboard.as_deref()) {
+        if let Err(e) = app_handle.clipboard().write_text(previous) {
+            crate::warn!("Failed to restore clipboard: {}", e);
+        }
+    }
+
+    crate::info!(
+        "Scratched {} characters from {}",
+        output.backspaces(),
+        app.as_deref().unwrap_or("unknown app")
+    );
+    Ok(output)
+}
+
+#[cfg(test)]
+#[path = "undo_test.rs"]
+mod tests;
@@ -0,0 +1,143 @@
+use super::*;
+
+#[test]
+fn test_scratch_phrases_ignore_case_and_punctuation() {
+    assert!(is_scratch_phrase("scratch that"));
+    assert!(is_scratch_phrase("Scratch that."));
+    assert!(is_scratch_phrase("  undo   that! "));
+    assert!(is_scratch_phrase("Delete that"));
+    assert!(!is_scratch_phrase("scratch that itch"));
+    assert!(!is_scratch_phrase("please scratch that"));
+    assert!(!is_scratch_phrase(""));
+}
+
+#[test]
+fn test_backspace_count_plain_text() {
+    assert_eq!(backspace_count(""), 0);
+    assert_eq!(backspace_count("hello world"), 11);
+    assert_eq!(backspace_count("line one\nline two"), 17);
+    assert_eq!(backspace_count("a\r\nb"), 3);
+}
+
+#[test]
+fn test_backspace_count_treats_clusters_as_one_character() {
+    // e + combining acute accent
+    assert_eq!(backspace_count("cafe\u{301}"), 4);
+    // thumbs up with skin tone
+    assert_eq!(backspace_count("ok \u{1F44D}\u{1F3FD}"), 4);
+    // family emoji joined with zero width joiners
+    assert_eq!(
+        backspace_count("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"),
+        1
+    );
+    // two flags, each a pair of regional indicators
+    assert_eq!(backspace_count("\u{1F1FA}\u{1F1F8}\u{1F1EC}\u{1F1E7}"), 2);
+    // heart with emoji presentation selector
+    assert_eq!(backspace_count("\u{2764}\u{FE0F}"), 1);
+}
+
+#[test]
+fn test_backspaces_include_enter() {
+    let output = InjectedOutput::pasted("hi".to_string(), None, true);
+    assert_eq!(output.backspaces(), 3);
+    assert_eq!(InjectedOutput::typed("hi".to_string()).backspaces(), 2);
+}
+
+#[test]
+fn test_clipboard_restored_only_while_it_holds_the_paste() {
+    let output = InjectedOutput::pasted("dictated".to_string(), Some("copied".to_string()), false);
+
+    assert_eq!(
+        output.clipboard_to_restore(Some("dictated")),
+        Some("copied")
+    );
+    assert_eq!(output.clipboard_to_restore(Some("copied later")), None);
+    assert_eq!(output.clipboard_to_restore(None), None);
+
+    let nothing_before = InjectedOutput::pasted("dictated".to_string(), None, false);
+    assert_eq!(nothing_before.clipboard_to_restore(Some("dictated")), None);
+
+    let typed = InjectedOutput::typed("dictated".to_string());
+    assert_eq!(typed.clipboard_to_restore(Some("dictated")), None);
+}
+
+#[test]
+fn test_history_walks_back_per_app() {
+    let history = OutputHistory::new();
+    history.record(Some("Notes"), InjectedOutput::typed("first".to_string()));
+    history.record(Some("Slack"), InjectedOutput::typed("other".to_string()));
+    history.record(Some("Notes"), InjectedOutput::typed("second".to_string()));
+
+    assert_eq!(history.len(Some("Notes")), 2);
+    assert_eq!(history.take_last(Some("Notes")).unwrap().text, "second");
+    assert_eq!(history.take_last(Some("Notes")).unwrap().text, "first");
+    assert!(history.take_last(Some("Notes")).is_none());
+    assert_eq!(history.take_last(Some("Slack")).unwrap().text, "other");
+}
+
+#[test]
+fn test_history_is_bounded_per_app() {
+    let history = OutputHistory::with_capacity(2);
+    for text in ["one", "two", "three"] {
+        history.record(Some("Notes"), InjectedOutput::typed(text.to_string()));
+    }
+
+    assert_eq!(history.len(Some("Notes")), 2);
+    assert_eq!(history.take_last(Some("Notes")).unwrap().text, "three");
+    assert_eq!(history.take_last(Some("Notes")).unwrap().text, "two");
+    assert!(history.take_last(Some("Notes")).is_none());
+}
+
+#[test]
+fn test_history_ignores_empty_output_and_keys_unknown_app() {
+    let history = OutputHistory::new();
+    history.record(None, InjectedOutput::typed(String::new()));
+    assert_eq!(history.len(None), 0);
+
+    history.record(None, InjectedOutput::typed("text".to_string()));
+    assert_eq!(history.len(None), 1);
+    assert!(history.take_last(Some("Notes")).is_none());
+    assert_eq!(history.take_last(None).unwrap().text, "text");
+}
+
+#[test]
+fn test_last_output_stays_until_removed() {
+    let history = OutputHistory::new();
+    history.record(Some("Notes"), InjectedOutput::typed("first".to_string()));
+    history.record(Some("Notes"), InjectedOutput::typed("second".to_string()));
+
+    // A scratch that failed leaves the entry for the next attempt
+    let last = history.last(Some("Notes")).unwrap();
+    assert_eq!(last.text, "second");
+    assert_eq!(history.len(Some("Notes")), 2);
+
+    // Output recorded while the scratch ran is kept
+    history.record(Some("Notes"), InjectedOutput::typed("third".to_string()));
+    history.remove(Some("Notes"), &last);
+    assert_eq!(history.take_last(Some("Notes")).unwrap().text, "third");
+    assert_eq!(history.take_last(Some("Notes")).unwrap().text, "first");
+}
+
+#[test]
+fn test_short_outputs_are_deleted_with_backspaces() {
+    let strokes = deletion_strokes(12);
+    assert_eq!(strokes.len(), 1);
+    assert_eq!(strokes[0].combo.key, Key::Named(NamedKey::Backspace));
+    assert!(strokes[0].combo.modifiers.is_empty());
+    assert_eq!(strokes[0].repeat, 12);
+}
+
+#[test]
+fn test_long_outputs_are_selected_then_deleted() {
+    let strokes = deletion_strokes(2_000);
+    let (last, selection) = strokes.split_last().unwrap();
+
+    assert_eq!(last.combo.key, Key::Named(NamedKey::Backspace));
+    assert_eq!(last.repeat, 1);
+    assert!(selection.iter().all(|stroke| {
+        stroke.combo.key == Key::Named(NamedKey::Left)
+            && stroke.combo.modifiers == vec![Modifier::Shift]
+            && stroke.repeat <= MAX_STROKE_REPEAT
+    }));
+    assert_eq!(selection.iter().map(|s| s.repeat).sum::<u32>(), 2_000);
+}
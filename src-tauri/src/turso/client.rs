@@ -4,6 +4,7 @@
 // database operations for all heycat data tables.
 
 use libsql::{Builder, Connection, Database};
+use std::future::Future;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -50,18 +51,24 @@ impl From<libsql::Error> for TursoError {
 const DB_DIR: &str = "turso";
 /// Database file name
 const DB_FILE: &str = "heycat.db";
+/// How long a statement waits for another connection's transaction (ms)
+const BUSY_TIMEOUT_MS: u32 = 5000;
 
 /// TursoClient wraps libsql::Database for embedded SQLite operations.
 ///
 /// The client uses an Arc<Mutex<Connection>> internally to ensure
 /// thread-safe access to the database connection. This allows the
 /// client to be cloned and shared across multiple Tauri commands.
+///
+/// Transactions run on a separate connection (see `transaction`), so
+/// statements from other tasks can never land inside them.
 #[derive(Clone)]
 pub struct TursoClient {
-    #[allow(dead_code)]
     db: Arc<Database>,
     conn: Arc<Mutex<Connection>>,
     db_path: PathBuf,
+    /// Whether `conn` is a transaction's own connection
+    in_transaction: bool,
 }
 
 impl TursoClient {
@@ -122,7 +129,18 @@ impl TursoClient {
             .await
             .map_err(|e| TursoError::Connection(format!("Failed to open database: {}", e)))?;
 
-        // Get a connection
+        let conn = Self::connect(&db).await?;
+
+        Ok(Self {
+            db: Arc::new(db),
+            conn: Arc::new(Mutex::new(conn)),
+            db_path,
+            in_transaction: false,
+        })
+    }
+
+    /// Open a connection with foreign keys enabled.
+    async fn connect(db: &Database) -> Result<Connection, TursoError> {
         let conn = db
             .connect()
             .map_err(|e| TursoError::Connection(format!("Failed to connect: {}", e)))?;
@@ -132,11 +150,12 @@ impl TursoClient {
             .await
             .map_err(|e| TursoError::Query(format!("Failed to enable foreign keys: {}", e)))?;
 
-        Ok(Self {
-            db: Arc::new(db),
-            conn: Arc::new(Mutex::new(conn)),
-            db_path,
-        })
+        // Wait for other connections' transactions instead of failing
+        conn.query(&format!("PRAGMA busy_timeout = {}", BUSY_TIMEOUT_MS), ())
+            .await
+            .map_err(|e| TursoError::Query(format!("Failed to set busy timeout: {}", e)))?;
+
+        Ok(conn)
     }
 
     /// Get the path to the database file.
@@ -176,6 +195,44 @@ impl TursoClient {
             .map_err(TursoError::from)
     }
 
+    /// Run `work` in a transaction, committing if it succeeds and rolling
+    /// back if it fails.
+    ///
+    /// `work` receives a client bound to a connection of its own, so the
+    /// whole transaction is isolated from statements issued through `self`
+    /// meanwhile. Calling this on a client already inside a transaction just
+    /// runs `work` as part of it.
+    pub async fn transaction<T, E, F, Fut>(&self, work: F) -> Result<T, E>
+    where
+        F: FnOnce(TursoClient) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<TursoError>,
+    {
+        if self.in_transaction {
+            return work(self.clone()).await;
+        }
+
+        let client = Self {
+            db: self.db.clone(),
+            conn: Arc::new(Mutex::new(Self::connect(&self.db).await?)),
+            db_path: self.db_path.clone(),
+            in_transaction: true,
+        };
+        client.execute("BEGIN IMMEDIATE", ()).await?;
+        match work(client.clone()).await {
+            Ok(value) => {
+                client.execute("COMMIT", ()).await?;
+                Ok(value)
+            }
+            Err(e) => {
+                if let Err(rollback) = client.execute("ROLLBACK", ()).await {
+                    crate::warn!("Failed to roll back transaction: {}", rollback);
+                }
+                Err(e)
+            }
+        }
+    }
+
     /// Check if the database connection is valid.
     /// Note: Currently only used in tests - will be used for health checks
     #[allow(dead_code)]
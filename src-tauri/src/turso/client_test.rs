@@ -128,3 +128,52 @@ async fn test_unique_constraint_detection() {
         Ok(_) => panic!("Should have failed with unique constraint violation"),
     }
 }
+
+/// Count rows in the transaction test table
+async fn count_rows(client: &TursoClient) -> i64 {
+    let mut rows = client
+        .query("SELECT COUNT(*) FROM tx_test", ())
+        .await
+        .expect("Should query rows");
+    let row = rows.next().await.expect("Should get next").expect("Should have row");
+    row.get(0).expect("Should get count")
+}
+
+/// Test that transactions commit on success and roll back on failure
+#[tokio::test]
+async fn test_transaction_commits_or_rolls_back() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create TursoClient");
+    client
+        .execute("CREATE TABLE tx_test (id TEXT PRIMARY KEY)", ())
+        .await
+        .expect("Should create table");
+
+    let failed: Result<(), TursoError> = client
+        .transaction(|tx| async move {
+            tx.execute("INSERT INTO tx_test (id) VALUES ('a')", ())
+                .await?;
+            Err(TursoError::Query("stop".to_string()))
+        })
+        .await;
+    assert!(failed.is_err());
+    assert_eq!(count_rows(&client).await, 0);
+
+    client
+        .transaction(|tx| async move {
+            tx.execute("INSERT INTO tx_test (id) VALUES ('a')", ())
+                .await?;
+            // Nested transactions join the outer one
+            tx.transaction(|inner| async move {
+                inner
+                    .execute("INSERT INTO tx_test (id) VALUES ('b')", ())
+                    .await
+            })
+            .await
+        })
+        .await
+        .expect("Transaction should commit");
+    assert_eq!(count_rows(&client).await, 2);
+}
@@ -0,0 +1,483 @@
+// Configuration bundle export and import using Turso/libsql
+//
+// A bundle holds the voice commands, dictionary entries and window contexts as
+// one versioned JSON document, so a team can share a command set or set up a
+// new machine in one step. Imported items get new IDs; the command and
+// dictionary IDs that window contexts refer to, and command IDs written in
+// command parameters (such as a script's run_command("<id>")), are rewritten
+// to match.
+//
+// Imported commands get the same checks as commands saved from the editor,
+// and all writes happen in one transaction, so a failed import leaves the
+// existing configuration untouched.
+
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::client::{TursoClient, TursoError};
+use crate::dictionary::DictionaryEntry;
+use crate::voice_commands::registry::{normalize_phrase, CommandDefinition};
+use crate::voice_commands::validate_command;
+use crate::window_context::WindowContext;
+
+/// Bundle format version written on export; newer bundles are rejected on import
+pub const CONFIG_BUNDLE_VERSION: u32 = 1;
+
+/// Commands, dictionary entries and window contexts exported together
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigBundle {
+    pub version: u32,
+    pub exported_at: String,
+    #[serde(default)]
+    pub commands: Vec<CommandDefinition>,
+    #[serde(default)]
+    pub dictionary: Vec<DictionaryEntry>,
+    #[serde(default)]
+    pub window_contexts: Vec<WindowContext>,
+}
+
+impl ConfigBundle {
+    /// Serialize the bundle as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, ConfigBundleError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| ConfigBundleError::InvalidBundle(e.to_string()))
+    }
+
+    /// Parse a bundle, rejecting versions newer than this build understands
+    pub fn from_json(json: &str) -> Result<Self, ConfigBundleError> {
+        let bundle: ConfigBundle = serde_json::from_str(json)
+            .map_err(|e| ConfigBundleError::InvalidBundle(e.to_string()))?;
+        if bundle.version > CONFIG_BUNDLE_VERSION {
+            return Err(ConfigBundleError::UnsupportedVersion(bundle.version));
+        }
+        Ok(bundle)
+    }
+}
+
+/// How an imported bundle is combined with the existing configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    /// Keep existing items; skip imported ones whose trigger or name is taken
+    #[default]
+    Merge,
+    /// Remove all existing items before importing
+    Replace,
+}
+
+/// Kind of item in a bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BundleItemKind {
+    Command,
+    DictionaryEntry,
+    WindowContext,
+}
+
+/// An imported item that was skipped because its trigger or name is taken
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportConflict {
+    pub kind: BundleItemKind,
+    /// The trigger phrase (or window context name) that clashed
+    pub key: String,
+    /// ID of the skipped item in the bundle
+    pub imported_id: String,
+    /// ID of the stored item holding the key; None if an earlier item of the
+    /// same bundle holds it
+    pub existing_id: Option<String>,
+}
+
+/// What an import did, or would do for a dry run
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub commands_added: usize,
+    pub dictionary_added: usize,
+    pub window_contexts_added: usize,
+    /// Existing items removed first (replace mode)
+    pub commands_removed: usize,
+    pub dictionary_removed: usize,
+    pub window_contexts_removed: usize,
+    pub conflicts: Vec<ImportConflict>,
+    /// Window context references to items missing from the bundle, which were dropped
+    pub unresolved_references: usize,
+}
+
+/// Error type for configuration bundle operations
+#[derive(Debug, Clone)]
+pub enum ConfigBundleError {
+    /// The bundle is not valid JSON or contains an invalid item
+    InvalidBundle(String),
+    /// The bundle was written by a newer version of the app
+    UnsupportedVersion(u32),
+    LoadError(String),
+    PersistenceError(String),
+}
+
+impl std::fmt::Display for ConfigBundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigBundleError::InvalidBundle(msg) => {
+                write!(f, "Invalid configuration bundle: {}", msg)
+            }
+            ConfigBundleError::UnsupportedVersion(version) => write!(
+                f,
+                "Configuration bundle version {} is newer than the supported version {}",
+                version, CONFIG_BUNDLE_VERSION
+            ),
+            ConfigBundleError::LoadError(msg) => write!(f, "Failed to load configuration: {}", msg),
+            ConfigBundleError::PersistenceError(msg) => {
+                write!(f, "Failed to import configuration: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigBundleError {}
+
+impl From<TursoError> for ConfigBundleError {
+    fn from(err: TursoError) -> Self {
+        ConfigBundleError::PersistenceError(err.to_string())
+    }
+}
+
+/// Items to write for an import, with conflicts already resolved
+struct ImportPlan {
+    /// Accepted commands, with their new IDs
+    commands: Vec<CommandDefinition>,
+    /// Accepted dictionary entries, with their new IDs
+    dictionary: Vec<DictionaryEntry>,
+    /// Accepted window contexts, with command and dictionary entry IDs rewritten
+    window_contexts: Vec<WindowContext>,
+    report: ImportReport,
+}
+
+/// Work out what importing `bundle` on top of the existing items would do
+///
+/// An item whose trigger (or name, for window contexts) is already taken is
+/// skipped and reported. References to a skipped command or dictionary entry
+/// are pointed at the item holding its trigger.
+fn plan_import(
+    bundle: &ConfigBundle,
+    existing_commands: &[CommandDefinition],
+    existing_dictionary: &[DictionaryEntry],
+    existing_contexts: &[WindowContext],
+) -> Result<ImportPlan, ConfigBundleError> {
+    let mut report = ImportReport::default();
+
+    // Commands: phrase key -> ID of the command that owns it
+    let stored_commands: HashSet<Uuid> = existing_commands.iter().map(|c| c.id).collect();
+    let mut command_phrases: HashMap<String, Uuid> = existing_commands
+        .iter()
+        .flat_map(|c| c.phrases().map(move |p| (normalize_phrase(p), c.id)))
+        .collect();
+    let mut command_ids: HashMap<Uuid, Uuid> = HashMap::new();
+    let mut commands = Vec::new();
+    for cmd in &bundle.commands {
+        let keys: Vec<String> = cmd.phrases().map(normalize_phrase).collect();
+        if keys.iter().any(|key| key.is_empty()) {
+            return Err(ConfigBundleError::InvalidBundle(format!(
+                "Command {} has an empty trigger",
+                cmd.id
+            )));
+        }
+        if keys.iter().collect::<HashSet<_>>().len() != keys.len() {
+            return Err(ConfigBundleError::InvalidBundle(format!(
+                "Command '{}' repeats a trigger phrase",
+                cmd.trigger
+            )));
+        }
+        validate_command(cmd).map_err(|e| {
+            ConfigBundleError::InvalidBundle(format!("Command '{}': {}", cmd.trigger, e))
+        })?;
+
+        if let Some((phrase, owner)) = cmd
+            .phrases()
+            .zip(&keys)
+            .find_map(|(phrase, key)| command_phrases.get(key).map(|owner| (phrase, *owner)))
+        {
+            report.conflicts.push(ImportConflict {
+                kind: BundleItemKind::Command,
+                key: phrase.to_string(),
+                imported_id: cmd.id.to_string(),
+                existing_id: stored_commands.contains(&owner).then(|| owner.to_string()),
+            });
+            command_ids.insert(cmd.id, owner);
+            continue;
+        }
+
+        let new_id = Uuid::new_v4();
+        for key in keys {
+            command_phrases.insert(key, new_id);
+        }
+        command_ids.insert(cmd.id, new_id);
+        commands.push(CommandDefinition {
+            id: new_id,
+            ..cmd.clone()
+        });
+    }
+
+    // Scripts and macro steps name other commands by ID, e.g. run_command("<id>")
+    remap_command_references(&mut commands, &command_ids);
+
+    // Dictionary entries: trigger key -> ID of the entry that owns it
+    let stored_entries: HashSet<&str> = existing_dictionary.iter().map(|e| e.id.as_str()).collect();
+    let mut entry_triggers: HashMap<String, String> = existing_dictionary
+        .iter()
+        .map(|e| (normalize_phrase(&e.trigger), e.id.clone()))
+        .collect();
+    let mut dictionary_ids: HashMap<&str, String> = HashMap::new();
+    let mut dictionary = Vec::new();
+    for entry in &bundle.dictionary {
+        let key = normalize_phrase(&entry.trigger);
+        if key.is_empty() {
+            return Err(ConfigBundleError::InvalidBundle(format!(
+                "Dictionary entry {} has an empty trigger",
+                entry.id
+            )));
+        }
+
+        if let Some(owner) = entry_triggers.get(&key) {
+            report.conflicts.push(ImportConflict {
+                kind: BundleItemKind::DictionaryEntry,
+                key: entry.trigger.clone(),
+                imported_id: entry.id.clone(),
+                existing_id: stored_entries
+                    .contains(owner.as_str())
+                    .then(|| owner.clone()),
+            });
+            dictionary_ids.insert(&entry.id, owner.clone());
+            continue;
+        }
+
+        let new_id = Uuid::new_v4().to_string();
+        entry_triggers.insert(key, new_id.clone());
+        dictionary_ids.insert(&entry.id, new_id.clone());
+        dictionary.push(DictionaryEntry {
+            id: new_id,
+            ..entry.clone()
+        });
+    }
+
+    // Window contexts, matched by name
+    let mut context_names: HashMap<String, Option<Uuid>> = existing_contexts
+        .iter()
+        .map(|c| (c.name.clone(), Some(c.id)))
+        .collect();
+    let mut window_contexts = Vec::new();
+    for context in &bundle.window_contexts {
+        if let Some(owner) = context_names.get(&context.name) {
+            report.conflicts.push(ImportConflict {
+                kind: BundleItemKind::WindowContext,
+                key: context.name.clone(),
+                imported_id: context.id.to_string(),
+                existing_id: owner.map(|id| id.to_string()),
+            });
+            continue;
+        }
+        context_names.insert(context.name.clone(), None);
+
+        let mapped_commands: Vec<Uuid> = context
+            .command_ids
+            .iter()
+            .filter_map(|id| command_ids.get(id).copied())
+            .collect();
+        let mapped_entries: Vec<String> = context
+            .dictionary_entry_ids
+            .iter()
+            .filter_map(|id| dictionary_ids.get(id.as_str()).cloned())
+            .collect();
+        report.unresolved_references += context.command_ids.len() - mapped_commands.len();
+        report.unresolved_references += context.dictionary_entry_ids.len() - mapped_entries.len();
+
+        window_contexts.push(WindowContext {
+            command_ids: mapped_commands,
+            dictionary_entry_ids: mapped_entries,
+            ..context.clone()
+        });
+    }
+
+    report.commands_added = commands.len();
+    report.dictionary_added = dictionary.len();
+    report.window_contexts_added = window_contexts.len();
+
+    Ok(ImportPlan {
+        commands,
+        dictionary,
+        window_contexts,
+        report,
+    })
+}
+
+/// Rewrite bundle command IDs written in command parameters to their
+/// imported IDs
+///
+/// IDs that don't belong to a bundle command are left as they are.
+fn remap_command_references(commands: &mut [CommandDefinition], command_ids: &HashMap<Uuid, Uuid>) {
+    let uuid =
+        Regex::new(r"[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}")
+            .expect("UUID pattern is valid");
+    for cmd in commands {
+        for value in cmd.parameters.values_mut() {
+            let remapped = uuid
+                .replace_all(value, |caps: &Captures| {
+                    Uuid::parse_str(&caps[0])
+                        .ok()
+                        .and_then(|id| command_ids.get(&id))
+                        .map_or_else(|| caps[0].to_string(), |new_id| new_id.to_string())
+                })
+                .into_owned();
+            *value = remapped;
+        }
+    }
+}
+
+impl TursoClient {
+    /// Export all voice commands, dictionary entries and window contexts.
+    pub async fn export_config_bundle(&self) -> Result<ConfigBundle, ConfigBundleError> {
+        let commands = self
+            .list_voice_commands()
+            .await
+            .map_err(|e| ConfigBundleError::LoadError(e.to_string()))?;
+        let dictionary = self
+            .list_dictionary_entries()
+            .await
+            .map_err(|e| ConfigBundleError::LoadError(e.to_string()))?;
+        let window_contexts = self
+            .list_window_contexts()
+            .await
+            .map_err(|e| ConfigBundleError::LoadError(e.to_string()))?;
+
+        Ok(ConfigBundle {
+            version: CONFIG_BUNDLE_VERSION,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            commands,
+            dictionary,
+            window_contexts,
+        })
+    }
+
+    /// Import a configuration bundle.
+    ///
+    /// Conflicts are resolved and commands checked before anything is written,
+    /// and the writes are applied in one transaction. With `dry_run` set,
+    /// nothing is written and the report describes what the import would do.
+    ///
+    /// # Arguments
+    /// * `bundle` - The bundle to import
+    /// * `mode` - Merge with or replace the existing configuration
+    /// * `dry_run` - Only report what would change
+    pub async fn import_config_bundle(
+        &self,
+        bundle: &ConfigBundle,
+        mode: ImportMode,
+        dry_run: bool,
+    ) -> Result<ImportReport, ConfigBundleError> {
+        let existing = self.export_config_bundle().await?;
+
+        let mut plan = match mode {
+            ImportMode::Merge => plan_import(
+                bundle,
+                &existing.commands,
+                &existing.dictionary,
+                &existing.window_contexts,
+            )?,
+            ImportMode::Replace => {
+                let mut plan = plan_import(bundle, &[], &[], &[])?;
+                plan.report.commands_removed = existing.commands.len();
+                plan.report.dictionary_removed = existing.dictionary.len();
+                plan.report.window_contexts_removed = existing.window_contexts.len();
+                plan
+            }
+        };
+        plan.report.dry_run = dry_run;
+        if dry_run {
+            return Ok(plan.report);
+        }
+
+        self.apply_import(&existing, &plan, mode).await?;
+
+        crate::info!(
+            "Imported configuration bundle: {} commands, {} dictionary entries, {} window contexts, {} conflicts",
+            plan.report.commands_added,
+            plan.report.dictionary_added,
+            plan.report.window_contexts_added,
+            plan.report.conflicts.len()
+        );
+        Ok(plan.report)
+    }
+
+    /// Write a planned import in one transaction, rolling back on any failure
+    async fn apply_import(
+        &self,
+        existing: &ConfigBundle,
+        plan: &ImportPlan,
+        mode: ImportMode,
+    ) -> Result<(), ConfigBundleError> {
+        self.transaction(|tx| async move { tx.write_import(existing, plan, mode).await })
+            .await
+    }
+
+    async fn write_import(
+        &self,
+        existing: &ConfigBundle,
+        plan: &ImportPlan,
+        mode: ImportMode,
+    ) -> Result<(), ConfigBundleError> {
+        if mode == ImportMode::Replace {
+            for context in &existing.window_contexts {
+                self.delete_window_context(context.id)
+                    .await
+                    .map_err(|e| ConfigBundleError::PersistenceError(e.to_string()))?;
+            }
+            for cmd in &existing.commands {
+                self.delete_voice_command(cmd.id)
+                    .await
+                    .map_err(|e| ConfigBundleError::PersistenceError(e.to_string()))?;
+            }
+            for entry in &existing.dictionary {
+                self.delete_dictionary_entry(&entry.id)
+                    .await
+                    .map_err(|e| ConfigBundleError::PersistenceError(e.to_string()))?;
+            }
+        }
+
+        for cmd in &plan.commands {
+            self.add_voice_command(cmd)
+                .await
+                .map_err(|e| ConfigBundleError::PersistenceError(e.to_string()))?;
+        }
+
+        for entry in &plan.dictionary {
+            self.insert_dictionary_entry(entry)
+                .await
+                .map_err(|e| ConfigBundleError::PersistenceError(e.to_string()))?;
+        }
+
+        for context in &plan.window_contexts {
+            self.add_window_context(
+                context.name.clone(),
+                context.matcher.clone(),
+                context.command_mode,
+                context.dictionary_mode,
+                context.command_ids.clone(),
+                context.dictionary_entry_ids.clone(),
+                context.enabled,
+                context.priority,
+                context.command_prefix,
+            )
+            .await
+            .map_err(|e| ConfigBundleError::PersistenceError(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "config_bundle_test.rs"]
+mod tests;
@@ -0,0 +1,479 @@
+use super::*;
+use crate::turso::initialize_schema;
+use crate::voice_commands::registry::ActionType;
+use crate::window_context::{CommandPrefixMode, OverrideMode, WindowMatcher};
+use tempfile::TempDir;
+
+async fn setup_client() -> (TursoClient, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+    initialize_schema(&client)
+        .await
+        .expect("Failed to initialize schema");
+    (client, temp_dir)
+}
+
+fn make_command(trigger: &str) -> CommandDefinition {
+    CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: trigger.to_string(),
+        action_type: ActionType::OpenApp,
+        parameters: HashMap::from([("app".to_string(), "Slack".to_string())]),
+        enabled: true,
+        requires_confirmation: false,
+        aliases: vec![],
+    }
+}
+
+fn make_entry(trigger: &str, expansion: &str) -> DictionaryEntry {
+    DictionaryEntry {
+        id: Uuid::new_v4().to_string(),
+        trigger: trigger.to_string(),
+        expansion: expansion.to_string(),
+        suffix: None,
+        auto_enter: false,
+        disable_suffix: false,
+        complete_match_only: false,
+    }
+}
+
+fn make_context(
+    name: &str,
+    command_ids: Vec<Uuid>,
+    dictionary_entry_ids: Vec<String>,
+) -> WindowContext {
+    WindowContext {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        matcher: WindowMatcher {
+            app_name: "Slack".to_string(),
+            title_pattern: None,
+            bundle_id: None,
+        },
+        command_mode: OverrideMode::Replace,
+        dictionary_mode: OverrideMode::Merge,
+        command_ids,
+        dictionary_entry_ids,
+        enabled: true,
+        priority: 0,
+        command_prefix: CommandPrefixMode::Inherit,
+    }
+}
+
+fn make_bundle(
+    commands: Vec<CommandDefinition>,
+    dictionary: Vec<DictionaryEntry>,
+    window_contexts: Vec<WindowContext>,
+) -> ConfigBundle {
+    ConfigBundle {
+        version: CONFIG_BUNDLE_VERSION,
+        exported_at: "2025-01-01T10:00:00Z".to_string(),
+        commands,
+        dictionary,
+        window_contexts,
+    }
+}
+
+#[tokio::test]
+async fn test_export_and_import_into_empty_database_remaps_ids() {
+    let (source, _source_dir) = setup_client().await;
+    let cmd = CommandDefinition {
+        aliases: vec!["launch slack".to_string()],
+        ..make_command("open slack")
+    };
+    source.add_voice_command(&cmd).await.unwrap();
+    let entry = source
+        .add_dictionary_entry(
+            "brb".to_string(),
+            "be right back".to_string(),
+            None,
+            false,
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+    source
+        .add_window_context(
+            "Chat".to_string(),
+            WindowMatcher {
+                app_name: "Slack".to_string(),
+                title_pattern: None,
+                bundle_id: None,
+            },
+            OverrideMode::Replace,
+            OverrideMode::Merge,
+            vec![cmd.id],
+            vec![entry.id.clone()],
+            true,
+            5,
+            CommandPrefixMode::Required,
+        )
+        .await
+        .unwrap();
+
+    let json = source
+        .export_config_bundle()
+        .await
+        .unwrap()
+        .to_json()
+        .unwrap();
+    let bundle = ConfigBundle::from_json(&json).unwrap();
+
+    let (target, _target_dir) = setup_client().await;
+    let report = target
+        .import_config_bundle(&bundle, ImportMode::Merge, false)
+        .await
+        .unwrap();
+    assert_eq!(report.commands_added, 1);
+    assert_eq!(report.dictionary_added, 1);
+    assert_eq!(report.window_contexts_added, 1);
+    assert!(report.conflicts.is_empty());
+    assert_eq!(report.unresolved_references, 0);
+
+    let commands = target.list_voice_commands().await.unwrap();
+    let entries = target.list_dictionary_entries().await.unwrap();
+    let contexts = target.list_window_contexts().await.unwrap();
+    assert_eq!(commands[0].trigger, "open slack");
+    assert_eq!(commands[0].aliases, vec!["launch slack"]);
+    assert_ne!(commands[0].id, cmd.id);
+    assert_ne!(entries[0].id, entry.id);
+    assert_eq!(contexts[0].priority, 5);
+    assert_eq!(contexts[0].command_prefix, CommandPrefixMode::Required);
+    assert_eq!(contexts[0].command_ids, vec![commands[0].id]);
+    assert_eq!(
+        contexts[0].dictionary_entry_ids,
+        vec![entries[0].id.clone()]
+    );
+}
+
+#[tokio::test]
+async fn test_merge_skips_conflicts_and_points_references_at_existing_items() {
+    let (client, _temp) = setup_client().await;
+    let existing_cmd = make_command("open slack");
+    client.add_voice_command(&existing_cmd).await.unwrap();
+    let existing_entry = client
+        .add_dictionary_entry(
+            "brb".to_string(),
+            "be right back".to_string(),
+            None,
+            false,
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+
+    // Conflicts on an alias and on a differently cased trigger
+    let clashing_cmd = CommandDefinition {
+        aliases: vec!["Open  Slack".to_string()],
+        ..make_command("start slack")
+    };
+    let new_cmd = make_command("open mail");
+    let clashing_entry = make_entry("BRB", "be back soon");
+    let context = make_context(
+        "Chat",
+        vec![clashing_cmd.id, new_cmd.id],
+        vec![clashing_entry.id.clone()],
+    );
+    let bundle = make_bundle(
+        vec![clashing_cmd.clone(), new_cmd],
+        vec![clashing_entry.clone()],
+        vec![context],
+    );
+
+    let report = client
+        .import_config_bundle(&bundle, ImportMode::Merge, false)
+        .await
+        .unwrap();
+    assert_eq!(report.commands_added, 1);
+    assert_eq!(report.dictionary_added, 0);
+    assert_eq!(report.window_contexts_added, 1);
+    assert_eq!(
+        report.conflicts,
+        vec![
+            ImportConflict {
+                kind: BundleItemKind::Command,
+                key: "Open  Slack".to_string(),
+                imported_id: clashing_cmd.id.to_string(),
+                existing_id: Some(existing_cmd.id.to_string()),
+            },
+            ImportConflict {
+                kind: BundleItemKind::DictionaryEntry,
+                key: "BRB".to_string(),
+                imported_id: clashing_entry.id.clone(),
+                existing_id: Some(existing_entry.id.clone()),
+            },
+        ]
+    );
+
+    let commands = client.list_voice_commands().await.unwrap();
+    assert_eq!(commands.len(), 2);
+    let contexts = client.list_window_contexts().await.unwrap();
+    assert_eq!(
+        contexts[0].command_ids,
+        vec![existing_cmd.id, commands[1].id]
+    );
+    assert_eq!(contexts[0].dictionary_entry_ids, vec![existing_entry.id]);
+}
+
+#[tokio::test]
+async fn test_reimporting_an_export_changes_nothing() {
+    let (client, _temp) = setup_client().await;
+    client
+        .add_voice_command(&make_command("open slack"))
+        .await
+        .unwrap();
+    client
+        .add_dictionary_entry(
+            "brb".to_string(),
+            "be right back".to_string(),
+            None,
+            false,
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+    let bundle = client.export_config_bundle().await.unwrap();
+
+    let report = client
+        .import_config_bundle(&bundle, ImportMode::Merge, false)
+        .await
+        .unwrap();
+    assert_eq!(report.commands_added, 0);
+    assert_eq!(report.dictionary_added, 0);
+    assert_eq!(report.conflicts.len(), 2);
+    assert_eq!(client.list_voice_commands().await.unwrap().len(), 1);
+    assert_eq!(client.list_dictionary_entries().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_conflicts_within_the_bundle_are_reported() {
+    let (client, _temp) = setup_client().await;
+    let first = make_command("open slack");
+    let second = make_command("open slack");
+    let context = make_context("Chat", vec![second.id], vec![]);
+    let duplicate_context = make_context("Chat", vec![], vec![]);
+    let bundle = make_bundle(
+        vec![first, second.clone()],
+        vec![],
+        vec![context, duplicate_context.clone()],
+    );
+
+    let report = client
+        .import_config_bundle(&bundle, ImportMode::Merge, false)
+        .await
+        .unwrap();
+    assert_eq!(report.commands_added, 1);
+    assert_eq!(report.window_contexts_added, 1);
+    assert_eq!(report.conflicts[0].imported_id, second.id.to_string());
+    assert_eq!(report.conflicts[0].existing_id, None);
+    assert_eq!(report.conflicts[1].kind, BundleItemKind::WindowContext);
+    assert_eq!(
+        report.conflicts[1].imported_id,
+        duplicate_context.id.to_string()
+    );
+
+    // The skipped duplicate resolves to the command that was imported
+    let commands = client.list_voice_commands().await.unwrap();
+    let contexts = client.list_window_contexts().await.unwrap();
+    assert_eq!(contexts[0].command_ids, vec![commands[0].id]);
+}
+
+#[tokio::test]
+async fn test_command_ids_in_scripts_are_remapped() {
+    let (client, _temp) = setup_client().await;
+    let existing = make_command("open chat");
+    client.add_voice_command(&existing).await.unwrap();
+
+    let slack = make_command("open slack");
+    let chat = make_command("open chat");
+    let unknown = Uuid::new_v4();
+    let script = CommandDefinition {
+        action_type: ActionType::Rhai,
+        parameters: HashMap::from([(
+            "source".to_string(),
+            format!(
+                r#"run_command("{}"); run_command("{}"); run_command("{}")"#,
+                slack.id, chat.id, unknown
+            ),
+        )]),
+        ..make_command("start the day")
+    };
+    // The script comes first, so it names a command that is planned after it
+    let bundle = make_bundle(vec![script, slack.clone(), chat], vec![], vec![]);
+
+    client
+        .import_config_bundle(&bundle, ImportMode::Merge, false)
+        .await
+        .unwrap();
+
+    let commands = client.list_voice_commands().await.unwrap();
+    let imported_slack = commands.iter().find(|c| c.trigger == "open slack").unwrap();
+    let imported_script = commands
+        .iter()
+        .find(|c| c.trigger == "start the day")
+        .unwrap();
+    assert_ne!(imported_slack.id, slack.id);
+    assert_eq!(
+        imported_script.parameters["source"],
+        format!(
+            r#"run_command("{}"); run_command("{}"); run_command("{}")"#,
+            imported_slack.id, existing.id, unknown
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_reports_without_writing() {
+    let (client, _temp) = setup_client().await;
+    client
+        .add_voice_command(&make_command("open slack"))
+        .await
+        .unwrap();
+    let bundle = make_bundle(
+        vec![make_command("open slack"), make_command("open mail")],
+        vec![make_entry("brb", "be right back")],
+        vec![make_context("Chat", vec![Uuid::new_v4()], vec![])],
+    );
+
+    let report = client
+        .import_config_bundle(&bundle, ImportMode::Merge, true)
+        .await
+        .unwrap();
+    assert!(report.dry_run);
+    assert_eq!(report.commands_added, 1);
+    assert_eq!(report.dictionary_added, 1);
+    assert_eq!(report.window_contexts_added, 1);
+    assert_eq!(report.conflicts.len(), 1);
+    assert_eq!(report.unresolved_references, 1);
+
+    assert_eq!(client.list_voice_commands().await.unwrap().len(), 1);
+    assert!(client.list_dictionary_entries().await.unwrap().is_empty());
+    assert!(client.list_window_contexts().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_replace_removes_existing_configuration() {
+    let (client, _temp) = setup_client().await;
+    client
+        .add_voice_command(&make_command("open slack"))
+        .await
+        .unwrap();
+    client
+        .add_dictionary_entry(
+            "brb".to_string(),
+            "be right back".to_string(),
+            None,
+            false,
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+    let bundle = make_bundle(
+        vec![make_command("open slack")],
+        vec![make_entry("ty", "thank you")],
+        vec![],
+    );
+
+    let report = client
+        .import_config_bundle(&bundle, ImportMode::Replace, false)
+        .await
+        .unwrap();
+    assert_eq!(report.commands_removed, 1);
+    assert_eq!(report.dictionary_removed, 1);
+    assert_eq!(report.commands_added, 1);
+    assert_eq!(report.dictionary_added, 1);
+    assert!(report.conflicts.is_empty());
+
+    let entries = client.list_dictionary_entries().await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].trigger, "ty");
+    assert_eq!(client.list_voice_commands().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_bundle_is_rejected_before_writing() {
+    let (client, _temp) = setup_client().await;
+    let bundle = make_bundle(
+        vec![make_command("open mail"), make_command("  ")],
+        vec![],
+        vec![],
+    );
+
+    let result = client
+        .import_config_bundle(&bundle, ImportMode::Merge, false)
+        .await;
+    assert!(matches!(result, Err(ConfigBundleError::InvalidBundle(_))));
+    assert!(client.list_voice_commands().await.unwrap().is_empty());
+}
+
+#[test]
+fn test_from_json_rejects_newer_versions_and_defaults_missing_sections() {
+    let newer = format!(
+        r#"{{"version": {}, "exportedAt": "2025-01-01T10:00:00Z"}}"#,
+        CONFIG_BUNDLE_VERSION + 1
+    );
+    assert!(matches!(
+        ConfigBundle::from_json(&newer),
+        Err(ConfigBundleError::UnsupportedVersion(_))
+    ));
+    assert!(matches!(
+        ConfigBundle::from_json("not json"),
+        Err(ConfigBundleError::InvalidBundle(_))
+    ));
+
+    let bundle =
+        ConfigBundle::from_json(r#"{"version": 1, "exportedAt": "2025-01-01T10:00:00Z"}"#).unwrap();
+    assert!(bundle.commands.is_empty());
+    assert!(bundle.dictionary.is_empty());
+    assert!(bundle.window_contexts.is_empty());
+}
+
+#[tokio::test]
+async fn test_dry_run_rejects_commands_that_fail_save_checks() {
+    let (client, _temp) = setup_client().await;
+    let bad_trigger = make_command("open {app");
+    let bad_macro = CommandDefinition {
+        action_type: ActionType::Macro,
+        parameters: HashMap::from([("steps".to_string(), "not json".to_string())]),
+        ..make_command("start standup")
+    };
+
+    for command in [bad_trigger, bad_macro] {
+        let bundle = make_bundle(vec![command], vec![], vec![]);
+        let result = client
+            .import_config_bundle(&bundle, ImportMode::Replace, true)
+            .await;
+        assert!(matches!(result, Err(ConfigBundleError::InvalidBundle(_))));
+    }
+}
+
+#[tokio::test]
+async fn test_failed_replace_leaves_existing_configuration_untouched() {
+    let (client, _temp) = setup_client().await;
+    let existing_command = make_command("open slack");
+    client.add_voice_command(&existing_command).await.unwrap();
+    let existing = client.export_config_bundle().await.unwrap();
+
+    // A hand-built plan with two commands sharing a trigger, so the second
+    // insert fails after the deletes have run
+    let plan = ImportPlan {
+        commands: vec![make_command("open mail"), make_command("open mail")],
+        dictionary: vec![],
+        window_contexts: vec![],
+        report: ImportReport::default(),
+    };
+    let result = client
+        .apply_import(&existing, &plan, ImportMode::Replace)
+        .await;
+    assert!(matches!(result, Err(ConfigBundleError::PersistenceError(_))));
+
+    let commands = client.list_voice_commands().await.unwrap();
+    assert_eq!(commands.len(), 1);
+    assert_eq!(commands[0].id, existing_command.id);
+}
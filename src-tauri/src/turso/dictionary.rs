@@ -33,7 +33,27 @@ impl TursoClient {
         disable_suffix: bool,
         complete_match_only: bool,
     ) -> Result<DictionaryEntry, DictionaryError> {
-        let id = Uuid::new_v4().to_string();
+        let entry = DictionaryEntry {
+            id: Uuid::new_v4().to_string(),
+            trigger,
+            expansion,
+            suffix,
+            auto_enter,
+            disable_suffix,
+            complete_match_only,
+        };
+        self.insert_dictionary_entry(&entry).await?;
+        Ok(entry)
+    }
+
+    /// Insert a dictionary entry with the ID it already has.
+    ///
+    /// Used by add_dictionary_entry and by configuration bundle imports,
+    /// which choose IDs up front so window contexts can refer to them.
+    pub(super) async fn insert_dictionary_entry(
+        &self,
+        entry: &DictionaryEntry,
+    ) -> Result<(), DictionaryError> {
         let created_at = chrono::Utc::now().to_rfc3339();
 
         self.execute(
@@ -41,33 +61,25 @@ impl TursoClient {
                (id, trigger, expansion, suffix, auto_enter, disable_suffix, complete_match_only, created_at)
                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"#,
             params![
-                id.clone(),
-                trigger.clone(),
-                expansion.clone(),
-                suffix.clone(),
-                auto_enter as i32,
-                disable_suffix as i32,
-                complete_match_only as i32,
+                entry.id.clone(),
+                entry.trigger.clone(),
+                entry.expansion.clone(),
+                entry.suffix.clone(),
+                entry.auto_enter as i32,
+                entry.disable_suffix as i32,
+                entry.complete_match_only as i32,
                 created_at
             ],
         )
         .await
         .map_err(|e| match e {
             TursoError::Constraint(_) => DictionaryError::PersistenceError(
-                format!("Trigger '{}' already exists", trigger),
+                format!("Trigger '{}' already exists", entry.trigger),
             ),
             other => DictionaryError::PersistenceError(other.to_string()),
         })?;
 
-        Ok(DictionaryEntry {
-            id,
-            trigger,
-            expansion,
-            suffix,
-            auto_enter,
-            disable_suffix,
-            complete_match_only,
-        })
+        Ok(())
     }
 
     /// Update an existing dictionary entry.
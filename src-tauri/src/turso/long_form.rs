@@ -0,0 +1,259 @@
+// Long-form document CRUD operations using Turso/libsql
+//
+// A long-form document is the growing transcript of a long-form recording
+// session. Each transcribed segment is appended to the document text.
+
+use libsql::params;
+use serde::Serialize;
+
+use super::client::TursoClient;
+
+/// Lifecycle status of a long-form document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LongFormStatus {
+    /// Session is still recording, segments are being appended
+    Recording,
+    /// Session finished, all segments transcribed
+    Completed,
+}
+
+impl LongFormStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LongFormStatus::Recording => "recording",
+            LongFormStatus::Completed => "completed",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "recording" => Some(LongFormStatus::Recording),
+            "completed" => Some(LongFormStatus::Completed),
+            _ => None,
+        }
+    }
+}
+
+/// Long-form document stored in Turso
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LongFormDocument {
+    pub id: String,
+    pub title: String,
+    pub text: String,
+    pub segment_count: u32,
+    pub status: LongFormStatus,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Error type for long-form document operations
+#[derive(Debug, Clone)]
+pub enum LongFormStoreError {
+    NotFound(String),
+    PersistenceError(String),
+    LoadError(String),
+}
+
+impl std::fmt::Display for LongFormStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LongFormStoreError::NotFound(id) => write!(f, "Long-form document not found: {}", id),
+            LongFormStoreError::PersistenceError(msg) => {
+                write!(f, "Long-form document persistence error: {}", msg)
+            }
+            LongFormStoreError::LoadError(msg) => {
+                write!(f, "Long-form document load error: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LongFormStoreError {}
+
+impl TursoClient {
+    /// Create a new, empty long-form document in Recording status.
+    pub async fn create_long_form_document(
+        &self,
+        id: String,
+        title: String,
+    ) -> Result<LongFormDocument, LongFormStoreError> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let status = LongFormStatus::Recording;
+
+        self.execute(
+            r#"INSERT INTO long_form_document
+               (id, title, text, segment_count, status, created_at, updated_at)
+               VALUES (?1, ?2, '', 0, ?3, ?4, ?5)"#,
+            params![
+                id.clone(),
+                title.clone(),
+                status.as_str(),
+                now.clone(),
+                now.clone()
+            ],
+        )
+        .await
+        .map_err(|e| LongFormStoreError::PersistenceError(e.to_string()))?;
+
+        Ok(LongFormDocument {
+            id,
+            title,
+            text: String::new(),
+            segment_count: 0,
+            status,
+            created_at: now.clone(),
+            updated_at: now,
+        })
+    }
+
+    /// Append a transcribed segment to a long-form document.
+    ///
+    /// Segments are separated by a blank line. Empty segments (silence) still
+    /// count towards `segment_count` but add no text.
+    pub async fn append_long_form_segment(
+        &self,
+        id: &str,
+        segment_text: &str,
+    ) -> Result<LongFormDocument, LongFormStoreError> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let segment_text = segment_text.trim();
+
+        let affected = self
+            .execute(
+                r#"UPDATE long_form_document
+                   SET text = CASE
+                           WHEN ?2 = '' THEN text
+                           WHEN text = '' THEN ?2
+                           ELSE text || char(10) || char(10) || ?2
+                       END,
+                       segment_count = segment_count + 1,
+                       updated_at = ?3
+                   WHERE id = ?1"#,
+                params![id.to_string(), segment_text.to_string(), now],
+            )
+            .await
+            .map_err(|e| LongFormStoreError::PersistenceError(e.to_string()))?;
+
+        if affected == 0 {
+            return Err(LongFormStoreError::NotFound(id.to_string()));
+        }
+
+        self.get_long_form_document(id)
+            .await?
+            .ok_or_else(|| LongFormStoreError::NotFound(id.to_string()))
+    }
+
+    /// Mark a long-form document as completed.
+    pub async fn complete_long_form_document(&self, id: &str) -> Result<(), LongFormStoreError> {
+        let now = chrono::Utc::now().to_rfc3339();
+
+        let affected = self
+            .execute(
+                "UPDATE long_form_document SET status = ?2, updated_at = ?3 WHERE id = ?1",
+                params![id.to_string(), LongFormStatus::Completed.as_str(), now],
+            )
+            .await
+            .map_err(|e| LongFormStoreError::PersistenceError(e.to_string()))?;
+
+        if affected == 0 {
+            return Err(LongFormStoreError::NotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Get a long-form document by ID.
+    pub async fn get_long_form_document(
+        &self,
+        id: &str,
+    ) -> Result<Option<LongFormDocument>, LongFormStoreError> {
+        let mut rows = self
+            .query(
+                r#"SELECT id, title, text, segment_count, status, created_at, updated_at
+                   FROM long_form_document
+                   WHERE id = ?1"#,
+                params![id.to_string()],
+            )
+            .await
+            .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+
+        match rows
+            .next()
+            .await
+            .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?
+        {
+            Some(row) => Ok(Some(parse_long_form_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// List all long-form documents ordered by created_at DESC.
+    pub async fn list_long_form_documents(
+        &self,
+    ) -> Result<Vec<LongFormDocument>, LongFormStoreError> {
+        let mut rows = self
+            .query(
+                r#"SELECT id, title, text, segment_count, status, created_at, updated_at
+                   FROM long_form_document
+                   ORDER BY created_at DESC"#,
+                (),
+            )
+            .await
+            .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+
+        let mut documents = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .await
+            .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?
+        {
+            documents.push(parse_long_form_row(&row)?);
+        }
+
+        Ok(documents)
+    }
+}
+
+/// Parse a database row into a LongFormDocument
+fn parse_long_form_row(row: &libsql::Row) -> Result<LongFormDocument, LongFormStoreError> {
+    let id: String = row
+        .get(0)
+        .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+    let title: String = row
+        .get(1)
+        .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+    let text: String = row
+        .get(2)
+        .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+    let segment_count: i64 = row
+        .get(3)
+        .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+    let status_str: String = row
+        .get(4)
+        .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+    let created_at: String = row
+        .get(5)
+        .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+    let updated_at: String = row
+        .get(6)
+        .map_err(|e| LongFormStoreError::LoadError(e.to_string()))?;
+
+    let status = LongFormStatus::parse(&status_str).ok_or_else(|| {
+        LongFormStoreError::LoadError(format!("Unknown long-form status: {}", status_str))
+    })?;
+
+    Ok(LongFormDocument {
+        id,
+        title,
+        text,
+        segment_count: segment_count as u32,
+        status,
+        created_at,
+        updated_at,
+    })
+}
+
+#[cfg(test)]
+#[path = "long_form_test.rs"]
+mod tests;
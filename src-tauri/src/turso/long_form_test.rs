@@ -0,0 +1,105 @@
+use crate::turso::{initialize_schema, LongFormStatus, LongFormStoreError, TursoClient};
+use tempfile::TempDir;
+
+async fn setup_client() -> (TursoClient, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+    initialize_schema(&client)
+        .await
+        .expect("Failed to initialize schema");
+    (client, temp_dir)
+}
+
+#[tokio::test]
+async fn test_create_long_form_document() {
+    let (client, _temp) = setup_client().await;
+
+    let doc = client
+        .create_long_form_document("doc-1".to_string(), "Standup".to_string())
+        .await
+        .expect("Failed to create document");
+
+    assert_eq!(doc.id, "doc-1");
+    assert_eq!(doc.title, "Standup");
+    assert_eq!(doc.text, "");
+    assert_eq!(doc.segment_count, 0);
+    assert_eq!(doc.status, LongFormStatus::Recording);
+}
+
+#[tokio::test]
+async fn test_append_segments_grows_document_in_order() {
+    let (client, _temp) = setup_client().await;
+    client
+        .create_long_form_document("doc-1".to_string(), "Meeting".to_string())
+        .await
+        .unwrap();
+
+    client
+        .append_long_form_segment("doc-1", "first part")
+        .await
+        .unwrap();
+    client
+        .append_long_form_segment("doc-1", "   ")
+        .await
+        .unwrap();
+    let doc = client
+        .append_long_form_segment("doc-1", "second part ")
+        .await
+        .unwrap();
+
+    assert_eq!(doc.text, "first part\n\nsecond part");
+    assert_eq!(doc.segment_count, 3);
+}
+
+#[tokio::test]
+async fn test_append_segment_to_missing_document() {
+    let (client, _temp) = setup_client().await;
+
+    let result = client.append_long_form_segment("missing", "text").await;
+    assert!(matches!(result, Err(LongFormStoreError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_complete_long_form_document() {
+    let (client, _temp) = setup_client().await;
+    client
+        .create_long_form_document("doc-1".to_string(), "Brainstorm".to_string())
+        .await
+        .unwrap();
+
+    client.complete_long_form_document("doc-1").await.unwrap();
+
+    let doc = client
+        .get_long_form_document("doc-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(doc.status, LongFormStatus::Completed);
+    assert!(matches!(
+        client.complete_long_form_document("missing").await,
+        Err(LongFormStoreError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_list_long_form_documents() {
+    let (client, _temp) = setup_client().await;
+    client
+        .create_long_form_document("doc-1".to_string(), "One".to_string())
+        .await
+        .unwrap();
+    client
+        .create_long_form_document("doc-2".to_string(), "Two".to_string())
+        .await
+        .unwrap();
+
+    let docs = client.list_long_form_documents().await.unwrap();
+    assert_eq!(docs.len(), 2);
+    assert!(client
+        .get_long_form_document("missing")
+        .await
+        .unwrap()
+        .is_none());
+}
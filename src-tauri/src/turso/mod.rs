@@ -4,8 +4,11 @@
 // It provides a simple, embedded solution for all data persistence.
 
 mod client;
+mod config_bundle;
 mod dictionary;
 pub mod events;
+mod long_form;
+mod pipeline_event;
 mod recording;
 mod schema;
 mod voice_command;
@@ -16,4 +19,18 @@ mod window_context;
 pub use recording::{RecordingRecord, RecordingStoreError, TranscriptionRecord, TranscriptionStoreError};
 
 pub use client::TursoClient;
+// Report and error types are returned to the frontend and matched on in tests
+#[allow(unused_imports)]
+pub use config_bundle::{
+    BundleItemKind, ConfigBundle, ConfigBundleError, ImportConflict, ImportMode, ImportReport,
+    CONFIG_BUNDLE_VERSION,
+};
+// Status/error types are used by callers matching on results and in tests
+#[allow(unused_imports)]
+pub use long_form::{LongFormDocument, LongFormStatus, LongFormStoreError};
+// Store error type is only matched on in tests
+#[allow(unused_imports)]
+pub use pipeline_event::{
+    PipelineEvent, PipelineEventFilter, PipelineEventStoreError, PipelineMatch, PipelineOutcome,
+};
 pub use schema::initialize_schema;
@@ -0,0 +1,336 @@
+// Pipeline event log using Turso/libsql
+//
+// One row per utterance that went through the transcription pipeline (or per
+// command run from the UI), recording how it was matched and what came of it.
+// The log answers "why did it paste instead of running my command?".
+
+use libsql::params;
+use serde::{Deserialize, Serialize};
+
+use super::client::TursoClient;
+
+/// Number of pipeline events kept; older events are dropped as new ones arrive
+pub const MAX_PIPELINE_EVENTS: u32 = 10_000;
+
+/// How an utterance was matched against voice commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineMatch {
+    /// Matched a trigger exactly
+    Exact,
+    /// Matched a trigger above the fuzzy threshold
+    Fuzzy,
+    /// Several commands matched equally well
+    Ambiguous,
+    /// Answer to a pending confirmation or ambiguous match
+    FollowUp,
+    /// No command matched
+    NoMatch,
+    /// Run from the UI rather than matched from speech
+    Manual,
+    /// A built-in phrase such as "scratch that"
+    BuiltIn,
+}
+
+impl PipelineMatch {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PipelineMatch::Exact => "exact",
+            PipelineMatch::Fuzzy => "fuzzy",
+            PipelineMatch::Ambiguous => "ambiguous",
+            PipelineMatch::FollowUp => "follow_up",
+            PipelineMatch::NoMatch => "no_match",
+            PipelineMatch::Manual => "manual",
+            PipelineMatch::BuiltIn => "built_in",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "exact" => Some(PipelineMatch::Exact),
+            "fuzzy" => Some(PipelineMatch::Fuzzy),
+            "ambiguous" => Some(PipelineMatch::Ambiguous),
+            "follow_up" => Some(PipelineMatch::FollowUp),
+            "no_match" => Some(PipelineMatch::NoMatch),
+            "manual" => Some(PipelineMatch::Manual),
+            "built_in" => Some(PipelineMatch::BuiltIn),
+            _ => None,
+        }
+    }
+}
+
+/// What happened to an utterance at the end of the pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineOutcome {
+    /// Text was pasted as transcribed
+    Pasted,
+    /// Text was pasted after dictionary expansion changed it
+    Expanded,
+    /// Text could not be copied or pasted
+    PasteFailed,
+    /// A command ran successfully
+    Executed,
+    /// A command ran and returned an error
+    Failed,
+    /// A command is waiting for the user to confirm it
+    AwaitingConfirmation,
+    /// The user was asked to pick one of several commands
+    AwaitingChoice,
+    /// The user declined a pending command or choice
+    Declined,
+    /// The last output was removed ("scratch that")
+    Undone,
+}
+
+impl PipelineOutcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PipelineOutcome::Pasted => "pasted",
+            PipelineOutcome::Expanded => "expanded",
+            PipelineOutcome::PasteFailed => "paste_failed",
+            PipelineOutcome::Executed => "executed",
+            PipelineOutcome::Failed => "failed",
+            PipelineOutcome::AwaitingConfirmation => "awaiting_confirmation",
+            PipelineOutcome::AwaitingChoice => "awaiting_choice",
+            PipelineOutcome::Declined => "declined",
+            PipelineOutcome::Undone => "undone",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pasted" => Some(PipelineOutcome::Pasted),
+            "expanded" => Some(PipelineOutcome::Expanded),
+            "paste_failed" => Some(PipelineOutcome::PasteFailed),
+            "executed" => Some(PipelineOutcome::Executed),
+            "failed" => Some(PipelineOutcome::Failed),
+            "awaiting_confirmation" => Some(PipelineOutcome::AwaitingConfirmation),
+            "awaiting_choice" => Some(PipelineOutcome::AwaitingChoice),
+            "declined" => Some(PipelineOutcome::Declined),
+            "undone" => Some(PipelineOutcome::Undone),
+            _ => None,
+        }
+    }
+}
+
+/// Pipeline event stored in Turso
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineEvent {
+    pub id: String,
+    /// Transcription the event is about, if it was stored
+    pub transcription_id: Option<String>,
+    pub match_result: PipelineMatch,
+    /// Confidence of the match (top candidate for ambiguous matches)
+    pub match_score: Option<f64>,
+    pub command_id: Option<String>,
+    pub outcome: PipelineOutcome,
+    /// `ActionErrorCode` of a failed command, e.g. "PERMISSION_DENIED"
+    pub error_code: Option<String>,
+    /// Time from the start of transcription (or of the UI action) to the outcome
+    pub latency_ms: u64,
+    /// Frontmost application when the outcome was reached
+    pub active_app: Option<String>,
+    pub created_at: String,
+}
+
+/// Filters for listing pipeline events; unset fields match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineEventFilter {
+    pub outcome: Option<PipelineOutcome>,
+    pub match_result: Option<PipelineMatch>,
+    pub command_id: Option<String>,
+    pub transcription_id: Option<String>,
+    /// Only events created at or after this RFC 3339 timestamp
+    pub since: Option<String>,
+    /// Maximum number of events, newest first
+    pub limit: Option<u32>,
+}
+
+/// Error type for pipeline event operations
+#[derive(Debug, Clone)]
+pub enum PipelineEventStoreError {
+    PersistenceError(String),
+    LoadError(String),
+}
+
+impl std::fmt::Display for PipelineEventStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipelineEventStoreError::PersistenceError(msg) => {
+                write!(f, "Pipeline event persistence error: {}", msg)
+            }
+            PipelineEventStoreError::LoadError(msg) => {
+                write!(f, "Pipeline event load error: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PipelineEventStoreError {}
+
+impl TursoClient {
+    /// Append an event to the pipeline log.
+    ///
+    /// Keeps at most `MAX_PIPELINE_EVENTS` events, dropping the oldest.
+    pub async fn add_pipeline_event(
+        &self,
+        event: &PipelineEvent,
+    ) -> Result<(), PipelineEventStoreError> {
+        self.execute(
+            r#"INSERT INTO pipeline_event
+               (id, transcription_id, match_result, match_score, command_id, outcome,
+                error_code, latency_ms, active_app, created_at)
+               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)"#,
+            params![
+                event.id.clone(),
+                event.transcription_id.clone(),
+                event.match_result.as_str(),
+                event.match_score,
+                event.command_id.clone(),
+                event.outcome.as_str(),
+                event.error_code.clone(),
+                event.latency_ms as i64,
+                event.active_app.clone(),
+                event.created_at.clone()
+            ],
+        )
+        .await
+        .map_err(|e| PipelineEventStoreError::PersistenceError(e.to_string()))?;
+
+        self.prune_pipeline_events(MAX_PIPELINE_EVENTS).await?;
+        Ok(())
+    }
+
+    /// Delete all but the `keep` newest pipeline events.
+    ///
+    /// Returns the number of events deleted.
+    pub(crate) async fn prune_pipeline_events(
+        &self,
+        keep: u32,
+    ) -> Result<u64, PipelineEventStoreError> {
+        // The subquery is NULL while there are no more than `keep` events;
+        // events sharing the cut-off timestamp are dropped with it
+        self.execute(
+            r#"DELETE FROM pipeline_event
+               WHERE created_at <= (SELECT created_at FROM pipeline_event
+                                    ORDER BY created_at DESC LIMIT 1 OFFSET ?1)"#,
+            params![i64::from(keep)],
+        )
+        .await
+        .map_err(|e| PipelineEventStoreError::PersistenceError(e.to_string()))
+    }
+
+    /// Delete every pipeline event.
+    ///
+    /// Returns the number of events deleted.
+    pub async fn clear_pipeline_events(&self) -> Result<u64, PipelineEventStoreError> {
+        self.execute("DELETE FROM pipeline_event", ())
+            .await
+            .map_err(|e| PipelineEventStoreError::PersistenceError(e.to_string()))
+    }
+
+    /// List pipeline events matching `filter`, ordered by created_at DESC.
+    pub async fn list_pipeline_events(
+        &self,
+        filter: &PipelineEventFilter,
+    ) -> Result<Vec<PipelineEvent>, PipelineEventStoreError> {
+        // SQLite treats a negative LIMIT as no limit
+        let limit = filter.limit.map(i64::from).unwrap_or(-1);
+
+        let mut rows = self
+            .query(
+                r#"SELECT id, transcription_id, match_result, match_score, command_id, outcome,
+                          error_code, latency_ms, active_app, created_at
+                   FROM pipeline_event
+                   WHERE (?1 IS NULL OR outcome = ?1)
+                     AND (?2 IS NULL OR match_result = ?2)
+                     AND (?3 IS NULL OR command_id = ?3)
+                     AND (?4 IS NULL OR transcription_id = ?4)
+                     AND (?5 IS NULL OR created_at >= ?5)
+                   ORDER BY created_at DESC
+                   LIMIT ?6"#,
+                params![
+                    filter.outcome.map(|o| o.as_str().to_string()),
+                    filter.match_result.map(|m| m.as_str().to_string()),
+                    filter.command_id.clone(),
+                    filter.transcription_id.clone(),
+                    filter.since.clone(),
+                    limit
+                ],
+            )
+            .await
+            .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+
+        let mut events = Vec::new();
+        while let Some(row) = rows
+            .next()
+            .await
+            .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?
+        {
+            events.push(parse_pipeline_event_row(&row)?);
+        }
+
+        Ok(events)
+    }
+}
+
+/// Parse a database row into a PipelineEvent
+fn parse_pipeline_event_row(row: &libsql::Row) -> Result<PipelineEvent, PipelineEventStoreError> {
+    let id: String = row
+        .get(0)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let transcription_id: Option<String> = row
+        .get(1)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let match_str: String = row
+        .get(2)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let match_score: Option<f64> = row
+        .get(3)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let command_id: Option<String> = row
+        .get(4)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let outcome_str: String = row
+        .get(5)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let error_code: Option<String> = row
+        .get(6)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let latency_ms: i64 = row
+        .get(7)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let active_app: Option<String> = row
+        .get(8)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+    let created_at: String = row
+        .get(9)
+        .map_err(|e| PipelineEventStoreError::LoadError(e.to_string()))?;
+
+    let match_result = PipelineMatch::parse(&match_str).ok_or_else(|| {
+        PipelineEventStoreError::LoadError(format!("Unknown match result: {}", match_str))
+    })?;
+    let outcome = PipelineOutcome::parse(&outcome_str).ok_or_else(|| {
+        PipelineEventStoreError::LoadError(format!("Unknown pipeline outcome: {}", outcome_str))
+    })?;
+
+    Ok(PipelineEvent {
+        id,
+        transcription_id,
+        match_result,
+        match_score,
+        command_id,
+        outcome,
+        error_code,
+        latency_ms: latency_ms as u64,
+        active_app,
+        created_at,
+    })
+}
+
+#[cfg(test)]
+#[path = "pipeline_event_test.rs"]
+mod tests;
@@ -0,0 +1,244 @@
+use crate::turso::{
+    initialize_schema, PipelineEvent, PipelineEventFilter, PipelineEventStoreError, PipelineMatch,
+    PipelineOutcome, TursoClient,
+};
+use tempfile::TempDir;
+
+async fn setup_client() -> (TursoClient, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+    initialize_schema(&client)
+        .await
+        .expect("Failed to initialize schema");
+    (client, temp_dir)
+}
+
+fn make_event(id: &str, outcome: PipelineOutcome, created_at: &str) -> PipelineEvent {
+    PipelineEvent {
+        id: id.to_string(),
+        transcription_id: Some(format!("tr-{}", id)),
+        match_result: PipelineMatch::NoMatch,
+        match_score: None,
+        command_id: None,
+        outcome,
+        error_code: None,
+        latency_ms: 120,
+        active_app: Some("Slack".to_string()),
+        created_at: created_at.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_add_and_list_pipeline_event_round_trips() {
+    let (client, _temp) = setup_client().await;
+    let event = PipelineEvent {
+        match_result: PipelineMatch::Fuzzy,
+        match_score: Some(0.82),
+        command_id: Some("cmd-1".to_string()),
+        error_code: Some("PERMISSION_DENIED".to_string()),
+        ..make_event("ev-1", PipelineOutcome::Failed, "2025-01-01T10:00:00Z")
+    };
+
+    client.add_pipeline_event(&event).await.unwrap();
+
+    let events = client
+        .list_pipeline_events(&PipelineEventFilter::default())
+        .await
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    let stored = &events[0];
+    assert_eq!(stored.id, "ev-1");
+    assert_eq!(stored.transcription_id.as_deref(), Some("tr-ev-1"));
+    assert_eq!(stored.match_result, PipelineMatch::Fuzzy);
+    assert_eq!(stored.match_score, Some(0.82));
+    assert_eq!(stored.command_id.as_deref(), Some("cmd-1"));
+    assert_eq!(stored.outcome, PipelineOutcome::Failed);
+    assert_eq!(stored.error_code.as_deref(), Some("PERMISSION_DENIED"));
+    assert_eq!(stored.latency_ms, 120);
+    assert_eq!(stored.active_app.as_deref(), Some("Slack"));
+}
+
+#[tokio::test]
+async fn test_list_pipeline_events_newest_first_with_limit() {
+    let (client, _temp) = setup_client().await;
+    for (id, created_at) in [
+        ("ev-1", "2025-01-01T10:00:00Z"),
+        ("ev-3", "2025-01-01T12:00:00Z"),
+        ("ev-2", "2025-01-01T11:00:00Z"),
+    ] {
+        client
+            .add_pipeline_event(&make_event(id, PipelineOutcome::Pasted, created_at))
+            .await
+            .unwrap();
+    }
+
+    let filter = PipelineEventFilter {
+        limit: Some(2),
+        ..Default::default()
+    };
+    let ids: Vec<_> = client
+        .list_pipeline_events(&filter)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids, vec!["ev-3", "ev-2"]);
+}
+
+#[tokio::test]
+async fn test_prune_keeps_newest_pipeline_events() {
+    let (client, _temp) = setup_client().await;
+    for (id, created_at) in [
+        ("ev-1", "2025-01-01T10:00:00Z"),
+        ("ev-3", "2025-01-01T12:00:00Z"),
+        ("ev-2", "2025-01-01T11:00:00Z"),
+    ] {
+        client
+            .add_pipeline_event(&make_event(id, PipelineOutcome::Pasted, created_at))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(client.prune_pipeline_events(5).await.unwrap(), 0);
+    assert_eq!(client.prune_pipeline_events(2).await.unwrap(), 1);
+
+    let ids: Vec<_> = client
+        .list_pipeline_events(&PipelineEventFilter::default())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(ids, vec!["ev-3", "ev-2"]);
+}
+
+#[tokio::test]
+async fn test_clear_pipeline_events_removes_everything() {
+    let (client, _temp) = setup_client().await;
+    for id in ["ev-1", "ev-2"] {
+        client
+            .add_pipeline_event(&make_event(
+                id,
+                PipelineOutcome::Pasted,
+                "2025-01-01T10:00:00Z",
+            ))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(client.clear_pipeline_events().await.unwrap(), 2);
+    assert!(client
+        .list_pipeline_events(&PipelineEventFilter::default())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_list_pipeline_events_filters() {
+    let (client, _temp) = setup_client().await;
+    let executed = PipelineEvent {
+        match_result: PipelineMatch::Exact,
+        match_score: Some(1.0),
+        command_id: Some("cmd-1".to_string()),
+        ..make_event("ev-1", PipelineOutcome::Executed, "2025-01-01T10:00:00Z")
+    };
+    client.add_pipeline_event(&executed).await.unwrap();
+    client
+        .add_pipeline_event(&make_event(
+            "ev-2",
+            PipelineOutcome::Pasted,
+            "2025-01-02T10:00:00Z",
+        ))
+        .await
+        .unwrap();
+    client
+        .add_pipeline_event(&make_event(
+            "ev-3",
+            PipelineOutcome::Expanded,
+            "2025-01-03T10:00:00Z",
+        ))
+        .await
+        .unwrap();
+
+    let list = |filter: PipelineEventFilter| {
+        let client = &client;
+        async move {
+            client
+                .list_pipeline_events(&filter)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|e| e.id)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    let by_outcome = list(PipelineEventFilter {
+        outcome: Some(PipelineOutcome::Pasted),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(by_outcome, vec!["ev-2"]);
+
+    let by_match = list(PipelineEventFilter {
+        match_result: Some(PipelineMatch::NoMatch),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(by_match, vec!["ev-3", "ev-2"]);
+
+    let by_command = list(PipelineEventFilter {
+        command_id: Some("cmd-1".to_string()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(by_command, vec!["ev-1"]);
+
+    let by_transcription = list(PipelineEventFilter {
+        transcription_id: Some("tr-ev-3".to_string()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(by_transcription, vec!["ev-3"]);
+
+    let since = list(PipelineEventFilter {
+        since: Some("2025-01-02T00:00:00Z".to_string()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(since, vec!["ev-3", "ev-2"]);
+}
+
+#[tokio::test]
+async fn test_list_pipeline_events_rejects_unknown_outcome() {
+    let (client, _temp) = setup_client().await;
+    client
+        .execute(
+            "INSERT INTO pipeline_event (id, match_result, outcome, latency_ms, created_at) VALUES ('ev-1', 'no_match', 'teleported', 0, '2025-01-01T10:00:00Z')",
+            (),
+        )
+        .await
+        .unwrap();
+
+    let result = client
+        .list_pipeline_events(&PipelineEventFilter::default())
+        .await;
+    assert!(matches!(result, Err(PipelineEventStoreError::LoadError(_))));
+}
+
+#[test]
+fn test_pipeline_event_filter_deserializes_from_frontend() {
+    let filter: PipelineEventFilter = serde_json::from_str(
+        r#"{"outcome": "awaiting_confirmation", "matchResult": "follow_up", "commandId": "cmd-1", "limit": 50}"#,
+    )
+    .unwrap();
+    assert_eq!(filter.outcome, Some(PipelineOutcome::AwaitingConfirmation));
+    assert_eq!(filter.match_result, Some(PipelineMatch::FollowUp));
+    assert_eq!(filter.command_id.as_deref(), Some("cmd-1"));
+    assert_eq!(filter.limit, Some(50));
+    assert!(filter.since.is_none());
+}
@@ -430,6 +430,7 @@ fn parse_transcription_row(row: &libsql::Row) -> Result<TranscriptionRecord, Tra
 fn parse_stop_reason(s: &str) -> Option<StopReason> {
     match s {
         "BufferFull" => Some(StopReason::BufferFull),
+        "MaxDurationReached" => Some(StopReason::MaxDurationReached),
         "LockError" => Some(StopReason::LockError),
         "StreamError" => Some(StopReason::StreamError),
         "ResampleOverflow" => Some(StopReason::ResampleOverflow),
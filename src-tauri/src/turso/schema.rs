@@ -4,9 +4,10 @@
 // and provides a migration system for future schema changes.
 
 use super::client::{TursoClient, TursoError};
+use crate::voice_commands::registry::normalize_phrase;
 
 /// Current schema version
-const SCHEMA_VERSION: i32 = 2;
+const SCHEMA_VERSION: i32 = 7;
 
 /// SQL statements to create all tables (each as a separate string)
 const CREATE_TABLES: &[&str] = &[
@@ -34,7 +35,8 @@ const CREATE_TABLES: &[&str] = &[
         dictionary_entry_ids_json TEXT NOT NULL,
         enabled INTEGER NOT NULL DEFAULT 1,
         priority INTEGER NOT NULL DEFAULT 0,
-        created_at TEXT NOT NULL
+        created_at TEXT NOT NULL,
+        command_prefix TEXT NOT NULL DEFAULT 'inherit'
     )"#,
     // Recording metadata
     r#"CREATE TABLE IF NOT EXISTS recording (
@@ -68,10 +70,69 @@ const CREATE_TABLES: &[&str] = &[
         action_type TEXT NOT NULL,
         parameters_json TEXT NOT NULL,
         enabled INTEGER NOT NULL DEFAULT 1,
+        requires_confirmation INTEGER NOT NULL DEFAULT 0,
         created_at TEXT NOT NULL
     )"#,
+    // Long-form documents built from incrementally transcribed segments
+    LONG_FORM_DOCUMENT_TABLE,
+    // Trigger phrases of voice commands, unique across all commands
+    VOICE_COMMAND_ALIAS_TABLE,
+    VOICE_COMMAND_ALIAS_INDEX,
+    // Outcome of each utterance through the transcription pipeline
+    PIPELINE_EVENT_TABLE,
+    PIPELINE_EVENT_INDEX,
 ];
 
+/// Long-form document table (added in schema v3)
+const LONG_FORM_DOCUMENT_TABLE: &str = r#"CREATE TABLE IF NOT EXISTS long_form_document (
+        id TEXT PRIMARY KEY,
+        title TEXT NOT NULL,
+        text TEXT NOT NULL DEFAULT '',
+        segment_count INTEGER NOT NULL DEFAULT 0,
+        status TEXT NOT NULL,
+        created_at TEXT NOT NULL,
+        updated_at TEXT NOT NULL
+    )"#;
+
+/// Voice command alias table (added in schema v5)
+///
+/// Holds every phrase of a command, including the canonical one mirrored in
+/// voice_command.trigger. phrase_key is the normalized phrase, so a phrase
+/// can only belong to one command.
+const VOICE_COMMAND_ALIAS_TABLE: &str = r#"CREATE TABLE IF NOT EXISTS voice_command_alias (
+        phrase_key TEXT PRIMARY KEY,
+        command_id TEXT NOT NULL,
+        phrase TEXT NOT NULL,
+        is_canonical INTEGER NOT NULL DEFAULT 0,
+        position INTEGER NOT NULL DEFAULT 0,
+        FOREIGN KEY (command_id) REFERENCES voice_command(id) ON DELETE CASCADE
+    )"#;
+
+/// Index for loading the aliases of a command (added in schema v5)
+const VOICE_COMMAND_ALIAS_INDEX: &str =
+    r#"CREATE INDEX IF NOT EXISTS idx_voice_command_alias_command_id ON voice_command_alias(command_id)"#;
+
+/// Pipeline event table (added in schema v6)
+///
+/// transcription_id and command_id are not foreign keys so the log outlives
+/// deleted transcriptions and commands.
+const PIPELINE_EVENT_TABLE: &str = r#"CREATE TABLE IF NOT EXISTS pipeline_event (
+        id TEXT PRIMARY KEY,
+        transcription_id TEXT,
+        match_result TEXT NOT NULL,
+        match_score REAL,
+        command_id TEXT,
+        outcome TEXT NOT NULL,
+        error_code TEXT,
+        latency_ms INTEGER NOT NULL,
+        active_app TEXT,
+        created_at TEXT NOT NULL
+    )"#;
+
+/// Index for listing recent pipeline events (added in schema v6)
+const PIPELINE_EVENT_INDEX: &str =
+    r#"CREATE INDEX IF NOT EXISTS idx_pipeline_event_created_at ON pipeline_event(created_at)"#;
+
 /// Initialize the database schema.
 ///
 /// Creates all tables if they don't exist and runs any pending migrations.
@@ -163,7 +224,12 @@ async fn run_migrations(
     for version in (from_version + 1)..=to_version {
         match version {
             2 => migrate_v1_to_v2(client).await?,
-            // 3 => migrate_v2_to_v3(client).await?,
+            3 => migrate_v2_to_v3(client).await?,
+            4 => migrate_v3_to_v4(client).await?,
+            5 => migrate_v4_to_v5(client).await?,
+            6 => migrate_v5_to_v6(client).await?,
+            7 => migrate_v6_to_v7(client).await?,
+            // 8 => migrate_v7_to_v8(client).await?,
             _ => {
                 // No migration needed for this version
                 crate::debug!("No migration needed for version {}", version);
@@ -187,6 +253,115 @@ async fn migrate_v1_to_v2(client: &TursoClient) -> Result<(), TursoError> {
     Ok(())
 }
 
+/// Migrate from schema version 2 to 3.
+/// Adds long_form_document table for long-form recording sessions.
+async fn migrate_v2_to_v3(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v2 -> v3: adding long_form_document table");
+    client.execute(LONG_FORM_DOCUMENT_TABLE, ()).await?;
+    Ok(())
+}
+
+/// Migrate from schema version 3 to 4.
+/// Adds requires_confirmation column to voice_command table, carrying over
+/// the "confirmation" parameter the command editor used to store.
+async fn migrate_v3_to_v4(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v3 -> v4: adding requires_confirmation column to voice_command");
+    let mut rows = client
+        .query(
+            "SELECT 1 FROM pragma_table_info('voice_command') WHERE name = 'requires_confirmation'",
+            (),
+        )
+        .await?;
+    let has_column = rows
+        .next()
+        .await
+        .map_err(|e| TursoError::Query(e.to_string()))?
+        .is_some();
+    if !has_column {
+        client
+            .execute(
+                "ALTER TABLE voice_command ADD COLUMN requires_confirmation INTEGER NOT NULL DEFAULT 0",
+                (),
+            )
+            .await?;
+    }
+    client
+        .execute(
+            "UPDATE voice_command SET requires_confirmation = 1 WHERE json_extract(parameters_json, '$.confirmation') = 'true'",
+            (),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Migrate from schema version 4 to 5.
+/// Adds voice_command_alias table and gives each existing command its
+/// trigger as the canonical alias.
+async fn migrate_v4_to_v5(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v4 -> v5: adding voice_command_alias table");
+    client.execute(VOICE_COMMAND_ALIAS_TABLE, ()).await?;
+    client.execute(VOICE_COMMAND_ALIAS_INDEX, ()).await?;
+
+    let mut rows = client
+        .query("SELECT id, trigger FROM voice_command", ())
+        .await?;
+    let mut commands = Vec::new();
+    while let Some(row) = rows
+        .next()
+        .await
+        .map_err(|e| TursoError::Query(e.to_string()))?
+    {
+        let id: String = row.get(0).map_err(|e| TursoError::Query(e.to_string()))?;
+        let trigger: String = row.get(1).map_err(|e| TursoError::Query(e.to_string()))?;
+        commands.push((id, trigger));
+    }
+    for (id, trigger) in commands {
+        // Triggers differing only in case or spacing keep just the first as an alias
+        client
+            .execute(
+                "INSERT OR IGNORE INTO voice_command_alias (phrase_key, command_id, phrase, is_canonical, position) VALUES (?1, ?2, ?3, 1, 0)",
+                libsql::params![normalize_phrase(&trigger), id, trigger],
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+/// Migrate from schema version 5 to 6.
+/// Adds pipeline_event table for the pipeline outcome log.
+async fn migrate_v5_to_v6(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v5 -> v6: adding pipeline_event table");
+    client.execute(PIPELINE_EVENT_TABLE, ()).await?;
+    client.execute(PIPELINE_EVENT_INDEX, ()).await?;
+    Ok(())
+}
+
+/// Migrate from schema version 6 to 7.
+/// Adds command_prefix column to window_context table.
+async fn migrate_v6_to_v7(client: &TursoClient) -> Result<(), TursoError> {
+    crate::info!("Running migration v6 -> v7: adding command_prefix column to window_context");
+    let mut rows = client
+        .query(
+            "SELECT 1 FROM pragma_table_info('window_context') WHERE name = 'command_prefix'",
+            (),
+        )
+        .await?;
+    let has_column = rows
+        .next()
+        .await
+        .map_err(|e| TursoError::Query(e.to_string()))?
+        .is_some();
+    if !has_column {
+        client
+            .execute(
+                "ALTER TABLE window_context ADD COLUMN command_prefix TEXT NOT NULL DEFAULT 'inherit'",
+                (),
+            )
+            .await?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 #[path = "schema_test.rs"]
 mod tests;
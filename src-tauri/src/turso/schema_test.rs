@@ -14,7 +14,7 @@ async fn test_schema_creates_all_tables() {
     initialize_schema(&client).await.expect("Failed to initialize schema");
 
     // Verify all tables exist
-    let tables = ["dictionary_entry", "window_context", "recording", "transcription", "voice_command", "schema_version"];
+    let tables = ["dictionary_entry", "window_context", "recording", "transcription", "voice_command", "voice_command_alias", "long_form_document", "pipeline_event", "schema_version"];
 
     for table in tables {
         let mut rows = client
@@ -210,3 +210,240 @@ async fn test_transcription_recording_id_index() {
         "Index idx_transcription_recording_id should exist"
     );
 }
+
+/// Test v2 databases gain the long_form_document table on upgrade
+#[tokio::test]
+async fn test_migrate_v2_to_v3_adds_long_form_document() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+
+    initialize_schema(&client).await.expect("Failed to initialize schema");
+
+    // Roll back to a v2 database
+    client
+        .execute("DROP TABLE long_form_document", ())
+        .await
+        .expect("Drop failed");
+    client
+        .execute("DELETE FROM schema_version", ())
+        .await
+        .expect("Delete failed");
+    set_schema_version(&client, 2).await.expect("Failed to set version");
+
+    initialize_schema(&client).await.expect("Migration failed");
+
+    let mut rows = client
+        .query(
+            "SELECT name FROM sqlite_master WHERE type='table' AND name='long_form_document'",
+            (),
+        )
+        .await
+        .expect("Query failed");
+    assert!(rows.next().await.expect("Failed to get next").is_some());
+    assert_eq!(get_schema_version(&client).await.unwrap(), SCHEMA_VERSION);
+}
+
+/// Test v3 databases gain requires_confirmation, carried over from the old parameter
+#[tokio::test]
+async fn test_migrate_v3_to_v4_adds_requires_confirmation() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+
+    initialize_schema(&client).await.expect("Failed to initialize schema");
+
+    // Roll back to a v3 voice_command table
+    client
+        .execute("DROP TABLE voice_command", ())
+        .await
+        .expect("Drop failed");
+    client
+        .execute(
+            r#"CREATE TABLE voice_command (
+                id TEXT PRIMARY KEY,
+                trigger TEXT UNIQUE NOT NULL,
+                action_type TEXT NOT NULL,
+                parameters_json TEXT NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL
+            )"#,
+            (),
+        )
+        .await
+        .expect("Create failed");
+    for (id, trigger, parameters) in [
+        ("id-1", "delete downloads", r#"{"script": "rm.sh", "confirmation": "true"}"#),
+        ("id-2", "open browser", r#"{"app": "Safari"}"#),
+    ] {
+        client
+            .execute(
+                "INSERT INTO voice_command (id, trigger, action_type, parameters_json, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![id, trigger, "custom", parameters, "2025-01-01T00:00:00Z"],
+            )
+            .await
+            .expect("Insert failed");
+    }
+    client
+        .execute("DELETE FROM schema_version", ())
+        .await
+        .expect("Delete failed");
+    set_schema_version(&client, 3).await.expect("Failed to set version");
+
+    initialize_schema(&client).await.expect("Migration failed");
+
+    let mut rows = client
+        .query(
+            "SELECT id, requires_confirmation FROM voice_command ORDER BY id",
+            (),
+        )
+        .await
+        .expect("Query failed");
+    let mut flags = Vec::new();
+    while let Some(row) = rows.next().await.expect("Failed to get next") {
+        let id: String = row.get(0).unwrap();
+        let flag: i32 = row.get(1).unwrap();
+        flags.push((id, flag));
+    }
+    assert_eq!(
+        flags,
+        vec![("id-1".to_string(), 1), ("id-2".to_string(), 0)]
+    );
+    assert_eq!(get_schema_version(&client).await.unwrap(), SCHEMA_VERSION);
+}
+
+/// Test v4 databases gain voice_command_alias, seeded with each command's trigger
+#[tokio::test]
+async fn test_migrate_v4_to_v5_adds_voice_command_alias() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+
+    initialize_schema(&client).await.expect("Failed to initialize schema");
+
+    // Roll back to v4, which had no alias table
+    client
+        .execute("DROP TABLE voice_command_alias", ())
+        .await
+        .expect("Drop failed");
+    for (id, trigger) in [("id-1", "Open  Browser"), ("id-2", "type {text}")] {
+        client
+            .execute(
+                "INSERT INTO voice_command (id, trigger, action_type, parameters_json, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![id, trigger, "open_app", "{}", "2025-01-01T00:00:00Z"],
+            )
+            .await
+            .expect("Insert failed");
+    }
+    client
+        .execute("DELETE FROM schema_version", ())
+        .await
+        .expect("Delete failed");
+    set_schema_version(&client, 4).await.expect("Failed to set version");
+
+    initialize_schema(&client).await.expect("Migration failed");
+
+    let mut rows = client
+        .query(
+            "SELECT command_id, phrase_key, phrase, is_canonical FROM voice_command_alias ORDER BY command_id",
+            (),
+        )
+        .await
+        .expect("Query failed");
+    let mut aliases = Vec::new();
+    while let Some(row) = rows.next().await.expect("Failed to get next") {
+        let id: String = row.get(0).unwrap();
+        let key: String = row.get(1).unwrap();
+        let phrase: String = row.get(2).unwrap();
+        let canonical: i32 = row.get(3).unwrap();
+        aliases.push((id, key, phrase, canonical));
+    }
+    assert_eq!(
+        aliases,
+        vec![
+            ("id-1".to_string(), "open browser".to_string(), "Open  Browser".to_string(), 1),
+            ("id-2".to_string(), "type {text}".to_string(), "type {text}".to_string(), 1),
+        ]
+    );
+    assert_eq!(get_schema_version(&client).await.unwrap(), SCHEMA_VERSION);
+}
+
+/// Test v5 databases gain the pipeline_event table on upgrade
+#[tokio::test]
+async fn test_migrate_v5_to_v6_adds_pipeline_event() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+
+    initialize_schema(&client).await.expect("Failed to initialize schema");
+
+    // Roll back to a v5 database
+    client
+        .execute("DROP TABLE pipeline_event", ())
+        .await
+        .expect("Drop failed");
+    client
+        .execute("DELETE FROM schema_version", ())
+        .await
+        .expect("Delete failed");
+    set_schema_version(&client, 5).await.expect("Failed to set version");
+
+    initialize_schema(&client).await.expect("Migration failed");
+
+    let mut rows = client
+        .query(
+            "SELECT name FROM sqlite_master WHERE type='index' AND name='idx_pipeline_event_created_at'",
+            (),
+        )
+        .await
+        .expect("Query failed");
+    assert!(rows.next().await.expect("Failed to get next").is_some());
+    assert_eq!(get_schema_version(&client).await.unwrap(), SCHEMA_VERSION);
+}
+
+/// Test v6 window contexts gain command_prefix, defaulting to inherit
+#[tokio::test]
+async fn test_migrate_v6_to_v7_adds_command_prefix() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let client = TursoClient::new(temp_dir.path().to_path_buf())
+        .await
+        .expect("Failed to create client");
+
+    initialize_schema(&client).await.expect("Failed to initialize schema");
+
+    // Roll back to a v6 window_context table
+    client
+        .execute("ALTER TABLE window_context DROP COLUMN command_prefix", ())
+        .await
+        .expect("Drop column failed");
+    client
+        .execute(
+            r#"INSERT INTO window_context
+               (id, name, matcher_app_name, command_mode, dictionary_mode, command_ids_json,
+                dictionary_entry_ids_json, created_at)
+               VALUES ('ctx-1', 'Chat', 'Slack', 'merge', 'merge', '[]', '[]', '2025-01-01T00:00:00Z')"#,
+            (),
+        )
+        .await
+        .expect("Insert failed");
+    client
+        .execute("DELETE FROM schema_version", ())
+        .await
+        .expect("Delete failed");
+    set_schema_version(&client, 6).await.expect("Failed to set version");
+
+    initialize_schema(&client).await.expect("Migration failed");
+
+    let mut rows = client
+        .query("SELECT command_prefix FROM window_context WHERE id = 'ctx-1'", ())
+        .await
+        .expect("Query failed");
+    let row = rows.next().await.expect("Failed to get next").unwrap();
+    let prefix: String = row.get(0).unwrap();
+    assert_eq!(prefix, "inherit");
+    assert_eq!(get_schema_version(&client).await.unwrap(), SCHEMA_VERSION);
+}
@@ -6,14 +6,23 @@ use libsql::params;
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use super::client::TursoClient;
-use crate::voice_commands::registry::{ActionType, CommandDefinition, RegistryError};
+use super::client::{TursoClient, TursoError};
+use crate::voice_commands::registry::{
+    normalize_phrase, ActionType, CommandDefinition, RegistryError,
+};
+
+impl From<TursoError> for RegistryError {
+    fn from(err: TursoError) -> Self {
+        RegistryError::PersistenceError(err.to_string())
+    }
+}
 
 impl TursoClient {
     /// Add a new voice command.
     ///
-    /// Validates trigger is not empty, generates timestamp,
-    /// and inserts into the database.
+    /// Validates trigger and aliases are not empty or used by another command,
+    /// generates timestamp, and inserts the command and its aliases in one
+    /// transaction.
     ///
     /// # Arguments
     /// * `cmd` - The command definition to add
@@ -21,8 +30,8 @@ impl TursoClient {
     /// # Returns
     /// Ok(()) on success
     pub async fn add_voice_command(&self, cmd: &CommandDefinition) -> Result<(), RegistryError> {
-        // Validate trigger
-        if cmd.trigger.trim().is_empty() {
+        // Validate trigger and aliases
+        if cmd.phrases().any(|phrase| phrase.trim().is_empty()) {
             return Err(RegistryError::EmptyTrigger);
         }
 
@@ -32,89 +41,86 @@ impl TursoClient {
         let parameters_json = serde_json::to_string(&cmd.parameters)
             .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
 
-        self.execute(
-            r#"INSERT INTO voice_command
-               (id, trigger, action_type, parameters_json, enabled, created_at)
-               VALUES (?1, ?2, ?3, ?4, ?5, ?6)"#,
-            params![
-                cmd.id.to_string(),
-                cmd.trigger.clone(),
-                action_type_to_string(&cmd.action_type),
-                parameters_json,
-                cmd.enabled as i32,
-                created_at
-            ],
-        )
-        .await
-        .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
+        self.transaction(|tx| async move {
+            tx.check_phrase_conflicts(cmd).await?;
 
-        Ok(())
+            tx.execute(
+                r#"INSERT INTO voice_command
+                   (id, trigger, action_type, parameters_json, enabled, requires_confirmation, created_at)
+                   VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"#,
+                params![
+                    cmd.id.to_string(),
+                    cmd.trigger.clone(),
+                    action_type_to_string(&cmd.action_type),
+                    parameters_json,
+                    cmd.enabled as i32,
+                    cmd.requires_confirmation as i32,
+                    created_at
+                ],
+            )
+            .await
+            .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
+
+            tx.replace_voice_command_aliases(cmd).await
+        })
+        .await
     }
 
     /// Update an existing voice command.
     ///
+    /// The command and its aliases are rewritten in one transaction.
+    ///
     /// # Arguments
     /// * `cmd` - The command definition with updated values
     ///
     /// # Returns
     /// Ok(()) on success
     pub async fn update_voice_command(&self, cmd: &CommandDefinition) -> Result<(), RegistryError> {
-        // Validate trigger
-        if cmd.trigger.trim().is_empty() {
+        // Validate trigger and aliases
+        if cmd.phrases().any(|phrase| phrase.trim().is_empty()) {
             return Err(RegistryError::EmptyTrigger);
         }
 
-        // Check if command exists
-        let exists = self.voice_command_exists(cmd.id).await?;
-        if !exists {
-            return Err(RegistryError::NotFound(cmd.id));
-        }
-
-        // Check for trigger conflict with other commands
-        let mut rows = self
-            .query(
-                "SELECT id FROM voice_command WHERE trigger = ?1 AND id != ?2",
-                params![cmd.trigger.clone(), cmd.id.to_string()],
-            )
-            .await
+        // Serialize parameters to JSON
+        let parameters_json = serde_json::to_string(&cmd.parameters)
             .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
 
-        if rows
-            .next()
-            .await
-            .map_err(|e| RegistryError::PersistenceError(e.to_string()))?
-            .is_some()
-        {
-            return Err(RegistryError::PersistenceError(format!(
-                "Trigger '{}' already exists",
-                cmd.trigger
-            )));
-        }
+        self.transaction(|tx| async move {
+            // Check if command exists
+            let exists = tx.voice_command_exists(cmd.id).await?;
+            if !exists {
+                return Err(RegistryError::NotFound(cmd.id));
+            }
 
-        // Serialize parameters to JSON
-        let parameters_json = serde_json::to_string(&cmd.parameters)
+            // Check for trigger conflict with other commands
+            tx.check_phrase_conflicts(cmd).await?;
+
+            tx.execute(
+                r#"UPDATE voice_command
+                   SET trigger = ?1, action_type = ?2, parameters_json = ?3, enabled = ?4,
+                       requires_confirmation = ?5
+                   WHERE id = ?6"#,
+                params![
+                    cmd.trigger.clone(),
+                    action_type_to_string(&cmd.action_type),
+                    parameters_json,
+                    cmd.enabled as i32,
+                    cmd.requires_confirmation as i32,
+                    cmd.id.to_string()
+                ],
+            )
+            .await
             .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
 
-        self.execute(
-            r#"UPDATE voice_command
-               SET trigger = ?1, action_type = ?2, parameters_json = ?3, enabled = ?4
-               WHERE id = ?5"#,
-            params![
-                cmd.trigger.clone(),
-                action_type_to_string(&cmd.action_type),
-                parameters_json,
-                cmd.enabled as i32,
-                cmd.id.to_string()
-            ],
-        )
+            tx.replace_voice_command_aliases(cmd).await
+        })
         .await
-        .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
-
-        Ok(())
     }
 
     /// Delete a voice command by ID.
     ///
+    /// Its aliases are removed by the foreign key cascade.
+    ///
     /// # Arguments
     /// * `id` - The command ID to delete
     pub async fn delete_voice_command(&self, id: Uuid) -> Result<(), RegistryError> {
@@ -134,19 +140,20 @@ impl TursoClient {
         Ok(())
     }
 
-    /// List all voice commands ordered by created_at.
+    /// List all voice commands ordered by created_at, with their aliases.
     ///
     /// # Returns
     /// Vector of all voice commands
     pub async fn list_voice_commands(&self) -> Result<Vec<CommandDefinition>, RegistryError> {
         let mut rows = self
             .query(
-                "SELECT id, trigger, action_type, parameters_json, enabled FROM voice_command ORDER BY created_at",
+                "SELECT id, trigger, action_type, parameters_json, enabled, requires_confirmation FROM voice_command ORDER BY created_at",
                 (),
             )
             .await
             .map_err(|e| RegistryError::LoadError(e.to_string()))?;
 
+        let mut aliases = self.load_voice_command_aliases().await?;
         let mut commands = Vec::new();
         while let Some(row) = rows
             .next()
@@ -158,6 +165,7 @@ impl TursoClient {
             let action_type_str: String = row.get(2).map_err(|e| RegistryError::LoadError(e.to_string()))?;
             let parameters_json: String = row.get(3).map_err(|e| RegistryError::LoadError(e.to_string()))?;
             let enabled: i32 = row.get(4).map_err(|e| RegistryError::LoadError(e.to_string()))?;
+            let requires_confirmation: i32 = row.get(5).map_err(|e| RegistryError::LoadError(e.to_string()))?;
 
             let id = Uuid::parse_str(&id_str)
                 .map_err(|e| RegistryError::LoadError(format!("Invalid UUID: {}", e)))?;
@@ -171,12 +179,93 @@ impl TursoClient {
                 action_type: string_to_action_type(&action_type_str),
                 parameters,
                 enabled: enabled != 0,
+                requires_confirmation: requires_confirmation != 0,
+                aliases: aliases.remove(&id).unwrap_or_default(),
             });
         }
 
         Ok(commands)
     }
 
+    /// Load the non-canonical aliases of all commands, in their saved order.
+    async fn load_voice_command_aliases(&self) -> Result<HashMap<Uuid, Vec<String>>, RegistryError> {
+        let mut rows = self
+            .query(
+                "SELECT command_id, phrase FROM voice_command_alias WHERE is_canonical = 0 ORDER BY position",
+                (),
+            )
+            .await
+            .map_err(|e| RegistryError::LoadError(e.to_string()))?;
+
+        let mut aliases: HashMap<Uuid, Vec<String>> = HashMap::new();
+        while let Some(row) = rows
+            .next()
+            .await
+            .map_err(|e| RegistryError::LoadError(e.to_string()))?
+        {
+            let id_str: String = row.get(0).map_err(|e| RegistryError::LoadError(e.to_string()))?;
+            let phrase: String = row.get(1).map_err(|e| RegistryError::LoadError(e.to_string()))?;
+            let id = Uuid::parse_str(&id_str)
+                .map_err(|e| RegistryError::LoadError(format!("Invalid UUID: {}", e)))?;
+            aliases.entry(id).or_default().push(phrase);
+        }
+        Ok(aliases)
+    }
+
+    /// Fail if any phrase of the command belongs to another command.
+    async fn check_phrase_conflicts(&self, cmd: &CommandDefinition) -> Result<(), RegistryError> {
+        for phrase in cmd.phrases() {
+            let mut rows = self
+                .query(
+                    "SELECT 1 FROM voice_command_alias WHERE phrase_key = ?1 AND command_id != ?2",
+                    params![normalize_phrase(phrase), cmd.id.to_string()],
+                )
+                .await
+                .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
+
+            if rows
+                .next()
+                .await
+                .map_err(|e| RegistryError::PersistenceError(e.to_string()))?
+                .is_some()
+            {
+                return Err(RegistryError::PersistenceError(format!(
+                    "Trigger '{}' already exists",
+                    phrase
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace the stored aliases of a command with its trigger and aliases.
+    async fn replace_voice_command_aliases(&self, cmd: &CommandDefinition) -> Result<(), RegistryError> {
+        self.execute(
+            "DELETE FROM voice_command_alias WHERE command_id = ?1",
+            params![cmd.id.to_string()],
+        )
+        .await
+        .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
+
+        for (position, phrase) in cmd.phrases().enumerate() {
+            self.execute(
+                r#"INSERT INTO voice_command_alias
+                   (phrase_key, command_id, phrase, is_canonical, position)
+                   VALUES (?1, ?2, ?3, ?4, ?5)"#,
+                params![
+                    normalize_phrase(phrase),
+                    cmd.id.to_string(),
+                    phrase.trim().to_string(),
+                    (position == 0) as i32,
+                    position as i64
+                ],
+            )
+            .await
+            .map_err(|e| RegistryError::PersistenceError(e.to_string()))?;
+        }
+        Ok(())
+    }
+
     /// Check if a voice command exists by ID.
     async fn voice_command_exists(&self, id: Uuid) -> Result<bool, RegistryError> {
         let mut rows = self
@@ -202,6 +291,10 @@ fn action_type_to_string(action_type: &ActionType) -> String {
         ActionType::TypeText => "type_text".to_string(),
         ActionType::SystemControl => "system_control".to_string(),
         ActionType::Custom => "custom".to_string(),
+        ActionType::Macro => "macro".to_string(),
+        ActionType::Webhook => "webhook".to_string(),
+        ActionType::SendKeys => "send_keys".to_string(),
+        ActionType::Rhai => "rhai".to_string(),
     }
 }
 
@@ -212,6 +305,10 @@ fn string_to_action_type(s: &str) -> ActionType {
         "type_text" => ActionType::TypeText,
         "system_control" => ActionType::SystemControl,
         "custom" => ActionType::Custom,
+        "macro" => ActionType::Macro,
+        "webhook" => ActionType::Webhook,
+        "send_keys" => ActionType::SendKeys,
+        "rhai" => ActionType::Rhai,
         _ => ActionType::Custom, // Default to Custom for unknown types
     }
 }
@@ -22,6 +22,8 @@ fn make_command(trigger: &str, action_type: ActionType) -> CommandDefinition {
         action_type,
         parameters: HashMap::new(),
         enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
     }
 }
 
@@ -57,6 +59,8 @@ async fn test_add_voice_command_with_parameters() {
         action_type: ActionType::OpenApp,
         parameters: params,
         enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
     };
 
     client
@@ -132,6 +136,137 @@ async fn test_update_voice_command() {
     assert_eq!(commands[0].parameters.get("text"), Some(&"hello".to_string()));
 }
 
+#[tokio::test]
+async fn test_requires_confirmation_roundtrip() {
+    let (client, _temp) = setup_client().await;
+
+    let mut cmd = make_command("delete downloads", ActionType::Custom);
+    cmd.requires_confirmation = true;
+    client.add_voice_command(&cmd).await.expect("Failed to add");
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert!(commands[0].requires_confirmation);
+
+    cmd.requires_confirmation = false;
+    client
+        .update_voice_command(&cmd)
+        .await
+        .expect("Failed to update");
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert!(!commands[0].requires_confirmation);
+}
+
+#[tokio::test]
+async fn test_aliases_roundtrip_in_order() {
+    let (client, _temp) = setup_client().await;
+
+    let mut cmd = make_command("open slack", ActionType::OpenApp);
+    cmd.aliases = vec!["start slack".to_string(), "launch slack".to_string()];
+    client.add_voice_command(&cmd).await.expect("Failed to add");
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert_eq!(commands[0].trigger, "open slack");
+    assert_eq!(commands[0].aliases, ["start slack", "launch slack"]);
+}
+
+#[tokio::test]
+async fn test_alias_can_become_canonical() {
+    let (client, _temp) = setup_client().await;
+
+    let mut cmd = make_command("open slack", ActionType::OpenApp);
+    cmd.aliases = vec!["launch slack".to_string()];
+    client.add_voice_command(&cmd).await.expect("Failed to add");
+
+    cmd.trigger = "launch slack".to_string();
+    cmd.aliases = vec!["open slack".to_string()];
+    client
+        .update_voice_command(&cmd)
+        .await
+        .expect("Failed to update");
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert_eq!(commands[0].trigger, "launch slack");
+    assert_eq!(commands[0].aliases, ["open slack"]);
+}
+
+#[tokio::test]
+async fn test_alias_conflicts_with_other_commands() {
+    let (client, _temp) = setup_client().await;
+
+    let mut slack = make_command("open slack", ActionType::OpenApp);
+    slack.aliases = vec!["launch slack".to_string()];
+    client.add_voice_command(&slack).await.expect("Failed to add");
+
+    // An alias may not reuse another command's trigger or alias, whatever the case
+    for phrase in ["Open Slack", "launch  slack"] {
+        let mut other = make_command("start chat", ActionType::OpenApp);
+        other.aliases = vec![phrase.to_string()];
+        match client.add_voice_command(&other).await.err().unwrap() {
+            RegistryError::PersistenceError(msg) => assert!(msg.contains("already exists")),
+            other => panic!("Expected PersistenceError, got {:?}", other),
+        }
+    }
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert_eq!(commands.len(), 1);
+}
+
+#[tokio::test]
+async fn test_failed_alias_write_leaves_commands_unchanged() {
+    let (client, _temp) = setup_client().await;
+
+    // An alias repeating the command's own trigger fails when the aliases are written
+    let mut cmd = make_command("open slack", ActionType::OpenApp);
+    cmd.aliases = vec!["Open Slack".to_string()];
+    assert!(client.add_voice_command(&cmd).await.is_err());
+    assert!(client
+        .list_voice_commands()
+        .await
+        .expect("Failed to list")
+        .is_empty());
+
+    cmd.aliases = vec!["launch slack".to_string()];
+    client.add_voice_command(&cmd).await.expect("Failed to add");
+    let mut updated = cmd.clone();
+    updated.trigger = "start slack".to_string();
+    updated.aliases = vec!["Start Slack".to_string()];
+    assert!(client.update_voice_command(&updated).await.is_err());
+
+    let commands = client.list_voice_commands().await.expect("Failed to list");
+    assert_eq!(commands[0].trigger, "open slack");
+    assert_eq!(commands[0].aliases, ["launch slack"]);
+}
+
+#[tokio::test]
+async fn test_empty_alias_fails() {
+    let (client, _temp) = setup_client().await;
+
+    let mut cmd = make_command("open slack", ActionType::OpenApp);
+    cmd.aliases = vec!["  ".to_string()];
+    let result = client.add_voice_command(&cmd).await;
+    assert!(matches!(result.err().unwrap(), RegistryError::EmptyTrigger));
+}
+
+#[tokio::test]
+async fn test_deleted_command_frees_its_aliases() {
+    let (client, _temp) = setup_client().await;
+
+    let mut cmd = make_command("open slack", ActionType::OpenApp);
+    cmd.aliases = vec!["launch slack".to_string()];
+    client.add_voice_command(&cmd).await.expect("Failed to add");
+    client
+        .delete_voice_command(cmd.id)
+        .await
+        .expect("Failed to delete");
+
+    let reuse = make_command("launch slack", ActionType::OpenApp);
+    client
+        .add_voice_command(&reuse)
+        .await
+        .expect("Alias of a deleted command should be free");
+}
+
 #[tokio::test]
 async fn test_update_voice_command_not_found() {
     let (client, _temp) = setup_client().await;
@@ -231,6 +366,8 @@ async fn test_action_type_roundtrip() {
             action_type: action_type.clone(),
             parameters: HashMap::new(),
             enabled: true,
+            requires_confirmation: false,
+            aliases: Vec::new(),
         };
         client.add_voice_command(&cmd).await.expect("Failed to add");
     }
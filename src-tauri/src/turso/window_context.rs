@@ -6,7 +6,9 @@ use libsql::params;
 use uuid::Uuid;
 
 use super::client::TursoClient;
-use crate::window_context::{OverrideMode, WindowContext, WindowContextStoreError, WindowMatcher};
+use crate::window_context::{
+    CommandPrefixMode, OverrideMode, WindowContext, WindowContextStoreError, WindowMatcher,
+};
 
 impl TursoClient {
     /// Add a new window context.
@@ -23,6 +25,7 @@ impl TursoClient {
     /// * `dictionary_entry_ids` - List of dictionary entry IDs to use
     /// * `enabled` - Whether the context is active
     /// * `priority` - Priority for matching (higher = matched first)
+    /// * `command_prefix` - Whether commands need the command prefix in this context
     ///
     /// # Returns
     /// The created WindowContext with generated ID
@@ -36,6 +39,7 @@ impl TursoClient {
         dictionary_entry_ids: Vec<String>,
         enabled: bool,
         priority: i32,
+        command_prefix: CommandPrefixMode,
     ) -> Result<WindowContext, WindowContextStoreError> {
         let id = Uuid::new_v4();
         let created_at = chrono::Utc::now().to_rfc3339();
@@ -50,8 +54,8 @@ impl TursoClient {
             r#"INSERT INTO window_context
                (id, name, matcher_app_name, matcher_title_pattern, matcher_bundle_id,
                 command_mode, dictionary_mode, command_ids_json, dictionary_entry_ids_json,
-                enabled, priority, created_at)
-               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)"#,
+                enabled, priority, created_at, command_prefix)
+               VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)"#,
             params![
                 id.to_string(),
                 name.clone(),
@@ -64,7 +68,8 @@ impl TursoClient {
                 dictionary_entry_ids_json,
                 enabled as i32,
                 priority,
-                created_at
+                created_at,
+                command_prefix_to_string(command_prefix)
             ],
         )
         .await
@@ -80,6 +85,7 @@ impl TursoClient {
             dictionary_entry_ids,
             enabled,
             priority,
+            command_prefix,
         })
     }
 
@@ -110,8 +116,8 @@ impl TursoClient {
             r#"UPDATE window_context
                SET name = ?1, matcher_app_name = ?2, matcher_title_pattern = ?3, matcher_bundle_id = ?4,
                    command_mode = ?5, dictionary_mode = ?6, command_ids_json = ?7, dictionary_entry_ids_json = ?8,
-                   enabled = ?9, priority = ?10
-               WHERE id = ?11"#,
+                   enabled = ?9, priority = ?10, command_prefix = ?11
+               WHERE id = ?12"#,
             params![
                 context.name.clone(),
                 context.matcher.app_name.clone(),
@@ -123,6 +129,7 @@ impl TursoClient {
                 dictionary_entry_ids_json,
                 context.enabled as i32,
                 context.priority,
+                command_prefix_to_string(context.command_prefix),
                 context.id.to_string()
             ],
         )
@@ -162,7 +169,7 @@ impl TursoClient {
             .query(
                 r#"SELECT id, name, matcher_app_name, matcher_title_pattern, matcher_bundle_id,
                           command_mode, dictionary_mode, command_ids_json, dictionary_entry_ids_json,
-                          enabled, priority
+                          enabled, priority, command_prefix
                    FROM window_context
                    ORDER BY priority DESC"#,
                 (),
@@ -195,7 +202,7 @@ impl TursoClient {
             .query(
                 r#"SELECT id, name, matcher_app_name, matcher_title_pattern, matcher_bundle_id,
                           command_mode, dictionary_mode, command_ids_json, dictionary_entry_ids_json,
-                          enabled, priority
+                          enabled, priority, command_prefix
                    FROM window_context
                    WHERE id = ?1"#,
                 params![id.to_string()],
@@ -247,6 +254,7 @@ fn parse_window_context_row(row: &libsql::Row) -> Result<WindowContext, WindowCo
     let dictionary_entry_ids_json: String = row.get(8).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
     let enabled: i32 = row.get(9).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
     let priority: i32 = row.get(10).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
+    let command_prefix_str: String = row.get(11).map_err(|e| WindowContextStoreError::LoadError(e.to_string()))?;
 
     let id = Uuid::parse_str(&id_str)
         .map_err(|e| WindowContextStoreError::LoadError(format!("Invalid UUID: {}", e)))?;
@@ -271,6 +279,7 @@ fn parse_window_context_row(row: &libsql::Row) -> Result<WindowContext, WindowCo
         dictionary_entry_ids,
         enabled: enabled != 0,
         priority,
+        command_prefix: string_to_command_prefix(&command_prefix_str),
     })
 }
 
@@ -290,6 +299,24 @@ fn string_to_override_mode(s: &str) -> OverrideMode {
     }
 }
 
+/// Convert CommandPrefixMode to string for database storage
+fn command_prefix_to_string(mode: CommandPrefixMode) -> String {
+    match mode {
+        CommandPrefixMode::Inherit => "inherit".to_string(),
+        CommandPrefixMode::Required => "required".to_string(),
+        CommandPrefixMode::Off => "off".to_string(),
+    }
+}
+
+/// Convert string to CommandPrefixMode
+fn string_to_command_prefix(s: &str) -> CommandPrefixMode {
+    match s {
+        "required" => CommandPrefixMode::Required,
+        "off" => CommandPrefixMode::Off,
+        _ => CommandPrefixMode::Inherit,
+    }
+}
+
 #[cfg(test)]
 #[path = "window_context_test.rs"]
 mod tests;
@@ -1,5 +1,7 @@
 use crate::turso::{initialize_schema, TursoClient};
-use crate::window_context::{OverrideMode, WindowContext, WindowContextStoreError, WindowMatcher};
+use crate::window_context::{
+    CommandPrefixMode, OverrideMode, WindowContext, WindowContextStoreError, WindowMatcher,
+};
 use tempfile::TempDir;
 use uuid::Uuid;
 
@@ -36,6 +38,7 @@ async fn test_add_window_context() {
             vec![],
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add context");
@@ -67,6 +70,7 @@ async fn test_add_window_context_with_full_matcher() {
             vec!["dict1".to_string(), "dict2".to_string()],
             false,
             10,
+            CommandPrefixMode::Required,
         )
         .await
         .expect("Failed to add context");
@@ -80,6 +84,7 @@ async fn test_add_window_context_with_full_matcher() {
     assert_eq!(context.dictionary_entry_ids, vec!["dict1", "dict2"]);
     assert!(!context.enabled);
     assert_eq!(context.priority, 10);
+    assert_eq!(context.command_prefix, CommandPrefixMode::Required);
 }
 
 #[tokio::test]
@@ -105,6 +110,7 @@ async fn test_list_window_contexts_ordered_by_priority() {
             vec![],
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add low priority");
@@ -119,6 +125,7 @@ async fn test_list_window_contexts_ordered_by_priority() {
             vec![],
             true,
             100,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add high priority");
@@ -133,6 +140,7 @@ async fn test_list_window_contexts_ordered_by_priority() {
             vec![],
             true,
             50,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add medium priority");
@@ -159,6 +167,7 @@ async fn test_get_window_context() {
             vec![],
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add");
@@ -199,6 +208,7 @@ async fn test_update_window_context() {
             vec![],
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add");
@@ -217,6 +227,7 @@ async fn test_update_window_context() {
         dictionary_entry_ids: vec!["entry1".to_string()],
         enabled: false,
         priority: 99,
+        command_prefix: CommandPrefixMode::Off,
     };
 
     client
@@ -236,6 +247,7 @@ async fn test_update_window_context() {
     assert!(matches!(fetched.command_mode, OverrideMode::Replace));
     assert!(!fetched.enabled);
     assert_eq!(fetched.priority, 99);
+    assert_eq!(fetched.command_prefix, CommandPrefixMode::Off);
 }
 
 #[tokio::test]
@@ -252,6 +264,7 @@ async fn test_update_window_context_not_found() {
         dictionary_entry_ids: vec![],
         enabled: true,
         priority: 0,
+        command_prefix: CommandPrefixMode::Inherit,
     };
 
     let result = client.update_window_context(context.clone()).await;
@@ -276,6 +289,7 @@ async fn test_delete_window_context() {
             vec![],
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add");
@@ -323,6 +337,7 @@ async fn test_json_serialization_roundtrip() {
             dictionary_entry_ids.clone(),
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add");
@@ -352,6 +367,7 @@ async fn test_override_mode_roundtrip() {
             vec![],
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add merge context");
@@ -367,6 +383,7 @@ async fn test_override_mode_roundtrip() {
             vec![],
             true,
             0,
+            CommandPrefixMode::Inherit,
         )
         .await
         .expect("Failed to add replace context");
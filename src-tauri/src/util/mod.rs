@@ -3,12 +3,15 @@
 //! This module provides common functionality used across the codebase:
 //! - `settings`: SettingsAccess trait for unified settings access
 //! - `runtime`: Tokio runtime helpers for async-to-sync bridges
+//! - `spoken`: Normalization of spoken text for phrase matching
 
 mod runtime;
 mod settings;
+mod spoken;
 
 pub use runtime::run_async;
 pub use settings::{get_settings_file, SettingsAccess};
+pub use spoken::{normalize_word, normalize_words, spoken_phrase};
 
 #[cfg(test)]
 #[path = "mod_test.rs"]
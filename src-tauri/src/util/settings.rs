@@ -53,6 +53,26 @@ pub trait SettingsAccess {
             .and_then(|v| v.as_str().map(|s| s.to_string()))
     }
 
+    /// Get a raw setting value by key.
+    ///
+    /// Use this for non-string settings (numbers, booleans, objects).
+    ///
+    /// # Arguments
+    /// * `key` - The dot-notation key path (e.g., "recording.longForm.maxDurationSecs")
+    ///
+    /// # Returns
+    /// The JSON value if found, None otherwise.
+    fn get_setting_value(&self, key: &str) -> Option<serde_json::Value> {
+        let app = self.app_handle()?;
+        let settings_file = self.settings_file_name();
+        app.store(&settings_file).ok().and_then(|store| store.get(key))
+    }
+}
+
+impl SettingsAccess for AppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        Some(self)
+    }
 }
 
 #[cfg(test)]
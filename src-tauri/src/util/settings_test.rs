@@ -17,6 +17,7 @@ fn test_settings_access_returns_none_without_app_handle() {
 
     // Without an app handle, get_setting should return None
     assert!(accessor.get_setting("any.key").is_none());
+    assert!(accessor.get_setting_value("any.key").is_none());
 }
 
 #[test]
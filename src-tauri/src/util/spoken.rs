@@ -0,0 +1,27 @@
+//! Normalization of spoken text for phrase matching.
+//!
+//! Transcripts arrive capitalized and punctuated ("Scratch that."), so
+//! anything compared against a configured phrase is normalized first.
+
+/// Lowercase a word and drop surrounding punctuation
+pub fn normalize_word(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// Split a phrase into normalized words, dropping empty ones
+pub fn normalize_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(normalize_word)
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Normalized words of a phrase joined by single spaces
+pub fn spoken_phrase(text: &str) -> String {
+    normalize_words(text).join(" ")
+}
+
+#[cfg(test)]
+#[path = "spoken_test.rs"]
+mod tests;
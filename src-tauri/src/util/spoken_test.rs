@@ -0,0 +1,18 @@
+// Tests for the spoken text normalization module
+
+use super::*;
+
+#[test]
+fn test_normalize_word_lowercases_and_trims_punctuation() {
+    assert_eq!(normalize_word("Scratch"), "scratch");
+    assert_eq!(normalize_word("that."), "that");
+    assert_eq!(normalize_word("\"Don't!\""), "don't");
+    assert_eq!(normalize_word("..."), "");
+}
+
+#[test]
+fn test_spoken_phrase_drops_empty_words() {
+    assert_eq!(normalize_words("Hey, - cat!"), vec!["hey", "cat"]);
+    assert_eq!(spoken_phrase("  Scratch   that. "), "scratch that");
+    assert_eq!(spoken_phrase("?!"), "");
+}
@@ -12,6 +12,7 @@ fn validate_app_name(name: &str) -> Result<(), ActionError> {
         return Err(ActionError {
             code: ActionErrorCode::InvalidParameter,
             message: "App name cannot be empty".to_string(),
+            data: None,
         });
     }
 
@@ -20,6 +21,7 @@ fn validate_app_name(name: &str) -> Result<(), ActionError> {
         return Err(ActionError {
             code: ActionErrorCode::InvalidAppName,
             message: "App name contains invalid characters".to_string(),
+            data: None,
         });
     }
 
@@ -28,6 +30,7 @@ fn validate_app_name(name: &str) -> Result<(), ActionError> {
         return Err(ActionError {
             code: ActionErrorCode::InvalidAppName,
             message: "App name contains invalid characters".to_string(),
+            data: None,
         });
     }
 
@@ -55,6 +58,7 @@ impl Action for AppLauncherAction {
         let app_name = parameters.get("app").ok_or_else(|| ActionError {
             code: ActionErrorCode::InvalidParameter,
             message: "Missing 'app' parameter".to_string(),
+            data: None,
         })?;
 
         // Validate app name for security
@@ -72,6 +76,17 @@ impl Action for AppLauncherAction {
             open_app(app_name)
         }
     }
+
+    /// Opening an app is undone by closing it, and vice versa
+    fn undo_parameters(&self, parameters: &HashMap<String, String>) -> Option<HashMap<String, String>> {
+        let mut undo = parameters.clone();
+        let closed = parameters
+            .get("close")
+            .map(|v| v.to_lowercase() == "true")
+            .unwrap_or(false);
+        undo.insert("close".to_string(), (!closed).to_string());
+        Some(undo)
+    }
 }
 
 /// Open an application using the macOS `open` command
@@ -83,6 +98,7 @@ fn open_app(app_name: &str) -> Result<ActionResult, ActionError> {
         .map_err(|e| ActionError {
             code: ActionErrorCode::ExecutionError,
             message: format!("Failed to execute open command: {}", e),
+            data: None,
         })?;
 
     if output.status.success() {
@@ -101,11 +117,13 @@ fn open_app(app_name: &str) -> Result<ActionResult, ActionError> {
             Err(ActionError {
                 code: ActionErrorCode::NotFound,
                 message: format!("Application not found: {}", app_name),
+                data: None,
             })
         } else {
             Err(ActionError {
                 code: ActionErrorCode::OpenFailed,
                 message: format!("Failed to open {}: {}", app_name, stderr.trim()),
+                data: None,
             })
         }
     }
@@ -125,6 +143,7 @@ fn close_app(app_name: &str) -> Result<ActionResult, ActionError> {
         .map_err(|e| ActionError {
             code: ActionErrorCode::ExecutionError,
             message: format!("Failed to execute osascript command: {}", e),
+            data: None,
         })?;
 
     if output.status.success() {
@@ -140,6 +159,7 @@ fn close_app(app_name: &str) -> Result<ActionResult, ActionError> {
         Err(ActionError {
             code: ActionErrorCode::CloseFailed,
             message: format!("Failed to close {}: {}", app_name, stderr.trim()),
+            data: None,
         })
     }
 }
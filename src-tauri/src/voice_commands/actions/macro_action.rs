@@ -0,0 +1,391 @@
+// Macro action - runs an ordered list of steps, each its own action
+//
+// A macro command stores its steps as a JSON array in the "steps" parameter
+// and an optional failure policy in "on_failure". For example, "start
+// standup" could be:
+//
+//   steps: [
+//     {"action_type": "open_app", "parameters": {"app": "Slack"}},
+//     {"action_type": "open_app", "parameters": {"app": "Zoom"}, "delay_ms": 500},
+//     {"action_type": "open_app", "parameters": {"app": "Notes"}, "delay_ms": 500},
+//     {"action_type": "type_text", "parameters": {"text": "Yesterday:\nToday:\nBlockers:"},
+//      "delay_ms": 1500}
+//   ]
+//   on_failure: "rollback"
+//
+// Steps may themselves be macros. Nested macros are expanded into one flat
+// plan up front, so depth and size limits are enforced before anything runs;
+// the outermost macro's failure policy applies to every step.
+//
+// Slot values and the transcription reach every step as parameters, so each
+// step's action escapes them its own way (e.g. per argument for Custom
+// steps). Placeholders in parameters a step's action doesn't template itself
+// are filled with the slot values, as for a top-level command.
+
+use crate::voice_commands::executor::{
+    Action, ActionDispatcher, ActionError, ActionErrorCode, ActionResult, TRANSCRIPTION_PARAM,
+};
+use crate::voice_commands::grammar::fill_placeholders;
+use crate::voice_commands::registry::ActionType;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Parameter holding the JSON list of steps
+pub const STEPS_PARAM: &str = "steps";
+/// Parameter holding the failure policy
+pub const ON_FAILURE_PARAM: &str = "on_failure";
+
+/// Parameters the action templates itself; slot binding must leave them alone
+pub const TEMPLATE_PARAMS: &[&str] = &[STEPS_PARAM];
+
+/// Deepest allowed nesting of macros inside macros
+pub const MAX_MACRO_DEPTH: usize = 3;
+/// Most steps a macro may run, after nesting and repeats are expanded
+pub const MAX_MACRO_STEPS: usize = 50;
+/// Longest delay allowed before a single step (milliseconds)
+pub const MAX_STEP_DELAY_MS: u64 = 10_000;
+/// Longest total delay across all steps (milliseconds)
+pub const MAX_TOTAL_DELAY_MS: u64 = 60_000;
+
+/// What to do when a step fails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailurePolicy {
+    /// Skip the remaining steps and fail the macro
+    #[default]
+    Stop,
+    /// Run the remaining steps anyway
+    Continue,
+    /// Undo the completed steps in reverse order, then fail the macro
+    Rollback,
+}
+
+/// One step of a macro as stored in the "steps" parameter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacroStep {
+    /// Action to run
+    pub action_type: ActionType,
+    /// Parameters for the action
+    #[serde(default)]
+    pub parameters: HashMap<String, String>,
+    /// Wait before running the step (milliseconds)
+    #[serde(default)]
+    pub delay_ms: u64,
+    /// How many times to run the step (default: 1)
+    #[serde(default = "default_repeat")]
+    pub repeat: u32,
+}
+
+fn default_repeat() -> u32 {
+    1
+}
+
+/// Outcome of one step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StepStatus {
+    Succeeded,
+    Failed,
+    /// Not run because an earlier step failed
+    Skipped,
+    /// Succeeded, then undone by a rollback
+    RolledBack,
+}
+
+/// Result of one step, reported in the macro's result (or error) data
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepResult {
+    /// Position in the expanded plan (0-based)
+    pub index: usize,
+    pub action_type: ActionType,
+    pub status: StepStatus,
+    /// Result or error message
+    pub message: Option<String>,
+    /// Error code of a failed step
+    pub error_code: Option<ActionErrorCode>,
+}
+
+/// A step ready to run: nested macros and repeats already expanded
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PlannedStep {
+    pub action_type: ActionType,
+    pub parameters: HashMap<String, String>,
+    pub delay_ms: u64,
+}
+
+/// Read the failure policy of a macro
+pub(crate) fn failure_policy(
+    parameters: &HashMap<String, String>,
+) -> Result<FailurePolicy, ActionError> {
+    match parameters.get(ON_FAILURE_PARAM) {
+        None => Ok(FailurePolicy::default()),
+        Some(policy) => serde_json::from_value(serde_json::Value::String(policy.to_lowercase()))
+            .map_err(|_| ActionError {
+                code: ActionErrorCode::InvalidParameter,
+                message: format!(
+                    "Unknown failure policy '{}' (use stop, continue or rollback)",
+                    policy
+                ),
+                data: None,
+            }),
+    }
+}
+
+/// Expand a macro's steps into a flat plan, enforcing the limits
+pub(crate) fn plan_macro(
+    parameters: &HashMap<String, String>,
+) -> Result<Vec<PlannedStep>, ActionError> {
+    let mut plan = Vec::new();
+    expand(parameters, 1, 0, &mut plan)?;
+    if plan.is_empty() {
+        return Err(invalid("Macro has no steps"));
+    }
+
+    let total_delay: u64 = plan.iter().map(|s| s.delay_ms).sum();
+    if total_delay > MAX_TOTAL_DELAY_MS {
+        return Err(invalid(format!(
+            "Macro delays add up to {}ms (max {}ms)",
+            total_delay, MAX_TOTAL_DELAY_MS
+        )));
+    }
+    Ok(plan)
+}
+
+/// Check a macro definition without running it (used when saving commands)
+pub(crate) fn validate_macro(parameters: &HashMap<String, String>) -> Result<(), ActionError> {
+    failure_policy(parameters)?;
+    plan_macro(parameters).map(|_| ())
+}
+
+fn expand(
+    parameters: &HashMap<String, String>,
+    depth: usize,
+    extra_delay_ms: u64,
+    plan: &mut Vec<PlannedStep>,
+) -> Result<(), ActionError> {
+    if depth > MAX_MACRO_DEPTH {
+        return Err(invalid(format!(
+            "Macros can only be nested {} levels deep",
+            MAX_MACRO_DEPTH
+        )));
+    }
+
+    let steps_json = parameters.get(STEPS_PARAM).ok_or_else(|| ActionError {
+        code: ActionErrorCode::MissingParam,
+        message: "Missing 'steps' parameter".to_string(),
+        data: None,
+    })?;
+    let steps: Vec<MacroStep> = serde_json::from_str(steps_json)
+        .map_err(|e| invalid(format!("Invalid macro steps: {}", e)))?;
+
+    // The delay before a nested macro applies to its first step
+    let mut pending_delay = extra_delay_ms;
+    for step in steps {
+        if step.delay_ms > MAX_STEP_DELAY_MS {
+            return Err(invalid(format!(
+                "Step delay of {}ms exceeds the {}ms limit",
+                step.delay_ms, MAX_STEP_DELAY_MS
+            )));
+        }
+        // Checked up front so an empty nested macro can't spin on a huge count
+        if step.repeat == 0 || step.repeat as usize > MAX_MACRO_STEPS {
+            return Err(invalid(format!(
+                "Step repeat must be between 1 and {}",
+                MAX_MACRO_STEPS
+            )));
+        }
+
+        for _ in 0..step.repeat {
+            let delay_ms = pending_delay + step.delay_ms;
+            pending_delay = 0;
+
+            if step.action_type == ActionType::Macro {
+                expand(&step.parameters, depth + 1, delay_ms, plan)?;
+                continue;
+            }
+            if plan.len() >= MAX_MACRO_STEPS {
+                return Err(invalid(format!(
+                    "Macro expands to more than {} steps",
+                    MAX_MACRO_STEPS
+                )));
+            }
+            plan.push(PlannedStep {
+                action_type: step.action_type.clone(),
+                parameters: step.parameters.clone(),
+                delay_ms,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Give a step the slot values and transcription the macro was run with
+///
+/// The step's own parameters win over outer values of the same name.
+fn bind_step(mut step: PlannedStep, outer: &HashMap<String, String>) -> PlannedStep {
+    let slot_values: HashMap<String, String> = outer
+        .iter()
+        .filter(|(name, _)| name.as_str() != TRANSCRIPTION_PARAM)
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    fill_placeholders(&step.action_type, &mut step.parameters, &slot_values);
+    for (name, value) in outer {
+        step.parameters
+            .entry(name.clone())
+            .or_insert_with(|| value.clone());
+    }
+    step
+}
+
+fn invalid(message: impl Into<String>) -> ActionError {
+    ActionError {
+        code: ActionErrorCode::InvalidParameter,
+        message: message.into(),
+        data: None,
+    }
+}
+
+/// Action that runs a macro's steps through the dispatcher's actions
+pub struct MacroAction {
+    dispatcher: ActionDispatcher,
+}
+
+impl MacroAction {
+    pub fn new(dispatcher: ActionDispatcher) -> Self {
+        Self { dispatcher }
+    }
+
+    /// Undo completed steps, newest first, marking the ones undone
+    async fn roll_back(&self, plan: &[PlannedStep], results: &mut [StepResult]) -> usize {
+        let mut undone = 0;
+        for (step, result) in plan.iter().zip(results.iter_mut()).rev() {
+            if result.status != StepStatus::Succeeded {
+                continue;
+            }
+            let action = self.dispatcher.get_action(&step.action_type);
+            let Some(undo) = action.undo_parameters(&step.parameters) else {
+                crate::debug!("[macro] Step {} cannot be undone", result.index);
+                continue;
+            };
+            match action.execute(&undo).await {
+                Ok(_) => {
+                    result.status = StepStatus::RolledBack;
+                    undone += 1;
+                }
+                Err(e) => crate::warn!("[macro] Failed to undo step {}: {}", result.index, e),
+            }
+        }
+        undone
+    }
+}
+
+#[async_trait]
+impl Action for MacroAction {
+    async fn execute(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let policy = failure_policy(parameters)?;
+        let outer: HashMap<String, String> = parameters
+            .iter()
+            .filter(|(name, _)| name.as_str() != STEPS_PARAM && name.as_str() != ON_FAILURE_PARAM)
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        let plan: Vec<PlannedStep> = plan_macro(parameters)?
+            .into_iter()
+            .map(|step| bind_step(step, &outer))
+            .collect();
+
+        let mut results: Vec<StepResult> = Vec::with_capacity(plan.len());
+        let mut first_error: Option<(usize, ActionError)> = None;
+
+        for (index, step) in plan.iter().enumerate() {
+            let mut result = StepResult {
+                index,
+                action_type: step.action_type.clone(),
+                status: StepStatus::Skipped,
+                message: None,
+                error_code: None,
+            };
+
+            let halted = first_error.is_some() && policy != FailurePolicy::Continue;
+            if !halted {
+                if step.delay_ms > 0 {
+                    tokio::time::sleep(Duration::from_millis(step.delay_ms)).await;
+                }
+                match self
+                    .dispatcher
+                    .run_action(&step.action_type, &step.parameters)
+                    .await
+                {
+                    Ok(outcome) => {
+                        result.status = StepStatus::Succeeded;
+                        result.message = Some(outcome.message);
+                    }
+                    Err(e) => {
+                        crate::warn!(
+                            "[macro] Step {} ({:?}) failed: {}",
+                            index,
+                            step.action_type,
+                            e
+                        );
+                        result.status = StepStatus::Failed;
+                        result.message = Some(e.message.clone());
+                        result.error_code = Some(e.code);
+                        first_error.get_or_insert((index, e));
+                    }
+                }
+            }
+            results.push(result);
+        }
+
+        let Some((failed_index, error)) = first_error else {
+            return Ok(ActionResult {
+                message: format!("Ran {} macro steps", results.len()),
+                data: Some(serde_json::json!({ "steps": results })),
+            });
+        };
+
+        match policy {
+            FailurePolicy::Continue => {
+                let succeeded = results
+                    .iter()
+                    .filter(|r| r.status == StepStatus::Succeeded)
+                    .count();
+                Ok(ActionResult {
+                    message: format!("{} of {} macro steps succeeded", succeeded, results.len()),
+                    data: Some(serde_json::json!({ "steps": results })),
+                })
+            }
+            FailurePolicy::Stop => Err(ActionError {
+                code: error.code,
+                message: format!(
+                    "Macro stopped at step {}: {}",
+                    failed_index + 1,
+                    error.message
+                ),
+                data: Some(serde_json::json!({ "steps": results })),
+            }),
+            FailurePolicy::Rollback => {
+                let undone = self.roll_back(&plan, &mut results).await;
+                Err(ActionError {
+                    code: error.code,
+                    message: format!(
+                        "Macro failed at step {} and rolled back {} step(s): {}",
+                        failed_index + 1,
+                        undone,
+                        error.message
+                    ),
+                    data: Some(serde_json::json!({ "steps": results })),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "macro_action_test.rs"]
+mod tests;
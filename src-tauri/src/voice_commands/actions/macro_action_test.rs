@@ -0,0 +1,406 @@
+use super::*;
+use crate::voice_commands::registry::CommandDefinition;
+use std::sync::{Arc, Mutex};
+
+type CallLog = Arc<Mutex<Vec<String>>>;
+
+/// Records "<name>:<parameter summary>" for every call; fails when
+/// parameters contain "fail" = "true"
+struct RecordingAction {
+    name: &'static str,
+    log: CallLog,
+    undoable: bool,
+}
+
+#[async_trait]
+impl Action for RecordingAction {
+    async fn execute(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let mut keys: Vec<_> = parameters
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        keys.sort();
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{}:{}", self.name, keys.join(",")));
+
+        if parameters.get("fail").map(String::as_str) == Some("true") {
+            return Err(ActionError {
+                code: ActionErrorCode::ExecutionError,
+                message: format!("{} failed", self.name),
+                data: None,
+            });
+        }
+        Ok(ActionResult {
+            message: format!("{} ok", self.name),
+            data: None,
+        })
+    }
+
+    fn undo_parameters(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Option<HashMap<String, String>> {
+        if !self.undoable {
+            return None;
+        }
+        let mut undo = parameters.clone();
+        undo.insert("undo".to_string(), "true".to_string());
+        Some(undo)
+    }
+}
+
+fn dispatcher(log: &CallLog) -> ActionDispatcher {
+    let action = |name, undoable| -> Arc<dyn Action> {
+        Arc::new(RecordingAction {
+            name,
+            log: log.clone(),
+            undoable,
+        })
+    };
+    ActionDispatcher::with_actions(
+        action("open", true),
+        action("type", false),
+        action("system", false),
+        action("custom", false),
+    )
+}
+
+fn macro_params(steps: serde_json::Value, on_failure: Option<&str>) -> HashMap<String, String> {
+    let mut params = HashMap::from([(STEPS_PARAM.to_string(), steps.to_string())]);
+    if let Some(policy) = on_failure {
+        params.insert(ON_FAILURE_PARAM.to_string(), policy.to_string());
+    }
+    params
+}
+
+fn step_statuses(result: &ActionResult) -> Vec<String> {
+    result.data.as_ref().unwrap()["steps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["status"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn standup_steps() -> serde_json::Value {
+    serde_json::json!([
+        {"action_type": "open_app", "parameters": {"app": "Slack"}},
+        {"action_type": "open_app", "parameters": {"app": "Zoom"}},
+        {"action_type": "open_app", "parameters": {"app": "Notes"}},
+        {
+            "action_type": "type_text",
+            "parameters": {"text": "Yesterday:\nToday:\nBlockers:"},
+            "delay_ms": 5
+        }
+    ])
+}
+
+#[tokio::test]
+async fn test_start_standup_runs_steps_in_order() {
+    let log = CallLog::default();
+    let action = MacroAction::new(dispatcher(&log));
+
+    let result = action
+        .execute(&macro_params(standup_steps(), None))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "open:app=Slack",
+            "open:app=Zoom",
+            "open:app=Notes",
+            "type:text=Yesterday:\nToday:\nBlockers:",
+        ]
+    );
+    assert_eq!(step_statuses(&result), vec!["succeeded"; 4]);
+    assert_eq!(result.message, "Ran 4 macro steps");
+}
+
+#[tokio::test]
+async fn test_dispatcher_routes_macro_type() {
+    let log = CallLog::default();
+    let command = CommandDefinition {
+        id: uuid::Uuid::new_v4(),
+        trigger: "start standup".to_string(),
+        action_type: ActionType::Macro,
+        parameters: macro_params(standup_steps(), None),
+        enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
+    };
+
+    let result = dispatcher(&log).execute(&command).await.unwrap();
+
+    assert_eq!(step_statuses(&result).len(), 4);
+    assert_eq!(log.lock().unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn test_stop_policy_skips_remaining_steps() {
+    let log = CallLog::default();
+    let steps = serde_json::json!([
+        {"action_type": "open_app", "parameters": {"app": "Slack"}},
+        {"action_type": "type_text", "parameters": {"fail": "true"}},
+        {"action_type": "open_app", "parameters": {"app": "Zoom"}}
+    ]);
+
+    let err = MacroAction::new(dispatcher(&log))
+        .execute(&macro_params(steps, None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.code, ActionErrorCode::ExecutionError);
+    assert!(err.message.contains("step 2"));
+    assert_eq!(log.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_continue_policy_runs_remaining_steps() {
+    let log = CallLog::default();
+    let steps = serde_json::json!([
+        {"action_type": "type_text", "parameters": {"fail": "true"}},
+        {"action_type": "open_app", "parameters": {"app": "Zoom"}}
+    ]);
+
+    let result = MacroAction::new(dispatcher(&log))
+        .execute(&macro_params(steps, Some("continue")))
+        .await
+        .unwrap();
+
+    assert_eq!(step_statuses(&result), vec!["failed", "succeeded"]);
+    assert_eq!(result.message, "1 of 2 macro steps succeeded");
+    let steps = &result.data.as_ref().unwrap()["steps"];
+    assert_eq!(steps[0]["errorCode"], "EXECUTION_ERROR");
+}
+
+#[tokio::test]
+async fn test_rollback_policy_undoes_completed_steps_in_reverse() {
+    let log = CallLog::default();
+    let steps = serde_json::json!([
+        {"action_type": "open_app", "parameters": {"app": "Slack"}},
+        {"action_type": "open_app", "parameters": {"app": "Zoom"}},
+        {"action_type": "type_text", "parameters": {"text": "hi"}},
+        {"action_type": "system_control", "parameters": {"fail": "true"}}
+    ]);
+
+    let err = MacroAction::new(dispatcher(&log))
+        .execute(&macro_params(steps, Some("rollback")))
+        .await
+        .unwrap_err();
+
+    // type_text cannot be undone, so only the two apps are rolled back
+    assert!(err.message.contains("rolled back 2 step(s)"));
+    assert_eq!(
+        log.lock().unwrap()[4..],
+        ["open:app=Zoom,undo=true", "open:app=Slack,undo=true"]
+    );
+}
+
+#[tokio::test]
+async fn test_nested_macros_are_expanded_inline() {
+    let log = CallLog::default();
+    let inner = serde_json::json!([
+        {"action_type": "type_text", "parameters": {"text": "a"}},
+        {"action_type": "type_text", "parameters": {"text": "b"}}
+    ]);
+    let steps = serde_json::json!([
+        {"action_type": "open_app", "parameters": {"app": "Notes"}},
+        {"action_type": "macro", "parameters": {"steps": inner.to_string()}, "repeat": 2}
+    ]);
+
+    let result = MacroAction::new(dispatcher(&log))
+        .execute(&macro_params(steps, None))
+        .await
+        .unwrap();
+
+    assert_eq!(step_statuses(&result).len(), 5);
+    assert_eq!(log.lock().unwrap()[4], "type:text=b");
+}
+
+#[test]
+fn test_nesting_beyond_max_depth_is_rejected() {
+    let mut steps = serde_json::json!([{"action_type": "type_text", "parameters": {"text": "x"}}]);
+    for _ in 0..MAX_MACRO_DEPTH {
+        steps = serde_json::json!([
+            {"action_type": "macro", "parameters": {"steps": steps.to_string()}}
+        ]);
+    }
+
+    let err = plan_macro(&macro_params(steps, None)).unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::InvalidParameter);
+    assert!(err.message.contains("nested"));
+}
+
+#[test]
+fn test_runaway_repeat_is_rejected_before_running() {
+    let steps = serde_json::json!([
+        {"action_type": "type_text", "parameters": {"text": "x"}, "repeat": 1_000_000}
+    ]);
+
+    let err = plan_macro(&macro_params(steps, None)).unwrap_err();
+    assert!(err.message.contains(&MAX_MACRO_STEPS.to_string()));
+
+    // Within the repeat limit but over the total step limit
+    let steps = serde_json::json!([
+        {"action_type": "type_text", "repeat": MAX_MACRO_STEPS},
+        {"action_type": "type_text"}
+    ]);
+    let err = plan_macro(&macro_params(steps, None)).unwrap_err();
+    assert!(err.message.contains("expands to more than"));
+}
+
+#[test]
+fn test_delay_limits_are_enforced() {
+    let too_long = serde_json::json!([
+        {"action_type": "type_text", "delay_ms": MAX_STEP_DELAY_MS + 1}
+    ]);
+    assert!(plan_macro(&macro_params(too_long, None)).is_err());
+
+    let too_many = serde_json::json!([
+        {"action_type": "type_text", "delay_ms": MAX_STEP_DELAY_MS, "repeat": 7}
+    ]);
+    let err = plan_macro(&macro_params(too_many, None)).unwrap_err();
+    assert!(err.message.contains("delays add up"));
+}
+
+#[test]
+fn test_nested_macro_delay_applies_to_its_first_step() {
+    let inner = serde_json::json!([
+        {"action_type": "type_text", "delay_ms": 10},
+        {"action_type": "type_text", "delay_ms": 20}
+    ]);
+    let steps = serde_json::json!([
+        {"action_type": "macro", "parameters": {"steps": inner.to_string()}, "delay_ms": 100}
+    ]);
+
+    let plan = plan_macro(&macro_params(steps, None)).unwrap();
+    let delays: Vec<u64> = plan.iter().map(|s| s.delay_ms).collect();
+    assert_eq!(delays, vec![110, 20]);
+}
+
+#[test]
+fn test_invalid_definitions_are_rejected() {
+    assert_eq!(
+        plan_macro(&HashMap::new()).unwrap_err().code,
+        ActionErrorCode::MissingParam
+    );
+    assert!(plan_macro(&macro_params(serde_json::json!([]), None)).is_err());
+    assert!(plan_macro(&macro_params(
+        serde_json::json!([{"action_type": "fly"}]),
+        None
+    ))
+    .is_err());
+    assert!(failure_policy(&macro_params(serde_json::json!([]), Some("retry"))).is_err());
+    assert_eq!(
+        failure_policy(&macro_params(serde_json::json!([]), Some("Rollback"))).unwrap(),
+        FailurePolicy::Rollback
+    );
+}
+
+#[test]
+fn test_validate_macro_checks_policy_and_steps() {
+    assert!(validate_macro(&macro_params(standup_steps(), Some("rollback"))).is_ok());
+    assert!(validate_macro(&macro_params(standup_steps(), Some("retry"))).is_err());
+    assert!(validate_macro(&macro_params(serde_json::json!("not a list"), None)).is_err());
+}
+
+#[tokio::test]
+async fn test_slot_values_reach_steps_unsubstituted_for_self_templating_actions() {
+    let log = CallLog::default();
+    let steps = serde_json::json!([
+        {"action_type": "custom", "parameters": {"script": "open.sh {app}"}},
+        {"action_type": "type_text", "parameters": {"text": "Deploying {app}"}},
+        {"action_type": "webhook", "parameters": {"url": "https://{app}.example.com/hook"}}
+    ]);
+    let command = CommandDefinition {
+        id: uuid::Uuid::new_v4(),
+        trigger: "deploy {app}".to_string(),
+        action_type: ActionType::Macro,
+        parameters: macro_params(steps, Some("continue")),
+        enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
+    };
+    let app = r#"the "big" app"#;
+    let slots = HashMap::from([("app".to_string(), app.to_string())]);
+    let transcription = format!("deploy {}", app);
+
+    let result = dispatcher(&log)
+        .execute_transcribed(&command, &slots, &transcription)
+        .await
+        .unwrap();
+
+    // The Custom step gets the value as a parameter to quote per argument
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            format!(
+                "custom:app={},script=open.sh {{app}},transcription={}",
+                app, transcription
+            ),
+            format!(
+                "type:app={},text=Deploying {},transcription={}",
+                app, app, transcription
+            ),
+        ]
+    );
+    // Speech can't fill in the webhook's host
+    assert_eq!(
+        step_statuses(&result),
+        vec!["succeeded", "succeeded", "failed"]
+    );
+    let webhook = &result.data.unwrap()["steps"][2];
+    assert_eq!(webhook["errorCode"], "INVALID_PARAMETER");
+    assert!(webhook["message"]
+        .as_str()
+        .unwrap()
+        .contains("path and query"));
+}
+
+fn error_step_statuses(err: &ActionError) -> Vec<String> {
+    err.data.as_ref().unwrap()["steps"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["status"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_failed_macros_report_per_step_results() {
+    let log = CallLog::default();
+    let steps = serde_json::json!([
+        {"action_type": "open_app", "parameters": {"app": "Slack"}},
+        {"action_type": "type_text", "parameters": {"fail": "true"}},
+        {"action_type": "open_app", "parameters": {"app": "Zoom"}}
+    ]);
+
+    let stopped = MacroAction::new(dispatcher(&log))
+        .execute(&macro_params(steps.clone(), None))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_step_statuses(&stopped),
+        vec!["succeeded", "failed", "skipped"]
+    );
+    assert_eq!(
+        stopped.data.unwrap()["steps"][1]["errorCode"],
+        "EXECUTION_ERROR"
+    );
+
+    let rolled_back = MacroAction::new(dispatcher(&log))
+        .execute(&macro_params(steps, Some("rollback")))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_step_statuses(&rolled_back),
+        vec!["rolledBack", "failed", "skipped"]
+    );
+}
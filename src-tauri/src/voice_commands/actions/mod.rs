@@ -1,7 +1,30 @@
 // Actions module - implementations for different action types
 
 pub mod app_launcher;
+pub mod macro_action;
+pub mod rhai_script;
+pub mod script;
+pub mod send_keys;
 pub mod text_input;
+pub mod webhook;
 
 pub use app_launcher::AppLauncherAction;
+pub use macro_action::MacroAction;
+pub use rhai_script::RhaiAction;
+pub use script::{ScriptAction, ScriptConfig};
+pub use send_keys::SendKeysAction;
 pub use text_input::TextInputAction;
+pub use webhook::WebhookAction;
+
+use crate::voice_commands::registry::ActionType;
+
+/// Parameters an action fills in itself, which slot binding leaves as templates
+pub fn self_templated_params(action_type: &ActionType) -> &'static [&'static str] {
+    match action_type {
+        ActionType::Custom => script::TEMPLATE_PARAMS,
+        ActionType::Webhook => webhook::TEMPLATE_PARAMS,
+        ActionType::Rhai => rhai_script::TEMPLATE_PARAMS,
+        ActionType::Macro => macro_action::TEMPLATE_PARAMS,
+        _ => &[],
+    }
+}
@@ -0,0 +1,501 @@
+// Rhai script action - runs an embedded Rhai script for a command
+//
+// The "source" parameter holds the script. Unlike Custom commands it never
+// starts a process: the script only sees a curated API backed by a
+// `ScriptHost`:
+//
+//   type_text(text)       type text into the focused app
+//   send_keys(keys)       press keys, same syntax as Send Keys commands
+//   clipboard()           read the clipboard text
+//   set_clipboard(text)   replace the clipboard text
+//   run_command(id)       run another enabled, non-script command by ID
+//   active_window()       #{app_name, bundle_id, title} of the front window
+//   params                the command's other parameters, including slot
+//                         values and the transcription
+//
+// For example, pasting the clipboard text wrapped in quotes:
+//
+//   let text = clipboard();
+//   set_clipboard(`"${text}"`);
+//   send_keys("mod+v");
+//
+// Scripts run on a blocking thread with an operation limit and a time limit.
+// The time limit also covers host calls, and a script started by another
+// script's run_command (e.g. as a macro step) shares the outer deadline.
+// Testing a command in dry-run mode runs its script against a `MockScriptHost`
+// that records calls instead of performing them.
+
+use crate::app::state::TursoClientState;
+use crate::storage::WindowContext;
+use crate::voice_commands::executor::{
+    Action, ActionDispatcher, ActionError, ActionErrorCode, ActionResult,
+};
+use crate::voice_commands::registry::{ActionType, CommandDefinition};
+use async_trait::async_trait;
+use rhai::{Dynamic, Engine, EvalAltResult, Map, Scope};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use uuid::Uuid;
+
+/// Parameter holding the script source
+pub const SOURCE_PARAM: &str = "source";
+
+/// Parameters the action templates itself; slot binding must leave them alone
+pub const TEMPLATE_PARAMS: &[&str] = &[SOURCE_PARAM];
+
+/// Most Rhai operations a script may perform
+pub const MAX_OPERATIONS: u64 = 1_000_000;
+/// Longest a script may run
+pub const SCRIPT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Most commands that may be running inside each other when a script calls
+/// run_command
+pub const MAX_COMMAND_DEPTH: usize = 4;
+/// Deepest allowed function call nesting inside a script
+const MAX_CALL_LEVELS: usize = 32;
+/// Longest string a script may build (bytes)
+const MAX_STRING_SIZE: usize = 1024 * 1024;
+/// Most elements an array or object map may hold
+const MAX_COLLECTION_SIZE: usize = 10_000;
+
+/// Front window as seen by a script
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScriptWindow {
+    pub app_name: Option<String>,
+    pub bundle_id: Option<String>,
+    pub title: Option<String>,
+}
+
+impl ScriptWindow {
+    /// Read the current front window
+    pub fn capture() -> Self {
+        let window = WindowContext::capture();
+        Self {
+            app_name: window.app_name,
+            bundle_id: window.bundle_id,
+            title: window.title,
+        }
+    }
+}
+
+/// Side effects available to scripts
+///
+/// Called from the script's blocking thread.
+pub trait ScriptHost: Send + Sync {
+    fn type_text(&self, text: &str) -> Result<(), String>;
+    fn send_keys(&self, keys: &str) -> Result<(), String>;
+    fn read_clipboard(&self) -> Result<String, String>;
+    fn write_clipboard(&self, text: &str) -> Result<(), String>;
+    /// Run a stored command and return its result message
+    fn run_command(&self, id: &str) -> Result<String, String>;
+    fn active_window(&self) -> ScriptWindow;
+}
+
+/// A call made by a script against a `MockScriptHost`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "call", content = "value")]
+pub enum ScriptCall {
+    TypeText(String),
+    SendKeys(String),
+    ReadClipboard,
+    WriteClipboard(String),
+    RunCommand(String),
+    ActiveWindow,
+}
+
+/// Host that records calls instead of performing them (dry runs and tests)
+#[derive(Default)]
+pub struct MockScriptHost {
+    calls: Mutex<Vec<ScriptCall>>,
+    clipboard: Mutex<String>,
+    window: ScriptWindow,
+}
+
+impl MockScriptHost {
+    /// Mock whose front window is `window`
+    pub fn with_window(window: ScriptWindow) -> Self {
+        Self {
+            window,
+            ..Self::default()
+        }
+    }
+
+    /// Calls made so far, oldest first
+    pub fn calls(&self) -> Vec<ScriptCall> {
+        self.calls.lock().map(|c| c.clone()).unwrap_or_default()
+    }
+
+    fn record(&self, call: ScriptCall) {
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.push(call);
+        }
+    }
+}
+
+impl ScriptHost for MockScriptHost {
+    fn type_text(&self, text: &str) -> Result<(), String> {
+        self.record(ScriptCall::TypeText(text.to_string()));
+        Ok(())
+    }
+
+    fn send_keys(&self, keys: &str) -> Result<(), String> {
+        crate::keyboard::shortcut::parse_shortcut(keys)
+            .map_err(|e| format!("Invalid shortcut '{}': {}", keys, e))?;
+        self.record(ScriptCall::SendKeys(keys.to_string()));
+        Ok(())
+    }
+
+    fn read_clipboard(&self) -> Result<String, String> {
+        self.record(ScriptCall::ReadClipboard);
+        Ok(self.clipboard.lock().map(|c| c.clone()).unwrap_or_default())
+    }
+
+    fn write_clipboard(&self, text: &str) -> Result<(), String> {
+        self.record(ScriptCall::WriteClipboard(text.to_string()));
+        if let Ok(mut clipboard) = self.clipboard.lock() {
+            *clipboard = text.to_string();
+        }
+        Ok(())
+    }
+
+    fn run_command(&self, id: &str) -> Result<String, String> {
+        self.record(ScriptCall::RunCommand(id.to_string()));
+        Ok(format!("Would run command {}", id))
+    }
+
+    fn active_window(&self) -> ScriptWindow {
+        self.record(ScriptCall::ActiveWindow);
+        self.window.clone()
+    }
+}
+
+/// Check that a script may run `command` while `call_chain` is running
+pub(crate) fn check_runnable(
+    command: &CommandDefinition,
+    call_chain: &[Uuid],
+) -> Result<(), String> {
+    if !command.enabled {
+        return Err(format!("Command '{}' is disabled", command.trigger));
+    }
+    // Scripts have nobody to ask, so they can't pass the confirmation gate
+    if command.requires_confirmation {
+        return Err(format!(
+            "Command '{}' requires confirmation and can't be run from a script",
+            command.trigger
+        ));
+    }
+    if command.action_type == ActionType::Rhai {
+        return Err("Scripts can't run other script commands".to_string());
+    }
+    // A macro with a script step could otherwise run itself forever
+    if call_chain.contains(&command.id) {
+        return Err(format!("Command '{}' is already running", command.trigger));
+    }
+    if call_chain.len() >= MAX_COMMAND_DEPTH {
+        return Err(format!(
+            "Commands can only run each other {} levels deep",
+            MAX_COMMAND_DEPTH
+        ));
+    }
+    Ok(())
+}
+
+/// Host backed by the app: the dispatcher's actions, the clipboard and the
+/// stored commands
+struct AppScriptHost {
+    dispatcher: ActionDispatcher,
+    app_handle: Option<AppHandle>,
+    runtime: tokio::runtime::Handle,
+    /// When the script must finish, host calls included
+    deadline: Instant,
+}
+
+impl AppScriptHost {
+    /// Wait for `future`, giving up at the script's deadline
+    fn block_on<F: Future>(&self, future: F) -> Result<F::Output, String> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        self.runtime
+            .block_on(tokio::time::timeout(remaining, future))
+            .map_err(|_| "Script ran out of time".to_string())
+    }
+
+    fn run_action(&self, action_type: ActionType, param: &str, value: &str) -> Result<(), String> {
+        let parameters = HashMap::from([(param.to_string(), value.to_string())]);
+        self.block_on(self.dispatcher.run_action(&action_type, &parameters))?
+            .map(|_| ())
+            .map_err(|e| e.message)
+    }
+
+    fn app_handle(&self) -> Result<&AppHandle, String> {
+        self.app_handle
+            .as_ref()
+            .ok_or_else(|| "Not available outside the app".to_string())
+    }
+}
+
+impl ScriptHost for AppScriptHost {
+    fn type_text(&self, text: &str) -> Result<(), String> {
+        self.run_action(ActionType::TypeText, "text", text)
+    }
+
+    fn send_keys(&self, keys: &str) -> Result<(), String> {
+        self.run_action(ActionType::SendKeys, "keys", keys)
+    }
+
+    fn read_clipboard(&self) -> Result<String, String> {
+        self.app_handle()?
+            .clipboard()
+            .read_text()
+            .map_err(|e| format!("Failed to read clipboard: {}", e))
+    }
+
+    fn write_clipboard(&self, text: &str) -> Result<(), String> {
+        self.app_handle()?
+            .clipboard()
+            .write_text(text)
+            .map_err(|e| format!("Failed to write clipboard: {}", e))
+    }
+
+    fn run_command(&self, id: &str) -> Result<String, String> {
+        let id = uuid::Uuid::parse_str(id).map_err(|e| format!("Invalid command ID: {}", e))?;
+        let client = self
+            .app_handle()?
+            .try_state::<TursoClientState>()
+            .ok_or_else(|| "Command storage is not available".to_string())?;
+        let command = self
+            .block_on(client.list_voice_commands())?
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .find(|c| c.id == id)
+            .ok_or_else(|| format!("Command not found: {}", id))?;
+
+        check_runnable(&command, self.dispatcher.call_chain())?;
+        self.block_on(self.dispatcher.execute(&command))?
+            .map(|result| result.message)
+            .map_err(|e| e.message)
+    }
+
+    fn active_window(&self) -> ScriptWindow {
+        ScriptWindow::capture()
+    }
+}
+
+/// Engine with the resource limits, but without the host API
+fn limited_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_call_levels(MAX_CALL_LEVELS);
+    engine.set_max_string_size(MAX_STRING_SIZE);
+    engine.set_max_array_size(MAX_COLLECTION_SIZE);
+    engine.set_max_map_size(MAX_COLLECTION_SIZE);
+    engine.disable_symbol("eval");
+    engine
+}
+
+fn source(parameters: &HashMap<String, String>) -> Result<&str, ActionError> {
+    parameters
+        .get(SOURCE_PARAM)
+        .map(String::as_str)
+        .ok_or_else(|| ActionError {
+            code: ActionErrorCode::MissingParam,
+            message: "Missing 'source' parameter".to_string(),
+            data: None,
+        })
+}
+
+/// Check that a script compiles (used when saving commands)
+pub(crate) fn validate_rhai_script(
+    parameters: &HashMap<String, String>,
+) -> Result<(), ActionError> {
+    let source = source(parameters)?;
+    if source.trim().is_empty() {
+        return Err(ActionError {
+            code: ActionErrorCode::InvalidParameter,
+            message: "Script cannot be empty".to_string(),
+            data: None,
+        });
+    }
+    limited_engine()
+        .compile(source)
+        .map(|_| ())
+        .map_err(|e| ActionError {
+            code: ActionErrorCode::InvalidParameter,
+            message: format!("Script does not compile: {}", e),
+            data: None,
+        })
+}
+
+fn optional(value: Option<String>) -> Dynamic {
+    value.map(Dynamic::from).unwrap_or(Dynamic::UNIT)
+}
+
+/// Run a script against `host`, blocking until it finishes
+pub(crate) fn run_script(
+    parameters: &HashMap<String, String>,
+    host: Arc<dyn ScriptHost>,
+    timeout: Duration,
+) -> Result<ActionResult, ActionError> {
+    let source = source(parameters)?;
+    let mut engine = limited_engine();
+
+    let started = Instant::now();
+    engine.on_progress(move |_| (started.elapsed() > timeout).then(|| Dynamic::from("timeout")));
+    let printed = Arc::new(Mutex::new(Vec::<String>::new()));
+    let sink = printed.clone();
+    engine.on_print(move |line| {
+        crate::debug!("[rhai] {}", line);
+        if let Ok(mut lines) = sink.lock() {
+            lines.push(line.to_string());
+        }
+    });
+
+    let h = host.clone();
+    engine.register_fn(
+        "type_text",
+        move |text: &str| -> Result<(), Box<EvalAltResult>> {
+            h.type_text(text).map_err(Into::into)
+        },
+    );
+    let h = host.clone();
+    engine.register_fn(
+        "send_keys",
+        move |keys: &str| -> Result<(), Box<EvalAltResult>> {
+            h.send_keys(keys).map_err(Into::into)
+        },
+    );
+    let h = host.clone();
+    engine.register_fn(
+        "clipboard",
+        move || -> Result<String, Box<EvalAltResult>> { h.read_clipboard().map_err(Into::into) },
+    );
+    let h = host.clone();
+    engine.register_fn(
+        "set_clipboard",
+        move |text: &str| -> Result<(), Box<EvalAltResult>> {
+            h.write_clipboard(text).map_err(Into::into)
+        },
+    );
+    let h = host.clone();
+    engine.register_fn(
+        "run_command",
+        move |id: &str| -> Result<String, Box<EvalAltResult>> {
+            h.run_command(id).map_err(Into::into)
+        },
+    );
+    let h = host;
+    engine.register_fn("active_window", move || -> Map {
+        let window = h.active_window();
+        let mut map = Map::new();
+        map.insert("app_name".into(), optional(window.app_name));
+        map.insert("bundle_id".into(), optional(window.bundle_id));
+        map.insert("title".into(), optional(window.title));
+        map
+    });
+
+    let params: Map = parameters
+        .iter()
+        .filter(|(name, _)| name.as_str() != SOURCE_PARAM)
+        .map(|(name, value)| (name.as_str().into(), Dynamic::from(value.clone())))
+        .collect();
+    let mut scope = Scope::new();
+    scope.push_constant("params", params);
+
+    let ast = engine.compile(source).map_err(|e| ActionError {
+        code: ActionErrorCode::InvalidParameter,
+        message: format!("Script does not compile: {}", e),
+        data: None,
+    })?;
+    let timed_out = || ActionError {
+        code: ActionErrorCode::Timeout,
+        message: format!("Script ran longer than {}ms", timeout.as_millis()),
+        data: None,
+    };
+    let value = engine
+        .eval_ast_with_scope::<Dynamic>(&mut scope, &ast)
+        .map_err(|e| match *e {
+            EvalAltResult::ErrorTooManyOperations(_) => ActionError {
+                code: ActionErrorCode::Timeout,
+                message: format!("Script exceeded {} operations", MAX_OPERATIONS),
+                data: None,
+            },
+            EvalAltResult::ErrorTerminated(..) => timed_out(),
+            // A host call cut off at the deadline fails with its own error
+            _ if started.elapsed() >= timeout => timed_out(),
+            other => ActionError {
+                code: ActionErrorCode::ExecutionError,
+                message: format!("Script failed: {}", other),
+                data: None,
+            },
+        })?;
+
+    let printed = printed.lock().map(|p| p.clone()).unwrap_or_default();
+    Ok(ActionResult {
+        message: if value.is_unit() {
+            "Script finished".to_string()
+        } else {
+            value.to_string()
+        },
+        data: Some(serde_json::json!({ "printed": printed })),
+    })
+}
+
+/// Action that runs a Rhai script against the app
+pub struct RhaiAction {
+    dispatcher: ActionDispatcher,
+    app_handle: Option<AppHandle>,
+}
+
+impl RhaiAction {
+    pub fn new(dispatcher: ActionDispatcher, app_handle: Option<AppHandle>) -> Self {
+        Self {
+            dispatcher,
+            app_handle,
+        }
+    }
+}
+
+/// Run a script on a blocking thread so it can't stall the async runtime
+pub async fn run_script_blocking(
+    parameters: &HashMap<String, String>,
+    host: Arc<dyn ScriptHost>,
+    timeout: Duration,
+) -> Result<ActionResult, ActionError> {
+    let parameters = parameters.clone();
+    tokio::task::spawn_blocking(move || run_script(&parameters, host, timeout))
+        .await
+        .map_err(|e| ActionError {
+            code: ActionErrorCode::TaskPanic,
+            message: format!("Script task failed: {}", e),
+            data: None,
+        })?
+}
+
+#[async_trait]
+impl Action for RhaiAction {
+    async fn execute(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let deadline = Instant::now() + SCRIPT_TIMEOUT;
+        let deadline = self
+            .dispatcher
+            .script_deadline()
+            .map_or(deadline, |outer| outer.min(deadline));
+        let host = Arc::new(AppScriptHost {
+            dispatcher: self.dispatcher.clone().with_script_deadline(deadline),
+            app_handle: self.app_handle.clone(),
+            runtime: tokio::runtime::Handle::current(),
+            deadline,
+        });
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        run_script_blocking(parameters, host, timeout).await
+    }
+}
+
+#[cfg(test)]
+#[path = "rhai_script_test.rs"]
+mod tests;
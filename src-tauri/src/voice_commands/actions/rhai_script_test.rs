@@ -0,0 +1,197 @@
+use super::*;
+
+fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn run(source: &str, host: &Arc<MockScriptHost>) -> Result<ActionResult, ActionError> {
+    run_script(
+        &params(&[(SOURCE_PARAM, source), ("name", "Ada")]),
+        host.clone(),
+        SCRIPT_TIMEOUT,
+    )
+}
+
+#[test]
+fn test_script_calls_the_host_api() {
+    let host = Arc::new(MockScriptHost::with_window(ScriptWindow {
+        app_name: Some("Slack".to_string()),
+        bundle_id: None,
+        title: Some("general".to_string()),
+    }));
+    let source = r#"
+        let window = active_window();
+        set_clipboard("Hi " + params.name);
+        type_text(clipboard() + " in " + window.app_name);
+        send_keys("mod+enter");
+        run_command("cmd-1");
+    "#;
+
+    run(source, &host).unwrap();
+    assert_eq!(
+        host.calls(),
+        vec![
+            ScriptCall::ActiveWindow,
+            ScriptCall::WriteClipboard("Hi Ada".to_string()),
+            ScriptCall::ReadClipboard,
+            ScriptCall::TypeText("Hi Ada in Slack".to_string()),
+            ScriptCall::SendKeys("mod+enter".to_string()),
+            ScriptCall::RunCommand("cmd-1".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_script_result_and_printed_output() {
+    let host = Arc::new(MockScriptHost::default());
+
+    let result = run(r#"print("working"); 40 + 2"#, &host).unwrap();
+    assert_eq!(result.message, "42");
+    assert_eq!(
+        result.data.unwrap()["printed"],
+        serde_json::json!(["working"])
+    );
+
+    let result = run("let x = 1;", &host).unwrap();
+    assert_eq!(result.message, "Script finished");
+}
+
+#[test]
+fn test_script_sees_params_but_not_its_source() {
+    let host = Arc::new(MockScriptHost::default());
+    let result = run(r#"params.contains("source")"#, &host).unwrap();
+    assert_eq!(result.message, "false");
+}
+
+#[test]
+fn test_host_errors_fail_the_script() {
+    let host = Arc::new(MockScriptHost::default());
+    let err = run(r#"send_keys("hyper+x"); type_text("never")"#, &host).unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::ExecutionError);
+    assert!(err.message.contains("hyper+x"));
+    assert!(host.calls().is_empty());
+}
+
+#[test]
+fn test_operation_limit_stops_runaway_scripts() {
+    let host = Arc::new(MockScriptHost::default());
+    let err = run("loop { }", &host).unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::Timeout);
+    assert!(err.message.contains("operations"));
+}
+
+#[test]
+fn test_time_limit_stops_slow_scripts() {
+    let host = Arc::new(MockScriptHost::default());
+    let err = run_script(
+        &params(&[(SOURCE_PARAM, "let x = 0; while true { x += 1; }")]),
+        host,
+        Duration::ZERO,
+    )
+    .unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::Timeout);
+    assert!(err.message.contains("longer than"));
+}
+
+#[test]
+fn test_validate_rhai_script() {
+    assert!(validate_rhai_script(&params(&[(SOURCE_PARAM, r#"type_text("hi")"#)])).is_ok());
+
+    let err = validate_rhai_script(&params(&[(SOURCE_PARAM, "let x = ;")])).unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::InvalidParameter);
+
+    let err = validate_rhai_script(&params(&[(SOURCE_PARAM, "   ")])).unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::InvalidParameter);
+
+    let err = validate_rhai_script(&HashMap::new()).unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::MissingParam);
+}
+
+/// Host whose calls block past the deadline, as an app call cut off by it would
+struct StuckHost;
+
+impl ScriptHost for StuckHost {
+    fn type_text(&self, _text: &str) -> Result<(), String> {
+        std::thread::sleep(Duration::from_millis(30));
+        Err("Script ran out of time".to_string())
+    }
+    fn send_keys(&self, _keys: &str) -> Result<(), String> {
+        Ok(())
+    }
+    fn read_clipboard(&self) -> Result<String, String> {
+        Ok(String::new())
+    }
+    fn write_clipboard(&self, _text: &str) -> Result<(), String> {
+        Ok(())
+    }
+    fn run_command(&self, _id: &str) -> Result<String, String> {
+        Ok(String::new())
+    }
+    fn active_window(&self) -> ScriptWindow {
+        ScriptWindow::default()
+    }
+}
+
+#[test]
+fn test_time_limit_covers_host_calls() {
+    let err = run_script(
+        &params(&[(SOURCE_PARAM, r#"type_text("hi")"#)]),
+        Arc::new(StuckHost),
+        Duration::from_millis(10),
+    )
+    .unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::Timeout);
+}
+
+fn stored_command(action_type: ActionType) -> CommandDefinition {
+    CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: "start standup".to_string(),
+        action_type,
+        parameters: HashMap::new(),
+        enabled: true,
+        requires_confirmation: false,
+        aliases: vec![],
+    }
+}
+
+#[test]
+fn test_check_runnable_refuses_commands_scripts_must_not_run() {
+    let command = stored_command(ActionType::Macro);
+    assert!(check_runnable(&command, &[Uuid::new_v4()]).is_ok());
+
+    let disabled = CommandDefinition {
+        enabled: false,
+        ..command.clone()
+    };
+    assert!(check_runnable(&disabled, &[])
+        .unwrap_err()
+        .contains("disabled"));
+
+    let gated = CommandDefinition {
+        requires_confirmation: true,
+        ..command.clone()
+    };
+    assert!(check_runnable(&gated, &[])
+        .unwrap_err()
+        .contains("confirmation"));
+
+    assert!(check_runnable(&stored_command(ActionType::Rhai), &[]).is_err());
+}
+
+#[test]
+fn test_check_runnable_stops_command_loops() {
+    let command = stored_command(ActionType::Macro);
+    // A macro whose script step runs the macro again
+    assert!(check_runnable(&command, &[command.id])
+        .unwrap_err()
+        .contains("already running"));
+
+    let chain: Vec<Uuid> = (0..MAX_COMMAND_DEPTH).map(|_| Uuid::new_v4()).collect();
+    assert!(check_runnable(&command, &chain)
+        .unwrap_err()
+        .contains("levels deep"));
+}
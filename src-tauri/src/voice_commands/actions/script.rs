@@ -0,0 +1,438 @@
+// Script action - runs user-defined scripts for Custom commands
+//
+// Scripts are executed directly, never through a shell. The "script"
+// parameter names the program, optionally followed by argument templates
+// ("move.sh {window} {display}"); arguments containing spaces go in the
+// "args" parameter as a JSON array instead. Every template becomes exactly
+// one argument, so a spoken value can't inject extra arguments or shell
+// syntax.
+//
+// The child gets a cleared environment plus the allowlisted variables, runs
+// in the configured working directory, is killed when it exceeds the
+// timeout, and has its captured output capped.
+
+use crate::util::SettingsAccess;
+use crate::voice_commands::executor::{Action, ActionError, ActionErrorCode, ActionResult};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::Command;
+
+/// Parameter naming the program (and optional argument templates)
+pub const SCRIPT_PARAM: &str = "script";
+/// Parameter holding argument templates as a JSON array
+pub const ARGS_PARAM: &str = "args";
+/// Parameter overriding the working directory for one command
+pub const WORKING_DIR_PARAM: &str = "working_dir";
+/// Parameter shortening the timeout for one command (milliseconds)
+pub const TIMEOUT_PARAM: &str = "timeout_ms";
+
+/// Parameters the action templates itself; slot binding must leave them alone
+pub const TEMPLATE_PARAMS: &[&str] = &[SCRIPT_PARAM, ARGS_PARAM];
+
+/// Settings key for the default working directory
+pub const SETTING_WORKING_DIRECTORY: &str = "voiceCommands.scripts.workingDirectory";
+/// Settings key for the environment variables passed to scripts
+pub const SETTING_ENV_ALLOWLIST: &str = "voiceCommands.scripts.envAllowlist";
+/// Settings key for the script timeout (milliseconds)
+pub const SETTING_TIMEOUT_MS: &str = "voiceCommands.scripts.timeoutMs";
+/// Settings key for the cap on captured stdout/stderr (bytes each)
+pub const SETTING_MAX_OUTPUT_BYTES: &str = "voiceCommands.scripts.maxOutputBytes";
+
+/// Default script timeout (milliseconds)
+pub const DEFAULT_SCRIPT_TIMEOUT_MS: u64 = 10_000;
+/// Longest timeout that can be configured (milliseconds)
+pub const MAX_SCRIPT_TIMEOUT_MS: u64 = 300_000;
+/// Default cap on captured output per stream (bytes)
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 64 * 1024;
+/// Stderr included in a failure message is cut to this many characters
+const MAX_ERROR_DETAIL_CHARS: usize = 500;
+/// Environment variables passed through by default
+pub const DEFAULT_ENV_ALLOWLIST: &[&str] = &["PATH", "HOME", "USER", "LANG", "TMPDIR"];
+
+/// Errors from validating a script configuration
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ScriptConfigError {
+    /// Timeout must be positive and at most the maximum
+    #[error("Script timeout must be between 1 and {max}ms (got {actual}ms)")]
+    InvalidTimeout { max: u64, actual: u64 },
+    /// Output cap must be positive
+    #[error("Script output cap must be greater than zero")]
+    ZeroOutputCap,
+    /// Working directory must be an absolute path
+    #[error("Script working directory must be an absolute path: {0}")]
+    RelativeWorkingDirectory(String),
+}
+
+/// Configuration shared by all script commands
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptConfig {
+    /// Directory scripts run in; the home directory when unset
+    pub working_directory: Option<PathBuf>,
+    /// Environment variables copied from the app into the script
+    pub env_allowlist: Vec<String>,
+    /// Scripts still running after this long are killed
+    pub timeout_ms: u64,
+    /// Captured stdout and stderr are each truncated to this many bytes
+    pub max_output_bytes: usize,
+}
+
+impl Default for ScriptConfig {
+    fn default() -> Self {
+        Self {
+            working_directory: None,
+            env_allowlist: DEFAULT_ENV_ALLOWLIST
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            timeout_ms: DEFAULT_SCRIPT_TIMEOUT_MS,
+            max_output_bytes: DEFAULT_MAX_OUTPUT_BYTES,
+        }
+    }
+}
+
+impl ScriptConfig {
+    /// Validate the configuration
+    pub fn validate(&self) -> Result<(), ScriptConfigError> {
+        if self.timeout_ms == 0 || self.timeout_ms > MAX_SCRIPT_TIMEOUT_MS {
+            return Err(ScriptConfigError::InvalidTimeout {
+                max: MAX_SCRIPT_TIMEOUT_MS,
+                actual: self.timeout_ms,
+            });
+        }
+        if self.max_output_bytes == 0 {
+            return Err(ScriptConfigError::ZeroOutputCap);
+        }
+        if let Some(dir) = &self.working_directory {
+            if !dir.is_absolute() {
+                return Err(ScriptConfigError::RelativeWorkingDirectory(
+                    dir.display().to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Load the configuration from settings, falling back to defaults
+    ///
+    /// Missing keys use their default value. An invalid combination is logged
+    /// and replaced by the default configuration as a whole.
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+        let config = Self {
+            working_directory: settings
+                .get_setting_value(SETTING_WORKING_DIRECTORY)
+                .and_then(|v| v.as_str().map(PathBuf::from))
+                .filter(|dir| !dir.as_os_str().is_empty()),
+            env_allowlist: settings
+                .get_setting_value(SETTING_ENV_ALLOWLIST)
+                .and_then(|v| serde_json::from_value(v).ok())
+                .unwrap_or(defaults.env_allowlist.clone()),
+            timeout_ms: settings
+                .get_setting_value(SETTING_TIMEOUT_MS)
+                .and_then(|v| v.as_u64())
+                .unwrap_or(defaults.timeout_ms),
+            max_output_bytes: settings
+                .get_setting_value(SETTING_MAX_OUTPUT_BYTES)
+                .and_then(|v| v.as_u64())
+                .map(|v| v as usize)
+                .unwrap_or(defaults.max_output_bytes),
+        };
+
+        match config.validate() {
+            Ok(()) => config,
+            Err(e) => {
+                crate::warn!("[script] Invalid script settings ({}), using defaults", e);
+                defaults
+            }
+        }
+    }
+}
+
+/// Program and arguments resolved for one run
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Invocation {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+/// Fill `{name}` placeholders in a template, passing each value through `encode`
+///
+/// Braces around anything other than a plain name (letters, digits and
+/// underscores) are kept as they are, so JSON templates work unchanged.
+pub(crate) fn fill_template(
+    template: &str,
+    parameters: &HashMap<String, String>,
+    encode: impl Fn(&str) -> String,
+) -> Result<String, ActionError> {
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let name_len = after
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(after.len());
+        if name_len == 0 || !after[name_len..].starts_with('}') {
+            filled.push_str(&rest[..=start]);
+            rest = after;
+            continue;
+        }
+        let name = &after[..name_len];
+        let value = parameters.get(name).ok_or_else(|| ActionError {
+            code: ActionErrorCode::MissingParam,
+            message: format!("Template uses unknown parameter '{}'", name),
+            data: None,
+        })?;
+        filled.push_str(&rest[..start]);
+        filled.push_str(&encode(value));
+        rest = &after[name_len + 1..];
+    }
+    filled.push_str(rest);
+    Ok(filled)
+}
+
+/// Resolve the program and arguments from the command's parameters
+pub(crate) fn build_invocation(
+    parameters: &HashMap<String, String>,
+) -> Result<Invocation, ActionError> {
+    let script = parameters.get(SCRIPT_PARAM).ok_or_else(|| ActionError {
+        code: ActionErrorCode::MissingParam,
+        message: "Missing 'script' parameter".to_string(),
+        data: None,
+    })?;
+
+    let mut words = script.split_whitespace();
+    let program = words.next().ok_or_else(|| ActionError {
+        code: ActionErrorCode::InvalidParameter,
+        message: "Script cannot be empty".to_string(),
+        data: None,
+    })?;
+    if program.contains('{') {
+        return Err(ActionError {
+            code: ActionErrorCode::InvalidParameter,
+            message: "The script path cannot contain placeholders".to_string(),
+            data: None,
+        });
+    }
+
+    let mut templates: Vec<String> = words.map(str::to_string).collect();
+    if let Some(args) = parameters.get(ARGS_PARAM) {
+        let extra: Vec<String> = serde_json::from_str(args).map_err(|e| ActionError {
+            code: ActionErrorCode::InvalidParameter,
+            message: format!("'args' must be a JSON array of strings: {}", e),
+            data: None,
+        })?;
+        templates.extend(extra);
+    }
+
+    let args = templates
+        .iter()
+        .map(|t| fill_template(t, parameters, str::to_string))
+        .collect::<Result<_, _>>()?;
+    Ok(Invocation {
+        program: program.to_string(),
+        args,
+    })
+}
+
+/// Read a stream up to `cap` bytes, draining (and dropping) the rest so the
+/// child never blocks on a full pipe. Returns the text and whether it was cut.
+async fn read_capped<R: AsyncRead + Unpin>(reader: Option<R>, cap: usize) -> (String, bool) {
+    let Some(mut reader) = reader else {
+        return (String::new(), false);
+    };
+    let mut kept = Vec::new();
+    let mut truncated = false;
+    let mut buf = [0u8; 8192];
+    loop {
+        match reader.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => {
+                let room = cap.saturating_sub(kept.len());
+                kept.extend_from_slice(&buf[..n.min(room)]);
+                truncated |= n > room;
+            }
+        }
+    }
+    (String::from_utf8_lossy(&kept).into_owned(), truncated)
+}
+
+/// Action that runs a user-defined script
+pub struct ScriptAction {
+    config: ScriptConfig,
+}
+
+impl ScriptAction {
+    pub fn new(config: ScriptConfig) -> Self {
+        Self { config }
+    }
+
+    fn working_dir(&self, parameters: &HashMap<String, String>) -> Result<PathBuf, ActionError> {
+        let dir = match parameters.get(WORKING_DIR_PARAM) {
+            Some(dir) => PathBuf::from(dir),
+            None => self
+                .config
+                .working_directory
+                .clone()
+                .or_else(dirs::home_dir)
+                .unwrap_or_else(std::env::temp_dir),
+        };
+        if !dir.is_absolute() || !dir.is_dir() {
+            return Err(ActionError {
+                code: ActionErrorCode::InvalidParameter,
+                message: format!("Working directory not found: {}", dir.display()),
+                data: None,
+            });
+        }
+        Ok(dir)
+    }
+
+    /// The command's own timeout can only shorten the configured one
+    fn timeout(&self, parameters: &HashMap<String, String>) -> Result<Duration, ActionError> {
+        let ms = match parameters.get(TIMEOUT_PARAM) {
+            Some(ms) => ms
+                .parse::<u64>()
+                .ok()
+                .filter(|ms| *ms > 0)
+                .ok_or_else(|| ActionError {
+                    code: ActionErrorCode::InvalidParameter,
+                    message: format!("Invalid timeout: {}", ms),
+                    data: None,
+                })?
+                .min(self.config.timeout_ms),
+            None => self.config.timeout_ms,
+        };
+        Ok(Duration::from_millis(ms))
+    }
+
+    fn command(&self, invocation: &Invocation, dir: &Path) -> Command {
+        let mut command = Command::new(&invocation.program);
+        command
+            .args(&invocation.args)
+            .current_dir(dir)
+            .env_clear()
+            .envs(
+                self.config
+                    .env_allowlist
+                    .iter()
+                    .filter_map(|key| std::env::var(key).ok().map(|value| (key, value))),
+            )
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        command
+    }
+}
+
+impl Default for ScriptAction {
+    fn default() -> Self {
+        Self::new(ScriptConfig::default())
+    }
+}
+
+#[async_trait]
+impl Action for ScriptAction {
+    async fn execute(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let invocation = build_invocation(parameters)?;
+        let dir = self.working_dir(parameters)?;
+        let timeout = self.timeout(parameters)?;
+        let cap = self.config.max_output_bytes;
+
+        crate::info!(
+            "[script] Running {} with {} argument(s) in {}",
+            invocation.program,
+            invocation.args.len(),
+            dir.display()
+        );
+        let started = Instant::now();
+        let mut child = self
+            .command(&invocation, &dir)
+            .spawn()
+            .map_err(|e| ActionError {
+                code: if e.kind() == std::io::ErrorKind::NotFound {
+                    ActionErrorCode::NotFound
+                } else {
+                    ActionErrorCode::ExecutionError
+                },
+                message: format!("Failed to start script {}: {}", invocation.program, e),
+                data: None,
+            })?;
+
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        let run = async {
+            let ((stdout, stdout_truncated), (stderr, stderr_truncated)) =
+                tokio::join!(read_capped(stdout, cap), read_capped(stderr, cap));
+            let status = child.wait().await;
+            (status, stdout, stdout_truncated, stderr, stderr_truncated)
+        };
+
+        // Dropping the future on timeout drops the child, which kills it
+        let (status, stdout, stdout_truncated, stderr, stderr_truncated) =
+            tokio::time::timeout(timeout, run)
+                .await
+                .map_err(|_| ActionError {
+                    code: ActionErrorCode::Timeout,
+                    message: format!(
+                        "Script {} timed out after {}ms",
+                        invocation.program,
+                        timeout.as_millis()
+                    ),
+                    data: None,
+                })?;
+        let status = status.map_err(|e| ActionError {
+            code: ActionErrorCode::ExecutionError,
+            message: format!("Failed to wait for script {}: {}", invocation.program, e),
+            data: None,
+        })?;
+
+        // Failures carry the same output as successes, for debugging
+        let data = serde_json::json!({
+            "script": invocation.program,
+            "args": invocation.args,
+            "exitCode": status.code(),
+            "stdout": stdout,
+            "stderr": stderr,
+            "stdoutTruncated": stdout_truncated,
+            "stderrTruncated": stderr_truncated,
+            "durationMs": started.elapsed().as_millis() as u64,
+        });
+
+        if !status.success() {
+            let exit = status
+                .code()
+                .map_or_else(|| "a signal".to_string(), |code| format!("code {}", code));
+            let detail: String = stderr.trim().chars().take(MAX_ERROR_DETAIL_CHARS).collect();
+            return Err(ActionError {
+                code: ActionErrorCode::ExecutionError,
+                message: if detail.is_empty() {
+                    format!("Script {} exited with {}", invocation.program, exit)
+                } else {
+                    format!(
+                        "Script {} exited with {}: {}",
+                        invocation.program, exit, detail
+                    )
+                },
+                data: Some(data),
+            });
+        }
+
+        Ok(ActionResult {
+            message: format!("Ran script: {}", invocation.program),
+            data: Some(data),
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "script_test.rs"]
+mod tests;
@@ -0,0 +1,226 @@
+use super::*;
+use tauri::AppHandle;
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn action_in(dir: &Path) -> ScriptAction {
+    ScriptAction::new(ScriptConfig {
+        working_directory: Some(dir.to_path_buf()),
+        ..ScriptConfig::default()
+    })
+}
+
+#[test]
+fn test_default_config_is_valid() {
+    assert!(ScriptConfig::default().validate().is_ok());
+    assert_eq!(
+        ScriptConfig::from_settings(&NoAppHandle),
+        ScriptConfig::default()
+    );
+}
+
+#[test]
+fn test_validate_rejects_bad_values() {
+    let config = |timeout_ms, max_output_bytes, dir: Option<&str>| ScriptConfig {
+        working_directory: dir.map(PathBuf::from),
+        timeout_ms,
+        max_output_bytes,
+        ..ScriptConfig::default()
+    };
+
+    assert!(matches!(
+        config(0, 10, None).validate(),
+        Err(ScriptConfigError::InvalidTimeout { .. })
+    ));
+    assert!(matches!(
+        config(MAX_SCRIPT_TIMEOUT_MS + 1, 10, None).validate(),
+        Err(ScriptConfigError::InvalidTimeout { .. })
+    ));
+    assert_eq!(
+        config(1000, 0, None).validate(),
+        Err(ScriptConfigError::ZeroOutputCap)
+    );
+    assert!(matches!(
+        config(1000, 10, Some("scripts")).validate(),
+        Err(ScriptConfigError::RelativeWorkingDirectory(_))
+    ));
+}
+
+#[test]
+fn test_each_template_becomes_one_argument() {
+    let invocation = build_invocation(&params(&[
+        ("script", "move.sh {window} to-{display}"),
+        ("args", r#"["--title", "{window} window"]"#),
+        ("window", "My Notes; rm -rf ~"),
+        ("display", "left"),
+    ]))
+    .unwrap();
+
+    assert_eq!(invocation.program, "move.sh");
+    assert_eq!(
+        invocation.args,
+        vec![
+            "My Notes; rm -rf ~",
+            "to-left",
+            "--title",
+            "My Notes; rm -rf ~ window"
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_invocations_are_rejected() {
+    let code = |pairs: &[(&str, &str)]| build_invocation(&params(pairs)).unwrap_err().code;
+
+    assert_eq!(code(&[]), ActionErrorCode::MissingParam);
+    assert_eq!(code(&[("script", "  ")]), ActionErrorCode::InvalidParameter);
+    assert_eq!(
+        code(&[("script", "{tool} run")]),
+        ActionErrorCode::InvalidParameter
+    );
+    assert_eq!(
+        code(&[("script", "run.sh"), ("args", "--flag")]),
+        ActionErrorCode::InvalidParameter
+    );
+    assert_eq!(
+        code(&[("script", "run.sh {who}")]),
+        ActionErrorCode::MissingParam
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_captures_output_in_result_data() {
+    let dir = tempfile::tempdir().unwrap();
+    let result = action_in(dir.path())
+        .execute(&params(&[
+            ("script", "sh"),
+            (
+                "args",
+                r#"["-c", "echo \"$1\"; echo oops >&2; pwd", "sh", "{name}"]"#,
+            ),
+            ("name", "$(whoami)"),
+        ]))
+        .await
+        .unwrap();
+
+    let data = result.data.unwrap();
+    let canonical = dir.path().canonicalize().unwrap();
+    assert_eq!(
+        data["stdout"],
+        format!("$(whoami)\n{}\n", canonical.display())
+    );
+    assert_eq!(data["stderr"], "oops\n");
+    assert_eq!(data["exitCode"], 0);
+    assert_eq!(data["stdoutTruncated"], false);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_environment_is_limited_to_allowlist() {
+    std::env::set_var("HEYCAT_SCRIPT_TEST_SECRET", "hidden");
+    let dir = tempfile::tempdir().unwrap();
+    let result = action_in(dir.path())
+        .execute(&params(&[
+            ("script", "/bin/sh"),
+            (
+                "args",
+                r#"["-c", "echo \"${HEYCAT_SCRIPT_TEST_SECRET:-unset}\""]"#,
+            ),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(result.data.unwrap()["stdout"], "unset\n");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_output_is_capped() {
+    let dir = tempfile::tempdir().unwrap();
+    let action = ScriptAction::new(ScriptConfig {
+        working_directory: Some(dir.path().to_path_buf()),
+        max_output_bytes: 4,
+        ..ScriptConfig::default()
+    });
+
+    let result = action
+        .execute(&params(&[
+            ("script", "sh"),
+            ("args", r#"["-c", "echo 0123456789"]"#),
+        ]))
+        .await
+        .unwrap();
+
+    let data = result.data.unwrap();
+    assert_eq!(data["stdout"], "0123");
+    assert_eq!(data["stdoutTruncated"], true);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_slow_script_times_out() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = action_in(dir.path())
+        .execute(&params(&[("script", "sleep 5"), ("timeout_ms", "100")]))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.code, ActionErrorCode::Timeout);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_failing_script_reports_exit_code_and_stderr() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = action_in(dir.path())
+        .execute(&params(&[
+            ("script", "sh"),
+            ("args", r#"["-c", "echo partial; echo broken >&2; exit 3"]"#),
+        ]))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.code, ActionErrorCode::ExecutionError);
+    assert!(err.message.contains("code 3"));
+    assert!(err.message.contains("broken"));
+    let data = err.data.expect("failure should carry the script output");
+    assert_eq!(data["exitCode"], 3);
+    assert_eq!(data["stdout"], "partial\n");
+    assert_eq!(data["stderr"], "broken\n");
+}
+
+#[tokio::test]
+async fn test_missing_program_and_directory_are_reported() {
+    let dir = tempfile::tempdir().unwrap();
+    let err = action_in(dir.path())
+        .execute(&params(&[(
+            "script",
+            "definitely-not-a-real-heycat-script",
+        )]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::NotFound);
+
+    let err = action_in(dir.path())
+        .execute(&params(&[
+            ("script", "sh"),
+            ("working_dir", "/no/such/heycat/dir"),
+        ]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::InvalidParameter);
+}
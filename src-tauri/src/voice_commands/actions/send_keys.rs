@@ -0,0 +1,115 @@
+// Send keys action - presses key combinations in the focused application
+//
+// The "keys" parameter uses the shortcut syntax documented in
+// `keyboard::shortcut`, e.g. "mod+s" (save file), "mod+w" (close tab),
+// "ctrl+tab" (next tab) or "down*3 enter".
+
+use crate::keyboard::shortcut::parse_shortcut;
+use crate::keyboard_capture::permissions::check_accessibility_permission;
+use crate::voice_commands::executor::{Action, ActionError, ActionErrorCode, ActionResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+/// Default delay between key presses in milliseconds
+pub const DEFAULT_KEY_DELAY_MS: u64 = 30;
+/// Longest delay allowed between key presses in milliseconds
+pub const MAX_KEY_DELAY_MS: u64 = 1_000;
+
+/// Action to send key combinations to the currently focused application
+pub struct SendKeysAction;
+
+impl SendKeysAction {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SendKeysAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Action for SendKeysAction {
+    async fn execute(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let keys = parameters.get("keys").ok_or_else(|| ActionError {
+            code: ActionErrorCode::MissingParam,
+            message: "Missing 'keys' parameter".to_string(),
+            data: None,
+        })?;
+        let strokes = parse_shortcut(keys).map_err(|e| ActionError {
+            code: ActionErrorCode::InvalidParameter,
+            message: format!("Invalid shortcut '{}': {}", keys, e),
+            data: None,
+        })?;
+        let delay_ms = match parameters.get("delay_ms") {
+            None => DEFAULT_KEY_DELAY_MS,
+            Some(ms) => ms
+                .parse::<u64>()
+                .ok()
+                .filter(|ms| *ms <= MAX_KEY_DELAY_MS)
+                .ok_or_else(|| ActionError {
+                    code: ActionErrorCode::InvalidParameter,
+                    message: format!("Key delay must be between 0 and {}ms", MAX_KEY_DELAY_MS),
+                    data: None,
+                })?,
+        };
+        let presses: u32 = strokes.iter().map(|s| s.repeat).sum();
+
+        // During shutdown, avoid starting new keyboard synthesis.
+        if crate::shutdown::is_shutting_down() {
+            return Ok(ActionResult {
+                message: "Skipped sending keys (app is shutting down)".to_string(),
+                data: None,
+            });
+        }
+
+        let has_permission = tokio::task::spawn_blocking(check_accessibility_permission)
+            .await
+            .map_err(|e| ActionError {
+                code: ActionErrorCode::TaskPanic,
+                message: format!("Permission check task panicked: {}", e),
+                data: None,
+            })?;
+        if !has_permission {
+            return Err(ActionError {
+                code: ActionErrorCode::PermissionDenied,
+                message: "Accessibility permission not granted. Please enable it in System Preferences > Security & Privacy > Privacy > Accessibility".to_string(),
+                data: None,
+            });
+        }
+
+        let to_send = strokes.clone();
+        tokio::task::spawn_blocking(move || {
+            crate::keyboard::synth::send_keystrokes(&to_send, delay_ms)
+        })
+        .await
+        .map_err(|e| ActionError {
+            code: ActionErrorCode::TaskPanic,
+            message: format!("Send keys task panicked: {}", e),
+            data: None,
+        })?
+        .map_err(|message| ActionError {
+            code: ActionErrorCode::EventError,
+            message,
+            data: None,
+        })?;
+
+        Ok(ActionResult {
+            message: format!("Sent {} ({} key presses)", keys, presses),
+            data: Some(serde_json::json!({
+                "keys": keys,
+                "strokes": strokes,
+                "presses": presses,
+            })),
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "send_keys_test.rs"]
+mod tests;
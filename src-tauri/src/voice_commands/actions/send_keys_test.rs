@@ -0,0 +1,55 @@
+use super::*;
+use std::collections::HashMap;
+
+fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_missing_keys_parameter_returns_error() {
+    let error = SendKeysAction::new()
+        .execute(&HashMap::new())
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code, ActionErrorCode::MissingParam);
+    assert!(error.message.contains("keys"));
+}
+
+#[tokio::test]
+async fn test_invalid_shortcut_is_rejected_before_sending() {
+    let error = SendKeysAction::new()
+        .execute(&params(&[("keys", "ctrl+banana")]))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code, ActionErrorCode::InvalidParameter);
+    assert!(error.message.contains("banana"));
+}
+
+#[tokio::test]
+async fn test_invalid_delay_is_rejected() {
+    let error = SendKeysAction::new()
+        .execute(&params(&[("keys", "mod+s"), ("delay_ms", "5000")]))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code, ActionErrorCode::InvalidParameter);
+}
+
+#[tokio::test]
+#[cfg(target_os = "macos")]
+#[ignore] // Sends keys to the active window
+async fn test_send_next_tab() {
+    let result = SendKeysAction::new()
+        .execute(&params(&[("keys", "ctrl+tab")]))
+        .await;
+
+    match result {
+        Ok(r) => assert_eq!(r.data.unwrap()["presses"], 1),
+        Err(e) => assert_eq!(e.code, ActionErrorCode::PermissionDenied),
+    }
+}
@@ -14,6 +14,7 @@ fn type_text_with_delay(text: &str, delay_ms: u64) -> Result<(), ActionError> {
     crate::keyboard::synth::type_unicode_text(text, delay_ms).map_err(|msg| ActionError {
         code: ActionErrorCode::EventError,
         message: msg,
+        data: None,
     })
 }
 
@@ -22,6 +23,7 @@ fn type_text_with_delay(_text: &str, _delay_ms: u64) -> Result<(), ActionError>
     Err(ActionError {
         code: ActionErrorCode::UnsupportedPlatform,
         message: "Text input is only supported on macOS".to_string(),
+        data: None,
     })
 }
 
@@ -46,6 +48,7 @@ impl Action for TextInputAction {
         let text = parameters.get("text").ok_or_else(|| ActionError {
             code: ActionErrorCode::InvalidParameter,
             message: "Missing 'text' parameter".to_string(),
+            data: None,
         })?;
 
         // Empty text is a no-op, return success
@@ -76,12 +79,14 @@ impl Action for TextInputAction {
             .map_err(|e| ActionError {
                 code: ActionErrorCode::TaskPanic,
                 message: format!("Permission check task panicked: {}", e),
+                data: None,
             })?;
 
         if !has_permission {
             return Err(ActionError {
                 code: ActionErrorCode::PermissionDenied,
                 message: "Accessibility permission not granted. Please enable it in System Preferences > Security & Privacy > Privacy > Accessibility".to_string(),
+                data: None,
             });
         }
 
@@ -104,6 +109,7 @@ impl Action for TextInputAction {
         .map_err(|e| ActionError {
             code: ActionErrorCode::TaskPanic,
             message: format!("Text input task panicked: {}", e),
+            data: None,
         })??;
 
         Ok(ActionResult {
@@ -0,0 +1,427 @@
+// Webhook action - sends an HTTP request for Webhook commands
+//
+// Parameters:
+//   url           - request URL; placeholders are allowed in the path and
+//                   query only, and their values are percent-encoded
+//   method        - GET, POST (default), PUT, PATCH or DELETE
+//   headers       - JSON object of header templates
+//   body          - body template; with a JSON content type, values are
+//                   escaped so `{"text": "{transcription}"}` stays valid JSON
+//   content_type  - body content type (default: application/json)
+//   timeout_ms    - per-attempt timeout
+//   retries       - extra attempts after timeouts, connection errors,
+//                   429 and 5xx responses
+//   response_path - JSON pointer (e.g. "/reply/text") into the response used
+//                   as the result message
+//
+// Placeholders are `{name}` with any action parameter, including slot values
+// and `{transcription}`. Plain http is only allowed to local-network hosts.
+
+use super::script::fill_template;
+use crate::voice_commands::executor::{Action, ActionError, ActionErrorCode, ActionResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::Duration;
+use tauri_plugin_http::reqwest::{self, Method, Url};
+
+/// Parameter holding the URL template
+pub const URL_PARAM: &str = "url";
+/// Parameter holding the HTTP method
+pub const METHOD_PARAM: &str = "method";
+/// Parameter holding header templates as a JSON object
+pub const HEADERS_PARAM: &str = "headers";
+/// Parameter holding the body template
+pub const BODY_PARAM: &str = "body";
+/// Parameter holding the body content type
+pub const CONTENT_TYPE_PARAM: &str = "content_type";
+/// Parameter holding the per-attempt timeout (milliseconds)
+pub const TIMEOUT_PARAM: &str = "timeout_ms";
+/// Parameter holding the number of retries
+pub const RETRIES_PARAM: &str = "retries";
+/// Parameter holding the JSON pointer used for the result message
+pub const RESPONSE_PATH_PARAM: &str = "response_path";
+
+/// Parameters the action templates itself; slot binding must leave them alone
+pub const TEMPLATE_PARAMS: &[&str] = &[URL_PARAM, HEADERS_PARAM, BODY_PARAM];
+
+/// Default per-attempt timeout (milliseconds)
+pub const DEFAULT_WEBHOOK_TIMEOUT_MS: u64 = 5_000;
+/// Longest per-attempt timeout (milliseconds)
+pub const MAX_WEBHOOK_TIMEOUT_MS: u64 = 30_000;
+/// Most retries a command may ask for
+pub const MAX_WEBHOOK_RETRIES: u32 = 3;
+/// Wait before the first retry; doubled for each further retry (milliseconds)
+pub const WEBHOOK_RETRY_BACKOFF_MS: u64 = 250;
+/// Response bodies are cut to this many bytes in the result
+pub const MAX_RESPONSE_BYTES: usize = 64 * 1024;
+
+const DEFAULT_CONTENT_TYPE: &str = "application/json";
+
+/// Request resolved from a command's parameters
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct WebhookRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    pub timeout: Duration,
+    pub retries: u32,
+}
+
+fn invalid(message: impl Into<String>) -> ActionError {
+    ActionError {
+        code: ActionErrorCode::InvalidParameter,
+        message: message.into(),
+        data: None,
+    }
+}
+
+/// Percent-encode everything but unreserved characters
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Escape a value for use inside a JSON string literal
+fn json_escape(value: &str) -> String {
+    let quoted = serde_json::Value::String(value.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+/// Whether plain http may be used for this host
+fn is_local_host(url: &Url) -> bool {
+    match url.host_str() {
+        Some("localhost") => true,
+        Some(host) if host.ends_with(".local") => true,
+        Some(host) => match host.trim_matches(['[', ']']).parse::<IpAddr>() {
+            Ok(IpAddr::V4(ip)) => ip.is_loopback() || ip.is_private() || ip.is_link_local(),
+            Ok(IpAddr::V6(ip)) => ip.is_loopback(),
+            Err(_) => false,
+        },
+        None => false,
+    }
+}
+
+/// Check the URL template and fill it in
+///
+/// Placeholders may only appear after the host, so spoken values can never
+/// change where the request goes.
+fn resolve_url(template: &str, parameters: &HashMap<String, String>) -> Result<Url, ActionError> {
+    let authority_end = template
+        .find("://")
+        .map(|i| i + 3)
+        .and_then(|start| {
+            template[start..]
+                .find(['/', '?', '#'])
+                .map(|end| start + end)
+                .or(Some(template.len()))
+        })
+        .ok_or_else(|| invalid(format!("Invalid webhook URL: {}", template)))?;
+    if template[..authority_end].contains('{') {
+        return Err(invalid(
+            "Webhook URL placeholders are only allowed in the path and query",
+        ));
+    }
+
+    let filled = fill_template(template, parameters, percent_encode)?;
+    let url = Url::parse(&filled).map_err(|e| invalid(format!("Invalid webhook URL: {}", e)))?;
+
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("Webhook URL must include a host"));
+    }
+    if !url.username().is_empty() || url.password().is_some() {
+        return Err(invalid(
+            "Webhook URL must not contain credentials; use a header instead",
+        ));
+    }
+    match url.scheme() {
+        "https" => {}
+        "http" if is_local_host(&url) => {}
+        "http" => {
+            return Err(invalid(
+                "Plain http is only allowed for local network hosts; use https",
+            ))
+        }
+        scheme => {
+            return Err(invalid(format!(
+                "Unsupported webhook URL scheme: {}",
+                scheme
+            )))
+        }
+    }
+    Ok(url)
+}
+
+/// Resolve the request for a command, validating every parameter
+pub(crate) fn build_request(
+    parameters: &HashMap<String, String>,
+) -> Result<WebhookRequest, ActionError> {
+    let url_template = parameters.get(URL_PARAM).ok_or_else(|| ActionError {
+        code: ActionErrorCode::MissingParam,
+        message: "Missing 'url' parameter".to_string(),
+        data: None,
+    })?;
+    let url = resolve_url(url_template, parameters)?;
+
+    let method = match parameters.get(METHOD_PARAM) {
+        None => Method::POST,
+        Some(method) => match method.to_uppercase().as_str() {
+            "GET" => Method::GET,
+            "POST" => Method::POST,
+            "PUT" => Method::PUT,
+            "PATCH" => Method::PATCH,
+            "DELETE" => Method::DELETE,
+            other => return Err(invalid(format!("Unsupported HTTP method: {}", other))),
+        },
+    };
+
+    let mut headers = Vec::new();
+    if let Some(json) = parameters.get(HEADERS_PARAM) {
+        let templates: HashMap<String, String> = serde_json::from_str(json)
+            .map_err(|e| invalid(format!("'headers' must be a JSON object of strings: {}", e)))?;
+        for (name, template) in templates {
+            let value = fill_template(&template, parameters, str::to_string)?;
+            if value.contains(['\r', '\n']) {
+                return Err(invalid(format!("Header '{}' contains a line break", name)));
+            }
+            headers.push((name, value));
+        }
+        headers.sort();
+    }
+
+    let content_type = parameters
+        .get(CONTENT_TYPE_PARAM)
+        .map(String::as_str)
+        .unwrap_or(DEFAULT_CONTENT_TYPE);
+    let body = match parameters.get(BODY_PARAM) {
+        None => None,
+        Some(template) if content_type.contains("json") => {
+            let body = fill_template(template, parameters, json_escape)?;
+            serde_json::from_str::<serde_json::Value>(&body)
+                .map_err(|e| invalid(format!("Webhook body is not valid JSON: {}", e)))?;
+            Some(body)
+        }
+        Some(template) => Some(fill_template(template, parameters, str::to_string)?),
+    };
+    if body.is_some() {
+        headers.push(("Content-Type".to_string(), content_type.to_string()));
+    }
+
+    let timeout_ms = match parameters.get(TIMEOUT_PARAM) {
+        None => DEFAULT_WEBHOOK_TIMEOUT_MS,
+        Some(ms) => ms
+            .parse::<u64>()
+            .ok()
+            .filter(|ms| (1..=MAX_WEBHOOK_TIMEOUT_MS).contains(ms))
+            .ok_or_else(|| {
+                invalid(format!(
+                    "Webhook timeout must be between 1 and {}ms",
+                    MAX_WEBHOOK_TIMEOUT_MS
+                ))
+            })?,
+    };
+    let retries = match parameters.get(RETRIES_PARAM) {
+        None => 0,
+        Some(n) => n
+            .parse::<u32>()
+            .ok()
+            .filter(|n| *n <= MAX_WEBHOOK_RETRIES)
+            .ok_or_else(|| {
+                invalid(format!(
+                    "Webhook retries must be between 0 and {}",
+                    MAX_WEBHOOK_RETRIES
+                ))
+            })?,
+    };
+
+    Ok(WebhookRequest {
+        method,
+        url,
+        headers,
+        body,
+        timeout: Duration::from_millis(timeout_ms),
+        retries,
+    })
+}
+
+/// Outcome of one attempt
+enum Attempt {
+    Response {
+        status: u16,
+        body: String,
+        truncated: bool,
+    },
+    TimedOut,
+    Failed(String),
+}
+
+impl Attempt {
+    fn should_retry(&self) -> bool {
+        match self {
+            Attempt::Response { status, .. } => *status == 429 || *status >= 500,
+            Attempt::TimedOut | Attempt::Failed(_) => true,
+        }
+    }
+}
+
+/// Action that sends an HTTP request
+pub struct WebhookAction {
+    client: reqwest::Client,
+}
+
+impl WebhookAction {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+
+    async fn attempt(&self, request: &WebhookRequest) -> Attempt {
+        let mut builder = self
+            .client
+            .request(request.method.clone(), request.url.clone())
+            .timeout(request.timeout);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.body(body.clone());
+        }
+
+        let response = match builder.send().await {
+            Ok(response) => response,
+            Err(e) if e.is_timeout() => return Attempt::TimedOut,
+            Err(e) => return Attempt::Failed(e.to_string()),
+        };
+        let status = response.status().as_u16();
+        match read_capped(response).await {
+            Ok((body, truncated)) => Attempt::Response {
+                status,
+                body: String::from_utf8_lossy(&body).into_owned(),
+                truncated,
+            },
+            Err(e) if e.is_timeout() => Attempt::TimedOut,
+            Err(e) => Attempt::Failed(e.to_string()),
+        }
+    }
+}
+
+/// Read a response body, stopping once `MAX_RESPONSE_BYTES` have arrived
+///
+/// Returns the kept bytes and whether the body was longer.
+async fn read_capped(mut response: reqwest::Response) -> reqwest::Result<(Vec<u8>, bool)> {
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        let room = MAX_RESPONSE_BYTES - body.len();
+        if chunk.len() > room {
+            body.extend_from_slice(&chunk[..room]);
+            return Ok((body, true));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok((body, false))
+}
+
+impl Default for WebhookAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Action for WebhookAction {
+    async fn execute(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let request = build_request(parameters)?;
+        let host = request.url.host_str().unwrap_or_default().to_string();
+
+        let mut attempts = 0;
+        let outcome = loop {
+            attempts += 1;
+            let outcome = self.attempt(&request).await;
+            if attempts > request.retries || !outcome.should_retry() {
+                break outcome;
+            }
+            let backoff = WEBHOOK_RETRY_BACKOFF_MS << (attempts - 1);
+            crate::debug!(
+                "[webhook] Attempt {} to {} failed, retrying in {}ms",
+                attempts,
+                host,
+                backoff
+            );
+            tokio::time::sleep(Duration::from_millis(backoff)).await;
+        };
+
+        let (status, body, truncated) = match outcome {
+            Attempt::Response {
+                status,
+                body,
+                truncated,
+            } => (status, body, truncated),
+            Attempt::TimedOut => {
+                return Err(ActionError {
+                    code: ActionErrorCode::Timeout,
+                    message: format!(
+                        "Webhook to {} timed out after {} attempt(s)",
+                        host, attempts
+                    ),
+                    data: None,
+                })
+            }
+            Attempt::Failed(e) => {
+                return Err(ActionError {
+                    code: ActionErrorCode::ExecutionError,
+                    message: format!("Webhook to {} failed: {}", host, e),
+                    data: None,
+                })
+            }
+        };
+
+        if !(200..300).contains(&status) {
+            return Err(ActionError {
+                code: ActionErrorCode::ExecutionError,
+                message: format!("Webhook to {} returned HTTP {}", host, status),
+                data: None,
+            });
+        }
+
+        let json = serde_json::from_str::<serde_json::Value>(&body).ok();
+        let message = parameters
+            .get(RESPONSE_PATH_PARAM)
+            .and_then(|path| json.as_ref()?.pointer(path))
+            .map(|value| match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .unwrap_or_else(|| format!("Webhook to {} returned HTTP {}", host, status));
+
+        Ok(ActionResult {
+            message,
+            data: Some(serde_json::json!({
+                "method": request.method.as_str(),
+                "url": request.url.as_str(),
+                "status": status,
+                "attempts": attempts,
+                "response": json.unwrap_or(serde_json::Value::String(body)),
+                "responseTruncated": truncated,
+            })),
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "webhook_test.rs"]
+mod tests;
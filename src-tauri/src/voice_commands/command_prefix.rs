@@ -0,0 +1,107 @@
+// Command prefix - keeps ordinary dictation from firing voice commands
+//
+// With the prefix required, an utterance is only matched against commands
+// when it starts with the prefix ("computer, open slack") or was recorded
+// with the command hotkey. The prefix is stripped before matching and
+// everything else goes straight to dictation. Window contexts can require
+// the prefix or turn it off regardless of the global setting.
+
+use crate::util::{normalize_word, normalize_words, SettingsAccess};
+use crate::window_context::CommandPrefixMode;
+use serde::{Deserialize, Serialize};
+
+/// Settings key for requiring the command prefix everywhere
+pub const SETTING_COMMAND_PREFIX_REQUIRED: &str = "voiceCommands.prefix.required";
+/// Settings key for the command prefix phrase
+pub const SETTING_COMMAND_PREFIX: &str = "voiceCommands.prefix.phrase";
+
+/// Prefix used when none is configured
+pub const DEFAULT_COMMAND_PREFIX: &str = "computer";
+
+/// Global command prefix configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandPrefixConfig {
+    /// Whether commands need the prefix when the context doesn't say otherwise
+    pub required: bool,
+    /// Phrase that marks an utterance as a command
+    pub prefix: String,
+}
+
+impl Default for CommandPrefixConfig {
+    fn default() -> Self {
+        Self {
+            required: false,
+            prefix: DEFAULT_COMMAND_PREFIX.to_string(),
+        }
+    }
+}
+
+impl CommandPrefixConfig {
+    /// Load the configuration from settings, falling back to defaults per key
+    pub fn from_settings<S: SettingsAccess + ?Sized>(settings: &S) -> Self {
+        let defaults = Self::default();
+
+        Self {
+            required: settings
+                .get_setting_value(SETTING_COMMAND_PREFIX_REQUIRED)
+                .and_then(|v| v.as_bool())
+                .unwrap_or(defaults.required),
+            prefix: settings
+                .get_setting(SETTING_COMMAND_PREFIX)
+                .filter(|p| !normalize_words(p).is_empty())
+                .unwrap_or(defaults.prefix),
+        }
+    }
+
+    /// Whether commands need the prefix in a context with `mode`
+    pub fn is_required(&self, mode: CommandPrefixMode) -> bool {
+        match mode {
+            CommandPrefixMode::Inherit => self.required,
+            CommandPrefixMode::Required => true,
+            CommandPrefixMode::Off => false,
+        }
+    }
+
+    /// The words after the prefix, or None if `text` doesn't start with it
+    pub fn strip_prefix(&self, text: &str) -> Option<String> {
+        let prefix = normalize_words(&self.prefix);
+        if prefix.is_empty() {
+            return None;
+        }
+
+        let words: Vec<&str> = text.split_whitespace().collect();
+        if words.len() <= prefix.len() {
+            return None;
+        }
+        let starts_with_prefix = words
+            .iter()
+            .zip(&prefix)
+            .all(|(word, expected)| normalize_word(word) == *expected);
+
+        starts_with_prefix.then(|| words[prefix.len()..].join(" "))
+    }
+
+    /// Text to match against commands, or None when the utterance is dictation
+    ///
+    /// Utterances from the command hotkey are always matched, with the prefix
+    /// stripped if it was said anyway.
+    pub fn command_text(
+        &self,
+        text: &str,
+        mode: CommandPrefixMode,
+        from_command_hotkey: bool,
+    ) -> Option<String> {
+        if from_command_hotkey {
+            return Some(self.strip_prefix(text).unwrap_or_else(|| text.to_string()));
+        }
+        if !self.is_required(mode) {
+            return Some(text.to_string());
+        }
+        self.strip_prefix(text)
+    }
+}
+
+#[cfg(test)]
+#[path = "command_prefix_test.rs"]
+mod tests;
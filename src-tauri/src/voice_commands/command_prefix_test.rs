@@ -0,0 +1,96 @@
+use super::*;
+use tauri::AppHandle;
+
+struct NoAppHandle;
+
+impl SettingsAccess for NoAppHandle {
+    fn app_handle(&self) -> Option<&AppHandle> {
+        None
+    }
+}
+
+fn required() -> CommandPrefixConfig {
+    CommandPrefixConfig {
+        required: true,
+        ..CommandPrefixConfig::default()
+    }
+}
+
+#[test]
+fn test_from_settings_without_app_handle_uses_defaults() {
+    let config = CommandPrefixConfig::from_settings(&NoAppHandle);
+    assert_eq!(config, CommandPrefixConfig::default());
+    assert!(!config.required);
+    assert_eq!(config.prefix, DEFAULT_COMMAND_PREFIX);
+}
+
+#[test]
+fn test_strip_prefix_ignores_case_and_punctuation() {
+    let config = required();
+    assert_eq!(
+        config.strip_prefix("Computer, open Slack."),
+        Some("open Slack.".to_string())
+    );
+    assert_eq!(config.strip_prefix("open slack computer"), None);
+    // The prefix alone leaves nothing to match
+    assert_eq!(config.strip_prefix("computer."), None);
+}
+
+#[test]
+fn test_strip_multi_word_prefix() {
+    let config = CommandPrefixConfig {
+        prefix: "hey cat".to_string(),
+        ..required()
+    };
+    assert_eq!(
+        config.strip_prefix("Hey cat open mail"),
+        Some("open mail".to_string())
+    );
+    assert_eq!(config.strip_prefix("hey open mail"), None);
+}
+
+#[test]
+fn test_required_prefix_sends_unprefixed_text_to_dictation() {
+    let config = required();
+    let inherit = CommandPrefixMode::Inherit;
+    assert_eq!(
+        config.command_text("open slack is my favorite", inherit, false),
+        None
+    );
+    assert_eq!(
+        config.command_text("computer open slack", inherit, false),
+        Some("open slack".to_string())
+    );
+}
+
+#[test]
+fn test_command_hotkey_matches_without_prefix() {
+    let config = required();
+    let inherit = CommandPrefixMode::Inherit;
+    assert_eq!(
+        config.command_text("open slack", inherit, true),
+        Some("open slack".to_string())
+    );
+    assert_eq!(
+        config.command_text("computer open slack", inherit, true),
+        Some("open slack".to_string())
+    );
+}
+
+#[test]
+fn test_context_mode_overrides_global_setting() {
+    let optional = CommandPrefixConfig::default();
+    assert_eq!(
+        optional.command_text("open slack", CommandPrefixMode::Required, false),
+        None
+    );
+    // Without the requirement the text is matched as spoken
+    assert_eq!(
+        optional.command_text("computer open slack", CommandPrefixMode::Inherit, false),
+        Some("computer open slack".to_string())
+    );
+    assert_eq!(
+        required().command_text("open slack", CommandPrefixMode::Off, false),
+        Some("open slack".to_string())
+    );
+}
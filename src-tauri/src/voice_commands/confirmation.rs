@@ -0,0 +1,264 @@
+// Confirmation - holds flagged commands until the user says "yes"
+//
+// Commands with `requires_confirmation` set don't run when matched. Instead a
+// command_confirmation_required event is emitted and the command waits for a
+// short window: "yes"/"confirm" (or a click in the UI) runs it, "no"/"cancel"
+// drops it, and anything else or the window running out cancels it too.
+
+use crate::events::{CommandConfirmationRequiredPayload, CommandEventEmitter};
+use crate::turso::PipelineMatch;
+use crate::util::spoken_phrase;
+use crate::voice_commands::disambiguation::{
+    emit_matched, run_resolution, Disambiguator, FollowUp, Resolution,
+};
+use crate::voice_commands::executor::{ActionDispatcher, ActionError, ActionResult};
+use crate::voice_commands::pipeline_log::PipelineRecord;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long a flagged command waits for confirmation
+pub const DEFAULT_CONFIRMATION_TIMEOUT_MS: u64 = 8_000;
+
+/// Phrases that run the waiting command
+const YES_PHRASES: &[&str] = &[
+    "yes",
+    "yeah",
+    "yep",
+    "yup",
+    "sure",
+    "ok",
+    "okay",
+    "confirm",
+    "confirmed",
+    "do it",
+    "go ahead",
+    "yes please",
+    "yes do it",
+];
+
+/// Phrases that drop the waiting command
+const NO_PHRASES: &[&str] = &[
+    "no",
+    "nope",
+    "cancel",
+    "stop",
+    "dont",
+    "don't",
+    "never mind",
+    "nevermind",
+    "no thanks",
+];
+
+/// Errors from confirming a waiting command
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConfirmationError {
+    /// No command is waiting, or its window has run out
+    #[error("No command is waiting for confirmation")]
+    NothingPending,
+    /// A different command is waiting
+    #[error("Command '{0}' is not waiting for confirmation")]
+    UnknownCommand(String),
+}
+
+/// What a follow-up utterance did to the waiting command
+#[derive(Debug, Clone)]
+pub enum Answer {
+    /// The command was confirmed and should run
+    Confirmed(Resolution),
+    /// The command was declined
+    Declined,
+    /// The utterance isn't an answer; the command is cancelled
+    NotAnAnswer,
+}
+
+struct PendingConfirmation {
+    resolution: Resolution,
+    expires_at: Instant,
+}
+
+/// Holds the last command that asked for confirmation
+pub struct ConfirmationGate {
+    pending: Mutex<Option<PendingConfirmation>>,
+    timeout: Duration,
+}
+
+impl Default for ConfirmationGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfirmationGate {
+    pub fn new() -> Self {
+        Self::with_timeout(Duration::from_millis(DEFAULT_CONFIRMATION_TIMEOUT_MS))
+    }
+
+    /// Create a gate that waits `timeout` for confirmation
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            pending: Mutex::new(None),
+            timeout,
+        }
+    }
+
+    /// How long a command waits for confirmation
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Hold `resolution` until it is confirmed, replacing any waiting command
+    pub fn request(&self, resolution: Resolution) {
+        let pending = PendingConfirmation {
+            resolution,
+            expires_at: Instant::now() + self.timeout,
+        };
+        if let Ok(mut slot) = self.pending.lock() {
+            *slot = Some(pending);
+        }
+    }
+
+    /// Drop the waiting command; returns whether one was waiting
+    pub fn cancel(&self) -> bool {
+        self.take_pending().is_some()
+    }
+
+    /// Apply a follow-up utterance to the waiting command
+    ///
+    /// Returns None when nothing is waiting. Any follow-up closes the window,
+    /// so a flagged command never runs on a later, unrelated "yes".
+    pub fn follow_up(&self, text: &str) -> Option<Answer> {
+        let pending = self.take_pending()?;
+        let spoken = spoken_phrase(text);
+        let answer = if YES_PHRASES.contains(&spoken.as_str()) {
+            Answer::Confirmed(pending.resolution)
+        } else if NO_PHRASES.contains(&spoken.as_str()) {
+            Answer::Declined
+        } else {
+            Answer::NotAnAnswer
+        };
+        Some(answer)
+    }
+
+    /// Confirm the waiting command by ID (e.g. clicked in the UI)
+    pub fn confirm(&self, command_id: &str) -> Result<Resolution, ConfirmationError> {
+        let mut slot = self
+            .pending
+            .lock()
+            .map_err(|_| ConfirmationError::NothingPending)?;
+        match slot.take() {
+            Some(pending) if Instant::now() >= pending.expires_at => {
+                Err(ConfirmationError::NothingPending)
+            }
+            Some(pending) if pending.resolution.candidate.command.id.to_string() == command_id => {
+                Ok(pending.resolution)
+            }
+            Some(pending) => {
+                // A stale click shouldn't cancel the command that is waiting
+                *slot = Some(pending);
+                Err(ConfirmationError::UnknownCommand(command_id.to_string()))
+            }
+            None => Err(ConfirmationError::NothingPending),
+        }
+    }
+
+    /// Take the waiting command if it hasn't expired
+    fn take_pending(&self) -> Option<PendingConfirmation> {
+        let pending = self.pending.lock().ok()?.take()?;
+        if Instant::now() >= pending.expires_at {
+            crate::debug!("Command confirmation expired");
+            return None;
+        }
+        Some(pending)
+    }
+}
+
+/// Run a matched command, or hold it for confirmation if it asks for one
+///
+/// Returns None when the command is waiting for confirmation.
+pub async fn run_or_confirm<C: CommandEventEmitter + ?Sized>(
+    dispatcher: &ActionDispatcher,
+    emitter: &C,
+    gate: &ConfirmationGate,
+    resolution: Resolution,
+) -> Option<Result<ActionResult, ActionError>> {
+    emit_matched(emitter, &resolution);
+
+    let command = &resolution.candidate.command;
+    if !command.requires_confirmation {
+        return Some(run_resolution(dispatcher, emitter, &resolution).await);
+    }
+
+    crate::info!("Command '{}' is waiting for confirmation", command.trigger);
+    emitter.emit_command_confirmation_required(CommandConfirmationRequiredPayload {
+        command_id: command.id.to_string(),
+        trigger: command.trigger.clone(),
+        transcription: resolution.transcription.clone(),
+        timeout_ms: gate.timeout().as_millis() as u64,
+    });
+    gate.request(resolution);
+    None
+}
+
+/// Treat an utterance as the answer to a waiting confirmation or ambiguous match
+///
+/// Returns what the answer did when the utterance was used up as one, None
+/// when it should be handled as normal input.
+pub async fn handle_follow_up<C: CommandEventEmitter + ?Sized>(
+    text: &str,
+    dispatcher: &ActionDispatcher,
+    emitter: &C,
+    gate: &ConfirmationGate,
+    disambiguator: Option<&Disambiguator>,
+) -> Option<PipelineRecord> {
+    match gate.follow_up(text) {
+        Some(Answer::Confirmed(resolution)) => {
+            crate::info!(
+                "Command '{}' confirmed",
+                resolution.candidate.command.trigger
+            );
+            let result = run_resolution(dispatcher, emitter, &resolution).await;
+            return Some(PipelineRecord::ran(
+                PipelineMatch::FollowUp,
+                &resolution.candidate,
+                Some(&result),
+            ));
+        }
+        Some(Answer::Declined) => {
+            crate::info!("Command confirmation declined");
+            return Some(PipelineRecord::declined());
+        }
+        Some(Answer::NotAnAnswer) => {
+            crate::info!("Command confirmation cancelled, handling follow-up as normal input");
+        }
+        None => {}
+    }
+
+    match disambiguator.and_then(|d| d.follow_up(text)) {
+        Some(FollowUp::Chosen(resolution)) => {
+            crate::info!(
+                "Ambiguous match resolved to: {}",
+                resolution.candidate.command.trigger
+            );
+            let candidate = resolution.candidate.clone();
+            let result = run_or_confirm(dispatcher, emitter, gate, resolution).await;
+            Some(PipelineRecord::ran(
+                PipelineMatch::FollowUp,
+                &candidate,
+                result.as_ref(),
+            ))
+        }
+        Some(FollowUp::Cancelled) => {
+            crate::info!("Ambiguous command choice cancelled");
+            Some(PipelineRecord::declined())
+        }
+        Some(FollowUp::NotAChoice) => {
+            crate::debug!("Follow-up is not a choice, handling as normal input");
+            None
+        }
+        None => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "confirmation_test.rs"]
+mod tests;
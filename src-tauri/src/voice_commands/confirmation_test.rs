@@ -0,0 +1,217 @@
+use super::*;
+use crate::test_utils::mock_emitters::MockEmitter;
+use crate::turso::PipelineOutcome;
+use crate::voice_commands::actions::ScriptAction;
+use crate::voice_commands::disambiguation::PendingCandidate;
+use crate::voice_commands::executor::Action;
+use crate::voice_commands::registry::{ActionType, CommandDefinition};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Action that counts how often it ran
+#[derive(Default)]
+struct CountingAction {
+    runs: AtomicUsize,
+}
+
+#[async_trait]
+impl Action for CountingAction {
+    async fn execute(
+        &self,
+        _parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        self.runs.fetch_add(1, Ordering::SeqCst);
+        Ok(ActionResult {
+            message: "done".to_string(),
+            data: None,
+        })
+    }
+}
+
+fn resolution(trigger: &str, requires_confirmation: bool) -> Resolution {
+    Resolution {
+        candidate: PendingCandidate {
+            command: CommandDefinition {
+                id: Uuid::new_v4(),
+                trigger: trigger.to_string(),
+                action_type: ActionType::TypeText,
+                parameters: HashMap::new(),
+                enabled: true,
+                requires_confirmation,
+                aliases: Vec::new(),
+            },
+            parameters: HashMap::new(),
+            confidence: 0.82,
+        },
+        transcription: trigger.to_string(),
+    }
+}
+
+fn dispatcher(action: Arc<CountingAction>) -> ActionDispatcher {
+    ActionDispatcher::with_actions(
+        Arc::new(CountingAction::default()),
+        action,
+        Arc::new(CountingAction::default()),
+        Arc::new(ScriptAction::default()),
+    )
+}
+
+#[test]
+fn test_yes_confirms_the_waiting_command() {
+    let gate = ConfirmationGate::new();
+    gate.request(resolution("clear downloads", true));
+
+    match gate.follow_up("Yes, do it!") {
+        Some(Answer::Confirmed(resolution)) => {
+            assert_eq!(resolution.candidate.command.trigger, "clear downloads")
+        }
+        other => panic!("expected confirmation, got {:?}", other),
+    }
+    assert!(gate.follow_up("yes").is_none());
+}
+
+#[test]
+fn test_no_and_other_input_cancel() {
+    let gate = ConfirmationGate::new();
+    gate.request(resolution("clear downloads", true));
+    assert!(matches!(gate.follow_up("Nope."), Some(Answer::Declined)));
+
+    gate.request(resolution("clear downloads", true));
+    assert!(matches!(
+        gate.follow_up("what time is it"),
+        Some(Answer::NotAnAnswer)
+    ));
+    // The command doesn't wait for a later "yes"
+    assert!(gate.follow_up("yes").is_none());
+}
+
+#[test]
+fn test_expired_confirmation_is_ignored() {
+    let gate = ConfirmationGate::with_timeout(Duration::ZERO);
+    let waiting = resolution("clear downloads", true);
+    let id = waiting.candidate.command.id.to_string();
+    gate.request(waiting);
+
+    assert_eq!(
+        gate.confirm(&id).unwrap_err(),
+        ConfirmationError::NothingPending
+    );
+    gate.request(resolution("clear downloads", true));
+    assert!(gate.follow_up("yes").is_none());
+}
+
+#[test]
+fn test_confirm_by_id() {
+    let gate = ConfirmationGate::new();
+    let waiting = resolution("clear downloads", true);
+    let id = waiting.candidate.command.id.to_string();
+    gate.request(waiting);
+
+    assert_eq!(
+        gate.confirm("other").unwrap_err(),
+        ConfirmationError::UnknownCommand("other".to_string())
+    );
+    assert_eq!(
+        gate.confirm(&id).unwrap().candidate.command.trigger,
+        "clear downloads"
+    );
+    assert!(!gate.cancel());
+}
+
+#[tokio::test]
+async fn test_flagged_command_waits_for_confirmation() {
+    let action = Arc::new(CountingAction::default());
+    let dispatcher = dispatcher(action.clone());
+    let emitter = MockEmitter::new();
+    let gate = ConfirmationGate::new();
+
+    let result = run_or_confirm(
+        &dispatcher,
+        &emitter,
+        &gate,
+        resolution("clear downloads", true),
+    )
+    .await;
+
+    assert!(result.is_none());
+    assert_eq!(action.runs.load(Ordering::SeqCst), 0);
+    let required = emitter
+        .command_confirmation_required
+        .lock()
+        .unwrap()
+        .clone();
+    assert_eq!(required.len(), 1);
+    assert_eq!(required[0].trigger, "clear downloads");
+    assert_eq!(required[0].timeout_ms, DEFAULT_CONFIRMATION_TIMEOUT_MS);
+
+    let record = handle_follow_up("confirm", &dispatcher, &emitter, &gate, None)
+        .await
+        .unwrap();
+    assert_eq!(record.match_result, PipelineMatch::FollowUp);
+    assert_eq!(record.outcome, PipelineOutcome::Executed);
+    assert_eq!(action.runs.load(Ordering::SeqCst), 1);
+    assert_eq!(emitter.command_matched.lock().unwrap().len(), 1);
+    assert_eq!(emitter.command_executed.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_unflagged_command_runs_immediately() {
+    let action = Arc::new(CountingAction::default());
+    let dispatcher = dispatcher(action.clone());
+    let emitter = MockEmitter::new();
+    let gate = ConfirmationGate::new();
+
+    let result = run_or_confirm(
+        &dispatcher,
+        &emitter,
+        &gate,
+        resolution("type greeting", false),
+    )
+    .await;
+
+    assert!(result.unwrap().is_ok());
+    assert_eq!(action.runs.load(Ordering::SeqCst), 1);
+    assert!(emitter
+        .command_confirmation_required
+        .lock()
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_ambiguous_choice_still_needs_confirmation() {
+    let action = Arc::new(CountingAction::default());
+    let dispatcher = dispatcher(action.clone());
+    let emitter = MockEmitter::new();
+    let gate = ConfirmationGate::new();
+    let disambiguator = Disambiguator::new();
+    disambiguator.begin(
+        "clear down",
+        vec![
+            resolution("clear downloads", true).candidate,
+            resolution("clear desktop", false).candidate,
+        ],
+    );
+
+    let record = handle_follow_up("one", &dispatcher, &emitter, &gate, Some(&disambiguator))
+        .await
+        .unwrap();
+    assert_eq!(record.outcome, PipelineOutcome::AwaitingConfirmation);
+    assert_eq!(action.runs.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        emitter.command_confirmation_required.lock().unwrap().len(),
+        1
+    );
+
+    let record = handle_follow_up("no", &dispatcher, &emitter, &gate, Some(&disambiguator))
+        .await
+        .unwrap();
+    assert_eq!(record.outcome, PipelineOutcome::Declined);
+    assert_eq!(action.runs.load(Ordering::SeqCst), 0);
+    assert!(handle_follow_up("yes", &dispatcher, &emitter, &gate, Some(&disambiguator))
+        .await
+        .is_none());
+}
@@ -0,0 +1,317 @@
+// Disambiguation - resolves ambiguous command matches with a follow-up choice
+//
+// When the matcher can't decide between several commands, the candidates are
+// kept for a short window. During that window the next utterance can pick one
+// by position ("one", "the second one", "number 2") or by saying its trigger,
+// or drop the choice ("cancel", "never mind"). Anything else, or the window
+// running out, goes back to normal dictation.
+
+use crate::events::{
+    CommandEventEmitter, CommandExecutedPayload, CommandFailedPayload, CommandMatchedPayload,
+};
+use crate::util::{normalize_words, spoken_phrase};
+use crate::voice_commands::executor::{ActionDispatcher, ActionError, ActionResult};
+use crate::voice_commands::grammar::parse_spoken_number;
+use crate::voice_commands::matcher::DEFAULT_THRESHOLD;
+use crate::voice_commands::registry::CommandDefinition;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use strsim::normalized_levenshtein;
+
+/// How long a follow-up choice is accepted after an ambiguous match
+pub const DEFAULT_CHOICE_TIMEOUT_MS: u64 = 8_000;
+
+/// Filler words that may surround a spoken choice ("the second one")
+const FILLER_WORDS: &[&str] = &["number", "option", "choice", "the"];
+
+/// Ordinal words, in candidate order
+const ORDINALS: &[&str] = &[
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth", "tenth",
+];
+
+/// Phrases that drop the pending choice without running anything
+const CANCEL_PHRASES: &[&str] = &[
+    "cancel",
+    "never mind",
+    "nevermind",
+    "neither",
+    "none of them",
+];
+
+/// Errors from resolving a pending choice
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DisambiguationError {
+    /// No ambiguous match is waiting, or its window has run out
+    #[error("No ambiguous command is waiting for a choice")]
+    NothingPending,
+    /// The chosen command isn't one of the candidates
+    #[error("Command '{0}' is not one of the candidates")]
+    UnknownCandidate(String),
+}
+
+/// A command the user can choose, with the slot values it matched
+#[derive(Debug, Clone)]
+pub struct PendingCandidate {
+    pub command: CommandDefinition,
+    pub parameters: HashMap<String, String>,
+    pub confidence: f64,
+}
+
+/// The chosen candidate and the utterance that was ambiguous
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    pub candidate: PendingCandidate,
+    /// Original transcription, passed to the command instead of the choice
+    pub transcription: String,
+}
+
+/// What a follow-up utterance did to the pending choice
+#[derive(Debug, Clone)]
+pub enum FollowUp {
+    /// A candidate was picked
+    Chosen(Resolution),
+    /// The choice was dropped
+    Cancelled,
+    /// The utterance isn't a choice; treat it as normal input
+    NotAChoice,
+}
+
+struct PendingChoice {
+    transcription: String,
+    candidates: Vec<PendingCandidate>,
+    expires_at: Instant,
+}
+
+/// Holds the candidates of the last ambiguous match until they are resolved
+pub struct Disambiguator {
+    pending: Mutex<Option<PendingChoice>>,
+    timeout: Duration,
+}
+
+impl Default for Disambiguator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator {
+    pub fn new() -> Self {
+        Self::with_timeout(Duration::from_millis(DEFAULT_CHOICE_TIMEOUT_MS))
+    }
+
+    /// Create a disambiguator that accepts choices for `timeout`
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            pending: Mutex::new(None),
+            timeout,
+        }
+    }
+
+    /// How long a choice stays open
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Open a choice between `candidates`, replacing any earlier one
+    pub fn begin(&self, transcription: &str, candidates: Vec<PendingCandidate>) {
+        let choice = PendingChoice {
+            transcription: transcription.to_string(),
+            candidates,
+            expires_at: Instant::now() + self.timeout,
+        };
+        if let Ok(mut pending) = self.pending.lock() {
+            *pending = Some(choice);
+        }
+    }
+
+    /// Drop the pending choice; returns whether one was open
+    pub fn dismiss(&self) -> bool {
+        self.take_pending().is_some()
+    }
+
+    /// Apply a follow-up utterance to the pending choice
+    ///
+    /// Returns None when no choice is open. Any follow-up closes the choice,
+    /// so an unrelated utterance falls back to normal dictation.
+    pub fn follow_up(&self, text: &str) -> Option<FollowUp> {
+        let choice = self.take_pending()?;
+        if is_cancel(text) {
+            return Some(FollowUp::Cancelled);
+        }
+        let follow_up = match choose(text, &choice.candidates) {
+            Some(index) => FollowUp::Chosen(Resolution {
+                candidate: choice.candidates[index].clone(),
+                transcription: choice.transcription,
+            }),
+            None => FollowUp::NotAChoice,
+        };
+        Some(follow_up)
+    }
+
+    /// Resolve the pending choice by command ID (e.g. clicked in the overlay)
+    pub fn resolve(&self, command_id: &str) -> Result<Resolution, DisambiguationError> {
+        let mut pending = self
+            .pending
+            .lock()
+            .map_err(|_| DisambiguationError::NothingPending)?;
+        let choice = match pending.take() {
+            Some(choice) if Instant::now() < choice.expires_at => choice,
+            _ => return Err(DisambiguationError::NothingPending),
+        };
+        let index = choice
+            .candidates
+            .iter()
+            .position(|c| c.command.id.to_string() == command_id);
+        match index {
+            Some(index) => Ok(Resolution {
+                candidate: choice.candidates[index].clone(),
+                transcription: choice.transcription,
+            }),
+            None => {
+                // A stale click shouldn't close the choice
+                let id = command_id.to_string();
+                *pending = Some(choice);
+                Err(DisambiguationError::UnknownCandidate(id))
+            }
+        }
+    }
+
+    /// Take the pending choice if it hasn't expired
+    fn take_pending(&self) -> Option<PendingChoice> {
+        let choice = self.pending.lock().ok()?.take()?;
+        if Instant::now() >= choice.expires_at {
+            crate::debug!("Ambiguous command choice expired");
+            return None;
+        }
+        Some(choice)
+    }
+}
+
+fn is_cancel(text: &str) -> bool {
+    let spoken = spoken_phrase(text);
+    CANCEL_PHRASES.contains(&spoken.as_str())
+}
+
+/// Position named by an ordinal or a number, counting from one
+fn spoken_position(words: &[String]) -> Option<usize> {
+    let mut core: &[String] = words;
+    while let Some((first, rest)) = core.split_first() {
+        if rest.is_empty() || !FILLER_WORDS.contains(&first.as_str()) {
+            break;
+        }
+        core = rest;
+    }
+    // "the second one"
+    if let [ordinal, last] = core {
+        if last == "one" && ORDINALS.contains(&ordinal.as_str()) {
+            core = &core[..1];
+        }
+    }
+
+    if let [word] = core {
+        if let Some(i) = ORDINALS.iter().position(|o| o == word) {
+            return Some(i + 1);
+        }
+        // "1st", "2nd", "3rd"
+        let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        if !digits.is_empty() && digits.len() < word.len() {
+            return digits.parse().ok();
+        }
+    }
+    let core: Vec<&str> = core.iter().map(String::as_str).collect();
+    parse_spoken_number(&core).and_then(|n| usize::try_from(n).ok())
+}
+
+/// Index of the candidate a follow-up utterance picks, if any
+///
+/// Positions are tried first, then the candidates' triggers and aliases, where
+/// a near-miss only counts if it is closer to one command than to all others.
+pub fn choose(text: &str, candidates: &[PendingCandidate]) -> Option<usize> {
+    let words = normalize_words(text);
+    if words.is_empty() {
+        return None;
+    }
+    if let Some(position) = spoken_position(&words) {
+        return (1..=candidates.len())
+            .contains(&position)
+            .then(|| position - 1);
+    }
+
+    let spoken = words.join(" ");
+    let mut scores: Vec<(usize, f64)> = candidates
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let best = c
+                .command
+                .phrases()
+                .map(|phrase| normalized_levenshtein(&spoken, &spoken_phrase(phrase)))
+                .fold(0.0, f64::max);
+            (i, best)
+        })
+        .collect();
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    match scores.as_slice() {
+        [(i, best), rest @ ..]
+            if *best >= DEFAULT_THRESHOLD && rest.first().map_or(true, |(_, s)| s < best) =>
+        {
+            Some(*i)
+        }
+        _ => None,
+    }
+}
+
+/// Emit command_matched for a resolved command
+pub fn emit_matched<C: CommandEventEmitter + ?Sized>(emitter: &C, resolution: &Resolution) {
+    let command = &resolution.candidate.command;
+    emitter.emit_command_matched(CommandMatchedPayload {
+        transcription: resolution.transcription.clone(),
+        command_id: command.id.to_string(),
+        trigger: command.trigger.clone(),
+        confidence: resolution.candidate.confidence,
+    });
+}
+
+/// Run a resolved command and emit command_executed or command_failed
+pub async fn run_resolution<C: CommandEventEmitter + ?Sized>(
+    dispatcher: &ActionDispatcher,
+    emitter: &C,
+    resolution: &Resolution,
+) -> Result<ActionResult, ActionError> {
+    let Resolution {
+        candidate,
+        transcription,
+    } = resolution;
+    let command = &candidate.command;
+
+    let result = dispatcher
+        .execute_transcribed(command, &candidate.parameters, transcription)
+        .await;
+    match &result {
+        Ok(action_result) => {
+            crate::info!("Command executed: {}", action_result.message);
+            emitter.emit_command_executed(CommandExecutedPayload {
+                command_id: command.id.to_string(),
+                trigger: command.trigger.clone(),
+                message: action_result.message.clone(),
+                data: action_result.data.clone(),
+            });
+        }
+        Err(action_error) => {
+            crate::error!("Command execution failed: {}", action_error);
+            emitter.emit_command_failed(CommandFailedPayload {
+                command_id: command.id.to_string(),
+                trigger: command.trigger.clone(),
+                error_code: action_error.code.to_string(),
+                error_message: action_error.message.clone(),
+                data: action_error.data.clone(),
+            });
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+#[path = "disambiguation_test.rs"]
+mod tests;
@@ -0,0 +1,218 @@
+use super::*;
+use crate::test_utils::mock_emitters::MockEmitter;
+use crate::voice_commands::actions::ScriptAction;
+use crate::voice_commands::executor::{Action, SystemControlAction, TRANSCRIPTION_PARAM};
+use crate::voice_commands::registry::ActionType;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Action that records the parameters it was run with
+#[derive(Default)]
+struct RecordingAction {
+    calls: Mutex<Vec<HashMap<String, String>>>,
+}
+
+#[async_trait]
+impl Action for RecordingAction {
+    async fn execute(
+        &self,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        self.calls.lock().unwrap().push(parameters.clone());
+        Ok(ActionResult {
+            message: "done".to_string(),
+            data: None,
+        })
+    }
+}
+
+fn candidate(trigger: &str) -> PendingCandidate {
+    PendingCandidate {
+        command: CommandDefinition {
+            id: Uuid::new_v4(),
+            trigger: trigger.to_string(),
+            action_type: ActionType::TypeText,
+            parameters: HashMap::new(),
+            enabled: true,
+            requires_confirmation: false,
+            aliases: Vec::new(),
+        },
+        parameters: HashMap::new(),
+        confidence: 0.85,
+    }
+}
+
+fn candidates() -> Vec<PendingCandidate> {
+    vec![candidate("open slack"), candidate("open safari")]
+}
+
+#[test]
+fn test_chooses_by_position() {
+    let candidates = candidates();
+    for (text, expected) in [
+        ("one", 0),
+        ("Two.", 1),
+        ("2", 1),
+        ("number one", 0),
+        ("the second one", 1),
+        ("option 1", 0),
+        ("first", 0),
+        ("2nd", 1),
+    ] {
+        assert_eq!(choose(text, &candidates), Some(expected), "{}", text);
+    }
+    assert_eq!(choose("three", &candidates), None);
+    assert_eq!(choose("zero", &candidates), None);
+}
+
+#[test]
+fn test_chooses_by_trigger() {
+    let candidates = candidates();
+    assert_eq!(choose("open safari", &candidates), Some(1));
+    assert_eq!(choose("Open Slack!", &candidates), Some(0));
+    assert_eq!(choose("open safary", &candidates), Some(1));
+    assert_eq!(choose("what's the weather", &candidates), None);
+}
+
+#[test]
+fn test_chooses_by_alias() {
+    let mut slack = candidate("open slack");
+    slack.command.aliases = vec!["launch chat".to_string()];
+    let candidates = vec![slack, candidate("open safari")];
+    assert_eq!(choose("launch chat", &candidates), Some(0));
+}
+
+#[test]
+fn test_equally_close_triggers_are_not_chosen() {
+    let candidates = vec![candidate("open notes"), candidate("open nodes")];
+    assert_eq!(choose("open noxes", &candidates), None);
+}
+
+#[test]
+fn test_follow_up_resolves_with_original_transcription() {
+    let disambiguator = Disambiguator::new();
+    disambiguator.begin("open sa", candidates());
+
+    match disambiguator.follow_up("two") {
+        Some(FollowUp::Chosen(resolution)) => {
+            assert_eq!(resolution.candidate.command.trigger, "open safari");
+            assert_eq!(resolution.transcription, "open sa");
+        }
+        other => panic!("expected a choice, got {:?}", other),
+    }
+    // The choice is used up
+    assert!(disambiguator.follow_up("one").is_none());
+}
+
+#[test]
+fn test_unrelated_follow_up_closes_the_choice() {
+    let disambiguator = Disambiguator::new();
+    disambiguator.begin("open sa", candidates());
+
+    assert!(matches!(
+        disambiguator.follow_up("hello there"),
+        Some(FollowUp::NotAChoice)
+    ));
+    assert!(disambiguator.follow_up("one").is_none());
+}
+
+#[test]
+fn test_follow_up_can_cancel() {
+    let disambiguator = Disambiguator::new();
+    disambiguator.begin("open sa", candidates());
+
+    assert!(matches!(
+        disambiguator.follow_up("Never mind."),
+        Some(FollowUp::Cancelled)
+    ));
+    assert!(!disambiguator.dismiss());
+}
+
+#[test]
+fn test_expired_choice_is_ignored() {
+    let disambiguator = Disambiguator::with_timeout(Duration::ZERO);
+    disambiguator.begin("open sa", candidates());
+
+    assert!(disambiguator.follow_up("one").is_none());
+}
+
+#[test]
+fn test_resolve_by_id() {
+    let disambiguator = Disambiguator::new();
+    let candidates = candidates();
+    let safari_id = candidates[1].command.id.to_string();
+    disambiguator.begin("open sa", candidates);
+
+    // An unknown ID leaves the choice open
+    assert_eq!(
+        disambiguator.resolve("not-a-candidate").unwrap_err(),
+        DisambiguationError::UnknownCandidate("not-a-candidate".to_string())
+    );
+    let resolution = disambiguator.resolve(&safari_id).unwrap();
+    assert_eq!(resolution.candidate.command.trigger, "open safari");
+    assert_eq!(
+        disambiguator.resolve(&safari_id).unwrap_err(),
+        DisambiguationError::NothingPending
+    );
+}
+
+#[tokio::test]
+async fn test_run_resolution_runs_command_and_emits_events() {
+    let type_text = Arc::new(RecordingAction::default());
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(RecordingAction::default()),
+        type_text.clone(),
+        Arc::new(RecordingAction::default()),
+        Arc::new(ScriptAction::default()),
+    );
+    let emitter = MockEmitter::new();
+    let resolution = Resolution {
+        candidate: candidate("type greeting"),
+        transcription: "type greet".to_string(),
+    };
+
+    let result = run_resolution(&dispatcher, &emitter, &resolution).await;
+
+    assert!(result.is_ok());
+    let calls = type_text.calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0][TRANSCRIPTION_PARAM], "type greet");
+    let executed = emitter.command_executed.lock().unwrap();
+    assert_eq!(executed[0].trigger, "type greeting");
+}
+
+#[tokio::test]
+async fn test_failed_macro_reports_its_steps_in_command_failed() {
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(RecordingAction::default()),
+        Arc::new(RecordingAction::default()),
+        Arc::new(SystemControlAction),
+        Arc::new(ScriptAction::default()),
+    );
+    let emitter = MockEmitter::new();
+    let mut macro_candidate = candidate("start standup");
+    macro_candidate.command.action_type = ActionType::Macro;
+    macro_candidate.command.parameters = HashMap::from([(
+        "steps".to_string(),
+        serde_json::json!([
+            {"action_type": "open_app", "parameters": {"app": "Slack"}},
+            {"action_type": "system_control", "parameters": {}}
+        ])
+        .to_string(),
+    )]);
+    let resolution = Resolution {
+        candidate: macro_candidate,
+        transcription: "start standup".to_string(),
+    };
+
+    assert!(run_resolution(&dispatcher, &emitter, &resolution)
+        .await
+        .is_err());
+
+    let failed = emitter.command_failed.lock().unwrap();
+    let steps = &failed[0].data.as_ref().unwrap()["steps"];
+    assert_eq!(steps[0]["status"], "succeeded");
+    assert_eq!(steps[1]["status"], "failed");
+    assert_eq!(steps[1]["errorCode"], "MISSING_PARAM");
+}
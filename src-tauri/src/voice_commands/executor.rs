@@ -1,15 +1,32 @@
 // Action executor - dispatches commands to action implementations
 
 use crate::events::{command_events, CommandExecutedPayload, CommandFailedPayload};
-use crate::voice_commands::actions::{AppLauncherAction, TextInputAction};
+use crate::storage::WindowContext;
+use crate::transcription::{InjectedOutput, OutputHistory};
+use crate::turso::PipelineMatch;
+use crate::voice_commands::actions::rhai_script::{
+    run_script_blocking, MockScriptHost, ScriptWindow, SCRIPT_TIMEOUT,
+};
+use crate::voice_commands::actions::{
+    AppLauncherAction, MacroAction, RhaiAction, ScriptAction, ScriptConfig, SendKeysAction,
+    TextInputAction, WebhookAction,
+};
+use crate::voice_commands::confirmation::ConfirmationGate;
+use crate::voice_commands::disambiguation::{Disambiguator, PendingCandidate};
+use crate::voice_commands::grammar::bind_parameters;
+use crate::voice_commands::pipeline_log::PipelineRecord;
 use crate::voice_commands::registry::{ActionType, CommandDefinition};
 use async_trait::async_trait;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use tauri::{AppHandle, Emitter};
 use uuid::Uuid;
 
+/// Parameter holding the transcribed text that triggered a command
+pub const TRANSCRIPTION_PARAM: &str = "transcription";
+
 /// Result of an action execution
 #[derive(Debug, Clone, Serialize)]
 pub struct ActionResult {
@@ -48,6 +65,8 @@ pub enum ActionErrorCode {
     OpenFailed,
     /// Failed to close application
     CloseFailed,
+    /// Action took longer than allowed
+    Timeout,
     /// Platform not supported for this action (used on non-macOS platforms)
     #[allow(dead_code)]
     UnsupportedPlatform,
@@ -67,6 +86,7 @@ impl std::fmt::Display for ActionErrorCode {
             ActionErrorCode::InvalidAppName => "INVALID_APP_NAME",
             ActionErrorCode::OpenFailed => "OPEN_FAILED",
             ActionErrorCode::CloseFailed => "CLOSE_FAILED",
+            ActionErrorCode::Timeout => "TIMEOUT",
             ActionErrorCode::UnsupportedPlatform => "UNSUPPORTED_PLATFORM",
         };
         write!(f, "{}", s)
@@ -81,6 +101,8 @@ pub struct ActionError {
     pub code: ActionErrorCode,
     /// Human-readable error message
     pub message: String,
+    /// Additional error data from the action (e.g. per-step macro results)
+    pub data: Option<serde_json::Value>,
 }
 
 /// Trait for action implementations
@@ -88,6 +110,12 @@ pub struct ActionError {
 pub trait Action: Send + Sync {
     /// Execute the action with the given parameters
     async fn execute(&self, parameters: &HashMap<String, String>) -> Result<ActionResult, ActionError>;
+
+    /// Parameters that undo a successful run with `parameters`, if the action
+    /// can be undone (used when a macro rolls back)
+    fn undo_parameters(&self, _parameters: &HashMap<String, String>) -> Option<HashMap<String, String>> {
+        None
+    }
 }
 
 // CommandExecutedPayload and CommandFailedPayload are imported from events.rs
@@ -102,41 +130,35 @@ impl Action for SystemControlAction {
         let control = parameters.get("control").ok_or_else(|| ActionError {
             code: ActionErrorCode::MissingParam,
             message: "Missing 'control' parameter".to_string(),
-        })?;
-
-        // Stub implementation
-        Ok(ActionResult {
-            message: format!("Would execute system control: {}", control),
             data: None,
-        })
-    }
-}
-
-/// Stub implementation for Custom action
-pub struct CustomAction;
-
-#[async_trait]
-impl Action for CustomAction {
-    async fn execute(&self, parameters: &HashMap<String, String>) -> Result<ActionResult, ActionError> {
-        let script = parameters.get("script").ok_or_else(|| ActionError {
-            code: ActionErrorCode::MissingParam,
-            message: "Missing 'script' parameter".to_string(),
         })?;
 
         // Stub implementation
         Ok(ActionResult {
-            message: format!("Would execute custom script: {}", script),
+            message: format!("Would execute system control: {}", control),
             data: None,
         })
     }
 }
 
 /// Action dispatcher - routes commands to their implementations
+#[derive(Clone)]
 pub struct ActionDispatcher {
     open_app: Arc<dyn Action>,
     type_text: Arc<dyn Action>,
     system_control: Arc<dyn Action>,
     custom: Arc<dyn Action>,
+    webhook: Arc<dyn Action>,
+    send_keys: Arc<dyn Action>,
+    /// Where typed text is remembered for "scratch that"
+    output_history: Option<Arc<OutputHistory>>,
+    /// Gives Rhai scripts the clipboard and stored commands
+    app_handle: Option<AppHandle>,
+    /// Commands already running around the actions this dispatcher runs,
+    /// outermost first (stops scripts running commands in a loop)
+    call_chain: Vec<Uuid>,
+    /// When the outermost running script must finish
+    script_deadline: Option<Instant>,
 }
 
 impl Default for ActionDispatcher {
@@ -152,7 +174,13 @@ impl ActionDispatcher {
             open_app: Arc::new(AppLauncherAction::new()),
             type_text: Arc::new(TextInputAction::new()),
             system_control: Arc::new(SystemControlAction),
-            custom: Arc::new(CustomAction),
+            custom: Arc::new(ScriptAction::default()),
+            webhook: Arc::new(WebhookAction::new()),
+            send_keys: Arc::new(SendKeysAction::new()),
+            output_history: None,
+            app_handle: None,
+            call_chain: Vec::new(),
+            script_deadline: None,
         }
     }
 
@@ -169,9 +197,49 @@ impl ActionDispatcher {
             type_text,
             system_control,
             custom,
+            webhook: Arc::new(WebhookAction::new()),
+            send_keys: Arc::new(SendKeysAction::new()),
+            output_history: None,
+            app_handle: None,
+            call_chain: Vec::new(),
+            script_deadline: None,
         }
     }
 
+    /// Use the given configuration for Custom script commands
+    pub fn with_script_config(mut self, config: ScriptConfig) -> Self {
+        self.custom = Arc::new(ScriptAction::new(config));
+        self
+    }
+
+    /// Remember text typed by TypeText commands in `history`
+    pub fn with_output_history(mut self, history: Arc<OutputHistory>) -> Self {
+        self.output_history = Some(history);
+        self
+    }
+
+    /// Let Rhai scripts reach the app (clipboard, stored commands)
+    pub fn with_app_handle(mut self, app_handle: AppHandle) -> Self {
+        self.app_handle = Some(app_handle);
+        self
+    }
+
+    /// Make scripts run by this dispatcher's actions finish by `deadline`
+    pub fn with_script_deadline(mut self, deadline: Instant) -> Self {
+        self.script_deadline = Some(deadline);
+        self
+    }
+
+    /// Commands already running around this dispatcher's actions, outermost first
+    pub fn call_chain(&self) -> &[Uuid] {
+        &self.call_chain
+    }
+
+    /// Deadline of the outermost running script, if any
+    pub fn script_deadline(&self) -> Option<Instant> {
+        self.script_deadline
+    }
+
     /// Get the action implementation for a given action type
     pub fn get_action(&self, action_type: &ActionType) -> Arc<dyn Action> {
         match action_type {
@@ -179,19 +247,90 @@ impl ActionDispatcher {
             ActionType::TypeText => self.type_text.clone(),
             ActionType::SystemControl => self.system_control.clone(),
             ActionType::Custom => self.custom.clone(),
+            ActionType::Webhook => self.webhook.clone(),
+            ActionType::SendKeys => self.send_keys.clone(),
+            // Macro steps run through this dispatcher's actions
+            ActionType::Macro => Arc::new(MacroAction::new(self.clone())),
+            // Script calls like type_text also run through this dispatcher
+            ActionType::Rhai => Arc::new(RhaiAction::new(self.clone(), self.app_handle.clone())),
+        }
+    }
+
+    /// Run one action, remembering any text it types for "scratch that"
+    ///
+    /// Top-level commands, macro steps and script calls all go through here,
+    /// so typed text is recorded however it was triggered.
+    pub async fn run_action(
+        &self,
+        action_type: &ActionType,
+        parameters: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let result = self.get_action(action_type).execute(parameters).await;
+
+        if let (ActionType::TypeText, Some(history), Ok(action_result)) =
+            (action_type, &self.output_history, &result)
+        {
+            let typed = action_result
+                .data
+                .as_ref()
+                .and_then(|data| data.get("typed"))
+                .and_then(|typed| typed.as_str());
+            if let Some(typed) = typed {
+                let app = WindowContext::capture().app_name;
+                history.record(app.as_deref(), InjectedOutput::typed(typed.to_string()));
+            }
         }
+        result
     }
 
     /// Execute a command asynchronously
     pub async fn execute(&self, command: &CommandDefinition) -> Result<ActionResult, ActionError> {
-        let action = self.get_action(&command.action_type);
-        action.execute(&command.parameters).await
+        // Macro steps and script calls see this command as running
+        let mut running = self.clone();
+        running.call_chain.push(command.id);
+        running
+            .run_action(&command.action_type, &command.parameters)
+            .await
+    }
+
+    /// Validate values matched for the trigger's slots, then execute
+    ///
+    /// Slot values that don't fit their type fail with `InvalidParameter`
+    /// (or `MissingParam`) without running the action.
+    pub async fn execute_with_slots(
+        &self,
+        command: &CommandDefinition,
+        slot_values: &HashMap<String, String>,
+    ) -> Result<ActionResult, ActionError> {
+        let bound = bind_parameters(command, slot_values)?;
+        self.execute(&bound).await
+    }
+
+    /// Execute a command matched from speech
+    ///
+    /// Like `execute_with_slots`, and additionally passes the transcribed
+    /// text to the action as the `transcription` parameter.
+    pub async fn execute_transcribed(
+        &self,
+        command: &CommandDefinition,
+        slot_values: &HashMap<String, String>,
+        transcription: &str,
+    ) -> Result<ActionResult, ActionError> {
+        let mut bound = bind_parameters(command, slot_values)?;
+        bound
+            .parameters
+            .insert(TRANSCRIPTION_PARAM.to_string(), transcription.to_string());
+        self.execute(&bound).await
     }
 }
 
 /// State for the executor
 pub struct ExecutorState {
     pub dispatcher: Arc<ActionDispatcher>,
+    /// Pending choice for ambiguous matches
+    pub disambiguator: Arc<Disambiguator>,
+    /// Command waiting for confirmation
+    pub confirmations: Arc<ConfirmationGate>,
 }
 
 impl Default for ExecutorState {
@@ -204,17 +343,55 @@ impl ExecutorState {
     pub fn new() -> Self {
         Self {
             dispatcher: Arc::new(ActionDispatcher::new()),
+            disambiguator: Arc::new(Disambiguator::new()),
+            confirmations: Arc::new(ConfirmationGate::new()),
+        }
+    }
+
+    /// Create executor state that runs commands with the given dispatcher
+    pub fn with_dispatcher(dispatcher: ActionDispatcher) -> Self {
+        Self {
+            dispatcher: Arc::new(dispatcher),
+            disambiguator: Arc::new(Disambiguator::new()),
+            confirmations: Arc::new(ConfirmationGate::new()),
         }
     }
 }
 
+/// Run a Rhai command against a mock host
+///
+/// Nothing is typed, pressed or run; the result data lists the calls the
+/// script made under "calls".
+async fn dry_run_script(command: &CommandDefinition) -> Result<ActionResult, ActionError> {
+    if command.action_type != ActionType::Rhai {
+        return Err(ActionError {
+            code: ActionErrorCode::InvalidParameter,
+            message: "Only Rhai script commands can be dry run".to_string(),
+            data: None,
+        });
+    }
+
+    let mut parameters = command.parameters.clone();
+    parameters.insert(TRANSCRIPTION_PARAM.to_string(), command.trigger.clone());
+    let host = Arc::new(MockScriptHost::with_window(ScriptWindow::capture()));
+    let mut result = run_script_blocking(&parameters, host.clone(), SCRIPT_TIMEOUT).await?;
+    if let Some(serde_json::Value::Object(data)) = result.data.as_mut() {
+        data.insert("calls".to_string(), serde_json::json!(host.calls()));
+    }
+    Ok(result)
+}
+
 /// Test a command by ID - executes immediately and returns result
+///
+/// With `dry_run` set, a Rhai script command runs against mocks instead
+/// (see `dry_run_script`) and nothing is recorded.
 #[tauri::command]
 pub async fn test_command(
     app_handle: AppHandle,
     turso_client: tauri::State<'_, crate::voice_commands::TursoClientState>,
     executor_state: tauri::State<'_, ExecutorState>,
     id: String,
+    dry_run: Option<bool>,
 ) -> Result<ActionResult, String> {
     let uuid = Uuid::parse_str(&id).map_err(|e| format!("Invalid UUID: {}", e))?;
 
@@ -229,7 +406,26 @@ pub async fn test_command(
         .find(|c| c.id == uuid)
         .ok_or_else(|| format!("Command not found: {}", id))?;
 
-    let result = executor_state.dispatcher.execute(&command).await;
+    if dry_run.unwrap_or(false) {
+        return dry_run_script(&command).await.map_err(|e| e.to_string());
+    }
+
+    // Testing behaves as if the trigger phrase itself had been spoken
+    let started = Instant::now();
+    let mut spoken = command.clone();
+    spoken
+        .parameters
+        .insert(TRANSCRIPTION_PARAM.to_string(), command.trigger.clone());
+    let result = executor_state.dispatcher.execute(&spoken).await;
+
+    let candidate = PendingCandidate {
+        command: command.clone(),
+        parameters: HashMap::new(),
+        confidence: 1.0,
+    };
+    PipelineRecord::ran(PipelineMatch::Manual, &candidate, Some(&result))
+        .write(&turso_client, None, started.elapsed())
+        .await;
 
     match &result {
         Ok(action_result) => {
@@ -237,6 +433,7 @@ pub async fn test_command(
                 command_id: command.id.to_string(),
                 trigger: command.trigger.clone(),
                 message: action_result.message.clone(),
+                data: action_result.data.clone(),
             };
             let _ = app_handle.emit(command_events::COMMAND_EXECUTED, payload);
         }
@@ -246,6 +443,7 @@ pub async fn test_command(
                 trigger: command.trigger.clone(),
                 error_code: action_error.code.to_string(),
                 error_message: action_error.message.clone(),
+                data: action_error.data.clone(),
             };
             let _ = app_handle.emit(command_events::COMMAND_FAILED, payload);
         }
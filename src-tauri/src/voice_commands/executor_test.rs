@@ -1,5 +1,5 @@
 use super::*;
-use crate::voice_commands::actions::{AppLauncherAction, TextInputAction};
+use crate::voice_commands::actions::{AppLauncherAction, ScriptAction, TextInputAction};
 use crate::voice_commands::registry::ActionType;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -28,6 +28,7 @@ impl MockAction {
             result: Err(ActionError {
                 code,
                 message: message.to_string(),
+                data: None,
             }),
             execution_count: AtomicUsize::new(0),
         }
@@ -58,6 +59,8 @@ fn create_test_command(action_type: ActionType) -> CommandDefinition {
             ("script".to_string(), "custom.sh".to_string()),
         ]),
         enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
     }
 }
 
@@ -68,7 +71,7 @@ async fn test_dispatch_open_app_action() {
         mock.clone(),
         Arc::new(TextInputAction::new()),
         Arc::new(SystemControlAction),
-        Arc::new(CustomAction),
+        Arc::new(ScriptAction::default()),
     );
 
     let command = create_test_command(ActionType::OpenApp);
@@ -86,7 +89,7 @@ async fn test_dispatch_type_text_action() {
         Arc::new(AppLauncherAction::new()),
         mock.clone(),
         Arc::new(SystemControlAction),
-        Arc::new(CustomAction),
+        Arc::new(ScriptAction::default()),
     );
 
     let command = create_test_command(ActionType::TypeText);
@@ -104,7 +107,7 @@ async fn test_action_failure_returns_error() {
         mock.clone(),
         Arc::new(TextInputAction::new()),
         Arc::new(SystemControlAction),
-        Arc::new(CustomAction),
+        Arc::new(ScriptAction::default()),
     );
 
     let command = create_test_command(ActionType::OpenApp);
@@ -165,13 +168,13 @@ async fn test_multiple_actions_execute_concurrently() {
         action1,
         Arc::new(TextInputAction::new()),
         Arc::new(SystemControlAction),
-        Arc::new(CustomAction),
+        Arc::new(ScriptAction::default()),
     );
     let dispatcher2 = ActionDispatcher::with_actions(
         action2,
         Arc::new(TextInputAction::new()),
         Arc::new(SystemControlAction),
-        Arc::new(CustomAction),
+        Arc::new(ScriptAction::default()),
     );
 
     let cmd1 = create_test_command(ActionType::OpenApp);
@@ -194,11 +197,8 @@ async fn test_multiple_actions_execute_concurrently() {
 async fn test_stub_action_types_dispatch_correctly() {
     let dispatcher = ActionDispatcher::new();
 
-    // Test stub action types only (OpenApp and TypeText use real implementations with system dependencies)
-    let test_cases = vec![
-        (ActionType::SystemControl, "Would execute system control"),
-        (ActionType::Custom, "Would execute custom script"),
-    ];
+    // Test stub action types only (OpenApp, TypeText and Custom use real implementations with system dependencies)
+    let test_cases = vec![(ActionType::SystemControl, "Would execute system control")];
 
     for (action_type, expected_prefix) in test_cases {
         let command = create_test_command(action_type.clone());
@@ -225,7 +225,7 @@ async fn test_type_text_dispatches_to_text_input() {
         Arc::new(AppLauncherAction::new()),
         mock.clone(),
         Arc::new(SystemControlAction),
-        Arc::new(CustomAction),
+        Arc::new(ScriptAction::default()),
     );
 
     let command = create_test_command(ActionType::TypeText);
@@ -243,7 +243,7 @@ async fn test_open_app_dispatches_to_app_launcher() {
         mock.clone(),
         Arc::new(TextInputAction::new()),
         Arc::new(SystemControlAction),
-        Arc::new(CustomAction),
+        Arc::new(ScriptAction::default()),
     );
 
     let command = create_test_command(ActionType::OpenApp);
@@ -253,3 +253,134 @@ async fn test_open_app_dispatches_to_app_launcher() {
     assert_eq!(mock.count(), 1);
 }
 
+
+#[tokio::test]
+async fn test_execute_with_slots_binds_slot_values() {
+    let dispatcher = ActionDispatcher::new();
+    let mut command = create_test_command(ActionType::SystemControl);
+    command.trigger = "move {window} to {display:left|right}".to_string();
+    command
+        .parameters
+        .insert("control".to_string(), "move {window} {display}".to_string());
+    let slots = HashMap::from([
+        ("window".to_string(), "Safari".to_string()),
+        ("display".to_string(), "left".to_string()),
+    ]);
+
+    let result = dispatcher.execute_with_slots(&command, &slots).await.unwrap();
+
+    assert_eq!(result.message, "Would execute system control: move Safari left");
+}
+
+#[tokio::test]
+async fn test_execute_with_slots_rejects_invalid_values_before_running() {
+    let mock = Arc::new(MockAction::new_success("Volume set"));
+    let dispatcher = ActionDispatcher::with_actions(
+        Arc::new(AppLauncherAction::new()),
+        Arc::new(TextInputAction::new()),
+        mock.clone(),
+        Arc::new(ScriptAction::default()),
+    );
+    let mut command = create_test_command(ActionType::SystemControl);
+    command.trigger = "volume {level:number}".to_string();
+
+    let slots = HashMap::from([("level".to_string(), "loud".to_string())]);
+    let err = dispatcher.execute_with_slots(&command, &slots).await.unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::InvalidParameter);
+
+    let err = dispatcher
+        .execute_with_slots(&command, &HashMap::new())
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::MissingParam);
+    assert_eq!(mock.count(), 0);
+}
+
+/// Dispatcher whose TypeText action reports typing "Hello"
+fn typing_dispatcher(history: &Arc<OutputHistory>) -> ActionDispatcher {
+    let typed = Arc::new(MockAction {
+        result: Ok(ActionResult {
+            message: "Typed 5 characters".to_string(),
+            data: Some(serde_json::json!({ "typed": "Hello", "length": 5 })),
+        }),
+        execution_count: AtomicUsize::new(0),
+    });
+    ActionDispatcher::with_actions(
+        Arc::new(AppLauncherAction::new()),
+        typed,
+        Arc::new(SystemControlAction),
+        Arc::new(ScriptAction::default()),
+    )
+    .with_output_history(history.clone())
+}
+
+#[tokio::test]
+async fn test_typed_text_is_remembered_for_scratch_that() {
+    let history = Arc::new(OutputHistory::new());
+    let dispatcher = typing_dispatcher(&history);
+
+    dispatcher
+        .execute(&create_test_command(ActionType::TypeText))
+        .await
+        .unwrap();
+    let _ = dispatcher
+        .execute(&create_test_command(ActionType::SystemControl))
+        .await;
+
+    let app = WindowContext::capture().app_name;
+    let output = history.take_last(app.as_deref()).unwrap();
+    assert_eq!(output.text, "Hello");
+    assert!(history.take_last(app.as_deref()).is_none());
+}
+
+#[tokio::test]
+async fn test_text_typed_by_macro_step_is_remembered() {
+    let history = Arc::new(OutputHistory::new());
+    let mut command = create_test_command(ActionType::Macro);
+    command.parameters.insert(
+        "steps".to_string(),
+        serde_json::json!([{ "action_type": "type_text", "parameters": { "text": "Hello" } }])
+            .to_string(),
+    );
+
+    typing_dispatcher(&history).execute(&command).await.unwrap();
+
+    let app = WindowContext::capture().app_name;
+    assert_eq!(history.take_last(app.as_deref()).unwrap().text, "Hello");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_text_typed_by_script_is_remembered() {
+    let history = Arc::new(OutputHistory::new());
+    let mut command = create_test_command(ActionType::Rhai);
+    command
+        .parameters
+        .insert("source".to_string(), r#"type_text("Hello")"#.to_string());
+
+    typing_dispatcher(&history).execute(&command).await.unwrap();
+
+    let app = WindowContext::capture().app_name;
+    assert_eq!(history.take_last(app.as_deref()).unwrap().text, "Hello");
+}
+
+#[tokio::test]
+async fn test_dry_run_reports_script_calls_without_running_them() {
+    let mut command = create_test_command(ActionType::Rhai);
+    command.parameters.insert(
+        "source".to_string(),
+        r#"type_text("Hello " + params.transcription); "done""#.to_string(),
+    );
+
+    let result = dry_run_script(&command).await.unwrap();
+    assert_eq!(result.message, "done");
+    let calls = &result.data.unwrap()["calls"];
+    assert_eq!(
+        calls,
+        &serde_json::json!([{ "call": "typeText", "value": "Hello test command" }])
+    );
+
+    let err = dry_run_script(&create_test_command(ActionType::TypeText))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, ActionErrorCode::InvalidParameter);
+}
@@ -0,0 +1,488 @@
+// Trigger grammar - typed slots and optional words in trigger phrases
+//
+// Syntax, by example:
+//   "type {text}"                      free text slot (one or more words)
+//   "volume {level:number}"            number slot ("twenty five" -> "25")
+//   "move {window} to {display:left|right|main}"
+//                                      enum slot, value is the matching choice
+//   "[please] open {app}"              optional words
+//
+// Literal words match case-insensitively, ignoring surrounding punctuation.
+// Slots can appear anywhere; when several ways to split the input exist the
+// first (leftmost-shortest) assignment that satisfies every slot wins.
+
+use crate::util::{normalize_word, normalize_words, spoken_phrase};
+use crate::voice_commands::actions::self_templated_params;
+use crate::voice_commands::executor::{ActionError, ActionErrorCode};
+use crate::voice_commands::registry::{normalize_phrase, ActionType, CommandDefinition};
+use std::collections::HashMap;
+
+/// Errors in a trigger phrase's grammar
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum GrammarError {
+    /// A `{` or `[` without its closing bracket
+    #[error("Unclosed '{0}' in trigger")]
+    Unclosed(char),
+    /// A closing bracket without an opening one
+    #[error("Unexpected '{0}' in trigger")]
+    Unexpected(char),
+    /// `{}` or `{:number}`
+    #[error("Slot name cannot be empty")]
+    EmptySlotName,
+    /// `{x:something}` where something is not a type or choice list
+    #[error("Unknown type '{kind}' for slot '{name}' (use number, text or a|b|c choices)")]
+    UnknownSlotType { name: String, kind: String },
+    /// `{x:a||b}` or `{x:|}`
+    #[error("Slot '{0}' has an empty choice")]
+    EmptyChoice(String),
+    /// The same slot name used twice
+    #[error("Slot '{0}' appears more than once")]
+    DuplicateSlot(String),
+    /// `[]` or an optional group containing a slot
+    #[error("Optional groups must contain one or more plain words")]
+    InvalidOptional,
+    /// An alias that is empty or only whitespace
+    #[error("Alias phrases cannot be empty")]
+    EmptyAlias,
+    /// An alias whose slots differ from the trigger's
+    #[error("Alias '{0}' must use the same slots as the trigger")]
+    AliasSlotMismatch(String),
+    /// The same phrase given twice for one command
+    #[error("'{0}' is listed more than once")]
+    DuplicatePhrase(String),
+}
+
+/// Errors in extracted slot values
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum SlotError {
+    /// A slot of the trigger has no value
+    #[error("Missing value for '{0}'")]
+    Missing(String),
+    /// A number slot whose value is not a number
+    #[error("'{value}' is not a number (for '{name}')")]
+    InvalidNumber { name: String, value: String },
+    /// An enum slot whose value is not one of its choices
+    #[error("'{value}' is not one of {choices:?} (for '{name}')")]
+    InvalidChoice {
+        name: String,
+        value: String,
+        choices: Vec<String>,
+    },
+}
+
+/// Type of value a slot accepts
+#[derive(Debug, Clone, PartialEq)]
+pub enum SlotKind {
+    /// Any one or more words, kept as spoken
+    Text,
+    /// A whole number, spoken as words or digits
+    Number,
+    /// One of a fixed set of (possibly multi-word) choices
+    Choice(Vec<String>),
+}
+
+/// A named slot in a trigger
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slot {
+    pub name: String,
+    pub kind: SlotKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Optional(Vec<String>),
+    Slot(Slot),
+}
+
+/// A parsed trigger phrase
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerPattern {
+    tokens: Vec<Token>,
+}
+
+impl TriggerPattern {
+    /// Parse a trigger phrase
+    pub fn parse(trigger: &str) -> Result<Self, GrammarError> {
+        let mut tokens = Vec::new();
+        let mut rest = trigger;
+
+        while let Some(start) = rest.find(['{', '[', '}', ']']) {
+            push_words(&mut tokens, &rest[..start]);
+            let open = rest[start..].chars().next().unwrap_or_default();
+            let close = match open {
+                '{' => '}',
+                '[' => ']',
+                other => return Err(GrammarError::Unexpected(other)),
+            };
+            let body_start = start + 1;
+            let body_len = rest[body_start..]
+                .find(close)
+                .ok_or(GrammarError::Unclosed(open))?;
+            let body = &rest[body_start..body_start + body_len];
+            if body.contains(['{', '[']) {
+                return Err(GrammarError::Unclosed(open));
+            }
+
+            if open == '{' {
+                tokens.push(Token::Slot(parse_slot(body)?));
+            } else {
+                let words = normalize_words(body);
+                if words.is_empty() || body.contains(['}', ']']) {
+                    return Err(GrammarError::InvalidOptional);
+                }
+                tokens.push(Token::Optional(words));
+            }
+            rest = &rest[body_start + body_len + 1..];
+        }
+        push_words(&mut tokens, rest);
+
+        let pattern = Self { tokens };
+        let mut seen = Vec::new();
+        for slot in pattern.slots() {
+            if seen.contains(&slot.name) {
+                return Err(GrammarError::DuplicateSlot(slot.name.clone()));
+            }
+            seen.push(slot.name.clone());
+        }
+        Ok(pattern)
+    }
+
+    /// Whether the trigger uses slots or optional words
+    ///
+    /// Plain triggers are better served by exact and fuzzy matching.
+    pub fn has_grammar(&self) -> bool {
+        self.tokens.iter().any(|t| !matches!(t, Token::Word(_)))
+    }
+
+    /// Slots in the order they appear
+    pub fn slots(&self) -> impl Iterator<Item = &Slot> {
+        self.tokens.iter().filter_map(|t| match t {
+            Token::Slot(slot) => Some(slot),
+            _ => None,
+        })
+    }
+
+    /// Match spoken input, returning the slot values on success
+    pub fn match_input(&self, input: &str) -> Option<HashMap<String, String>> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let mut values = HashMap::new();
+        match_tokens(&self.tokens, &words, &mut values).then_some(values)
+    }
+
+    /// Check that every slot has a value its type accepts
+    pub fn validate(&self, values: &HashMap<String, String>) -> Result<(), SlotError> {
+        for slot in self.slots() {
+            let value = values
+                .get(&slot.name)
+                .filter(|v| !v.trim().is_empty())
+                .ok_or_else(|| SlotError::Missing(slot.name.clone()))?;
+            let words: Vec<&str> = value.split_whitespace().collect();
+            match &slot.kind {
+                SlotKind::Text => {}
+                SlotKind::Number => {
+                    if parse_spoken_number(&words).is_none() {
+                        return Err(SlotError::InvalidNumber {
+                            name: slot.name.clone(),
+                            value: value.clone(),
+                        });
+                    }
+                }
+                SlotKind::Choice(choices) => {
+                    if match_choice(choices, &words).is_none() {
+                        return Err(SlotError::InvalidChoice {
+                            name: slot.name.clone(),
+                            value: value.clone(),
+                            choices: choices.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check a command's aliases against its trigger
+///
+/// Every alias must parse and declare the same slots as the trigger, so slot
+/// values bind the same way whichever phrase was spoken. No two phrases of the
+/// command may be the same after normalization.
+pub fn validate_aliases(trigger: &str, aliases: &[String]) -> Result<(), GrammarError> {
+    let sorted_slots = |pattern: &TriggerPattern| {
+        let mut slots: Vec<Slot> = pattern.slots().cloned().collect();
+        slots.sort_by(|a, b| a.name.cmp(&b.name));
+        slots
+    };
+    let expected = sorted_slots(&TriggerPattern::parse(trigger)?);
+
+    let mut seen = vec![normalize_phrase(trigger)];
+    for alias in aliases {
+        let key = normalize_phrase(alias);
+        if key.is_empty() {
+            return Err(GrammarError::EmptyAlias);
+        }
+        if seen.contains(&key) {
+            return Err(GrammarError::DuplicatePhrase(alias.trim().to_string()));
+        }
+        if sorted_slots(&TriggerPattern::parse(alias)?) != expected {
+            return Err(GrammarError::AliasSlotMismatch(alias.trim().to_string()));
+        }
+        seen.push(key);
+    }
+    Ok(())
+}
+
+/// Validate slot values and merge them into a copy of the command
+///
+/// Slot values become action parameters of the same name, and `{name}`
+/// placeholders in the command's stored parameters are filled in, so a
+/// TypeText action can use e.g. `"text": "Meeting with {person}"`. Parameters
+/// that Custom and Webhook actions fill in themselves (with per-argument or
+/// URL/JSON escaping), and a macro's steps, are left alone.
+pub fn bind_parameters(
+    command: &CommandDefinition,
+    values: &HashMap<String, String>,
+) -> Result<CommandDefinition, ActionError> {
+    let pattern = match TriggerPattern::parse(&command.trigger) {
+        Ok(pattern) => pattern,
+        // Triggers saved before slots existed only match exactly or fuzzily
+        Err(_) if values.is_empty() => return Ok(command.clone()),
+        Err(e) => {
+            return Err(ActionError {
+                code: ActionErrorCode::InvalidParameter,
+                message: format!("Invalid trigger '{}': {}", command.trigger, e),
+                data: None,
+            })
+        }
+    };
+    pattern.validate(values).map_err(|e| ActionError {
+        code: match e {
+            SlotError::Missing(_) => ActionErrorCode::MissingParam,
+            _ => ActionErrorCode::InvalidParameter,
+        },
+        message: e.to_string(),
+        data: None,
+    })?;
+
+    let slot_values: HashMap<String, String> = pattern
+        .slots()
+        .map(|slot| (slot.name.clone(), values[&slot.name].clone()))
+        .collect();
+    let mut bound = command.clone();
+    fill_placeholders(&command.action_type, &mut bound.parameters, &slot_values);
+    bound.parameters.extend(slot_values);
+    Ok(bound)
+}
+
+/// Fill `{name}` placeholders with `values` in the parameters the action
+/// doesn't template itself
+pub(crate) fn fill_placeholders(
+    action_type: &ActionType,
+    parameters: &mut HashMap<String, String>,
+    values: &HashMap<String, String>,
+) {
+    let templated = self_templated_params(action_type);
+    for (key, value) in parameters.iter_mut() {
+        if templated.contains(&key.as_str()) {
+            continue;
+        }
+        for (name, slot_value) in values {
+            *value = value.replace(&format!("{{{}}}", name), slot_value);
+        }
+    }
+}
+
+/// Convert a spoken number to its value
+///
+/// Accepts digits ("42", "1,200") or English number words up to the
+/// millions ("twenty five", "one hundred and three", "a thousand").
+pub fn parse_spoken_number(words: &[&str]) -> Option<i64> {
+    let words: Vec<String> = words
+        .iter()
+        .flat_map(|w| w.split('-'))
+        .map(normalize_word)
+        .filter(|w| !w.is_empty())
+        .collect();
+    let (negative, words) = match words.split_first() {
+        Some((first, rest)) if first == "minus" || first == "negative" => (true, rest),
+        _ => (false, words.as_slice()),
+    };
+    if words.is_empty() {
+        return None;
+    }
+
+    if let [digits] = words {
+        let digits = digits.replace(',', "");
+        if let Ok(n) = digits.parse::<i64>() {
+            return Some(if negative { -n } else { n });
+        }
+    }
+
+    let mut total: i64 = 0;
+    let mut current: i64 = 0;
+    let mut seen_number = false;
+    for (i, word) in words.iter().enumerate() {
+        match word.as_str() {
+            // "one hundred and five"; "a hundred"
+            "and" if seen_number && i + 1 < words.len() => continue,
+            "a" if i == 0 && words.len() > 1 => current = 1,
+            "hundred" if current > 0 => current = current.checked_mul(100)?,
+            scale @ ("thousand" | "million") if current > 0 => {
+                let scale = if scale == "thousand" {
+                    1_000
+                } else {
+                    1_000_000
+                };
+                total = total.checked_add(current.checked_mul(scale)?)?;
+                current = 0;
+            }
+            other => {
+                let n = small_number(other)?;
+                // Only "twenty" + unit combines; "five five" is not a number
+                let tail = current % 100;
+                if tail != 0 && !(tail >= 20 && tail % 10 == 0 && (1..10).contains(&n)) {
+                    return None;
+                }
+                current = current.checked_add(n)?;
+            }
+        }
+        seen_number = true;
+    }
+
+    // Runaway phrases ("nine hundred hundred hundred ...") overflow: no number
+    let value = total.checked_add(current)?;
+    Some(if negative { -value } else { value })
+}
+
+/// Value of a number word below one hundred
+fn small_number(word: &str) -> Option<i64> {
+    const UNITS: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 8] = [
+        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    if let Some(n) = UNITS.iter().position(|&u| u == word) {
+        return Some(n as i64);
+    }
+    TENS.iter()
+        .position(|&t| t == word)
+        .map(|n| (n as i64 + 2) * 10)
+}
+
+fn push_words(tokens: &mut Vec<Token>, text: &str) {
+    tokens.extend(normalize_words(text).into_iter().map(Token::Word));
+}
+
+fn parse_slot(body: &str) -> Result<Slot, GrammarError> {
+    let (name, kind) = match body.split_once(':') {
+        Some((name, kind)) => (name.trim(), Some(kind.trim())),
+        None => (body.trim(), None),
+    };
+    if name.is_empty() {
+        return Err(GrammarError::EmptySlotName);
+    }
+
+    let kind = match kind {
+        None | Some("text") => SlotKind::Text,
+        Some("number") => SlotKind::Number,
+        Some(choices) if choices.contains('|') => {
+            let choices: Vec<String> = choices.split('|').map(spoken_phrase).collect();
+            if choices.iter().any(|c| c.is_empty()) {
+                return Err(GrammarError::EmptyChoice(name.to_string()));
+            }
+            SlotKind::Choice(choices)
+        }
+        Some(other) => {
+            return Err(GrammarError::UnknownSlotType {
+                name: name.to_string(),
+                kind: other.to_string(),
+            })
+        }
+    };
+
+    Ok(Slot {
+        name: name.to_string(),
+        kind,
+    })
+}
+
+/// The choice spoken by `words`, if any
+fn match_choice<'a>(choices: &'a [String], words: &[&str]) -> Option<&'a String> {
+    let spoken = words
+        .iter()
+        .map(|w| normalize_word(w))
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    choices.iter().find(|c| **c == spoken)
+}
+
+/// Backtracking match of tokens against words
+fn match_tokens(tokens: &[Token], words: &[&str], values: &mut HashMap<String, String>) -> bool {
+    let Some((token, rest)) = tokens.split_first() else {
+        // Trailing punctuation-only "words" are not content
+        return words.iter().all(|w| normalize_word(w).is_empty());
+    };
+
+    match token {
+        Token::Word(expected) => match words.split_first() {
+            Some((word, remaining)) if normalize_word(word) == *expected => {
+                match_tokens(rest, remaining, values)
+            }
+            _ => false,
+        },
+        Token::Optional(expected) => {
+            let present = words.len() >= expected.len()
+                && words
+                    .iter()
+                    .zip(expected)
+                    .all(|(w, e)| normalize_word(w) == *e);
+            (present && match_tokens(rest, &words[expected.len()..], values))
+                || match_tokens(rest, words, values)
+        }
+        Token::Slot(slot) => {
+            for len in 1..=words.len() {
+                let (taken, remaining) = words.split_at(len);
+                let value = match &slot.kind {
+                    SlotKind::Text => Some(taken.join(" ")),
+                    SlotKind::Number => parse_spoken_number(taken).map(|n| n.to_string()),
+                    SlotKind::Choice(choices) => match_choice(choices, taken).cloned(),
+                };
+                if let Some(value) = value {
+                    values.insert(slot.name.clone(), value);
+                    if match_tokens(rest, remaining, values) {
+                        return true;
+                    }
+                    values.remove(&slot.name);
+                }
+            }
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "grammar_test.rs"]
+mod tests;
@@ -0,0 +1,251 @@
+use super::*;
+use crate::voice_commands::registry::ActionType;
+use uuid::Uuid;
+
+fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_parse_plain_trigger_has_no_grammar() {
+    let pattern = TriggerPattern::parse("open slack").unwrap();
+    assert!(!pattern.has_grammar());
+    assert_eq!(pattern.slots().count(), 0);
+}
+
+#[test]
+fn test_parse_slot_types() {
+    let pattern =
+        TriggerPattern::parse("set {thing} to {level:number} on {side:left | top left}").unwrap();
+    let slots: Vec<&Slot> = pattern.slots().collect();
+
+    assert_eq!(slots[0].kind, SlotKind::Text);
+    assert_eq!(slots[1].kind, SlotKind::Number);
+    assert_eq!(
+        slots[2].kind,
+        SlotKind::Choice(vec!["left".to_string(), "top left".to_string()])
+    );
+}
+
+#[test]
+fn test_parse_rejects_malformed_triggers() {
+    assert_eq!(
+        TriggerPattern::parse("type {text"),
+        Err(GrammarError::Unclosed('{'))
+    );
+    assert_eq!(
+        TriggerPattern::parse("type text}"),
+        Err(GrammarError::Unexpected('}'))
+    );
+    assert_eq!(
+        TriggerPattern::parse("type {}"),
+        Err(GrammarError::EmptySlotName)
+    );
+    assert_eq!(
+        TriggerPattern::parse("[] go"),
+        Err(GrammarError::InvalidOptional)
+    );
+    assert_eq!(
+        TriggerPattern::parse("{a} and {a}"),
+        Err(GrammarError::DuplicateSlot("a".to_string()))
+    );
+    assert!(matches!(
+        TriggerPattern::parse("go {where:somewhere}"),
+        Err(GrammarError::UnknownSlotType { .. })
+    ));
+    assert_eq!(
+        TriggerPattern::parse("go {where:up||down}"),
+        Err(GrammarError::EmptyChoice("where".to_string()))
+    );
+}
+
+#[test]
+fn test_match_slots_anywhere_in_phrase() {
+    let pattern = TriggerPattern::parse("move {window} to {display}").unwrap();
+
+    assert_eq!(
+        pattern.match_input("move slack to the left screen"),
+        Some(values(&[
+            ("window", "slack"),
+            ("display", "the left screen")
+        ]))
+    );
+    assert_eq!(pattern.match_input("move slack"), None);
+}
+
+#[test]
+fn test_match_optional_words() {
+    let pattern = TriggerPattern::parse("[please] open {app} [now]").unwrap();
+
+    assert_eq!(
+        pattern.match_input("open Slack"),
+        Some(values(&[("app", "Slack")]))
+    );
+    assert_eq!(
+        pattern.match_input("Please open Slack now."),
+        Some(values(&[("app", "Slack")]))
+    );
+}
+
+#[test]
+fn test_choice_slot_only_accepts_listed_values() {
+    let pattern = TriggerPattern::parse("snap {side:left|right|top half}").unwrap();
+
+    assert_eq!(
+        pattern.match_input("snap Top Half"),
+        Some(values(&[("side", "top half")]))
+    );
+    assert_eq!(pattern.match_input("snap middle"), None);
+}
+
+#[test]
+fn test_number_slot_backtracks_around_literals() {
+    let pattern = TriggerPattern::parse("wait {secs:number} seconds then {action}").unwrap();
+
+    assert_eq!(
+        pattern.match_input("wait one hundred and five seconds then lock"),
+        Some(values(&[("secs", "105"), ("action", "lock")]))
+    );
+}
+
+#[test]
+fn test_parse_spoken_number() {
+    let parse = |s: &str| parse_spoken_number(&s.split_whitespace().collect::<Vec<_>>());
+
+    assert_eq!(parse("7"), Some(7));
+    assert_eq!(parse("1,200"), Some(1200));
+    assert_eq!(parse("zero"), Some(0));
+    assert_eq!(parse("twenty-one"), Some(21));
+    assert_eq!(parse("a hundred"), Some(100));
+    assert_eq!(parse("two thousand three hundred and four"), Some(2304));
+    assert_eq!(parse("minus five"), Some(-5));
+    assert_eq!(parse("five five"), None);
+    assert_eq!(parse("hundred"), None);
+    assert_eq!(parse("loud"), None);
+}
+
+#[test]
+fn test_parse_spoken_number_rejects_overflow() {
+    let parse = |s: &str| parse_spoken_number(&s.split_whitespace().collect::<Vec<_>>());
+
+    let hundreds = format!("nine{}", " hundred".repeat(10));
+    assert_eq!(parse(&hundreds), None);
+    let millions = format!("nine{} million", " hundred".repeat(7));
+    assert_eq!(parse(&millions), None);
+    // 9e18 fits, twice that does not
+    let large = format!("nine{} million", " hundred".repeat(6));
+    assert_eq!(parse(&large), Some(9_000_000_000_000_000_000));
+    assert_eq!(parse(&format!("{} {}", large, large)), None);
+    assert_eq!(parse("nine hundred hundred hundred"), Some(9_000_000));
+}
+
+#[test]
+fn test_validate_checks_types_and_presence() {
+    let pattern = TriggerPattern::parse("volume {level:number} on {side:left|right}").unwrap();
+
+    assert!(pattern
+        .validate(&values(&[("level", "30"), ("side", "left")]))
+        .is_ok());
+    assert_eq!(
+        pattern.validate(&values(&[("side", "left")])),
+        Err(SlotError::Missing("level".to_string()))
+    );
+    assert!(matches!(
+        pattern.validate(&values(&[("level", "loud"), ("side", "left")])),
+        Err(SlotError::InvalidNumber { .. })
+    ));
+    assert!(matches!(
+        pattern.validate(&values(&[("level", "30"), ("side", "up")])),
+        Err(SlotError::InvalidChoice { .. })
+    ));
+}
+
+#[test]
+fn test_bind_parameters_fills_placeholders() {
+    let command = CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: "open {app} on {display:left|right}".to_string(),
+        action_type: ActionType::TypeText,
+        parameters: values(&[("text", "Opening '{app}' on the {display}")]),
+        enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
+    };
+
+    let bound =
+        bind_parameters(&command, &values(&[("app", "Notes"), ("display", "right")])).unwrap();
+
+    assert_eq!(bound.parameters["text"], "Opening 'Notes' on the right");
+    assert_eq!(bound.parameters["app"], "Notes");
+    assert_eq!(bound.parameters["display"], "right");
+}
+
+#[test]
+fn test_bind_parameters_leaves_script_templates_to_the_action() {
+    let command = CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: "open {app}".to_string(),
+        action_type: ActionType::Custom,
+        parameters: values(&[("script", "open.sh {app}"), ("args", r#"["{app}"]"#)]),
+        enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
+    };
+
+    let bound = bind_parameters(&command, &values(&[("app", "a; rm -rf ~")])).unwrap();
+
+    assert_eq!(bound.parameters["script"], "open.sh {app}");
+    assert_eq!(bound.parameters["args"], r#"["{app}"]"#);
+    assert_eq!(bound.parameters["app"], "a; rm -rf ~");
+}
+
+#[test]
+fn test_bind_parameters_leaves_legacy_triggers_alone() {
+    let command = CommandDefinition {
+        id: Uuid::new_v4(),
+        trigger: "smile :}".to_string(),
+        action_type: ActionType::TypeText,
+        parameters: values(&[("text", "🙂")]),
+        enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
+    };
+
+    assert_eq!(bind_parameters(&command, &HashMap::new()).unwrap(), command);
+}
+
+#[test]
+fn test_validate_aliases() {
+    let aliases = |list: &[&str]| list.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+
+    assert!(validate_aliases("open slack", &aliases(&["launch slack", "start slack"])).is_ok());
+    assert!(validate_aliases(
+        "set volume to {level:number}",
+        &aliases(&["volume {level:number}", "make it {level:number} percent"])
+    )
+    .is_ok());
+
+    assert_eq!(
+        validate_aliases("open slack", &aliases(&["  "])),
+        Err(GrammarError::EmptyAlias)
+    );
+    assert_eq!(
+        validate_aliases("open slack", &aliases(&["Open  Slack"])),
+        Err(GrammarError::DuplicatePhrase("Open  Slack".to_string()))
+    );
+    assert_eq!(
+        validate_aliases("type {text}", &aliases(&["write {words}"])),
+        Err(GrammarError::AliasSlotMismatch("write {words}".to_string()))
+    );
+    assert_eq!(
+        validate_aliases("volume {level:number}", &aliases(&["volume {level}"])),
+        Err(GrammarError::AliasSlotMismatch("volume {level}".to_string()))
+    );
+    assert_eq!(
+        validate_aliases("open slack", &aliases(&["open {app"])),
+        Err(GrammarError::Unclosed('{'))
+    );
+}
@@ -1,14 +1,22 @@
 // Fuzzy matcher - matches transcribed text against commands
 
+use crate::voice_commands::grammar::TriggerPattern;
+use crate::voice_commands::phonetic::phonetic_similarity;
 use crate::voice_commands::registry::CommandDefinition;
 use serde::Serialize;
 use strsim::normalized_levenshtein;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 /// Default similarity threshold for fuzzy matching (0.0 to 1.0)
 pub const DEFAULT_THRESHOLD: f64 = 0.8;
 
+/// Default weight of spelling (edit distance) similarity in the fuzzy score
+pub const DEFAULT_EDIT_WEIGHT: f64 = 0.5;
+
+/// Default weight of phonetic similarity in the fuzzy score
+pub const DEFAULT_PHONETIC_WEIGHT: f64 = 0.5;
+
 /// Result of matching transcribed text against commands
 #[derive(Debug, Clone, Serialize)]
 pub enum MatchResult {
@@ -46,6 +54,80 @@ pub struct MatchCandidate {
     pub parameters: HashMap<String, String>,
 }
 
+/// Outcome of matching the input against a trigger's slots and optional words
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ParameterExtraction {
+    /// Plain phrase without slots or optional words
+    NotApplicable,
+    /// The input fits the grammar
+    Matched { parameters: HashMap<String, String> },
+    /// The phrase has a grammar the input doesn't fit
+    NoMatch,
+    /// The phrase's grammar doesn't parse, so it is compared as plain text
+    InvalidPattern { error: String },
+}
+
+/// How one phrase (trigger or alias) of a command scored against the input
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhraseExplanation {
+    pub phrase: String,
+    pub parameter_extraction: ParameterExtraction,
+    /// Input equals the phrase after normalization
+    pub exact: bool,
+    /// Normalized Levenshtein similarity
+    pub edit_score: f64,
+    /// Phonetic similarity (0 when the phonetic weight is 0)
+    pub phonetic_score: f64,
+    /// Weighted blend of the edit and phonetic scores
+    pub fuzzy_score: f64,
+    /// 1.0 for an exact or grammar match, the fuzzy score otherwise
+    pub score: f64,
+    /// Whether the phrase matched (exact, grammar, or fuzzy at or above the threshold)
+    pub accepted: bool,
+}
+
+/// How one command fared against the input
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateExplanation {
+    pub command_id: Uuid,
+    pub trigger: String,
+    pub enabled: bool,
+    /// False when the active window context filters the command out
+    pub in_context: bool,
+    /// Every phrase of the command, trigger first
+    pub phrases: Vec<PhraseExplanation>,
+    /// Best phrase score
+    pub score: f64,
+    /// Whether the command took part in the decision (enabled, in context, accepted)
+    pub eligible: bool,
+    /// Eligible and within the ambiguity delta of the top score
+    pub within_ambiguity_delta: bool,
+}
+
+/// Full breakdown of how an input would be matched, without running anything
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchExplanation {
+    pub input: String,
+    pub threshold: f64,
+    pub ambiguity_delta: f64,
+    pub edit_weight: f64,
+    pub phonetic_weight: f64,
+    /// Every command, best score first
+    pub candidates: Vec<CandidateExplanation>,
+    /// Score of the best eligible command
+    pub top_score: Option<f64>,
+    /// Score of the second best eligible command
+    pub runner_up_score: Option<f64>,
+    /// Whether several eligible commands were within the ambiguity delta
+    pub ambiguous: bool,
+    /// What `match_commands` returns for the commands in context
+    pub result: MatchResult,
+}
+
 /// Configuration for the matcher
 #[derive(Debug, Clone)]
 pub struct MatcherConfig {
@@ -53,6 +135,10 @@ pub struct MatcherConfig {
     pub threshold: f64,
     /// Maximum difference between top matches to consider ambiguous
     pub ambiguity_delta: f64,
+    /// Weight of edit distance similarity in the fuzzy score
+    pub edit_weight: f64,
+    /// Weight of phonetic similarity in the fuzzy score (0.0 disables it)
+    pub phonetic_weight: f64,
 }
 
 impl Default for MatcherConfig {
@@ -60,7 +146,24 @@ impl Default for MatcherConfig {
         Self {
             threshold: DEFAULT_THRESHOLD,
             ambiguity_delta: 0.1,
+            edit_weight: DEFAULT_EDIT_WEIGHT,
+            phonetic_weight: DEFAULT_PHONETIC_WEIGHT,
+        }
+    }
+}
+
+impl MatcherConfig {
+    /// Combine edit and phonetic similarity using the configured weights
+    ///
+    /// Falls back to edit similarity alone if neither weight is positive.
+    fn weighted_score(&self, edit: f64, phonetic: f64) -> f64 {
+        let edit_weight = self.edit_weight.max(0.0);
+        let phonetic_weight = self.phonetic_weight.max(0.0);
+        let total = edit_weight + phonetic_weight;
+        if total <= 0.0 {
+            return edit;
         }
+        (edit_weight * edit + phonetic_weight * phonetic) / total
     }
 }
 
@@ -94,50 +197,32 @@ impl CommandMatcher {
         input.trim().to_lowercase()
     }
 
-    /// Try to extract parameters from a parameterized trigger
-    /// Returns (matched, parameters) if the trigger pattern matches
-    fn try_extract_params(
-        input: &str,
-        trigger: &str,
-    ) -> Option<(bool, HashMap<String, String>)> {
-        // Check for parameterized pattern like "type {text}"
-        if !trigger.contains('{') {
-            return None;
-        }
-
-        // Split trigger into prefix and parameter name
-        let parts: Vec<&str> = trigger.splitn(2, '{').collect();
-        if parts.len() != 2 {
-            return None;
-        }
-
-        let prefix = parts[0].trim();
-        let param_part = parts[1];
-
-        // Extract parameter name (remove trailing })
-        let param_name = param_part.trim_end_matches('}').trim();
-        if param_name.is_empty() {
-            return None;
+    /// Try to match a trigger that uses slots or optional words
+    ///
+    /// Gives the slot values if the trigger has a grammar and the input
+    /// fits it. Otherwise the trigger is compared by exact/fuzzy matching.
+    fn extract_params(input: &str, trigger: &str) -> ParameterExtraction {
+        let pattern = match TriggerPattern::parse(trigger) {
+            Ok(pattern) => pattern,
+            Err(e) => {
+                return ParameterExtraction::InvalidPattern {
+                    error: e.to_string(),
+                }
+            }
+        };
+        if !pattern.has_grammar() {
+            return ParameterExtraction::NotApplicable;
         }
-
-        // Check if input starts with the prefix
-        let normalized_input = Self::normalize(input);
-        let normalized_prefix = Self::normalize(prefix);
-
-        if !normalized_input.starts_with(&normalized_prefix) {
-            return None;
+        match pattern.match_input(input) {
+            Some(parameters) => ParameterExtraction::Matched { parameters },
+            None => ParameterExtraction::NoMatch,
         }
-
-        // Extract the parameter value
-        let param_value = input[prefix.len()..].trim().to_string();
-
-        let mut params = HashMap::new();
-        params.insert(param_name.to_string(), param_value);
-
-        Some((true, params))
     }
 
     /// Match input against a single command
+    ///
+    /// Every phrase of the command (trigger and aliases) is tried and the best
+    /// score wins; ties go to the earlier phrase.
     fn match_command(
         &self,
         input: &str,
@@ -147,47 +232,68 @@ impl CommandMatcher {
             return None;
         }
 
-        let normalized_input = Self::normalize(input);
-        let normalized_trigger = Self::normalize(&command.trigger);
-
-        // Try parameterized match first
-        if let Some((_, params)) = Self::try_extract_params(input, &command.trigger) {
-            return Some(MatchCandidate {
-                command: MatchedCommand {
-                    id: command.id,
-                    trigger: command.trigger.clone(),
-                },
-                score: 1.0,
-                parameters: params,
-            });
-        }
+        command
+            .phrases()
+            .filter_map(|phrase| self.match_phrase(input, command, phrase))
+            .reduce(|best, c| if c.score > best.score { c } else { best })
+    }
 
-        // Exact match
-        if normalized_input == normalized_trigger {
-            return Some(MatchCandidate {
-                command: MatchedCommand {
-                    id: command.id,
-                    trigger: command.trigger.clone(),
-                },
-                score: 1.0,
-                parameters: HashMap::new(),
-            });
+    /// Match input against one phrase of a command
+    fn match_phrase(
+        &self,
+        input: &str,
+        command: &CommandDefinition,
+        phrase: &str,
+    ) -> Option<MatchCandidate> {
+        let explanation = self.explain_phrase(input, phrase);
+        if !explanation.accepted {
+            return None;
         }
 
-        // Fuzzy match using normalized Levenshtein distance
-        let score = normalized_levenshtein(&normalized_input, &normalized_trigger);
+        let parameters = match explanation.parameter_extraction {
+            ParameterExtraction::Matched { parameters } => parameters,
+            _ => HashMap::new(),
+        };
+        Some(MatchCandidate {
+            command: MatchedCommand {
+                id: command.id,
+                trigger: command.trigger.clone(),
+            },
+            score: explanation.score,
+            parameters,
+        })
+    }
 
-        if score >= self.config.threshold {
-            Some(MatchCandidate {
-                command: MatchedCommand {
-                    id: command.id,
-                    trigger: command.trigger.clone(),
-                },
-                score,
-                parameters: HashMap::new(),
-            })
+    /// Score input against one phrase, keeping every intermediate result
+    fn explain_phrase(&self, input: &str, phrase: &str) -> PhraseExplanation {
+        let normalized_input = Self::normalize(input);
+        let normalized_trigger = Self::normalize(phrase);
+
+        // A parameterized match wins first, then an exact one
+        let parameter_extraction = Self::extract_params(input, phrase);
+        let extracted = matches!(parameter_extraction, ParameterExtraction::Matched { .. });
+        let exact = normalized_input == normalized_trigger;
+
+        // Fuzzy match: spelling (normalized Levenshtein) blended with sound,
+        // since recognition errors are usually phonetic ("often" for "open")
+        let edit_score = normalized_levenshtein(&normalized_input, &normalized_trigger);
+        let phonetic_score = if self.config.phonetic_weight > 0.0 {
+            phonetic_similarity(&normalized_input, &normalized_trigger)
         } else {
-            None
+            0.0
+        };
+        let fuzzy_score = self.config.weighted_score(edit_score, phonetic_score);
+
+        let score = if extracted || exact { 1.0 } else { fuzzy_score };
+        PhraseExplanation {
+            phrase: phrase.to_string(),
+            parameter_extraction,
+            exact,
+            edit_score,
+            phonetic_score,
+            fuzzy_score,
+            score,
+            accepted: extracted || exact || fuzzy_score >= self.config.threshold,
         }
     }
 
@@ -254,6 +360,77 @@ impl CommandMatcher {
             }
         }
     }
+
+    /// Explain how `input` would be matched against `commands`
+    ///
+    /// `in_context` holds the IDs the active window context keeps; the others
+    /// are scored but left out of the decision, like in `match_commands`.
+    pub fn explain(
+        &self,
+        input: &str,
+        commands: &[CommandDefinition],
+        in_context: &HashSet<Uuid>,
+    ) -> MatchExplanation {
+        let mut candidates: Vec<CandidateExplanation> = commands
+            .iter()
+            .map(|command| {
+                let phrases: Vec<PhraseExplanation> = command
+                    .phrases()
+                    .map(|phrase| self.explain_phrase(input, phrase))
+                    .collect();
+                let score = phrases.iter().map(|p| p.score).fold(0.0, f64::max);
+                let in_context = in_context.contains(&command.id);
+                CandidateExplanation {
+                    command_id: command.id,
+                    trigger: command.trigger.clone(),
+                    enabled: command.enabled,
+                    in_context,
+                    eligible: command.enabled
+                        && in_context
+                        && score.is_finite()
+                        && phrases.iter().any(|p| p.accepted),
+                    phrases,
+                    score,
+                    within_ambiguity_delta: false,
+                }
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let mut eligible_scores = candidates.iter().filter(|c| c.eligible).map(|c| c.score);
+        let top_score = eligible_scores.next();
+        let runner_up_score = eligible_scores.next();
+        if let Some(top) = top_score {
+            for candidate in candidates.iter_mut().filter(|c| c.eligible) {
+                candidate.within_ambiguity_delta =
+                    top - candidate.score <= self.config.ambiguity_delta;
+            }
+        }
+        let ambiguous = candidates
+            .iter()
+            .filter(|c| c.within_ambiguity_delta)
+            .count()
+            > 1;
+
+        let effective: Vec<CommandDefinition> = commands
+            .iter()
+            .filter(|c| in_context.contains(&c.id))
+            .cloned()
+            .collect();
+
+        MatchExplanation {
+            input: input.to_string(),
+            threshold: self.config.threshold,
+            ambiguity_delta: self.config.ambiguity_delta,
+            edit_weight: self.config.edit_weight,
+            phonetic_weight: self.config.phonetic_weight,
+            candidates,
+            top_score,
+            runner_up_score,
+            ambiguous,
+            result: self.match_commands(input, &effective),
+        }
+    }
 }
 
 #[cfg(test)]
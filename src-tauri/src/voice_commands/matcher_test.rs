@@ -13,6 +13,8 @@ fn create_command(trigger: &str) -> CommandDefinition {
         action_type: ActionType::OpenApp,
         parameters: HashMap::new(),
         enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
     }
 }
 
@@ -104,6 +106,7 @@ fn test_ambiguous_similar_commands() {
     let config = MatcherConfig {
         threshold: 0.7,
         ambiguity_delta: 0.15,
+        ..Default::default()
     };
     let matcher = CommandMatcher::with_config(config);
     // Input that's similar to both: "slaik" is between "slack" and "slick"
@@ -164,6 +167,7 @@ fn test_custom_threshold() {
     let config = MatcherConfig {
         threshold: 0.99,
         ambiguity_delta: 0.1,
+        ..Default::default()
     };
     let matcher = CommandMatcher::with_config(config);
     let result = matcher.match_commands("opn slack", &commands);
@@ -189,3 +193,336 @@ fn test_best_match_selected_when_not_ambiguous() {
         _ => panic!("Expected Exact match, got {:?}", result),
     }
 }
+
+#[test]
+fn test_multi_slot_trigger_extracts_typed_values() {
+    let cmd = create_command("move {window} to {display:left|right|main}");
+    let commands = vec![cmd];
+
+    let matcher = CommandMatcher::new();
+    let result = matcher.match_commands("Move the Safari window to Left.", &commands);
+
+    match result {
+        MatchResult::Exact { parameters, .. } => {
+            assert_eq!(parameters.get("window"), Some(&"the Safari window".to_string()));
+            assert_eq!(parameters.get("display"), Some(&"left".to_string()));
+        }
+        _ => panic!("Expected Exact match with parameters, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_number_slot_converts_spoken_words() {
+    let cmd = create_command("[please] set volume to {level:number} [percent]");
+    let commands = vec![cmd];
+
+    let matcher = CommandMatcher::new();
+    let result = matcher.match_commands("set volume to twenty five percent", &commands);
+
+    match result {
+        MatchResult::Exact { parameters, .. } => {
+            assert_eq!(parameters.get("level"), Some(&"25".to_string()));
+        }
+        _ => panic!("Expected Exact match with parameters, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_slot_trigger_requires_word_boundary() {
+    let cmd = create_command("type {text}");
+    let commands = vec![cmd];
+
+    let matcher = CommandMatcher::new();
+    let result = matcher.match_commands("typewriter", &commands);
+
+    assert!(!matches!(result, MatchResult::Exact { .. }));
+}
+
+fn create_command_with_aliases(trigger: &str, aliases: &[&str]) -> CommandDefinition {
+    let mut cmd = create_command(trigger);
+    cmd.aliases = aliases.iter().map(|a| a.to_string()).collect();
+    cmd
+}
+
+#[test]
+fn test_alias_matches_with_canonical_trigger() {
+    let commands = vec![create_command_with_aliases(
+        "open slack",
+        &["launch slack", "start slack"],
+    )];
+
+    let matcher = CommandMatcher::new();
+    match matcher.match_commands("Launch Slack", &commands) {
+        MatchResult::Exact { command, .. } => {
+            // Events and the UI show the canonical trigger
+            assert_eq!(command.trigger, "open slack");
+        }
+        other => panic!("Expected Exact match, got {:?}", other),
+    }
+    assert!(matches!(
+        matcher.match_commands("start slak", &commands),
+        MatchResult::Fuzzy { .. }
+    ));
+}
+
+#[test]
+fn test_alias_slots_are_extracted() {
+    let commands = vec![create_command_with_aliases(
+        "set volume to {level:number}",
+        &["volume {level:number}"],
+    )];
+
+    let matcher = CommandMatcher::new();
+    match matcher.match_commands("volume forty", &commands) {
+        MatchResult::Exact { parameters, .. } => {
+            assert_eq!(parameters.get("level"), Some(&"40".to_string()));
+        }
+        other => panic!("Expected Exact match, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_close_aliases_of_one_command_are_not_ambiguous() {
+    let commands = vec![create_command_with_aliases(
+        "open slack",
+        &["open slacks", "open the slack"],
+    )];
+
+    let matcher = CommandMatcher::new();
+    assert!(matches!(
+        matcher.match_commands("open slak", &commands),
+        MatchResult::Fuzzy { .. }
+    ));
+}
+
+/// Triggers from a typical command set, used by the misrecognition corpus
+const CORPUS_TRIGGERS: &[&str] = &[
+    "open slack",
+    "open safari",
+    "open chrome",
+    "open photos",
+    "play music",
+    "new tab",
+    "lock the screen",
+    "scroll down",
+    "scroll up",
+    "volume up",
+    "volume down",
+    "take a screenshot",
+    "mute the call",
+    "turn off wifi",
+    "copy that",
+    "close window",
+    "dark mode",
+    "next track",
+    "previous track",
+];
+
+/// Misrecognitions seen from the speech model, paired with the intended trigger
+const MISRECOGNITIONS: &[(&str, &str)] = &[
+    ("often slack", "open slack"),
+    ("open safary", "open safari"),
+    ("open sofa ree", "open safari"),
+    ("open crome", "open chrome"),
+    ("open fotos", "open photos"),
+    ("play musik", "play music"),
+    ("knew tab", "new tab"),
+    ("log the screen", "lock the screen"),
+    ("lock the scream", "lock the screen"),
+    ("scroll town", "scroll down"),
+    ("volume of", "volume up"),
+    ("volume town", "volume down"),
+    ("take a screen shot", "take a screenshot"),
+    ("mute the cull", "mute the call"),
+    ("mute the col", "mute the call"),
+    ("turn of wifi", "turn off wifi"),
+    ("coffee that", "copy that"),
+    ("clothes window", "close window"),
+    ("dark mowed", "dark mode"),
+    ("previous truck", "previous track"),
+];
+
+/// Utterances that sound partly like a trigger but must not run one
+const NEAR_MISSES: &[&str] = &["open zoom", "close slack", "read me"];
+
+fn corpus_commands() -> Vec<CommandDefinition> {
+    CORPUS_TRIGGERS.iter().map(|t| create_command(t)).collect()
+}
+
+#[test]
+fn test_misrecognition_corpus_matches_intended_command() {
+    let commands = corpus_commands();
+    let matcher = CommandMatcher::new();
+
+    for (heard, intended) in MISRECOGNITIONS {
+        match matcher.match_commands(heard, &commands) {
+            MatchResult::Fuzzy { command, .. } => {
+                assert_eq!(command.trigger, *intended, "heard {:?}", heard)
+            }
+            other => panic!("heard {:?}: expected {:?}, got {:?}", heard, intended, other),
+        }
+    }
+}
+
+#[test]
+fn test_misrecognition_corpus_near_misses_do_not_match() {
+    let commands = corpus_commands();
+    let matcher = CommandMatcher::new();
+
+    for heard in NEAR_MISSES {
+        let result = matcher.match_commands(heard, &commands);
+        assert!(
+            matches!(result, MatchResult::NoMatch),
+            "heard {:?}: got {:?}",
+            heard,
+            result
+        );
+    }
+}
+
+#[test]
+fn test_phonetic_weight_rescues_sound_alike_input() {
+    let commands = vec![create_command("copy that")];
+
+    let spelling_only = CommandMatcher::with_config(MatcherConfig {
+        phonetic_weight: 0.0,
+        ..Default::default()
+    });
+    assert!(matches!(
+        spelling_only.match_commands("coffee that", &commands),
+        MatchResult::NoMatch
+    ));
+
+    let matcher = CommandMatcher::new();
+    assert!(matches!(
+        matcher.match_commands("coffee that", &commands),
+        MatchResult::Fuzzy { .. }
+    ));
+}
+
+#[test]
+fn test_zero_weights_fall_back_to_edit_distance() {
+    let config = MatcherConfig {
+        edit_weight: 0.0,
+        phonetic_weight: 0.0,
+        ..Default::default()
+    };
+    assert_eq!(config.weighted_score(0.6, 1.0), 0.6);
+
+    let config = MatcherConfig::default();
+    assert!((config.weighted_score(0.6, 1.0) - 0.8).abs() < 1e-9);
+}
+
+fn all_in_context(commands: &[CommandDefinition]) -> HashSet<Uuid> {
+    commands.iter().map(|c| c.id).collect()
+}
+
+#[test]
+fn test_explain_scores_every_command_and_marks_context_filtering() {
+    let slack = CommandDefinition {
+        aliases: vec!["launch slack".to_string()],
+        ..create_command("open slack")
+    };
+    let safari = create_command("open safari");
+    let disabled = CommandDefinition {
+        enabled: false,
+        ..create_command("open slack now")
+    };
+    let commands = vec![safari.clone(), slack.clone(), disabled.clone()];
+    let in_context = HashSet::from([slack.id, disabled.id]);
+
+    let matcher = CommandMatcher::new();
+    let explanation = matcher.explain("Open Slack", &commands, &in_context);
+
+    assert_eq!(explanation.threshold, DEFAULT_THRESHOLD);
+    assert_eq!(explanation.candidates.len(), 3);
+    let top = &explanation.candidates[0];
+    assert_eq!(top.command_id, slack.id);
+    assert!(top.eligible && top.in_context);
+    assert_eq!(top.phrases.len(), 2);
+    assert!(top.phrases[0].exact);
+    assert!(!top.phrases[1].accepted);
+
+    let safari_explained = explanation
+        .candidates
+        .iter()
+        .find(|c| c.command_id == safari.id)
+        .unwrap();
+    assert!(!safari_explained.in_context);
+    assert!(!safari_explained.eligible);
+    let disabled_explained = explanation
+        .candidates
+        .iter()
+        .find(|c| c.command_id == disabled.id)
+        .unwrap();
+    assert!(!disabled_explained.enabled && !disabled_explained.eligible);
+
+    assert_eq!(explanation.top_score, Some(1.0));
+    assert_eq!(explanation.runner_up_score, None);
+    assert!(!explanation.ambiguous);
+    assert!(matches!(explanation.result, MatchResult::Exact { .. }));
+}
+
+#[test]
+fn test_explain_reports_parameter_extraction() {
+    let commands = vec![
+        create_command("type {text}"),
+        create_command("open {app:slack|mail}"),
+        create_command("type hello"),
+        create_command("type {text"),
+    ];
+
+    let matcher = CommandMatcher::new();
+    let explanation = matcher.explain("type hello world", &commands, &all_in_context(&commands));
+    let extraction = |trigger: &str| {
+        explanation
+            .candidates
+            .iter()
+            .find(|c| c.trigger == trigger)
+            .map(|c| c.phrases[0].parameter_extraction.clone())
+            .unwrap()
+    };
+
+    assert_eq!(
+        extraction("type {text}"),
+        ParameterExtraction::Matched {
+            parameters: HashMap::from([("text".to_string(), "hello world".to_string())]),
+        }
+    );
+    assert_eq!(
+        extraction("open {app:slack|mail}"),
+        ParameterExtraction::NoMatch
+    );
+    assert_eq!(extraction("type hello"), ParameterExtraction::NotApplicable);
+    assert!(matches!(
+        extraction("type {text"),
+        ParameterExtraction::InvalidPattern { .. }
+    ));
+    assert!(matches!(explanation.result, MatchResult::Exact { .. }));
+}
+
+#[test]
+fn test_explain_reports_threshold_and_ambiguity_decisions() {
+    let commands = vec![
+        create_command("open slack"),
+        create_command("open slick"),
+        create_command("close mail"),
+    ];
+    let matcher = CommandMatcher::with_config(MatcherConfig {
+        phonetic_weight: 0.0,
+        ..Default::default()
+    });
+
+    let explanation = matcher.explain("open slak", &commands, &all_in_context(&commands));
+
+    assert!((explanation.top_score.unwrap() - 0.9).abs() < 1e-9);
+    assert!((explanation.runner_up_score.unwrap() - 0.8).abs() < 1e-9);
+    assert!(explanation.ambiguous);
+    assert!(explanation.candidates[0].within_ambiguity_delta);
+    assert!(explanation.candidates[1].within_ambiguity_delta);
+    let below_threshold = &explanation.candidates[2];
+    assert_eq!(below_threshold.trigger, "close mail");
+    assert!(!below_threshold.phrases[0].accepted);
+    assert!(!below_threshold.eligible && !below_threshold.within_ambiguity_delta);
+    assert!(matches!(explanation.result, MatchResult::Ambiguous { .. }));
+}
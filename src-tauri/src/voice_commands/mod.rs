@@ -5,15 +5,27 @@
 #![cfg_attr(coverage_nightly, coverage(off))]
 
 pub mod actions;
+pub mod command_prefix;
+pub mod confirmation;
+pub mod disambiguation;
 pub mod executor;
+pub mod grammar;
 pub mod matcher;
+pub mod phonetic;
+pub mod pipeline_log;
 pub mod registry;
 
-use crate::turso::{events as turso_events, TursoClient};
+use crate::app::state::{CommandMatcherState, ContextResolverState};
+use crate::turso::{events as turso_events, PipelineMatch, TursoClient};
+use actions::macro_action::validate_macro;
+use actions::rhai_script::validate_rhai_script;
+use grammar::{validate_aliases, TriggerPattern};
+use pipeline_log::PipelineRecord;
 use registry::{ActionType, CommandDefinition, RegistryError};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Instant;
 use tauri::AppHandle;
 use uuid::Uuid;
 
@@ -28,6 +40,11 @@ pub struct CommandDto {
     pub action_type: String,
     pub parameters: HashMap<String, String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub requires_confirmation: bool,
+    /// Other phrases that run the command; `trigger` is the canonical one
+    #[serde(default)]
+    pub aliases: Vec<String>,
 }
 
 impl From<&CommandDefinition> for CommandDto {
@@ -37,6 +54,10 @@ impl From<&CommandDefinition> for CommandDto {
             ActionType::TypeText => "type_text",
             ActionType::SystemControl => "system_control",
             ActionType::Custom => "custom",
+            ActionType::Macro => "macro",
+            ActionType::Webhook => "webhook",
+            ActionType::SendKeys => "send_keys",
+            ActionType::Rhai => "rhai",
         };
         Self {
             id: cmd.id.to_string(),
@@ -44,6 +65,8 @@ impl From<&CommandDefinition> for CommandDto {
             action_type: action_type.to_string(),
             parameters: cmd.parameters.clone(),
             enabled: cmd.enabled,
+            requires_confirmation: cmd.requires_confirmation,
+            aliases: cmd.aliases.clone(),
         }
     }
 }
@@ -61,6 +84,10 @@ pub struct AddCommandInput {
     pub action_type: String,
     pub parameters: HashMap<String, String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub requires_confirmation: bool,
+    #[serde(default)]
+    pub aliases: Vec<String>,
 }
 
 /// Input for updating an existing command
@@ -71,6 +98,10 @@ pub struct UpdateCommandInput {
     pub action_type: String,
     pub parameters: HashMap<String, String>,
     pub enabled: bool,
+    #[serde(default)]
+    pub requires_confirmation: bool,
+    #[serde(default)]
+    pub aliases: Vec<String>,
 }
 
 /// Map RegistryError to user-friendly error messages
@@ -83,6 +114,27 @@ fn to_user_error(error: RegistryError) -> String {
     }
 }
 
+/// Check a command before it is saved: trigger grammar, aliases, macro steps
+/// and scripts
+pub(crate) fn validate_command(cmd: &CommandDefinition) -> Result<(), String> {
+    TriggerPattern::parse(&cmd.trigger).map_err(|e| e.to_string())?;
+    validate_aliases(&cmd.trigger, &cmd.aliases).map_err(|e| e.to_string())?;
+    match cmd.action_type {
+        ActionType::Macro => validate_macro(&cmd.parameters).map_err(|e| e.message),
+        ActionType::Rhai => validate_rhai_script(&cmd.parameters).map_err(|e| e.message),
+        _ => Ok(()),
+    }
+}
+
+/// Trim alias phrases, dropping blank ones left over from the editor
+fn clean_aliases(aliases: Vec<String>) -> Vec<String> {
+    aliases
+        .into_iter()
+        .map(|alias| alias.trim().to_string())
+        .filter(|alias| !alias.is_empty())
+        .collect()
+}
+
 /// Get all registered commands
 #[tauri::command]
 pub async fn get_commands(
@@ -109,7 +161,10 @@ pub async fn add_command(
         action_type,
         parameters: input.parameters,
         enabled: input.enabled,
+        requires_confirmation: input.requires_confirmation,
+        aliases: clean_aliases(input.aliases),
     };
+    validate_command(&cmd)?;
 
     turso_client
         .add_voice_command(&cmd)
@@ -159,7 +214,10 @@ pub async fn update_command(
         action_type,
         parameters: input.parameters,
         enabled: input.enabled,
+        requires_confirmation: input.requires_confirmation,
+        aliases: clean_aliases(input.aliases),
     };
+    validate_command(&cmd)?;
 
     turso_client
         .update_voice_command(&cmd)
@@ -172,3 +230,98 @@ pub async fn update_command(
     crate::info!("Updated voice command: {}", input.id);
     Ok(CommandDto::from(&cmd))
 }
+
+/// Run one of the candidates of a pending ambiguous match (e.g. clicked in the overlay)
+///
+/// Returns None when the chosen command is waiting for confirmation.
+#[tauri::command]
+pub async fn resolve_ambiguous_command(
+    app_handle: AppHandle,
+    turso_client: tauri::State<'_, TursoClientState>,
+    executor_state: tauri::State<'_, executor::ExecutorState>,
+    id: String,
+) -> Result<Option<executor::ActionResult>, String> {
+    let started = Instant::now();
+    let resolution = executor_state
+        .disambiguator
+        .resolve(&id)
+        .map_err(|e| e.to_string())?;
+    let candidate = resolution.candidate.clone();
+    let emitter = crate::commands::TauriEventEmitter::new(app_handle);
+    let result = confirmation::run_or_confirm(
+        &executor_state.dispatcher,
+        &emitter,
+        &executor_state.confirmations,
+        resolution,
+    )
+    .await;
+    PipelineRecord::ran(PipelineMatch::Manual, &candidate, result.as_ref())
+        .write(&turso_client, None, started.elapsed())
+        .await;
+    result.transpose().map_err(|e| e.to_string())
+}
+
+/// Drop a pending ambiguous match without running any candidate
+#[tauri::command]
+pub fn dismiss_ambiguous_command(executor_state: tauri::State<'_, executor::ExecutorState>) {
+    if executor_state.disambiguator.dismiss() {
+        crate::debug!("Ambiguous command choice dismissed");
+    }
+}
+
+/// Run the command waiting for confirmation (e.g. confirmed in the overlay)
+#[tauri::command]
+pub async fn confirm_command(
+    app_handle: AppHandle,
+    turso_client: tauri::State<'_, TursoClientState>,
+    executor_state: tauri::State<'_, executor::ExecutorState>,
+    id: String,
+) -> Result<executor::ActionResult, String> {
+    let started = Instant::now();
+    let resolution = executor_state
+        .confirmations
+        .confirm(&id)
+        .map_err(|e| e.to_string())?;
+    let emitter = crate::commands::TauriEventEmitter::new(app_handle);
+    let result =
+        disambiguation::run_resolution(&executor_state.dispatcher, &emitter, &resolution).await;
+    PipelineRecord::ran(PipelineMatch::Manual, &resolution.candidate, Some(&result))
+        .write(&turso_client, None, started.elapsed())
+        .await;
+    result.map_err(|e| e.to_string())
+}
+
+/// Drop the command waiting for confirmation without running it
+#[tauri::command]
+pub fn cancel_command_confirmation(executor_state: tauri::State<'_, executor::ExecutorState>) {
+    if executor_state.confirmations.cancel() {
+        crate::debug!("Command confirmation cancelled");
+    }
+}
+
+/// Explain how a phrase would be matched, without running anything
+///
+/// Every command is scored; commands outside the active window context are
+/// reported but not eligible, the same as when the phrase is spoken.
+#[tauri::command]
+pub async fn explain_match(
+    turso_client: tauri::State<'_, TursoClientState>,
+    matcher: tauri::State<'_, CommandMatcherState>,
+    resolver: tauri::State<'_, ContextResolverState>,
+    phrase: String,
+) -> Result<matcher::MatchExplanation, String> {
+    let all_commands = turso_client
+        .list_voice_commands()
+        .await
+        .map_err(to_user_error)?;
+
+    // Like the transcription service, an empty context falls back to all commands
+    let effective_commands = resolver.get_effective_commands_async(&all_commands).await;
+    let in_context: HashSet<Uuid> = if effective_commands.is_empty() {
+        all_commands.iter().map(|cmd| cmd.id).collect()
+    } else {
+        effective_commands.iter().map(|cmd| cmd.id).collect()
+    };
+
+    Ok(matcher.explain(&phrase, &all_commands, &in_context))
+}
@@ -0,0 +1,219 @@
+// Phonetic keys - compares phrases by how they sound rather than how they are spelled
+//
+// Speech recognition errors are phonetic ("often slack" for "open slack"), so
+// plain edit distance undersells them. Each word is reduced to a Metaphone-style
+// key: silent letters are dropped, digraphs become single sounds, vowels after
+// the first letter are removed, and consonants are folded into coarse sound
+// classes (p/b/f/v, t/d, k/g/q, s/z, m/n) that recognizers commonly confuse.
+
+use strsim::normalized_levenshtein;
+
+/// Leading letter pairs whose first letter is silent ("knew", "wrap", "psalm")
+const SILENT_STARTS: &[&str] = &["kn", "gn", "pn", "wr", "ps", "ae"];
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn is_front_vowel(c: Option<char>) -> bool {
+    matches!(c, Some('e' | 'i' | 'y'))
+}
+
+/// Phonetic key for a single word
+///
+/// Words that sound alike get the same key, e.g. "open" and "often",
+/// "photos" and "fotos", "knew" and "new".
+pub fn phonetic_key(word: &str) -> String {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let at = |i: usize| letters.get(i).copied();
+
+    let mut key = String::new();
+    let mut last: Option<char> = None;
+    let mut i = 0;
+
+    let start: String = letters.iter().take(2).collect();
+    if SILENT_STARTS.contains(&start.as_str()) {
+        i = 1;
+    } else if start == "wh" {
+        key.push('W');
+        last = Some('W');
+        i = 2;
+    }
+
+    while i < letters.len() {
+        let c = letters[i];
+        let prev = i.checked_sub(1).and_then(at);
+        let next = at(i + 1);
+        let mut step = 1;
+
+        let code: Option<char> = match c {
+            '0'..='9' => Some(c),
+            c if is_vowel(c) => {
+                if key.is_empty() {
+                    Some(if c == 'y' && next.is_some_and(is_vowel) {
+                        'Y'
+                    } else {
+                        'A'
+                    })
+                } else {
+                    // Vowels are dropped but still separate repeated consonants
+                    last = None;
+                    None
+                }
+            }
+            'b' => {
+                // "climb", "thumb"
+                if prev == Some('m') && next.is_none() {
+                    None
+                } else {
+                    Some('P')
+                }
+            }
+            'p' | 'f' | 'v' => {
+                if c == 'p' && next == Some('h') {
+                    step = 2;
+                }
+                Some('P')
+            }
+            'c' => match next {
+                // "chrome", "chlorine"
+                Some('h') if matches!(at(i + 2), Some('r' | 'l')) => {
+                    step = 2;
+                    Some('K')
+                }
+                Some('h') => {
+                    step = 2;
+                    Some('X')
+                }
+                Some('k') => {
+                    step = 2;
+                    Some('K')
+                }
+                n if is_front_vowel(n) => Some('S'),
+                _ => Some('K'),
+            },
+            'd' => {
+                if next == Some('g') && is_front_vowel(at(i + 2)) {
+                    step = 2;
+                    Some('J')
+                } else {
+                    Some('T')
+                }
+            }
+            't' => match next {
+                Some('h') => {
+                    step = 2;
+                    Some('0')
+                }
+                Some('c') if at(i + 2) == Some('h') => {
+                    step = 3;
+                    Some('X')
+                }
+                Some('i') if matches!(at(i + 2), Some('o' | 'a')) => Some('X'),
+                // "often", "listen", "soften"
+                Some('e')
+                    if matches!(prev, Some('s' | 'f'))
+                        && at(i + 2) == Some('n')
+                        && i + 3 == letters.len() =>
+                {
+                    None
+                }
+                _ => Some('T'),
+            },
+            'g' => match next {
+                Some('h') => {
+                    step = 2;
+                    // "light", "though" lose the gh; "ghost" keeps a hard g
+                    if key.is_empty() || at(i + 2).is_some_and(is_vowel) {
+                        Some('K')
+                    } else {
+                        None
+                    }
+                }
+                // "sign", "signed"
+                Some('n') if i + 2 == letters.len() || letters[i + 2..] == ['e', 'd'] => None,
+                n if is_front_vowel(n) => Some('J'),
+                _ => Some('K'),
+            },
+            'h' => {
+                if next.is_some_and(is_vowel) && !prev.is_some_and(|p| !is_vowel(p)) {
+                    Some('H')
+                } else {
+                    None
+                }
+            }
+            'w' => {
+                if next.is_some_and(is_vowel) {
+                    Some('W')
+                } else {
+                    None
+                }
+            }
+            'j' => Some('J'),
+            'k' | 'q' => {
+                if c == 'q' && next == Some('u') {
+                    step = 2;
+                }
+                Some('K')
+            }
+            'l' => Some('L'),
+            'r' => Some('R'),
+            'm' | 'n' => Some('N'),
+            's' => match next {
+                Some('h') => {
+                    step = 2;
+                    Some('X')
+                }
+                Some('i') if matches!(at(i + 2), Some('o' | 'a')) => Some('X'),
+                _ => Some('S'),
+            },
+            'z' => Some('S'),
+            'x' => {
+                if key.is_empty() {
+                    Some('S')
+                } else {
+                    if last != Some('K') {
+                        key.push('K');
+                    }
+                    last = Some('K');
+                    Some('S')
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(code) = code {
+            if last != Some(code) {
+                key.push(code);
+            }
+            last = Some(code);
+        }
+        i += step;
+    }
+    key
+}
+
+/// Phonetic key for a phrase
+///
+/// Word keys are joined without separators so that split or merged words
+/// ("screen shot" / "screenshot") still compare equal.
+pub fn phrase_key(phrase: &str) -> String {
+    phrase.split_whitespace().map(phonetic_key).collect()
+}
+
+/// Similarity of two phrases by sound (0.0 to 1.0)
+pub fn phonetic_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (phrase_key(a), phrase_key(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    normalized_levenshtein(&a, &b)
+}
+
+#[cfg(test)]
+#[path = "phonetic_test.rs"]
+mod tests;
@@ -0,0 +1,51 @@
+use super::*;
+
+#[test]
+fn test_sound_alike_words_share_a_key() {
+    for (a, b) in [
+        ("open", "often"),
+        ("photos", "fotos"),
+        ("knew", "new"),
+        ("music", "musik"),
+        ("chrome", "crome"),
+        ("call", "cull"),
+        ("listen", "lissen"),
+        ("light", "lite"),
+        ("write", "rite"),
+    ] {
+        assert_eq!(phonetic_key(a), phonetic_key(b), "{} / {}", a, b);
+    }
+}
+
+#[test]
+fn test_different_sounds_get_different_keys() {
+    for (a, b) in [
+        ("slack", "zoom"),
+        ("up", "down"),
+        ("chat", "cat"),
+        ("ship", "sip"),
+    ] {
+        assert_ne!(phonetic_key(a), phonetic_key(b), "{} / {}", a, b);
+    }
+}
+
+#[test]
+fn test_key_ignores_case_and_punctuation() {
+    assert_eq!(phonetic_key("Slack!"), phonetic_key("slack"));
+    assert_eq!(phonetic_key(""), "");
+    assert_eq!(phonetic_key("42"), "42");
+}
+
+#[test]
+fn test_phrase_key_ignores_word_boundaries() {
+    assert_eq!(phrase_key("screen shot"), phrase_key("screenshot"));
+    assert_eq!(phrase_key("  open   slack "), phrase_key("open slack"));
+}
+
+#[test]
+fn test_phonetic_similarity() {
+    assert_eq!(phonetic_similarity("often slack", "open slack"), 1.0);
+    assert!(phonetic_similarity("open zoom", "open slack") < 0.8);
+    assert_eq!(phonetic_similarity("", "open slack"), 0.0);
+    assert_eq!(phonetic_similarity("...", "..."), 0.0);
+}
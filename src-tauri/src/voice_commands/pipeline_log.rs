@@ -0,0 +1,126 @@
+// Pipeline log - what happened to each utterance, written to the pipeline_event table
+//
+// The transcription service and the executor describe their outcome as a
+// `PipelineRecord`; writing it adds the transcription, latency and frontmost
+// app. Logging is best effort: a failed write is only reported in the app log.
+
+use crate::storage::WindowContext;
+use crate::turso::{PipelineEvent, PipelineMatch, PipelineOutcome, TursoClient};
+use crate::voice_commands::disambiguation::PendingCandidate;
+use crate::voice_commands::executor::{ActionError, ActionErrorCode, ActionResult};
+use std::time::Duration;
+
+/// Outcome of one utterance (or UI action), before it is written to the log
+#[derive(Debug, Clone, PartialEq)]
+pub struct PipelineRecord {
+    pub match_result: PipelineMatch,
+    pub match_score: Option<f64>,
+    pub command_id: Option<String>,
+    pub outcome: PipelineOutcome,
+    pub error_code: Option<ActionErrorCode>,
+}
+
+impl PipelineRecord {
+    /// A command was run, or held for confirmation when `run` is None
+    pub fn ran(
+        match_result: PipelineMatch,
+        candidate: &PendingCandidate,
+        run: Option<&Result<ActionResult, ActionError>>,
+    ) -> Self {
+        let (outcome, error_code) = match run {
+            Some(Ok(_)) => (PipelineOutcome::Executed, None),
+            Some(Err(e)) => (PipelineOutcome::Failed, Some(e.code)),
+            None => (PipelineOutcome::AwaitingConfirmation, None),
+        };
+        Self {
+            match_result,
+            match_score: Some(candidate.confidence),
+            command_id: Some(candidate.command.id.to_string()),
+            outcome,
+            error_code,
+        }
+    }
+
+    /// Several commands matched; the user was asked to pick one
+    pub fn awaiting_choice(candidates: &[PendingCandidate]) -> Self {
+        Self {
+            match_result: PipelineMatch::Ambiguous,
+            match_score: candidates.iter().map(|c| c.confidence).reduce(f64::max),
+            command_id: None,
+            outcome: PipelineOutcome::AwaitingChoice,
+            error_code: None,
+        }
+    }
+
+    /// The user said no to a pending confirmation or ambiguous match
+    pub fn declined() -> Self {
+        Self {
+            match_result: PipelineMatch::FollowUp,
+            match_score: None,
+            command_id: None,
+            outcome: PipelineOutcome::Declined,
+            error_code: None,
+        }
+    }
+
+    /// A built-in phrase such as "scratch that" was handled
+    pub fn built_in(outcome: PipelineOutcome) -> Self {
+        Self {
+            match_result: PipelineMatch::BuiltIn,
+            match_score: None,
+            command_id: None,
+            outcome,
+            error_code: None,
+        }
+    }
+
+    /// No command matched and the text was delivered by pasting
+    pub fn delivered(outcome: PipelineOutcome) -> Self {
+        Self {
+            match_result: PipelineMatch::NoMatch,
+            match_score: None,
+            command_id: None,
+            outcome,
+            error_code: None,
+        }
+    }
+
+    /// Build the stored event
+    pub fn into_event(
+        self,
+        transcription_id: Option<String>,
+        latency: Duration,
+        active_app: Option<String>,
+    ) -> PipelineEvent {
+        PipelineEvent {
+            id: uuid::Uuid::new_v4().to_string(),
+            transcription_id,
+            match_result: self.match_result,
+            match_score: self.match_score,
+            command_id: self.command_id,
+            outcome: self.outcome,
+            error_code: self.error_code.map(|code| code.to_string()),
+            latency_ms: latency.as_millis() as u64,
+            active_app,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Write the record to the pipeline log, tagged with the frontmost app
+    pub async fn write(
+        self,
+        client: &TursoClient,
+        transcription_id: Option<String>,
+        latency: Duration,
+    ) {
+        let active_app = WindowContext::capture().app_name;
+        let event = self.into_event(transcription_id, latency, active_app);
+        if let Err(e) = client.add_pipeline_event(&event).await {
+            crate::warn!("Failed to record pipeline event: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "pipeline_log_test.rs"]
+mod tests;
@@ -0,0 +1,96 @@
+use super::*;
+use crate::voice_commands::registry::{ActionType, CommandDefinition};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+fn candidate(confidence: f64) -> PendingCandidate {
+    PendingCandidate {
+        command: CommandDefinition {
+            id: Uuid::new_v4(),
+            trigger: "open slack".to_string(),
+            action_type: ActionType::OpenApp,
+            parameters: HashMap::new(),
+            enabled: true,
+            requires_confirmation: false,
+            aliases: Vec::new(),
+        },
+        parameters: HashMap::new(),
+        confidence,
+    }
+}
+
+#[test]
+fn test_ran_records_executed_command() {
+    let candidate = candidate(0.9);
+    let result = Ok(ActionResult {
+        message: "Opened Slack".to_string(),
+        data: None,
+    });
+
+    let record = PipelineRecord::ran(PipelineMatch::Fuzzy, &candidate, Some(&result));
+
+    assert_eq!(record.match_result, PipelineMatch::Fuzzy);
+    assert_eq!(record.match_score, Some(0.9));
+    assert_eq!(record.command_id, Some(candidate.command.id.to_string()));
+    assert_eq!(record.outcome, PipelineOutcome::Executed);
+    assert_eq!(record.error_code, None);
+}
+
+#[test]
+fn test_ran_records_error_code_of_failed_command() {
+    let result = Err(ActionError {
+        code: ActionErrorCode::PermissionDenied,
+        message: "Accessibility permission required".to_string(),
+        data: None,
+    });
+
+    let record = PipelineRecord::ran(PipelineMatch::Exact, &candidate(1.0), Some(&result));
+
+    assert_eq!(record.outcome, PipelineOutcome::Failed);
+    assert_eq!(record.error_code, Some(ActionErrorCode::PermissionDenied));
+}
+
+#[test]
+fn test_ran_without_result_is_awaiting_confirmation() {
+    let record = PipelineRecord::ran(PipelineMatch::Exact, &candidate(1.0), None);
+    assert_eq!(record.outcome, PipelineOutcome::AwaitingConfirmation);
+}
+
+#[test]
+fn test_awaiting_choice_keeps_top_score() {
+    let record = PipelineRecord::awaiting_choice(&[candidate(0.81), candidate(0.84)]);
+
+    assert_eq!(record.match_result, PipelineMatch::Ambiguous);
+    assert_eq!(record.match_score, Some(0.84));
+    assert_eq!(record.command_id, None);
+    assert_eq!(record.outcome, PipelineOutcome::AwaitingChoice);
+}
+
+#[test]
+fn test_into_event_fills_in_context() {
+    let result = Err(ActionError {
+        code: ActionErrorCode::Timeout,
+        message: "Timed out".to_string(),
+        data: None,
+    });
+    let record = PipelineRecord::ran(PipelineMatch::Exact, &candidate(1.0), Some(&result));
+
+    let event = record.into_event(
+        Some("tr-1".to_string()),
+        Duration::from_millis(1250),
+        Some("Safari".to_string()),
+    );
+
+    assert!(Uuid::parse_str(&event.id).is_ok());
+    assert_eq!(event.transcription_id.as_deref(), Some("tr-1"));
+    assert_eq!(event.error_code.as_deref(), Some("TIMEOUT"));
+    assert_eq!(event.latency_ms, 1250);
+    assert_eq!(event.active_app.as_deref(), Some("Safari"));
+}
+
+#[test]
+fn test_delivered_is_unmatched() {
+    let record = PipelineRecord::delivered(PipelineOutcome::Expanded);
+    assert_eq!(record.match_result, PipelineMatch::NoMatch);
+    assert_eq!(record.outcome, PipelineOutcome::Expanded);
+}
@@ -18,6 +18,14 @@ pub enum ActionType {
     SystemControl,
     /// Custom user-defined action
     Custom,
+    /// Ordered list of other actions
+    Macro,
+    /// Send an HTTP request
+    Webhook,
+    /// Press key combinations (shortcuts)
+    SendKeys,
+    /// Run an embedded Rhai script
+    Rhai,
 }
 
 impl std::str::FromStr for ActionType {
@@ -29,6 +37,10 @@ impl std::str::FromStr for ActionType {
             "type_text" => Ok(ActionType::TypeText),
             "system_control" => Ok(ActionType::SystemControl),
             "custom" => Ok(ActionType::Custom),
+            "macro" => Ok(ActionType::Macro),
+            "webhook" => Ok(ActionType::Webhook),
+            "send_keys" => Ok(ActionType::SendKeys),
+            "rhai" => Ok(ActionType::Rhai),
             _ => Err(format!("Unknown action type: {}", s)),
         }
     }
@@ -47,6 +59,30 @@ pub struct CommandDefinition {
     pub parameters: HashMap<String, String>,
     /// Whether the command is enabled
     pub enabled: bool,
+    /// Whether the command waits for a spoken or clicked confirmation before running
+    #[serde(default)]
+    pub requires_confirmation: bool,
+    /// Other phrases that run the command; `trigger` is the canonical one shown in the UI
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl CommandDefinition {
+    /// All phrases that run the command, canonical trigger first
+    pub fn phrases(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.trigger.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+/// Key used to compare trigger phrases: trimmed, lowercased, single-spaced
+///
+/// Two phrases with the same key can't belong to different commands.
+pub fn normalize_phrase(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
 }
 
 /// Error types for voice command operations
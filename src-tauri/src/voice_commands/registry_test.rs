@@ -13,6 +13,8 @@ fn create_test_command(trigger: &str) -> CommandDefinition {
         action_type: ActionType::OpenApp,
         parameters: HashMap::new(),
         enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
     }
 }
 
@@ -38,6 +40,8 @@ fn test_command_definition_with_parameters() {
         action_type: ActionType::OpenApp,
         parameters: params.clone(),
         enabled: true,
+        requires_confirmation: false,
+        aliases: Vec::new(),
     };
 
     // Verify parameters are stored correctly
@@ -51,3 +55,25 @@ fn test_command_definition_with_parameters() {
     assert!(json.contains("Slack"));
 }
 
+
+#[test]
+fn test_phrases_lists_trigger_first() {
+    let mut cmd = create_test_command("open slack");
+    cmd.aliases = vec!["launch slack".to_string(), "start slack".to_string()];
+
+    let phrases: Vec<&str> = cmd.phrases().collect();
+    assert_eq!(phrases, ["open slack", "launch slack", "start slack"]);
+}
+
+#[test]
+fn test_aliases_default_when_missing_from_json() {
+    let json = r#"{"id":"5f8f2a53-5f0e-4e53-9d5b-3f6f1d1c2b7a","trigger":"open slack","action_type":"open_app","parameters":{},"enabled":true}"#;
+    let cmd: CommandDefinition = serde_json::from_str(json).unwrap();
+    assert!(cmd.aliases.is_empty());
+}
+
+#[test]
+fn test_normalize_phrase() {
+    assert_eq!(normalize_phrase("  Open   Slack "), "open slack");
+    assert_eq!(normalize_phrase("open slack"), normalize_phrase("OPEN SLACK"));
+}
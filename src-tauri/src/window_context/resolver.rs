@@ -8,7 +8,7 @@
 // to determine which commands and dictionary entries are active for the current
 // window context.
 
-use super::{OverrideMode, WindowMonitor};
+use super::{CommandPrefixMode, OverrideMode, WindowMonitor};
 use crate::dictionary::DictionaryEntry;
 use crate::turso::TursoClient;
 use crate::voice_commands::registry::CommandDefinition;
@@ -535,6 +535,34 @@ impl ContextResolver {
             }
         }
     }
+
+    /// Command prefix mode of the active context
+    ///
+    /// Returns `Inherit` when no context is active or it cannot be loaded.
+    pub async fn get_command_prefix_mode_async(&self) -> CommandPrefixMode {
+        let context_id = match self.monitor.lock() {
+            Ok(monitor) => monitor.get_current_context(),
+            Err(_) => {
+                crate::warn!("[ContextResolver] Failed to lock monitor, inheriting command prefix");
+                return CommandPrefixMode::Inherit;
+            }
+        };
+        let Some(context_id) = context_id else {
+            return CommandPrefixMode::Inherit;
+        };
+
+        match self.client.get_window_context(context_id).await {
+            Ok(Some(context)) => context.command_prefix,
+            Ok(None) => CommandPrefixMode::Inherit,
+            Err(e) => {
+                crate::warn!(
+                    "[ContextResolver] Failed to get context from Turso: {}, inheriting command prefix",
+                    e
+                );
+                CommandPrefixMode::Inherit
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -43,6 +43,19 @@ pub enum OverrideMode {
     Replace,
 }
 
+/// Whether voice commands in a context need the command prefix
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandPrefixMode {
+    /// Follow the global command prefix setting
+    #[default]
+    Inherit,
+    /// Commands only fire after the prefix (or from the command hotkey)
+    Required,
+    /// Commands fire without the prefix
+    Off,
+}
+
 /// A window context definition
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -56,6 +69,8 @@ pub struct WindowContext {
     pub dictionary_entry_ids: Vec<String>,
     pub enabled: bool,
     pub priority: i32,
+    #[serde(default)]
+    pub command_prefix: CommandPrefixMode,
 }
 
 #[cfg(test)]
@@ -4,3 +4,20 @@ use super::*;
 fn override_mode_defaults_to_merge() {
     assert_eq!(OverrideMode::default(), OverrideMode::Merge);
 }
+
+#[test]
+fn command_prefix_mode_defaults_to_inherit_when_missing() {
+    let json = r#"{
+        "id": "00000000-0000-0000-0000-000000000001",
+        "name": "Chat",
+        "matcher": {"appName": "Slack"},
+        "commandMode": "merge",
+        "dictionaryMode": "merge",
+        "commandIds": [],
+        "dictionaryEntryIds": [],
+        "enabled": true,
+        "priority": 0
+    }"#;
+    let context: WindowContext = serde_json::from_str(json).unwrap();
+    assert_eq!(context.command_prefix, CommandPrefixMode::Inherit);
+}
@@ -11,7 +11,10 @@ private class SharedAudioEngineManager {
     private var audioEngine: AVAudioEngine?
     private var isRunning = false
     private var isCapturing = false
+    private var isCapturePaused = false
     private var recordingStartTime: Date?
+    private var pauseStartTime: Date?
+    private var pausedDuration: TimeInterval = 0
     private var lastError: String?
     private var currentLevel: UInt8 = 0
     private var currentDeviceName: String?
@@ -28,6 +31,11 @@ private class SharedAudioEngineManager {
     private var sumSquares: Float = 0.0
     private let samplesPerLevelEmission: Int = 16000 / 20 // ~50ms at 16kHz
 
+    // Sample tap for listening mode (guarded by stateLock, drained by Rust)
+    private var isTapEnabled = false
+    private var tapSamples: [Float] = []
+    private let maxTapSamples: Int = 16000 * 10 // ~10s at 16kHz
+
     // Serial queue for thread-safe audio operations
     private let audioQueue = DispatchQueue(label: "com.heycat.sharedaudioengine", qos: .userInteractive)
 
@@ -319,7 +327,10 @@ private class SharedAudioEngineManager {
                 captureFileURL = fileURL
                 captureFormat = format
                 isCapturing = true
+                isCapturePaused = false
                 recordingStartTime = Date()
+                pauseStartTime = nil
+                pausedDuration = 0
                 return true
             } catch {
                 stateLock.lock()
@@ -339,6 +350,8 @@ private class SharedAudioEngineManager {
             }
 
             isCapturing = false
+            isCapturePaused = false
+            pauseStartTime = nil
 
             // Close the capture file (this flushes any buffered data)
             captureFile = nil
@@ -351,6 +364,87 @@ private class SharedAudioEngineManager {
         }
     }
 
+    /// Close the current capture file and continue capturing into a new one.
+    /// Used by long-form recordings to spill audio into rotating segment files.
+    /// Returns the URL of the closed segment, or nil if not capturing.
+    func rotateCapture() -> URL? {
+        return audioQueue.sync {
+            guard isCapturing, let format = captureFormat else {
+                return nil
+            }
+
+            let tempDir = FileManager.default.temporaryDirectory
+            let nextURL = tempDir.appendingPathComponent("capture_\(UUID().uuidString).wav")
+
+            do {
+                let nextFile = try AVAudioFile(forWriting: nextURL, settings: format.settings)
+
+                // Closing the previous file flushes buffered data
+                let finishedURL = captureFileURL
+                captureFile = nextFile
+                captureFileURL = nextURL
+
+                // Duration tracking restarts with the new segment
+                stateLock.lock()
+                recordingStartTime = Date()
+                pausedDuration = 0
+                pauseStartTime = isCapturePaused ? Date() : nil
+                stateLock.unlock()
+
+                return finishedURL
+            } catch {
+                stateLock.lock()
+                lastError = "Failed to create segment file: \(error.localizedDescription)"
+                stateLock.unlock()
+                return nil
+            }
+        }
+    }
+
+    /// Pause capturing without closing the capture file.
+    /// Incoming audio is dropped until resumeCapture() is called.
+    func pauseCapture() -> Bool {
+        return audioQueue.sync {
+            guard isCapturing else {
+                stateLock.lock()
+                lastError = "Not capturing"
+                stateLock.unlock()
+                return false
+            }
+
+            if !isCapturePaused {
+                isCapturePaused = true
+                stateLock.lock()
+                pauseStartTime = Date()
+                stateLock.unlock()
+            }
+            return true
+        }
+    }
+
+    /// Resume a paused capture, appending to the same capture file.
+    func resumeCapture() -> Bool {
+        return audioQueue.sync {
+            guard isCapturing else {
+                stateLock.lock()
+                lastError = "Not capturing"
+                stateLock.unlock()
+                return false
+            }
+
+            if isCapturePaused {
+                stateLock.lock()
+                if let pauseStart = pauseStartTime {
+                    pausedDuration += Date().timeIntervalSince(pauseStart)
+                }
+                pauseStartTime = nil
+                stateLock.unlock()
+                isCapturePaused = false
+            }
+            return true
+        }
+    }
+
     // MARK: - Audio Processing
 
     /// Process incoming audio buffer for both level monitoring and capture.
@@ -397,10 +491,21 @@ private class SharedAudioEngineManager {
         let frameCount = Int(audioBuffer.frameLength)
 
         // Write to capture file if capturing (AVAudioFile handles buffering internally)
-        if isCapturing, let file = captureFile {
+        if isCapturing, !isCapturePaused, let file = captureFile {
             try? file.write(from: audioBuffer)
         }
 
+        // Copy mono samples into the listening tap if enabled
+        stateLock.lock()
+        if isTapEnabled {
+            tapSamples.append(contentsOf: UnsafeBufferPointer(start: channelData[0], count: frameCount))
+            // Drop the oldest samples if the consumer falls behind
+            if tapSamples.count > maxTapSamples {
+                tapSamples.removeFirst(tapSamples.count - maxTapSamples)
+            }
+        }
+        stateLock.unlock()
+
         // Calculate RMS level for monitoring (lightweight, no lock needed)
         var sumSq: Float = 0.0
         let channelCount = Int(audioBuffer.format.channelCount)
@@ -439,6 +544,25 @@ private class SharedAudioEngineManager {
         stateLock.unlock()
     }
 
+    // MARK: - Sample Tap
+
+    /// Enable or disable the sample tap. Disabling discards pending samples.
+    func setTapEnabled(_ enabled: Bool) {
+        stateLock.lock()
+        isTapEnabled = enabled
+        tapSamples.removeAll()
+        stateLock.unlock()
+    }
+
+    /// Take all samples accumulated by the tap since the last drain.
+    func drainTap() -> [Float] {
+        stateLock.lock()
+        defer { stateLock.unlock() }
+        let samples = tapSamples
+        tapSamples.removeAll(keepingCapacity: true)
+        return samples
+    }
+
     // MARK: - State Queries
 
     func getLevel() -> UInt8 {
@@ -462,7 +586,12 @@ private class SharedAudioEngineManager {
         guard let startTime = recordingStartTime, isCapturing else {
             return 0.0
         }
-        return Date().timeIntervalSince(startTime)
+        // Exclude paused time (including an in-progress pause)
+        var paused = pausedDuration
+        if let pauseStart = pauseStartTime {
+            paused += Date().timeIntervalSince(pauseStart)
+        }
+        return max(Date().timeIntervalSince(startTime) - paused, 0.0)
     }
 
     func getSampleCount() -> Int {
@@ -473,6 +602,11 @@ private class SharedAudioEngineManager {
         return 0
     }
 
+    /// Name of the device the engine was started with (nil = system default)
+    func getCurrentDevice() -> String? {
+        return audioQueue.sync { currentDeviceName }
+    }
+
     func getLastError() -> String? {
         stateLock.lock()
         defer { stateLock.unlock() }
@@ -616,6 +750,43 @@ public func audioEngineStopCapture() -> SRString {
     return SRString("")
 }
 
+/// Rotate the capture file. Returns the path of the finished segment,
+/// or empty string if not capturing or on error.
+@_cdecl("swift_audio_engine_rotate_capture")
+public func audioEngineRotateCapture() -> SRString {
+    if let url = SharedAudioEngineManager.shared.rotateCapture() {
+        return SRString(url.path)
+    }
+    return SRString("")
+}
+
+/// Pause audio capture without finalizing the file. Returns true on success.
+@_cdecl("swift_audio_engine_pause_capture")
+public func audioEnginePauseCapture() -> Bool {
+    return SharedAudioEngineManager.shared.pauseCapture()
+}
+
+/// Resume a paused audio capture. Returns true on success.
+@_cdecl("swift_audio_engine_resume_capture")
+public func audioEngineResumeCapture() -> Bool {
+    return SharedAudioEngineManager.shared.resumeCapture()
+}
+
+/// Enable or disable the 16kHz mono sample tap used by listening mode.
+@_cdecl("swift_audio_engine_set_tap_enabled")
+public func audioEngineSetTapEnabled(enabled: Bool) {
+    SharedAudioEngineManager.shared.setTapEnabled(enabled)
+}
+
+/// Drain samples from the tap as native-endian Float32 bytes.
+/// Returns empty data if the tap is disabled or no samples are pending.
+@_cdecl("swift_audio_engine_drain_tap")
+public func audioEngineDrainTap() -> SRData {
+    let samples = SharedAudioEngineManager.shared.drainTap()
+    let bytes = samples.withUnsafeBufferPointer { Array(UnsafeRawBufferPointer($0)) }
+    return SRData(bytes)
+}
+
 /// Check if currently capturing.
 @_cdecl("swift_audio_engine_is_capturing")
 public func audioEngineIsCapturing() -> Bool {
@@ -634,6 +805,12 @@ public func audioEngineGetSampleCount() -> Int {
     return SharedAudioEngineManager.shared.getSampleCount()
 }
 
+/// Get the name of the active input device (empty string = system default).
+@_cdecl("swift_audio_engine_get_device")
+public func audioEngineGetDevice() -> SRString {
+    return SRString(SharedAudioEngineManager.shared.getCurrentDevice() ?? "")
+}
+
 /// Get the last error message, if any.
 @_cdecl("swift_audio_engine_get_error")
 public func audioEngineGetError() -> SRString {
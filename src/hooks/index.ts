@@ -62,6 +62,7 @@ export { useAudioDevices } from "./useAudioDevices";
 export { useAudioLevelMonitor } from "./useAudioLevelMonitor";
 export { useAudioPlayback } from "./useAudioPlayback";
 export { useCatOverlay } from "./useCatOverlay";
+export { useCommandConfirmation } from "./useCommandConfirmation";
 export { useDictionary } from "./useDictionary";
 export { useDisambiguation } from "./useDisambiguation";
 export { useMultiModelStatus } from "./useMultiModelStatus";
@@ -0,0 +1,121 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from "vitest";
+import { renderHook, act, waitFor } from "@testing-library/react";
+import { useCommandConfirmation } from "./useCommandConfirmation";
+
+// Mock Tauri APIs
+const mockListen = vi.fn();
+const mockUnlisten = vi.fn();
+const mockInvoke = vi.fn();
+
+vi.mock("@tauri-apps/api/event", () => ({
+  listen: (...args: unknown[]) => mockListen(...args),
+}));
+
+vi.mock("@tauri-apps/api/core", () => ({
+  invoke: (...args: unknown[]) => mockInvoke(...args),
+}));
+
+const requiredPayload = {
+  command_id: "cmd-1",
+  trigger: "clear downloads",
+  transcription: "clear downloads",
+  timeout_ms: 8000,
+};
+
+describe("useCommandConfirmation", () => {
+  let requiredCallback: ((event: { payload: unknown }) => void) | null;
+  let executedCallback: (() => void) | null;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    requiredCallback = null;
+    executedCallback = null;
+    mockInvoke.mockResolvedValue(undefined);
+    mockListen.mockImplementation(
+      (eventName: string, callback: (event: { payload: unknown }) => void) => {
+        if (eventName === "command_confirmation_required") {
+          requiredCallback = callback;
+        }
+        if (eventName === "command_executed") {
+          executedCallback = callback as () => void;
+        }
+        return Promise.resolve(mockUnlisten);
+      }
+    );
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+  });
+
+  it("shows the waiting command and confirms it by ID", async () => {
+    const { result } = renderHook(() => useCommandConfirmation());
+
+    await waitFor(() => {
+      expect(requiredCallback).not.toBeNull();
+    });
+
+    act(() => {
+      requiredCallback!({ payload: requiredPayload });
+    });
+
+    expect(result.current.pending).toEqual({
+      commandId: "cmd-1",
+      trigger: "clear downloads",
+      transcription: "clear downloads",
+    });
+
+    await act(async () => {
+      await result.current.confirm();
+    });
+
+    expect(mockInvoke).toHaveBeenCalledWith("confirm_command", { id: "cmd-1" });
+
+    act(() => {
+      executedCallback!();
+    });
+
+    expect(result.current.pending).toBeNull();
+  });
+
+  it("cancels the waiting command", async () => {
+    const { result } = renderHook(() => useCommandConfirmation());
+
+    await waitFor(() => {
+      expect(requiredCallback).not.toBeNull();
+    });
+
+    act(() => {
+      requiredCallback!({ payload: requiredPayload });
+    });
+
+    act(() => {
+      result.current.cancel();
+    });
+
+    expect(result.current.pending).toBeNull();
+    expect(mockInvoke).toHaveBeenCalledWith("cancel_command_confirmation");
+  });
+
+  it("clears the waiting command when it times out", async () => {
+    const { result } = renderHook(() => useCommandConfirmation());
+
+    await waitFor(() => {
+      expect(requiredCallback).not.toBeNull();
+    });
+
+    vi.useFakeTimers();
+    act(() => {
+      requiredCallback!({ payload: requiredPayload });
+    });
+
+    expect(result.current.pending).not.toBeNull();
+
+    act(() => {
+      vi.advanceTimersByTime(8000);
+    });
+    vi.useRealTimers();
+
+    expect(result.current.pending).toBeNull();
+  });
+});
@@ -0,0 +1,125 @@
+import { useState, useEffect, useCallback, useRef } from "react";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
+import { invoke } from "@tauri-apps/api/core";
+
+/** Payload for command_confirmation_required event */
+interface CommandConfirmationRequiredPayload {
+  command_id: string;
+  trigger: string;
+  transcription: string;
+  /** How long the backend waits for a "yes", in milliseconds */
+  timeout_ms: number;
+}
+
+/** A command waiting for confirmation */
+export interface PendingConfirmation {
+  commandId: string;
+  trigger: string;
+  transcription: string;
+}
+
+/** Return type of the useCommandConfirmation hook */
+export interface UseCommandConfirmationResult {
+  /** The command waiting for confirmation, if any */
+  pending: PendingConfirmation | null;
+  /** Run the waiting command */
+  confirm: () => Promise<void>;
+  /** Drop the waiting command without running it */
+  cancel: () => void;
+}
+
+/**
+ * Custom hook for commands flagged as requiring confirmation
+ * Listens to backend command_confirmation_required events and lets the user
+ * confirm or cancel by click (a spoken "yes"/"no" is handled by the backend)
+ */
+export function useCommandConfirmation(): UseCommandConfirmationResult {
+  const [pending, setPending] = useState<PendingConfirmation | null>(null);
+  const pendingRef = useRef<PendingConfirmation | null>(null);
+  const expiryTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+
+  const clear = useCallback(() => {
+    if (expiryTimer.current) {
+      clearTimeout(expiryTimer.current);
+      expiryTimer.current = null;
+    }
+    pendingRef.current = null;
+    setPending(null);
+  }, []);
+
+  useEffect(() => {
+    const unlistenFns: UnlistenFn[] = [];
+
+    /* v8 ignore start -- @preserve */
+    const setupListeners = async () => {
+      const unlistenRequired = await listen<CommandConfirmationRequiredPayload>(
+        "command_confirmation_required",
+        (event) => {
+          if (expiryTimer.current) {
+            clearTimeout(expiryTimer.current);
+          }
+          const next = {
+            commandId: event.payload.command_id,
+            trigger: event.payload.trigger,
+            transcription: event.payload.transcription,
+          };
+          pendingRef.current = next;
+          setPending(next);
+          // The backend cancels the command after the timeout
+          if (event.payload.timeout_ms > 0) {
+            expiryTimer.current = setTimeout(clear, event.payload.timeout_ms);
+          }
+        }
+      );
+      unlistenFns.push(unlistenRequired);
+
+      // A spoken "yes" runs the command; any outcome ends the wait
+      const unlistenExecuted = await listen("command_executed", clear);
+      unlistenFns.push(unlistenExecuted);
+
+      const unlistenFailed = await listen("command_failed", clear);
+      unlistenFns.push(unlistenFailed);
+    };
+
+    setupListeners();
+    /* v8 ignore stop */
+
+    return () => {
+      /* v8 ignore start -- @preserve */
+      unlistenFns.forEach((unlisten) => unlisten());
+      if (expiryTimer.current) {
+        clearTimeout(expiryTimer.current);
+      }
+      /* v8 ignore stop */
+    };
+  }, [clear]);
+
+  const confirm = useCallback(async () => {
+    const waiting = pendingRef.current;
+    if (!waiting) return;
+    /* v8 ignore start -- @preserve */
+    try {
+      await invoke("confirm_command", { id: waiting.commandId });
+      // State will be cleared by the command_executed listener
+    } catch (error) {
+      console.error("[heycat] Failed to confirm command:", error);
+      clear();
+    }
+    /* v8 ignore stop */
+  }, [clear]);
+
+  const cancel = useCallback(() => {
+    clear();
+    /* v8 ignore start -- @preserve */
+    invoke("cancel_command_confirmation").catch((error) => {
+      console.error("[heycat] Failed to cancel command confirmation:", error);
+    });
+    /* v8 ignore stop */
+  }, [clear]);
+
+  return {
+    pending,
+    confirm,
+    cancel,
+  };
+}
@@ -206,5 +206,49 @@ describe("useDisambiguation", () => {
     expect(result.current.isAmbiguous).toBe(false);
     expect(result.current.transcription).toBeNull();
     expect(result.current.candidates).toEqual([]);
+    expect(mockInvoke).toHaveBeenCalledWith("dismiss_ambiguous_command");
+  });
+
+  it("clears state when the choice times out", async () => {
+    let ambiguousCallback: ((event: { payload: unknown }) => void) | null = null;
+
+    mockListen.mockImplementation(
+      (
+        eventName: string,
+        callback: (event: { payload: unknown }) => void
+      ) => {
+        if (eventName === "command_ambiguous") {
+          ambiguousCallback = callback;
+        }
+        return Promise.resolve(mockUnlisten);
+      }
+    );
+
+    const { result } = renderHook(() => useDisambiguation());
+
+    await waitFor(() => {
+      expect(ambiguousCallback).not.toBeNull();
+    });
+
+    vi.useFakeTimers();
+    act(() => {
+      ambiguousCallback!({
+        payload: {
+          transcription: "open app",
+          candidates: [{ id: "1", trigger: "open slack", confidence: 0.85 }],
+          timeout_ms: 8000,
+        },
+      });
+    });
+
+    expect(result.current.isAmbiguous).toBe(true);
+
+    act(() => {
+      vi.advanceTimersByTime(8000);
+    });
+    vi.useRealTimers();
+
+    expect(result.current.isAmbiguous).toBe(false);
+    expect(result.current.candidates).toEqual([]);
   });
 });
@@ -1,4 +1,4 @@
-import { useState, useEffect, useCallback } from "react";
+import { useState, useEffect, useCallback, useRef } from "react";
 import { listen, UnlistenFn } from "@tauri-apps/api/event";
 import { invoke } from "@tauri-apps/api/core";
 
@@ -13,6 +13,8 @@ export interface CommandCandidate {
 interface CommandAmbiguousPayload {
   transcription: string;
   candidates: CommandCandidate[];
+  /** How long the backend accepts a spoken choice, in milliseconds */
+  timeout_ms: number;
 }
 
 /** Return type of the useDisambiguation hook */
@@ -25,7 +27,7 @@ export interface UseDisambiguationResult {
   candidates: CommandCandidate[];
   /** Execute a specific command by ID */
   executeCommand: (commandId: string) => Promise<void>;
-  /** Dismiss disambiguation without executing */
+  /** Dismiss disambiguation without executing (also cancels the spoken choice) */
   dismiss: () => void;
 }
 
@@ -37,6 +39,17 @@ export function useDisambiguation(): UseDisambiguationResult {
   const [isAmbiguous, setIsAmbiguous] = useState(false);
   const [transcription, setTranscription] = useState<string | null>(null);
   const [candidates, setCandidates] = useState<CommandCandidate[]>([]);
+  const expiryTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+
+  const clear = useCallback(() => {
+    if (expiryTimer.current) {
+      clearTimeout(expiryTimer.current);
+      expiryTimer.current = null;
+    }
+    setIsAmbiguous(false);
+    setTranscription(null);
+    setCandidates([]);
+  }, []);
 
   useEffect(() => {
     const unlistenFns: UnlistenFn[] = [];
@@ -46,28 +59,34 @@ export function useDisambiguation(): UseDisambiguationResult {
       const unlistenAmbiguous = await listen<CommandAmbiguousPayload>(
         "command_ambiguous",
         (event) => {
+          if (expiryTimer.current) {
+            clearTimeout(expiryTimer.current);
+          }
           setIsAmbiguous(true);
           setTranscription(event.payload.transcription);
           setCandidates(event.payload.candidates);
+          // The backend stops accepting a choice after the timeout
+          if (event.payload.timeout_ms > 0) {
+            expiryTimer.current = setTimeout(clear, event.payload.timeout_ms);
+          }
         }
       );
       unlistenFns.push(unlistenAmbiguous);
 
       // Also listen for command_executed to auto-dismiss
-      const unlistenExecuted = await listen("command_executed", () => {
-        setIsAmbiguous(false);
-        setTranscription(null);
-        setCandidates([]);
-      });
+      const unlistenExecuted = await listen("command_executed", clear);
       unlistenFns.push(unlistenExecuted);
 
       // And command_failed to auto-dismiss
-      const unlistenFailed = await listen("command_failed", () => {
-        setIsAmbiguous(false);
-        setTranscription(null);
-        setCandidates([]);
-      });
+      const unlistenFailed = await listen("command_failed", clear);
       unlistenFns.push(unlistenFailed);
+
+      // A chosen command that needs confirmation ends the choice too
+      const unlistenConfirmation = await listen(
+        "command_confirmation_required",
+        clear
+      );
+      unlistenFns.push(unlistenConfirmation);
     };
 
     setupListeners();
@@ -76,30 +95,34 @@ export function useDisambiguation(): UseDisambiguationResult {
     return () => {
       /* v8 ignore start -- @preserve */
       unlistenFns.forEach((unlisten) => unlisten());
+      if (expiryTimer.current) {
+        clearTimeout(expiryTimer.current);
+      }
       /* v8 ignore stop */
     };
-  }, []);
+  }, [clear]);
 
   const executeCommand = useCallback(async (commandId: string) => {
     /* v8 ignore start -- @preserve */
     try {
-      await invoke("test_command", { id: commandId });
+      await invoke("resolve_ambiguous_command", { id: commandId });
       // State will be cleared by the command_executed listener
     } catch (error) {
       console.error("[heycat] Failed to execute command:", error);
       // Clear state on error
-      setIsAmbiguous(false);
-      setTranscription(null);
-      setCandidates([]);
+      clear();
     }
     /* v8 ignore stop */
-  }, []);
+  }, [clear]);
 
   const dismiss = useCallback(() => {
-    setIsAmbiguous(false);
-    setTranscription(null);
-    setCandidates([]);
-  }, []);
+    clear();
+    /* v8 ignore start -- @preserve */
+    invoke("dismiss_ambiguous_command").catch((error) => {
+      console.error("[heycat] Failed to dismiss command choice:", error);
+    });
+    /* v8 ignore stop */
+  }, [clear]);
 
   return {
     isAmbiguous,
@@ -7,6 +7,8 @@ export interface RecordingMetadata {
   duration_secs: number;
   file_path: string;
   sample_count: number;
+  /** Duration of the saved file after silence trimming (null = not trimmed) */
+  trimmed_duration_secs: number | null;
 }
 
 /** Response from get_recording_state command */
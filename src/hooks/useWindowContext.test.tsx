@@ -28,6 +28,7 @@ function createTestContext(overrides: Partial<WindowContext> = {}): WindowContex
     dictionaryEntryIds: [],
     enabled: true,
     priority: 0,
+    commandPrefix: "inherit",
     ...overrides,
   };
 }
@@ -116,6 +117,7 @@ describe("useWindowContext", () => {
         dictionaryEntryIds: undefined,
         priority: 5,
         enabled: true,
+        commandPrefix: undefined,
       });
     });
   });
@@ -139,6 +141,7 @@ describe("useWindowContext", () => {
         dictionaryMode: "replace",
         priority: 10,
         enabled: false,
+        commandPrefix: "required",
       });
 
       expect(mockInvoke).toHaveBeenCalledWith("update_window_context", {
@@ -153,6 +156,7 @@ describe("useWindowContext", () => {
         dictionaryEntryIds: undefined,
         priority: 10,
         enabled: false,
+        commandPrefix: "required",
       });
     });
   });
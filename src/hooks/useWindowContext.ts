@@ -30,6 +30,7 @@ export function useWindowContext() {
       dictionaryEntryIds?: string[];
       enabled?: boolean;
       priority?: number;
+      commandPrefix?: string;
     }) =>
       invoke<WindowContext>("add_window_context", {
         name: data.name,
@@ -42,6 +43,7 @@ export function useWindowContext() {
         dictionaryEntryIds: data.dictionaryEntryIds,
         enabled: data.enabled,
         priority: data.priority,
+        commandPrefix: data.commandPrefix,
       }),
     // Note: NO onSuccess invalidation - Event Bridge handles it
   });
@@ -59,6 +61,7 @@ export function useWindowContext() {
       dictionaryEntryIds?: string[];
       enabled?: boolean;
       priority?: number;
+      commandPrefix?: string;
     }) =>
       invoke<void>("update_window_context", {
         id: data.id,
@@ -72,6 +75,7 @@ export function useWindowContext() {
         dictionaryEntryIds: data.dictionaryEntryIds,
         enabled: data.enabled,
         priority: data.priority,
+        commandPrefix: data.commandPrefix,
       }),
     // Note: NO onSuccess invalidation - Event Bridge handles it
   });
@@ -22,6 +22,8 @@ export const eventNames = {
   RECORDING_STARTED: "recording_started",
   RECORDING_STOPPED: "recording_stopped",
   RECORDING_CANCELLED: "recording_cancelled",
+  RECORDING_PAUSED: "recording_paused",
+  RECORDING_RESUMED: "recording_resumed",
   RECORDING_ERROR: "recording_error",
 
   // Transcription events
@@ -147,6 +149,22 @@ export async function setupEventBridge(
     })
   );
 
+  unlistenFns.push(
+    await listen(eventNames.RECORDING_PAUSED, () => {
+      queryClient.invalidateQueries({
+        queryKey: queryKeys.tauri.getRecordingState,
+      });
+    })
+  );
+
+  unlistenFns.push(
+    await listen(eventNames.RECORDING_RESUMED, () => {
+      queryClient.invalidateQueries({
+        queryKey: queryKeys.tauri.getRecordingState,
+      });
+    })
+  );
+
   // Note: TRANSCRIPTION_COMPLETED is handled below with store update AND query invalidation
 
   // Model events - invalidate all model status queries
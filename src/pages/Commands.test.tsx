@@ -40,6 +40,8 @@ const sampleCommands: CommandDto[] = [
     action_type: "open_app",
     parameters: { app: "Slack" },
     enabled: true,
+    requires_confirmation: false,
+    aliases: [],
   },
   {
     id: "2",
@@ -47,6 +49,8 @@ const sampleCommands: CommandDto[] = [
     action_type: "type_text",
     parameters: { text: "hello@example.com" },
     enabled: true,
+    requires_confirmation: false,
+    aliases: [],
   },
   {
     id: "3",
@@ -54,6 +58,8 @@ const sampleCommands: CommandDto[] = [
     action_type: "system_control",
     parameters: { control: "volume_up" },
     enabled: false,
+    requires_confirmation: false,
+    aliases: [],
   },
 ];
 
@@ -220,6 +226,8 @@ describe("Commands", () => {
         action_type: "open_app",
         parameters: { app: "Slack" },
         enabled: false,
+        requires_confirmation: false,
+        aliases: [],
       },
     });
   });
@@ -277,6 +285,46 @@ describe("Commands", () => {
     expect(triggerInput).toHaveValue("open slack");
   });
 
+  it("saves an alias as the trigger phrase when marked canonical", async () => {
+    const user = userEvent.setup();
+    const slack = { ...sampleCommands[0], aliases: ["launch slack"] };
+    mockInvoke.mockImplementation((cmd: string) => {
+      if (cmd === "get_commands") return Promise.resolve([slack]);
+      if (cmd === "list_window_contexts") return Promise.resolve([]);
+      if (cmd === "update_command") return Promise.resolve(slack);
+      return Promise.resolve(undefined);
+    });
+
+    renderWithProviders(<Commands />);
+
+    await waitFor(() => {
+      expect(screen.getByText('Also: "launch slack"')).toBeDefined();
+    });
+
+    await user.click(screen.getByRole("button", { name: /edit open slack/i }));
+    const dialog = screen.getByRole("dialog", { name: /edit voice command/i });
+
+    await user.click(
+      within(dialog).getByRole("button", { name: /use alias 1 as trigger phrase/i })
+    );
+    expect(
+      within(dialog).getByRole("textbox", { name: /trigger phrase/i })
+    ).toHaveValue("launch slack");
+
+    await user.click(
+      within(dialog).getByRole("button", { name: /save changes/i })
+    );
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith("update_command", {
+        input: expect.objectContaining({
+          trigger: "launch slack",
+          aliases: ["open slack"],
+        }),
+      });
+    });
+  });
+
   it("creates new command through modal form", async () => {
     const user = userEvent.setup();
     const newCommand: CommandDto = {
@@ -285,6 +333,8 @@ describe("Commands", () => {
       action_type: "open_app",
       parameters: { app: "Spotify" },
       enabled: true,
+      requires_confirmation: false,
+      aliases: [],
     };
 
     mockInvoke.mockImplementation((cmd: string) => {
@@ -328,6 +378,8 @@ describe("Commands", () => {
           action_type: "open_app",
           parameters: { app: "Spotify" },
           enabled: true,
+          requires_confirmation: false,
+          aliases: [],
         },
       });
     });
@@ -22,6 +22,10 @@ export interface CommandDto {
   action_type: string;
   parameters: Record<string, string>;
   enabled: boolean;
+  /** Ask "yes" or "no" before running the command */
+  requires_confirmation: boolean;
+  /** Other phrases that run the command; `trigger` is the canonical one */
+  aliases: string[];
 }
 
 export interface CommandsProps {
@@ -96,6 +100,8 @@ export function Commands(_props: CommandsProps) {
     trigger: string,
     actionType: string,
     parameters: Record<string, string>,
+    requiresConfirmation: boolean,
+    aliases: string[],
     contextIds: string[]
   ) => {
     try {
@@ -110,6 +116,8 @@ export function Commands(_props: CommandsProps) {
             action_type: actionType,
             parameters,
             enabled: editingCommand.enabled,
+            requires_confirmation: requiresConfirmation,
+            aliases,
           },
         });
         commandId = updatedCommand.id;
@@ -128,6 +136,8 @@ export function Commands(_props: CommandsProps) {
             action_type: actionType,
             parameters,
             enabled: true,
+            requires_confirmation: requiresConfirmation,
+            aliases,
           },
         });
         commandId = newCommand.id;
@@ -166,6 +176,7 @@ export function Commands(_props: CommandsProps) {
             dictionaryEntryIds: ctx.dictionaryEntryIds,
             priority: ctx.priority,
             enabled: ctx.enabled,
+            commandPrefix: ctx.commandPrefix,
           });
         }
       }
@@ -186,6 +197,7 @@ export function Commands(_props: CommandsProps) {
             dictionaryEntryIds: ctx.dictionaryEntryIds,
             priority: ctx.priority,
             enabled: ctx.enabled,
+            commandPrefix: ctx.commandPrefix,
           });
         }
       }
@@ -232,6 +244,8 @@ export function Commands(_props: CommandsProps) {
           action_type: command.action_type,
           parameters: command.parameters,
           enabled: !command.enabled,
+          requires_confirmation: command.requires_confirmation,
+          aliases: command.aliases,
         },
       });
       // Invalidate to refetch with updated enabled state
@@ -279,7 +293,7 @@ export function Commands(_props: CommandsProps) {
 
   const existingTriggers = commands
     .filter((c) => c.id !== editingCommand?.id)
-    .map((c) => c.trigger.toLowerCase());
+    .flatMap((c) => [c.trigger, ...c.aliases].map((t) => t.toLowerCase()));
 
   return (
     <div className="p-6 space-y-6">
@@ -51,6 +51,7 @@ const sampleContexts: WindowContext[] = [
     priority: 0,
     commandMode: "merge",
     dictionaryMode: "merge",
+    commandPrefix: "inherit",
     commandIds: [],
     dictionaryEntryIds: ["1"], // Entry "brb" is assigned to Slack
   },
@@ -62,6 +63,7 @@ const sampleContexts: WindowContext[] = [
     priority: 0,
     commandMode: "merge",
     dictionaryMode: "merge",
+    commandPrefix: "inherit",
     commandIds: [],
     dictionaryEntryIds: ["1", "2"], // Both "brb" and "omw" assigned to VS Code
   },
@@ -73,6 +75,7 @@ const sampleContexts: WindowContext[] = [
     priority: 0,
     commandMode: "merge",
     dictionaryMode: "merge",
+    commandPrefix: "inherit",
     commandIds: [],
     dictionaryEntryIds: ["1"], // Entry "brb" also assigned to Terminal
   },
@@ -498,6 +501,7 @@ describe("Dictionary", () => {
           priority: 0,
           commandMode: "merge",
           dictionaryMode: "merge",
+          commandPrefix: "inherit",
           commandIds: [],
           dictionaryEntryIds: ["2"], // Only "omw"
         },
@@ -509,6 +513,7 @@ describe("Dictionary", () => {
           priority: 0,
           commandMode: "merge",
           dictionaryMode: "merge",
+          commandPrefix: "inherit",
           commandIds: [],
           dictionaryEntryIds: ["2"], // Only "omw"
         },
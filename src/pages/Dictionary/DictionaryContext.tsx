@@ -220,6 +220,7 @@ export function DictionaryProvider({ children }: DictionaryProviderProps) {
               dictionaryEntryIds: [...ctx.dictionaryEntryIds, newEntry.id],
               priority: ctx.priority,
               enabled: ctx.enabled,
+              commandPrefix: ctx.commandPrefix,
             });
           }
         }
@@ -338,6 +339,7 @@ export function DictionaryProvider({ children }: DictionaryProviderProps) {
             dictionaryEntryIds: [...ctx.dictionaryEntryIds, editingId],
             priority: ctx.priority,
             enabled: ctx.enabled,
+            commandPrefix: ctx.commandPrefix,
           });
         }
       }
@@ -357,6 +359,7 @@ export function DictionaryProvider({ children }: DictionaryProviderProps) {
             dictionaryEntryIds: ctx.dictionaryEntryIds.filter((id) => id !== editingId),
             priority: ctx.priority,
             enabled: ctx.enabled,
+            commandPrefix: ctx.commandPrefix,
           });
         }
       }
@@ -1,8 +1,18 @@
 import { useState } from "react";
 import { Pencil, Trash2, Check, X, BookText } from "lucide-react";
-import { Card, Button, Input, FormField, Toggle, Combobox, MultiSelect } from "../../components/ui";
+import {
+  Card,
+  Button,
+  Input,
+  FormField,
+  Toggle,
+  Combobox,
+  MultiSelect,
+  Select,
+  SelectItem,
+} from "../../components/ui";
 import type { ComboboxOption } from "../../components/ui";
-import type { WindowContext } from "../../types/windowContext";
+import type { WindowContext, CommandPrefixMode } from "../../types/windowContext";
 import { validateRegexPattern } from "../../lib/validation";
 import { useWindowContextsContext } from "./WindowContextsContext";
 
@@ -125,6 +135,19 @@ export function ContextItem({ context }: ContextItemProps) {
               {editValues.dictionaryMode === "replace" ? "Context Only" : "Merge"}
             </span>
           </div>
+          <div className="flex items-center gap-2">
+            <label className="text-sm text-text-secondary">Command prefix:</label>
+            <Select
+              value={editValues.commandPrefix}
+              onValueChange={(value) =>
+                handleEditChange("commandPrefix", value as CommandPrefixMode)
+              }
+            >
+              <SelectItem value="inherit">Use global setting</SelectItem>
+              <SelectItem value="required">Required</SelectItem>
+              <SelectItem value="off">Off</SelectItem>
+            </Select>
+          </div>
         </div>
         {dictionaryOptions.length > 0 && (
           <FormField
@@ -4,7 +4,12 @@ import { useWindowContext, useRunningApplications } from "../../hooks/useWindowC
 import { useDictionary } from "../../hooks/useDictionary";
 import { useSearch } from "../../hooks/useSearch";
 import { useDeleteConfirmation } from "../../hooks/useDeleteConfirmation";
-import type { WindowContext, OverrideMode, RunningApplication } from "../../types/windowContext";
+import type {
+  WindowContext,
+  OverrideMode,
+  CommandPrefixMode,
+  RunningApplication,
+} from "../../types/windowContext";
 import type { DictionaryEntry } from "../../types/dictionary";
 import type { ComboboxOption, MultiSelectOption } from "../../components/ui";
 import { validateRegexPattern } from "../../lib/validation";
@@ -22,6 +27,7 @@ export interface EditState {
   dictionaryEntryIds: string[];
   priority: number;
   enabled: boolean;
+  commandPrefix: CommandPrefixMode;
 }
 
 /**
@@ -110,6 +116,7 @@ const DEFAULT_EDIT_STATE: EditState = {
   dictionaryEntryIds: [],
   priority: 0,
   enabled: true,
+  commandPrefix: "inherit",
 };
 
 /**
@@ -237,6 +244,7 @@ export function WindowContextsProvider({ children }: WindowContextsProviderProps
       dictionaryEntryIds: ctx.dictionaryEntryIds,
       priority: ctx.priority,
       enabled: ctx.enabled,
+      commandPrefix: ctx.commandPrefix,
     });
     setEditError(null);
     setPatternError(null);
@@ -285,6 +293,7 @@ export function WindowContextsProvider({ children }: WindowContextsProviderProps
         dictionaryEntryIds: editValues.dictionaryEntryIds,
         priority: editValues.priority,
         enabled: editValues.enabled,
+        commandPrefix: editValues.commandPrefix,
       });
 
       toast({
@@ -330,6 +339,7 @@ export function WindowContextsProvider({ children }: WindowContextsProviderProps
           dictionaryEntryIds: ctx.dictionaryEntryIds,
           priority: ctx.priority,
           enabled,
+          commandPrefix: ctx.commandPrefix,
         });
       } catch (e) {
         toast({
@@ -8,6 +8,10 @@ const ACTION_TYPE_LABELS: Record<string, string> = {
   type_text: "Type Text",
   system_control: "System Control",
   custom: "Custom",
+  macro: "Macro",
+  webhook: "Webhook",
+  send_keys: "Send Keys",
+  rhai: "Rhai Script",
 };
 
 const ACTION_TYPE_COLORS: Record<string, string> = {
@@ -15,6 +19,10 @@ const ACTION_TYPE_COLORS: Record<string, string> = {
   type_text: "bg-heycat-purple/10 text-heycat-purple",
   system_control: "bg-heycat-orange/10 text-heycat-orange",
   custom: "bg-text-secondary/10 text-text-secondary",
+  macro: "bg-heycat-teal/10 text-heycat-teal",
+  webhook: "bg-heycat-purple/10 text-heycat-purple",
+  send_keys: "bg-heycat-orange/10 text-heycat-orange",
+  rhai: "bg-text-secondary/10 text-text-secondary",
 };
 
 interface ContextBadgesProps {
@@ -86,6 +94,18 @@ function getActionDescription(command: CommandDto): string {
         : "System control";
     case "custom":
       return "Custom script";
+    case "macro":
+      return "Runs a sequence of actions";
+    case "webhook":
+      return command.parameters.url
+        ? `${command.parameters.method || "POST"} ${command.parameters.url}`
+        : "Sends a web request";
+    case "send_keys":
+      return command.parameters.keys
+        ? `Presses ${command.parameters.keys}`
+        : "Presses keys";
+    case "rhai":
+      return "Runs a Rhai script";
     default:
       return "";
   }
@@ -137,6 +157,12 @@ export function CommandItem({
             {/* Context badge */}
             <ContextBadges contexts={assignedContexts} />
           </div>
+          {/* Aliases */}
+          {command.aliases.length > 0 && (
+            <p className="text-xs text-text-secondary mt-0.5 truncate">
+              Also: {command.aliases.map((a) => `"${a}"`).join(", ")}
+            </p>
+          )}
           {/* Description */}
           {description && (
             <p className="text-xs text-text-secondary mt-0.5 truncate">
@@ -1,6 +1,6 @@
 import { useState, useEffect, useMemo } from "react";
 import * as Dialog from "@radix-ui/react-dialog";
-import { X, ChevronDown, ChevronUp } from "lucide-react";
+import { X, ChevronDown, ChevronUp, Plus, Star } from "lucide-react";
 import {
   Button,
   Input,
@@ -50,6 +50,8 @@ export interface CommandModalProps {
     trigger: string,
     actionType: string,
     parameters: Record<string, string>,
+    requiresConfirmation: boolean,
+    aliases: string[],
     contextIds: string[]
   ) => Promise<void>;
 }
@@ -66,6 +68,8 @@ export function CommandModal({
   const [trigger, setTrigger] = useState("");
   const [actionType, setActionType] = useState<ActionType>("open_app");
   const [parameters, setParameters] = useState<Record<string, string>>({});
+  const [requiresConfirmation, setRequiresConfirmation] = useState(false);
+  const [aliases, setAliases] = useState<string[]>([]);
   const [selectedContextIds, setSelectedContextIds] = useState<string[]>([]);
   const [errors, setErrors] = useState<Record<string, string>>({});
   const [saving, setSaving] = useState(false);
@@ -89,21 +93,25 @@ export function CommandModal({
     if (open) {
       if (command) {
         setTrigger(command.trigger);
+        setAliases(command.aliases);
         setActionType(command.action_type as ActionType);
         setParameters(command.parameters);
+        setRequiresConfirmation(command.requires_confirmation);
         setSelectedContextIds(assignedContextIds);
         // Show advanced if any advanced params are set
         setShowAdvanced(
           Boolean(
-            command.parameters.confirmation ||
+            command.requires_confirmation ||
               command.parameters.conditions ||
               command.parameters.custom_params
           )
         );
       } else {
         setTrigger("");
+        setAliases([]);
         setActionType("open_app");
         setParameters({});
+        setRequiresConfirmation(false);
         setSelectedContextIds([]);
         setShowAdvanced(false);
       }
@@ -120,6 +128,20 @@ export function CommandModal({
       newErrors.trigger = "This trigger phrase already exists";
     }
 
+    const seen = new Set([trigger.trim().toLowerCase()]);
+    for (const alias of aliases.map((a) => a.trim().toLowerCase())) {
+      if (!alias) continue;
+      if (seen.has(alias)) {
+        newErrors.aliases = `"${alias}" is listed more than once`;
+        break;
+      }
+      if (existingTriggers.includes(alias)) {
+        newErrors.aliases = `"${alias}" is already used by another command`;
+        break;
+      }
+      seen.add(alias);
+    }
+
     switch (actionType) {
       case "open_app":
         if (!parameters.app?.trim()) {
@@ -154,7 +176,14 @@ export function CommandModal({
 
     try {
       setSaving(true);
-      await onSave(trigger.trim(), actionType, parameters, selectedContextIds);
+      await onSave(
+        trigger.trim(),
+        actionType,
+        parameters,
+        requiresConfirmation,
+        aliases.map((a) => a.trim()).filter(Boolean),
+        selectedContextIds
+      );
     } catch {
       // Error handled by parent via toast
     } finally {
@@ -174,15 +203,35 @@ export function CommandModal({
   };
 
   const clearTriggerError = () => {
-    if (errors.trigger) {
+    if (errors.trigger || errors.aliases) {
       setErrors((prev) => {
         const next = { ...prev };
         delete next.trigger;
+        delete next.aliases;
         return next;
       });
     }
   };
 
+  const updateAlias = (index: number, value: string) => {
+    setAliases((prev) => prev.map((a, i) => (i === index ? value : a)));
+    clearTriggerError();
+  };
+
+  const removeAlias = (index: number) => {
+    setAliases((prev) => prev.filter((_, i) => i !== index));
+    clearTriggerError();
+  };
+
+  // Swap an alias with the trigger so it becomes the phrase shown in the list
+  const makeCanonical = (index: number) => {
+    const alias = aliases[index];
+    if (!alias.trim()) return;
+    setAliases((prev) => prev.map((a, i) => (i === index ? trigger : a)));
+    setTrigger(alias);
+    clearTriggerError();
+  };
+
   const renderParameterFields = () => {
     switch (actionType) {
       case "open_app":
@@ -321,6 +370,49 @@ export function CommandModal({
               />
             </FormField>
 
+            {/* Aliases */}
+            <FormField error={errors.aliases} className="space-y-2">
+              <Label>Also Responds To</Label>
+              {aliases.map((alias, index) => (
+                <div key={index} className="flex items-center gap-2">
+                  <Input
+                    type="text"
+                    aria-label={`Alias ${index + 1}`}
+                    error={Boolean(errors.aliases)}
+                    value={alias}
+                    onChange={(e) => updateAlias(index, e.target.value)}
+                    placeholder='e.g., "launch browser"'
+                  />
+                  <button
+                    type="button"
+                    onClick={() => makeCanonical(index)}
+                    className="p-1 rounded text-text-secondary hover:text-heycat-orange"
+                    aria-label={`Use alias ${index + 1} as trigger phrase`}
+                    title="Use as trigger phrase"
+                  >
+                    <Star className="h-4 w-4" />
+                  </button>
+                  <button
+                    type="button"
+                    onClick={() => removeAlias(index)}
+                    className="p-1 rounded text-text-secondary hover:text-error"
+                    aria-label={`Remove alias ${index + 1}`}
+                  >
+                    <X className="h-4 w-4" />
+                  </button>
+                </div>
+              ))}
+              <Button
+                type="button"
+                variant="ghost"
+                size="sm"
+                onClick={() => setAliases((prev) => [...prev, ""])}
+              >
+                <Plus className="h-4 w-4" />
+                Add alias
+              </Button>
+            </FormField>
+
             {/* Action Type */}
             <FormField>
               <Label htmlFor="action-type">Action Type</Label>
@@ -412,13 +504,8 @@ export function CommandModal({
                     <input
                       id="confirmation"
                       type="checkbox"
-                      checked={parameters.confirmation === "true"}
-                      onChange={(e) =>
-                        updateParameter(
-                          "confirmation",
-                          e.target.checked ? "true" : ""
-                        )
-                      }
+                      checked={requiresConfirmation}
+                      onChange={(e) => setRequiresConfirmation(e.target.checked)}
                       className="h-4 w-4 rounded border-border text-heycat-orange focus:ring-heycat-teal"
                     />
                   </div>
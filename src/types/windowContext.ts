@@ -15,6 +15,9 @@ export interface WindowMatcher {
 
 export type OverrideMode = "merge" | "replace";
 
+/** Whether voice commands in a context need the command prefix */
+export type CommandPrefixMode = "inherit" | "required" | "off";
+
 export interface WindowContext {
   id: string;
   name: string;
@@ -25,6 +28,7 @@ export interface WindowContext {
   dictionaryEntryIds: string[];
   enabled: boolean;
   priority: number;
+  commandPrefix: CommandPrefixMode;
 }
 
 export interface ActiveWindowChangedPayload {